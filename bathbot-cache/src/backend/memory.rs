@@ -0,0 +1,466 @@
+use std::{
+    collections::{HashMap, HashSet},
+    future::ready,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use rkyv::AlignedVec;
+
+use super::{BackendFuture, CacheBackend};
+use crate::key::RedisKey;
+
+/// Amount of insertions after which expired entries are purged
+const PURGE_INTERVAL: usize = 1024;
+
+/// Keeps all entries in memory.
+///
+/// Nothing is shared with other processes and everything is lost on shutdown
+/// so this is intended for local development and tests.
+#[derive(Default)]
+pub(crate) struct MemoryBackend {
+    inner: Mutex<MemoryData>,
+}
+
+#[derive(Default)]
+struct MemoryData {
+    values: HashMap<Box<[u8]>, MemoryValue>,
+    sets: HashMap<Box<[u8]>, HashSet<u64>>,
+    tickets: HashMap<Box<[u8]>, Ticket>,
+    /// Insertions since the last purge
+    inserts: usize,
+}
+
+struct MemoryValue {
+    bytes: AlignedVec,
+    expires_at: Option<Instant>,
+}
+
+impl MemoryValue {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// State of a ratelimit bucket, times are in unix seconds
+#[derive(Copy, Clone)]
+struct Ticket {
+    last: i64,
+    set: i64,
+    tickets: u32,
+    expires_at: i64,
+}
+
+impl MemoryBackend {
+    fn lock(&self) -> MutexGuard<'_, MemoryData> {
+        self.inner.lock().unwrap()
+    }
+}
+
+impl MemoryData {
+    fn value(&mut self, key: &[u8]) -> Option<&MemoryValue> {
+        let now = Instant::now();
+
+        if self.values.get(key)?.is_expired(now) {
+            self.values.remove(key);
+
+            return None;
+        }
+
+        self.values.get(key)
+    }
+
+    fn insert(&mut self, key: RedisKey<'_>, bytes: AlignedVec, expire: Option<Duration>) {
+        let now = Instant::now();
+
+        self.inserts += 1;
+
+        if self.inserts >= PURGE_INTERVAL {
+            self.inserts = 0;
+            self.values.retain(|_, value| !value.is_expired(now));
+
+            let now = unix_now();
+            self.tickets.retain(|_, ticket| ticket.expires_at > now);
+        }
+
+        let value = MemoryValue {
+            bytes,
+            expires_at: expire.map(|expire| now + expire),
+        };
+
+        self.values.insert(Box::from(key.to_bytes()), value);
+    }
+}
+
+impl CacheBackend for MemoryBackend {
+    fn ping(&self) -> BackendFuture<'_, ()> {
+        Box::pin(ready(Ok(())))
+    }
+
+    fn get<'a>(&'a self, key: RedisKey<'a>) -> BackendFuture<'a, Option<AlignedVec>> {
+        let value = self
+            .lock()
+            .value(&key.to_bytes())
+            .map(|value| value.bytes.clone());
+
+        Box::pin(ready(Ok(value)))
+    }
+
+    fn get_with_ttl<'a>(
+        &'a self,
+        key: RedisKey<'a>,
+    ) -> BackendFuture<'a, Option<(AlignedVec, Option<Duration>)>> {
+        let now = Instant::now();
+
+        let value = self.lock().value(&key.to_bytes()).map(|value| {
+            let ttl = value
+                .expires_at
+                .map(|expires_at| expires_at.saturating_duration_since(now));
+
+            (value.bytes.clone(), ttl)
+        });
+
+        Box::pin(ready(Ok(value)))
+    }
+
+    fn get_many<'a>(&'a self, keys: Vec<RedisKey<'a>>) -> BackendFuture<'a, Vec<AlignedVec>> {
+        let mut data = self.lock();
+
+        let values = keys
+            .iter()
+            .filter_map(|key| data.value(&key.to_bytes()).map(|value| value.bytes.clone()))
+            .collect();
+
+        Box::pin(ready(Ok(values)))
+    }
+
+    fn set<'a>(
+        &'a self,
+        key: RedisKey<'a>,
+        value: &'a [u8],
+        expire: Option<Duration>,
+    ) -> BackendFuture<'a, ()> {
+        let mut bytes = AlignedVec::with_capacity(value.len());
+        bytes.extend_from_slice(value);
+
+        self.lock().insert(key, bytes, expire);
+
+        Box::pin(ready(Ok(())))
+    }
+
+    fn set_many<'a>(&'a self, entries: Vec<(RedisKey<'a>, AlignedVec)>) -> BackendFuture<'a, ()> {
+        let mut data = self.lock();
+
+        for (key, bytes) in entries {
+            data.insert(key, bytes, None);
+        }
+
+        Box::pin(ready(Ok(())))
+    }
+
+    fn delete<'a>(&'a self, keys: Vec<RedisKey<'a>>) -> BackendFuture<'a, ()> {
+        let mut data = self.lock();
+
+        for key in keys {
+            let key = key.to_bytes();
+            data.values.remove(key.as_ref());
+            data.sets.remove(key.as_ref());
+            data.tickets.remove(key.as_ref());
+        }
+
+        Box::pin(ready(Ok(())))
+    }
+
+    fn set_add<'a>(&'a self, key: RedisKey<'a>, ids: &'a [u64]) -> BackendFuture<'a, usize> {
+        let mut data = self.lock();
+        let set = data.sets.entry(Box::from(key.to_bytes())).or_default();
+        let added = ids.iter().filter(|&&id| set.insert(id)).count();
+
+        Box::pin(ready(Ok(added)))
+    }
+
+    fn set_remove<'a>(&'a self, key: RedisKey<'a>, ids: &'a [u64]) -> BackendFuture<'a, usize> {
+        let mut data = self.lock();
+
+        let removed = match data.sets.get_mut(key.to_bytes().as_ref()) {
+            Some(set) => ids.iter().filter(|&&id| set.remove(&id)).count(),
+            None => 0,
+        };
+
+        Box::pin(ready(Ok(removed)))
+    }
+
+    fn set_move<'a>(
+        &'a self,
+        src: RedisKey<'a>,
+        dst: RedisKey<'a>,
+        id: u64,
+    ) -> BackendFuture<'a, bool> {
+        let mut data = self.lock();
+
+        let moved = data
+            .sets
+            .get_mut(src.to_bytes().as_ref())
+            .is_some_and(|set| set.remove(&id));
+
+        if moved {
+            data.sets
+                .entry(Box::from(dst.to_bytes()))
+                .or_default()
+                .insert(id);
+        }
+
+        Box::pin(ready(Ok(moved)))
+    }
+
+    fn set_members<'a>(&'a self, key: RedisKey<'a>) -> BackendFuture<'a, Vec<u64>> {
+        let members = self
+            .lock()
+            .sets
+            .get(key.to_bytes().as_ref())
+            .map_or_else(Vec::new, |set| set.iter().copied().collect());
+
+        Box::pin(ready(Ok(members)))
+    }
+
+    fn set_len<'a>(&'a self, key: RedisKey<'a>) -> BackendFuture<'a, usize> {
+        let len = self
+            .lock()
+            .sets
+            .get(key.to_bytes().as_ref())
+            .map_or(0, HashSet::len);
+
+        Box::pin(ready(Ok(len)))
+    }
+
+    fn take_ticket<'a>(
+        &'a self,
+        key: &'a str,
+        delay: u32,
+        time_span: u32,
+        limit: u32,
+    ) -> BackendFuture<'a, i64> {
+        let wait = self.take_ticket_at(key, delay, time_span, limit, unix_now());
+
+        Box::pin(ready(Ok(wait)))
+    }
+
+    fn flush(&self) -> BackendFuture<'_, ()> {
+        *self.lock() = MemoryData::default();
+
+        Box::pin(ready(Ok(())))
+    }
+}
+
+impl MemoryBackend {
+    fn take_ticket_at(&self, key: &str, delay: u32, time_span: u32, limit: u32, now: i64) -> i64 {
+        let (delay, time_span) = (i64::from(delay), i64::from(time_span));
+        let mut data = self.lock();
+
+        let mut ticket = data
+            .tickets
+            .get(key.as_bytes())
+            .filter(|ticket| ticket.expires_at > now)
+            .copied()
+            .unwrap_or(Ticket {
+                last: 0,
+                set: now,
+                tickets: 0,
+                expires_at: 0,
+            });
+
+        // Same logic as the redis script
+        if ticket.tickets + 1 > limit {
+            if now < ticket.set + time_span {
+                return ticket.set + time_span - now;
+            }
+
+            ticket.tickets = 0;
+            ticket.set = now;
+        }
+
+        if now < ticket.last + delay {
+            return ticket.last + delay - now;
+        }
+
+        ticket.last = now;
+        ticket.tickets += 1;
+        ticket.expires_at = now + delay.max(time_span).max(1);
+
+        data.tickets.insert(Box::from(key.as_bytes()), ticket);
+
+        0
+    }
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::util::block_on;
+
+    fn key(key: &str) -> RedisKey<'_> {
+        RedisKey::from(key)
+    }
+
+    #[test]
+    fn get_set_delete() {
+        let backend = MemoryBackend::default();
+
+        block_on(backend.set(key("a"), b"value", None)).unwrap();
+
+        let value = block_on(backend.get(key("a"))).unwrap();
+        assert_eq!(value.as_deref(), Some(&b"value"[..]));
+
+        let (_, ttl) = block_on(backend.get_with_ttl(key("a"))).unwrap().unwrap();
+        assert_eq!(ttl, None);
+
+        block_on(backend.delete(vec![key("a")])).unwrap();
+        assert!(block_on(backend.get(key("a"))).unwrap().is_none());
+    }
+
+    #[test]
+    fn get_many_skips_missing() {
+        let backend = MemoryBackend::default();
+        let entries = vec![(key("a"), AlignedVec::new()), (key("c"), AlignedVec::new())];
+        block_on(backend.set_many(entries)).unwrap();
+
+        let values = block_on(backend.get_many(vec![key("a"), key("b"), key("c")])).unwrap();
+        assert_eq!(values.len(), 2);
+    }
+
+    #[test]
+    fn values_expire() {
+        let backend = MemoryBackend::default();
+        let expire = Duration::from_millis(50);
+        block_on(backend.set(key("a"), b"value", Some(expire))).unwrap();
+
+        let (_, ttl) = block_on(backend.get_with_ttl(key("a"))).unwrap().unwrap();
+        assert!(ttl.is_some_and(|ttl| ttl <= expire));
+
+        thread::sleep(expire);
+
+        assert!(block_on(backend.get(key("a"))).unwrap().is_none());
+        assert!(block_on(backend.get_with_ttl(key("a"))).unwrap().is_none());
+    }
+
+    #[test]
+    fn set_ops() {
+        let backend = MemoryBackend::default();
+
+        assert_eq!(block_on(backend.set_add(key("a"), &[1, 2, 3])).unwrap(), 3);
+        assert_eq!(block_on(backend.set_add(key("a"), &[3, 4])).unwrap(), 1);
+        assert_eq!(block_on(backend.set_len(key("a"))).unwrap(), 4);
+
+        assert_eq!(block_on(backend.set_remove(key("a"), &[4, 5])).unwrap(), 1);
+        assert_eq!(block_on(backend.set_remove(key("b"), &[1])).unwrap(), 0);
+
+        assert!(block_on(backend.set_move(key("a"), key("b"), 1)).unwrap());
+        assert!(!block_on(backend.set_move(key("a"), key("b"), 1)).unwrap());
+
+        let mut members = block_on(backend.set_members(key("a"))).unwrap();
+        members.sort_unstable();
+        assert_eq!(members, [2, 3]);
+        assert_eq!(block_on(backend.set_members(key("b"))).unwrap(), [1]);
+
+        block_on(backend.delete(vec![key("a")])).unwrap();
+        assert_eq!(block_on(backend.set_len(key("a"))).unwrap(), 0);
+        assert!(block_on(backend.set_members(key("c"))).unwrap().is_empty());
+    }
+
+    #[test]
+    fn flush_removes_everything() {
+        let backend = MemoryBackend::default();
+        block_on(backend.set(key("a"), b"value", None)).unwrap();
+        block_on(backend.set_add(key("b"), &[1])).unwrap();
+        backend.take_ticket_at("c", 0, 10, 1, 100);
+
+        block_on(backend.flush()).unwrap();
+
+        assert!(block_on(backend.get(key("a"))).unwrap().is_none());
+        assert_eq!(block_on(backend.set_len(key("b"))).unwrap(), 0);
+        assert_eq!(backend.take_ticket_at("c", 0, 10, 1, 100), 0);
+    }
+
+    // Expected waiting times follow the `TAKE_TICKET` script of the redis
+    // backend.
+
+    #[test]
+    fn tickets_limit_per_time_span() {
+        let backend = MemoryBackend::default();
+        let take = |now| backend.take_ticket_at("key", 0, 10, 2, now);
+
+        assert_eq!(take(100), 0);
+        assert_eq!(take(101), 0);
+        assert_eq!(take(102), 8);
+        assert_eq!(take(109), 1);
+        // The time span started with the first ticket
+        assert_eq!(take(110), 0);
+        assert_eq!(take(111), 0);
+        assert_eq!(take(112), 8);
+    }
+
+    #[test]
+    fn tickets_delay() {
+        let backend = MemoryBackend::default();
+        let take = |now| backend.take_ticket_at("key", 3, 0, 5, now);
+
+        assert_eq!(take(100), 0);
+        assert_eq!(take(101), 2);
+        assert_eq!(take(102), 1);
+        assert_eq!(take(103), 0);
+    }
+
+    #[test]
+    fn tickets_delay_after_time_span() {
+        let backend = MemoryBackend::default();
+        let take = |now| backend.take_ticket_at("key", 5, 2, 1, now);
+
+        assert_eq!(take(100), 0);
+        assert_eq!(take(101), 1);
+        // The time span passed but the delay did not
+        assert_eq!(take(102), 3);
+        assert_eq!(take(105), 0);
+    }
+
+    #[test]
+    fn tickets_expire() {
+        let backend = MemoryBackend::default();
+        let take = |now| backend.take_ticket_at("key", 2, 10, 1, now);
+
+        // The bucket expires after max(delay, time_span, 1) seconds like the
+        // redis key
+        assert_eq!(take(100), 0);
+        assert_eq!(backend.tickets_expire_at("key"), Some(110));
+        assert_eq!(take(109), 1);
+        assert_eq!(take(110), 0);
+
+        assert_eq!(backend.take_ticket_at("other", 0, 0, 1, 100), 0);
+        assert_eq!(backend.tickets_expire_at("other"), Some(101));
+    }
+
+    #[test]
+    fn tickets_are_per_key() {
+        let backend = MemoryBackend::default();
+
+        assert_eq!(backend.take_ticket_at("a", 0, 10, 1, 100), 0);
+        assert_eq!(backend.take_ticket_at("b", 0, 10, 1, 100), 0);
+        assert_eq!(backend.take_ticket_at("a", 0, 10, 1, 100), 10);
+    }
+
+    impl MemoryBackend {
+        fn tickets_expire_at(&self, key: &str) -> Option<i64> {
+            self.lock()
+                .tickets
+                .get(key.as_bytes())
+                .map(|ticket| ticket.expires_at)
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::{future::Future, pin::Pin, time::Duration};
+
+use eyre::Result;
+use rkyv::AlignedVec;
+
+pub(crate) use self::{memory::MemoryBackend, redis::RedisBackend};
+use crate::key::RedisKey;
+
+mod memory;
+mod redis;
+
+pub(crate) type BackendFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// Storage operations that the cache is built upon.
+///
+/// Values are plain bytes and sets only contain ids.
+pub(crate) trait CacheBackend: Send + Sync {
+    /// Checks whether the backend is reachable.
+    fn ping(&self) -> BackendFuture<'_, ()>;
+
+    fn get<'a>(&'a self, key: RedisKey<'a>) -> BackendFuture<'a, Option<AlignedVec>>;
+
+    /// Get a value alongside its remaining time to live.
+    ///
+    /// The time to live is `None` if the value does not expire.
+    fn get_with_ttl<'a>(
+        &'a self,
+        key: RedisKey<'a>,
+    ) -> BackendFuture<'a, Option<(AlignedVec, Option<Duration>)>>;
+
+    /// Get the values of all keys, skipping missing ones.
+    fn get_many<'a>(&'a self, keys: Vec<RedisKey<'a>>) -> BackendFuture<'a, Vec<AlignedVec>>;
+
+    /// Store a value, optionally expiring after the given duration.
+    fn set<'a>(
+        &'a self,
+        key: RedisKey<'a>,
+        value: &'a [u8],
+        expire: Option<Duration>,
+    ) -> BackendFuture<'a, ()>;
+
+    /// Store multiple values without expiration.
+    fn set_many<'a>(&'a self, entries: Vec<(RedisKey<'a>, AlignedVec)>) -> BackendFuture<'a, ()>;
+
+    /// Remove the values or sets of all keys.
+    fn delete<'a>(&'a self, keys: Vec<RedisKey<'a>>) -> BackendFuture<'a, ()>;
+
+    /// Add ids to a set and return how many were newly added.
+    fn set_add<'a>(&'a self, key: RedisKey<'a>, ids: &'a [u64]) -> BackendFuture<'a, usize>;
+
+    /// Remove ids from a set and return how many were contained.
+    fn set_remove<'a>(&'a self, key: RedisKey<'a>, ids: &'a [u64]) -> BackendFuture<'a, usize>;
+
+    /// Move an id from one set to another and return whether the source set
+    /// contained it.
+    fn set_move<'a>(
+        &'a self,
+        src: RedisKey<'a>,
+        dst: RedisKey<'a>,
+        id: u64,
+    ) -> BackendFuture<'a, bool>;
+
+    fn set_members<'a>(&'a self, key: RedisKey<'a>) -> BackendFuture<'a, Vec<u64>>;
+
+    fn set_len<'a>(&'a self, key: RedisKey<'a>) -> BackendFuture<'a, usize>;
+
+    /// Atomically take a ticket of the ratelimit bucket stored under the key.
+    ///
+    /// Returns the amount of seconds until the next ticket is available or
+    /// `0` if a ticket was taken.
+    fn take_ticket<'a>(
+        &'a self,
+        key: &'a str,
+        delay: u32,
+        time_span: u32,
+        limit: u32,
+    ) -> BackendFuture<'a, i64>;
+
+    /// Remove all entries.
+    fn flush(&self) -> BackendFuture<'_, ()>;
+}
@@ -0,0 +1,353 @@
+use std::{fmt::Display, sync::RwLock, time::Duration};
+
+use bb8_redis::{
+    bb8::{Pool, PooledConnection},
+    redis::{self, AsyncCommands, Cmd, ErrorKind, RedisResult},
+    RedisConnectionManager,
+};
+use eyre::{Report, Result, WrapErr};
+use rkyv::AlignedVec;
+
+use super::{BackendFuture, CacheBackend};
+use crate::{
+    key::RedisKey,
+    util::{AlignedVecRedisArgs, AlignedVecRedisValue},
+};
+
+/// Atomically takes a ticket of a bucket.
+///
+/// The bucket is a hash with the fields `last` (time of the last use), `set`
+/// (start of the current time span), and `tickets` (uses within the time span).
+/// The time is taken from redis itself so that all processes agree on it.
+///
+/// Returns the amount of seconds until the next ticket is available or `0` if
+/// a ticket was taken.
+const TAKE_TICKET: &str = r#"
+local delay = tonumber(ARGV[1])
+local time_span = tonumber(ARGV[2])
+local limit = tonumber(ARGV[3])
+local now = tonumber(redis.call('TIME')[1])
+
+local state = redis.call('HMGET', KEYS[1], 'last', 'set', 'tickets')
+local last = tonumber(state[1]) or 0
+local set = tonumber(state[2]) or now
+local tickets = tonumber(state[3]) or 0
+
+if tickets + 1 > limit then
+    if now < set + time_span then
+        return set + time_span - now
+    end
+
+    tickets = 0
+    set = now
+end
+
+if now < last + delay then
+    return last + delay - now
+end
+
+redis.call('HSET', KEYS[1], 'last', now, 'set', set, 'tickets', tickets + 1)
+redis.call('EXPIRE', KEYS[1], math.max(delay, time_span, 1))
+
+return 0
+"#;
+
+pub(crate) struct RedisBackend {
+    pool: Pool<RedisConnectionManager>,
+    /// SHA1 digest of the loaded [`TAKE_TICKET`] script
+    take_ticket_sha: RwLock<Option<String>>,
+}
+
+impl RedisBackend {
+    pub(crate) async fn new(host: impl Display, port: u16, db_idx: u8) -> Result<Self> {
+        let redis_uri = format!("redis://{host}:{port}/{db_idx}");
+
+        let redis_manager =
+            RedisConnectionManager::new(redis_uri).wrap_err("Failed to create redis manager")?;
+
+        let pool = Pool::builder()
+            .max_size(16)
+            .build(redis_manager)
+            .await
+            .wrap_err("Failed to create redis pool")?;
+
+        Ok(Self {
+            pool,
+            take_ticket_sha: RwLock::new(None),
+        })
+    }
+
+    async fn connection(&self) -> Result<PooledConnection<'_, RedisConnectionManager>> {
+        self.pool
+            .get()
+            .await
+            .wrap_err("Failed to get redis connection")
+    }
+
+    /// Load the [`TAKE_TICKET`] script into redis' script cache and return
+    /// its digest.
+    async fn load_take_ticket(
+        &self,
+        conn: &mut PooledConnection<'_, RedisConnectionManager>,
+    ) -> Result<String> {
+        let sha: String = redis::cmd("SCRIPT")
+            .arg("LOAD")
+            .arg(TAKE_TICKET)
+            .query_async(&mut **conn)
+            .await
+            .wrap_err("Failed to load ticket script")?;
+
+        *self.take_ticket_sha.write().unwrap() = Some(sha.clone());
+
+        Ok(sha)
+    }
+
+    async fn eval_take_ticket(
+        conn: &mut PooledConnection<'_, RedisConnectionManager>,
+        sha: &str,
+        key: &str,
+        delay: u32,
+        time_span: u32,
+        limit: u32,
+    ) -> RedisResult<i64> {
+        redis::cmd("EVALSHA")
+            .arg(sha)
+            .arg(1)
+            .arg(key)
+            .arg(delay)
+            .arg(time_span)
+            .arg(limit)
+            .query_async(&mut **conn)
+            .await
+    }
+}
+
+impl CacheBackend for RedisBackend {
+    fn ping(&self) -> BackendFuture<'_, ()> {
+        Box::pin(async move {
+            let mut conn = self.connection().await?;
+
+            redis::cmd("PING")
+                .query_async::<_, ()>(&mut *conn)
+                .await
+                .wrap_err("Failed to ping redis")
+        })
+    }
+
+    fn get<'a>(&'a self, key: RedisKey<'a>) -> BackendFuture<'a, Option<AlignedVec>> {
+        Box::pin(async move {
+            let value: Option<AlignedVecRedisValue> = self
+                .connection()
+                .await?
+                .get(key)
+                .await
+                .wrap_err("Failed to get value")?;
+
+            Ok(value.map(|AlignedVecRedisValue(bytes)| bytes))
+        })
+    }
+
+    fn get_with_ttl<'a>(
+        &'a self,
+        key: RedisKey<'a>,
+    ) -> BackendFuture<'a, Option<(AlignedVec, Option<Duration>)>> {
+        Box::pin(async move {
+            let mut conn = self.connection().await?;
+
+            let (value, pttl): (Option<AlignedVecRedisValue>, i64) = redis::pipe()
+                .get(&key)
+                .pttl(&key)
+                .query_async(&mut *conn)
+                .await
+                .wrap_err("Failed to get value with ttl")?;
+
+            // A pttl of -1 means the value does not expire
+            let ttl = (pttl != -1).then(|| Duration::from_millis(pttl.max(0) as u64));
+
+            Ok(value.map(|AlignedVecRedisValue(bytes)| (bytes, ttl)))
+        })
+    }
+
+    fn get_many<'a>(&'a self, keys: Vec<RedisKey<'a>>) -> BackendFuture<'a, Vec<AlignedVec>> {
+        Box::pin(async move {
+            if keys.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let values: Vec<Option<AlignedVecRedisValue>> = self
+                .connection()
+                .await?
+                .mget(keys)
+                .await
+                .wrap_err("Failed to get values")?;
+
+            let values = values
+                .into_iter()
+                .flatten()
+                .map(|AlignedVecRedisValue(bytes)| bytes)
+                .collect();
+
+            Ok(values)
+        })
+    }
+
+    fn set<'a>(
+        &'a self,
+        key: RedisKey<'a>,
+        value: &'a [u8],
+        expire: Option<Duration>,
+    ) -> BackendFuture<'a, ()> {
+        Box::pin(async move {
+            let mut conn = self.connection().await?;
+
+            match expire {
+                Some(expire) => conn.set_ex(key, value, expire.as_secs()).await,
+                None => conn.set(key, value).await,
+            }
+            .wrap_err("Failed to set value")
+        })
+    }
+
+    fn set_many<'a>(&'a self, entries: Vec<(RedisKey<'a>, AlignedVec)>) -> BackendFuture<'a, ()> {
+        Box::pin(async move {
+            if entries.is_empty() {
+                return Ok(());
+            }
+
+            let entries: Vec<_> = entries
+                .into_iter()
+                .map(|(key, bytes)| (key, AlignedVecRedisArgs(bytes)))
+                .collect();
+
+            self.connection()
+                .await?
+                .mset(&entries)
+                .await
+                .wrap_err("Failed to set values")
+        })
+    }
+
+    fn delete<'a>(&'a self, keys: Vec<RedisKey<'a>>) -> BackendFuture<'a, ()> {
+        Box::pin(async move {
+            if keys.is_empty() {
+                return Ok(());
+            }
+
+            self.connection()
+                .await?
+                .del(keys)
+                .await
+                .wrap_err("Failed to delete keys")
+        })
+    }
+
+    fn set_add<'a>(&'a self, key: RedisKey<'a>, ids: &'a [u64]) -> BackendFuture<'a, usize> {
+        Box::pin(async move {
+            if ids.is_empty() {
+                return Ok(0);
+            }
+
+            self.connection()
+                .await?
+                .sadd(key, ids)
+                .await
+                .wrap_err("Failed to add to set")
+        })
+    }
+
+    fn set_remove<'a>(&'a self, key: RedisKey<'a>, ids: &'a [u64]) -> BackendFuture<'a, usize> {
+        Box::pin(async move {
+            if ids.is_empty() {
+                return Ok(0);
+            }
+
+            self.connection()
+                .await?
+                .srem(key, ids)
+                .await
+                .wrap_err("Failed to remove from set")
+        })
+    }
+
+    fn set_move<'a>(
+        &'a self,
+        src: RedisKey<'a>,
+        dst: RedisKey<'a>,
+        id: u64,
+    ) -> BackendFuture<'a, bool> {
+        Box::pin(async move {
+            self.connection()
+                .await?
+                .smove(src, dst, id)
+                .await
+                .wrap_err("Failed to move between sets")
+        })
+    }
+
+    fn set_members<'a>(&'a self, key: RedisKey<'a>) -> BackendFuture<'a, Vec<u64>> {
+        Box::pin(async move {
+            self.connection()
+                .await?
+                .smembers(key)
+                .await
+                .wrap_err("Failed to get set members")
+        })
+    }
+
+    fn set_len<'a>(&'a self, key: RedisKey<'a>) -> BackendFuture<'a, usize> {
+        Box::pin(async move {
+            self.connection()
+                .await?
+                .scard(key)
+                .await
+                .wrap_err("Failed to get set cardinality")
+        })
+    }
+
+    fn take_ticket<'a>(
+        &'a self,
+        key: &'a str,
+        delay: u32,
+        time_span: u32,
+        limit: u32,
+    ) -> BackendFuture<'a, i64> {
+        Box::pin(async move {
+            let mut conn = self.connection().await?;
+
+            let sha_opt = self.take_ticket_sha.read().unwrap().clone();
+
+            let sha = match sha_opt {
+                Some(sha) => sha,
+                None => self.load_take_ticket(&mut conn).await?,
+            };
+
+            let res = Self::eval_take_ticket(&mut conn, &sha, key, delay, time_span, limit).await;
+
+            match res {
+                Ok(cooldown) => Ok(cooldown),
+                // The script cache was flushed, e.g. due to a redis restart
+                Err(err) if err.kind() == ErrorKind::NoScriptError => {
+                    let sha = self.load_take_ticket(&mut conn).await?;
+
+                    Self::eval_take_ticket(&mut conn, &sha, key, delay, time_span, limit)
+                        .await
+                        .wrap_err("Failed to evaluate ticket script")
+                }
+                Err(err) => Err(Report::new(err).wrap_err("Failed to evaluate ticket script")),
+            }
+        })
+    }
+
+    fn flush(&self) -> BackendFuture<'_, ()> {
+        Box::pin(async move {
+            let mut conn = self.connection().await?;
+
+            let mut cmd = Cmd::new();
+            cmd.arg("FLUSHDB");
+
+            cmd.query_async::<_, ()>(&mut *conn)
+                .await
+                .wrap_err("Failed to flush redis entries")
+        })
+    }
+}
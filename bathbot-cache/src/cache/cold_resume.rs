@@ -1,7 +1,6 @@
-use std::{collections::HashMap, hash::BuildHasher};
+use std::{collections::HashMap, hash::BuildHasher, time::Duration};
 
 use bathbot_model::twilight_gateway::SessionsRkyv;
-use bb8_redis::redis::{aio::ConnectionLike, AsyncCommands, Cmd};
 use eyre::{Result, WrapErr};
 use rkyv::with::With;
 use tracing::info;
@@ -17,9 +16,10 @@ impl Cache {
         let bytes =
             rkyv::to_bytes::<_, 128>(resume_data).wrap_err("Failed to serialize resume data")?;
 
-        self.connection()
-            .await?
-            .set_ex(RedisKey::resume_data(), bytes.as_slice(), STORE_DURATION)
+        let expire = Duration::from_secs(STORE_DURATION);
+
+        self.backend
+            .set(RedisKey::resume_data(), bytes.as_slice(), Some(expire))
             .await
             .wrap_err("Failed to store resume data bytes")?;
 
@@ -29,12 +29,12 @@ impl Cache {
     }
 
     pub async fn defrost<S: BuildHasher + Default>(&self) -> Result<HashMap<u64, Session, S>> {
-        let mut conn = self.connection().await?;
-
-        let resume_data_opt: Option<CachedArchive<HashMap<u64, Session, S>>> = conn
+        let resume_data_opt = self
+            .backend
             .get(RedisKey::resume_data())
             .await
-            .wrap_err("Failed to get stored resume data")?;
+            .wrap_err("Failed to get stored resume data")?
+            .map(CachedArchive::<HashMap<u64, Session, S>>::new);
 
         if let Some(resume_data) = resume_data_opt {
             info!("Successfully defrosted cache");
@@ -42,12 +42,12 @@ impl Cache {
             return Ok(resume_data.deserialize_with::<SessionsRkyv>());
         }
 
-        let mut cmd = Cmd::new();
-        cmd.arg("FLUSHDB");
-
-        conn.req_packed_command(&cmd)
+        self.backend
+            .flush()
             .await
-            .wrap_err("Failed to flush redis entries")?;
+            .wrap_err("Failed to flush cache entries")?;
+
+        self.local.clear();
 
         info!("Empty resume data, starting with fresh cache");
 
@@ -1,65 +1,83 @@
 use std::iter;
 
-use bb8_redis::{bb8::PooledConnection, redis::AsyncCommands, RedisConnectionManager};
 use eyre::{Result, WrapErr};
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
     Id,
 };
 
-use crate::{key::RedisKey, model::CacheChange, Cache};
+use crate::{
+    backend::CacheBackend,
+    key::{RedisKey, ToCacheKey},
+    model::CacheChange,
+    Cache,
+};
 
 impl Cache {
+    /// Removes the entry of the given key.
+    pub async fn delete<K>(&self, key: &K) -> Result<()>
+    where
+        K: ToCacheKey + ?Sized,
+    {
+        self.local.remove(key.to_key());
+
+        self.backend
+            .delete(vec![RedisKey::from(key)])
+            .await
+            .wrap_err("Failed to delete entry")
+    }
+
     pub(crate) async fn delete_channel(
         &self,
         guild: Option<Id<GuildMarker>>,
         channel: Id<ChannelMarker>,
     ) -> Result<CacheChange> {
-        let mut conn = self.connection().await?;
-
-        conn.del(RedisKey::channel(guild, channel))
+        self.backend
+            .delete(vec![RedisKey::channel(guild, channel)])
             .await
             .wrap_err("Failed to delete channel entry")?;
 
         if let Some(guild) = guild {
-            conn.srem(RedisKey::guild_channels(guild), channel.get())
+            self.backend
+                .set_remove(RedisKey::guild_channels(guild), &[channel.get()])
                 .await
                 .wrap_err("Failed to remove channel as guild channel")?;
         }
 
-        let removed: isize = conn
-            .srem(RedisKey::channels(), channel.get())
+        let removed = self
+            .backend
+            .set_remove(RedisKey::channels(), &[channel.get()])
             .await
             .wrap_err("Failed to remove channel from channel ids")?;
 
         Ok(CacheChange {
-            channels: -removed,
+            channels: -(removed as isize),
             ..Default::default()
         })
     }
 
     pub(crate) async fn delete_guild(&self, guild: Id<GuildMarker>) -> Result<CacheChange> {
-        let mut conn = self.connection().await?;
-
-        conn.del(RedisKey::guild(guild))
+        self.backend
+            .delete(vec![RedisKey::guild(guild)])
             .await
             .wrap_err("Failed to delete guild entry")?;
 
-        let removed: isize = conn
-            .srem(RedisKey::guilds(), guild.get())
+        let removed = self
+            .backend
+            .set_remove(RedisKey::guilds(), &[guild.get()])
             .await
             .wrap_err("Failed to remove guild id entry")?;
 
         let mut change = self.delete_guild_items(guild).await?;
 
-        change.guilds -= removed;
+        change.guilds -= removed as isize;
 
         Ok(change)
     }
 
     pub(crate) async fn delete_guild_items(&self, guild: Id<GuildMarker>) -> Result<CacheChange> {
         async fn remove_ids<G, I, K, C>(
-            conn: &mut PooledConnection<'_, RedisConnectionManager>,
+            backend: &dyn CacheBackend,
             guild: Id<GuildMarker>,
             guild_set_key_fn: G,
             id_set_key_fn: Option<I>,
@@ -74,35 +92,35 @@ impl Cache {
         {
             let guild_key = (guild_set_key_fn)(guild);
 
-            let guild_ids: Vec<u64> = conn
-                .smembers(&guild_key)
+            let guild_ids = backend
+                .set_members(guild_key.clone())
                 .await
                 .wrap_err("Failed smembers")?;
 
             if let Some(id_set_key_fn) = id_set_key_fn.filter(|_| !guild_ids.is_empty()) {
-                let removed: isize = conn
-                    .srem((id_set_key_fn)(), &guild_ids)
+                let removed = backend
+                    .set_remove((id_set_key_fn)(), &guild_ids)
                     .await
                     .wrap_err("Failed srem")?;
-                change_fn(removed);
+                change_fn(removed as isize);
             }
 
-            let redis_keys: Vec<_> = guild_ids
+            let keys: Vec<_> = guild_ids
                 .into_iter()
                 .map(|id| (item_key_fn)(guild, id))
                 .chain(iter::once(guild_key))
                 .collect();
 
-            conn.del(&redis_keys).await.wrap_err("Failed del")?;
+            backend.delete(keys).await.wrap_err("Failed del")?;
 
             Ok(())
         }
 
         let mut change = CacheChange::default();
-        let mut conn = self.connection().await?;
+        let backend = self.backend.as_ref();
 
         remove_ids(
-            &mut conn,
+            backend,
             guild,
             RedisKey::guild_channels,
             Some(RedisKey::channels),
@@ -113,7 +131,7 @@ impl Cache {
         .wrap_err("Failed to remove guild channels")?;
 
         remove_ids(
-            &mut conn,
+            backend,
             guild,
             RedisKey::guild_members,
             None::<fn() -> RedisKey<'static>>,
@@ -124,7 +142,7 @@ impl Cache {
         .wrap_err("Failed to remove guild members")?;
 
         remove_ids(
-            &mut conn,
+            backend,
             guild,
             RedisKey::guild_roles,
             Some(RedisKey::roles),
@@ -142,15 +160,13 @@ impl Cache {
         guild: Id<GuildMarker>,
         user: Id<UserMarker>,
     ) -> Result<CacheChange> {
-        let mut conn = self.connection().await?;
-
-        let key = RedisKey::member(guild, user);
-
-        conn.del(key)
+        self.backend
+            .delete(vec![RedisKey::member(guild, user)])
             .await
             .wrap_err("Failed to delete member entry")?;
 
-        conn.srem(RedisKey::guild_members(guild), user.get())
+        self.backend
+            .set_remove(RedisKey::guild_members(guild), &[user.get()])
             .await
             .wrap_err("Failed to remove guild member entry")?;
 
@@ -167,23 +183,24 @@ impl Cache {
         guild: Id<GuildMarker>,
         role: Id<RoleMarker>,
     ) -> Result<CacheChange> {
-        let mut conn = self.connection().await?;
-
-        conn.del(RedisKey::role(guild, role))
+        self.backend
+            .delete(vec![RedisKey::role(guild, role)])
             .await
             .wrap_err("Failed to delete role entry")?;
 
-        conn.srem(RedisKey::guild_roles(guild), role.get())
+        self.backend
+            .set_remove(RedisKey::guild_roles(guild), &[role.get()])
             .await
             .wrap_err("Failed to remove role as guild role")?;
 
-        let removed: isize = conn
-            .srem(RedisKey::roles(), role.get())
+        let removed = self
+            .backend
+            .set_remove(RedisKey::roles(), &[role.get()])
             .await
             .wrap_err("Failed to remove role from role ids")?;
 
         Ok(CacheChange {
-            roles: -removed,
+            roles: -(removed as isize),
             ..Default::default()
         })
     }
@@ -1,9 +1,10 @@
+use std::time::Duration;
+
 use bathbot_model::twilight_model::{
     channel::Channel,
     guild::{Guild, Member, Role},
     user::{CurrentUser, User},
 };
-use bb8_redis::redis::AsyncCommands;
 use eyre::{Result, WrapErr};
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
@@ -19,17 +20,57 @@ use crate::{
 type FetchResult<T> = Result<Option<CachedArchive<T>>>;
 
 impl Cache {
-    #[inline]
+    /// Fetch the archived value of the given key.
+    ///
+    /// The value is looked up in memory first and only then in the backend.
     pub async fn fetch<K, T>(&self, key: &K) -> Result<Result<CachedArchive<T>, CacheConnection>>
     where
         K: ToCacheKey + ?Sized,
     {
-        let mut conn = self.connection().await?;
+        if let Some(bytes) = self.local.get(key.to_key()) {
+            return Ok(Ok(CachedArchive::shared(bytes)));
+        }
+
+        let value = self
+            .backend
+            .get_with_ttl(RedisKey::from(key))
+            .await
+            .wrap_err("Failed to fetch stored data")?;
+
+        match value {
+            Some((bytes, ttl)) => {
+                let ttl = ttl.unwrap_or(Duration::MAX);
+                self.local.insert(key.to_key(), &bytes, ttl);
+
+                Ok(Ok(CachedArchive::new(bytes)))
+            }
+            None => Ok(Err(CacheConnection(self))),
+        }
+    }
+
+    /// Fetch bytes that were stored through [`Cache::store_new_raw`].
+    pub async fn fetch_raw<K>(&self, key: &K) -> Result<Option<Vec<u8>>>
+    where
+        K: ToCacheKey + ?Sized,
+    {
+        if let Some(bytes) = self.local.get(key.to_key()) {
+            return Ok(Some(bytes.to_vec()));
+        }
 
-        conn.get::<_, Option<CachedArchive<T>>>(RedisKey::from(key))
+        let value = self
+            .backend
+            .get_with_ttl(RedisKey::from(key))
             .await
-            .map(|archived| archived.ok_or(CacheConnection(conn)))
-            .wrap_err("Failed to fetch stored data")
+            .wrap_err("Failed to fetch stored bytes")?;
+
+        let Some((bytes, ttl)) = value else {
+            return Ok(None);
+        };
+
+        let ttl = ttl.unwrap_or(Duration::MAX);
+        self.local.insert(key.to_key(), &bytes, ttl);
+
+        Ok(Some(bytes.to_vec()))
     }
 
     #[inline]
@@ -38,58 +79,55 @@ impl Cache {
         guild: Option<Id<GuildMarker>>,
         channel: Id<ChannelMarker>,
     ) -> FetchResult<Channel> {
-        self.connection()
-            .await?
-            .get(RedisKey::channel(guild, channel))
+        self.fetch_archive(RedisKey::channel(guild, channel))
             .await
             .wrap_err("Failed to get stored channel")
     }
 
     #[inline]
     pub async fn current_user(&self) -> FetchResult<CurrentUser> {
-        self.connection()
-            .await?
-            .get(RedisKey::current_user())
+        self.fetch_archive(RedisKey::current_user())
             .await
             .wrap_err("Failed to get stored current user")
     }
 
     #[inline]
     pub async fn guild(&self, guild: Id<GuildMarker>) -> FetchResult<Guild> {
-        self.connection()
-            .await?
-            .get(RedisKey::guild(guild))
+        self.fetch_archive(RedisKey::guild(guild))
             .await
             .wrap_err("Failed to get stored guild")
     }
 
     #[inline]
     pub async fn members(&self, guild: Id<GuildMarker>) -> Result<Vec<u64>> {
-        self.connection()
-            .await?
-            .smembers(RedisKey::guild_members(guild))
+        self.backend
+            .set_members(RedisKey::guild_members(guild))
             .await
             .wrap_err("Failed to get member ids")
     }
 
+    #[inline]
+    pub async fn guild_channels(&self, guild: Id<GuildMarker>) -> Result<Vec<u64>> {
+        self.backend
+            .set_members(RedisKey::guild_channels(guild))
+            .await
+            .wrap_err("Failed to get channel ids")
+    }
+
     #[inline]
     pub async fn member(
         &self,
         guild: Id<GuildMarker>,
         user: Id<UserMarker>,
     ) -> FetchResult<Member> {
-        self.connection()
-            .await?
-            .get(RedisKey::member(guild, user))
+        self.fetch_archive(RedisKey::member(guild, user))
             .await
             .wrap_err("Failed to get stored member")
     }
 
     #[inline]
     pub async fn role(&self, guild: Id<GuildMarker>, role: Id<RoleMarker>) -> FetchResult<Role> {
-        self.connection()
-            .await?
-            .get(RedisKey::role(guild, role))
+        self.fetch_archive(RedisKey::role(guild, role))
             .await
             .wrap_err("Failed to get stored role")
     }
@@ -108,19 +146,26 @@ impl Cache {
             .map(|role| RedisKey::role(guild, role))
             .collect();
 
-        self.connection()
-            .await?
-            .mget(keys)
+        let roles = self
+            .backend
+            .get_many(keys)
             .await
-            .wrap_err("Failed to get stored roles")
+            .wrap_err("Failed to get stored roles")?;
+
+        Ok(roles.into_iter().map(CachedArchive::new).collect())
     }
 
     #[inline]
     pub async fn user(&self, user: Id<UserMarker>) -> FetchResult<User> {
-        self.connection()
-            .await?
-            .get(RedisKey::user(user))
+        self.fetch_archive(RedisKey::user(user))
             .await
             .wrap_err("Failed to get stored user")
     }
+
+    async fn fetch_archive<T>(&self, key: RedisKey<'_>) -> FetchResult<T> {
+        self.backend
+            .get(key)
+            .await
+            .map(|bytes| bytes.map(CachedArchive::new))
+    }
 }
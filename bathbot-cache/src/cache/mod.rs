@@ -1,44 +1,56 @@
 use std::fmt::Display;
 
-use bb8_redis::{
-    bb8::{Pool, PooledConnection},
-    RedisConnectionManager,
-};
 use eyre::{Result, WrapErr};
 use tracing::error;
 use twilight_gateway::Event;
 use twilight_model::application::interaction::InteractionData;
 
-use crate::model::{CacheChange, CacheStats, CacheStatsInternal};
+use crate::{
+    backend::{CacheBackend, MemoryBackend, RedisBackend},
+    metrics::CacheMetrics,
+    model::{CacheChange, CacheStats, CacheStatsInternal, LocalCache},
+};
 
 mod cold_resume;
 mod delete;
 mod fetch;
+mod ratelimit;
 mod store;
 
 pub struct Cache {
-    redis: Pool<RedisConnectionManager>,
+    backend: Box<dyn CacheBackend>,
+    local: LocalCache,
     stats: CacheStatsInternal,
 }
 
 impl Cache {
+    /// Create a cache that stores its data in redis.
     pub async fn new(host: impl Display, port: u16, db_idx: u8) -> Result<Self> {
-        let redis_uri = format!("redis://{host}:{port}/{db_idx}");
+        let backend = RedisBackend::new(host, port, db_idx).await?;
 
-        let redis_manager =
-            RedisConnectionManager::new(redis_uri).wrap_err("Failed to create redis manager")?;
+        Self::with_backend(Box::new(backend)).await
+    }
 
-        let redis = Pool::builder()
-            .max_size(16)
-            .build(redis_manager)
-            .await
-            .wrap_err("Failed to create redis pool")?;
+    /// Create a cache that keeps all data in memory instead of redis.
+    ///
+    /// Nothing is shared with other processes and everything is lost on
+    /// shutdown so this is only intended for local development and tests.
+    pub async fn in_memory() -> Result<Self> {
+        Self::with_backend(Box::<MemoryBackend>::default()).await
+    }
 
-        let stats = CacheStatsInternal::new(&redis)
+    async fn with_backend(backend: Box<dyn CacheBackend>) -> Result<Self> {
+        let stats = CacheStatsInternal::new(backend.as_ref())
             .await
             .wrap_err("Failed to create cache stats")?;
 
-        Ok(Self { redis, stats })
+        CacheMetrics::init();
+
+        Ok(Self {
+            backend,
+            local: LocalCache::new(),
+            stats,
+        })
     }
 
     pub async fn update(&self, event: &Event) -> Option<CacheChange> {
@@ -152,10 +164,46 @@ impl Cache {
         self.stats.get()
     }
 
-    pub(crate) async fn connection(&self) -> Result<PooledConnection<RedisConnectionManager>> {
-        self.redis
-            .get()
-            .await
-            .wrap_err("Failed to get redis connection")
+    /// Checks whether the storage backend is reachable.
+    pub async fn ping(&self) -> Result<()> {
+        self.backend.ping().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::block_on;
+
+    #[test]
+    fn in_memory_store_fetch_delete() {
+        let cache = block_on(Cache::in_memory()).unwrap();
+
+        assert!(block_on(cache.fetch_raw("key")).unwrap().is_none());
+
+        block_on(cache.store_new_raw("key", b"value", 60)).unwrap();
+        let bytes = block_on(cache.fetch_raw("key")).unwrap();
+        assert_eq!(bytes.as_deref(), Some(&b"value"[..]));
+
+        // Not only removed from the backend but also from the local cache
+        block_on(cache.delete("key")).unwrap();
+        assert!(block_on(cache.fetch_raw("key")).unwrap().is_none());
+    }
+
+    #[test]
+    fn in_memory_sets() {
+        let cache = block_on(Cache::in_memory()).unwrap();
+
+        assert!(block_on(cache.insert_into_set("set", 1)).unwrap());
+        assert!(!block_on(cache.insert_into_set("set", 1)).unwrap());
+        assert!(block_on(cache.insert_into_set("set", 2)).unwrap());
+    }
+
+    #[test]
+    fn in_memory_tickets() {
+        let cache = block_on(Cache::in_memory()).unwrap();
+
+        assert_eq!(block_on(cache.take_ticket("bucket", 0, 60, 1)).unwrap(), 0);
+        assert!(block_on(cache.take_ticket("bucket", 0, 60, 1)).unwrap() > 0);
     }
 }
@@ -0,0 +1,22 @@
+use eyre::{Result, WrapErr};
+
+use crate::Cache;
+
+impl Cache {
+    /// Take a ticket of the ratelimit bucket stored under the given key.
+    ///
+    /// Returns the amount of seconds until the bucket is available again or
+    /// `0` if the ticket was taken.
+    pub async fn take_ticket(
+        &self,
+        key: &str,
+        delay: u32,
+        time_span: u32,
+        limit: u32,
+    ) -> Result<i64> {
+        self.backend
+            .take_ticket(key, delay, time_span, limit)
+            .await
+            .wrap_err("Failed to take ratelimit ticket")
+    }
+}
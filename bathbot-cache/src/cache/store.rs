@@ -1,6 +1,7 @@
+use std::time::Duration;
+
 use bathbot_model::twilight_model::guild::Member;
-use bb8_redis::redis::AsyncCommands;
-use eyre::{Report, Result, WrapErr};
+use eyre::{Result, WrapErr};
 use rkyv::{
     with::{ArchiveWith, SerializeWith},
     AlignedVec, Serialize,
@@ -18,7 +19,7 @@ use crate::{
     key::{RedisKey, ToCacheKey},
     model::{CacheChange, CacheConnection},
     serializer::{CacheSerializer, MemberSerializer, MultiSerializer, SingleSerializer},
-    util::{AlignedVecRedisArgs, Zipped},
+    util::Zipped,
     Cache,
 };
 
@@ -40,7 +41,7 @@ impl Cache {
     }
 
     async fn store_raw<K>(
-        CacheConnection(conn): &mut CacheConnection<'_>,
+        CacheConnection(cache): &mut CacheConnection<'_>,
         key: &K,
         bytes: &[u8],
         expire_seconds: u64,
@@ -48,15 +49,21 @@ impl Cache {
     where
         K: ToCacheKey + ?Sized,
     {
-        let key = RedisKey::from(key);
+        let expire = Duration::from_secs(expire_seconds);
+        let res = cache
+            .backend
+            .set(RedisKey::from(key), bytes, Some(expire))
+            .await;
+
+        match res {
+            Ok(_) => cache.local.insert(key.to_key(), bytes, expire),
+            Err(_) => cache.local.remove(key.to_key()),
+        }
 
-        conn.set_ex(key, bytes, expire_seconds)
-            .await
-            .map_err(Report::new)
+        res
     }
 
-    /// Serializes a values into bytes, opens a new connection, and stores the
-    /// bytes for the given key.
+    /// Serializes a values into bytes and stores the bytes for the given key.
     ///
     /// **Note**: `Cache::store` should always be preferred if `Cache::fetch`
     /// was called beforehand.
@@ -70,18 +77,14 @@ impl Cache {
         K: ToCacheKey + ?Sized,
         T: Serialize<CacheSerializer<N>>,
     {
-        let mut conn = CacheConnection(self.connection().await?);
-
-        Self::store(&mut conn, key, value, expire_seconds).await
+        Self::store(&mut CacheConnection(self), key, value, expire_seconds).await
     }
 
     pub async fn store_new_raw<K>(&self, key: &K, bytes: &[u8], expire_seconds: u64) -> Result<()>
     where
         K: ToCacheKey + ?Sized,
     {
-        let mut conn = CacheConnection(self.connection().await?);
-
-        Self::store_raw(&mut conn, key, bytes, expire_seconds).await
+        Self::store_raw(&mut CacheConnection(self), key, bytes, expire_seconds).await
     }
 
     /// Insert a value into a set.
@@ -97,36 +100,36 @@ impl Cache {
     where
         K: ToCacheKey + ?Sized,
     {
-        let key = RedisKey::from(key);
-        let count: u8 = self.connection().await?.sadd(key, value).await?;
+        let count = self.backend.set_add(RedisKey::from(key), &[value]).await?;
 
         Ok(count == 1)
     }
 
     pub(crate) async fn cache_channel(&self, channel: &Channel) -> Result<CacheChange> {
         let bytes = SingleSerializer::channel(channel)?;
-        let mut conn = self.connection().await?;
-        let key = RedisKey::from(channel);
 
-        conn.set(key, bytes.as_slice())
+        self.backend
+            .set(RedisKey::from(channel), bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store channel bytes")?;
 
         if let Some(guild) = channel.guild_id {
             let guild_key = RedisKey::guild_channels(guild);
 
-            conn.sadd(guild_key, channel.id.get())
+            self.backend
+                .set_add(guild_key, &[channel.id.get()])
                 .await
                 .wrap_err("Failed to add channel as guild channel")?;
         }
 
-        let added: isize = conn
-            .sadd(RedisKey::channels(), channel.id.get())
+        let added = self
+            .backend
+            .set_add(RedisKey::channels(), &[channel.id.get()])
             .await
             .wrap_err("Failed to add channel as channel id")?;
 
         Ok(CacheChange {
-            channels: added,
+            channels: added as isize,
             ..Default::default()
         })
     }
@@ -144,37 +147,36 @@ impl Cache {
         #[allow(clippy::default_constructed_unit_structs)]
         let mut serializer = MultiSerializer::default();
 
-        let (channels, channel_ids) = channels
+        let (channels, channel_ids): (Vec<_>, Vec<_>) = channels
             .iter()
             .map(|channel| {
-                serializer.channel(channel).map(|bytes| {
-                    let key = RedisKey::from(channel);
-
-                    ((key, AlignedVecRedisArgs(bytes)), channel.id.get())
-                })
+                serializer
+                    .channel(channel)
+                    .map(|bytes| ((RedisKey::from(channel), bytes), channel.id.get()))
             })
             .collect::<Result<Zipped<Vec<_>, Vec<_>>, _>>()?
             .into_parts();
 
-        let mut conn = self.connection().await?;
-
-        conn.mset(&channels)
+        self.backend
+            .set_many(channels)
             .await
             .wrap_err("Failed to store channels bytes")?;
 
         let guild_key = RedisKey::guild_channels(guild);
 
-        conn.sadd(guild_key, &channel_ids)
+        self.backend
+            .set_add(guild_key, &channel_ids)
             .await
             .wrap_err("Failed to add users as guild members")?;
 
-        let added: isize = conn
-            .sadd(RedisKey::channels(), &channel_ids)
+        let added = self
+            .backend
+            .set_add(RedisKey::channels(), &channel_ids)
             .await
             .wrap_err("Failed to add channels as channel ids")?;
 
         Ok(CacheChange {
-            channels: added,
+            channels: added as isize,
             ..Default::default()
         })
     }
@@ -182,9 +184,8 @@ impl Cache {
     pub(crate) async fn cache_current_user(&self, user: &CurrentUser) -> Result<()> {
         let bytes = SingleSerializer::current_user(user)?;
 
-        self.connection()
-            .await?
-            .set(RedisKey::current_user(), bytes.as_slice())
+        self.backend
+            .set(RedisKey::current_user(), bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store current user bytes")?;
 
@@ -200,25 +201,26 @@ impl Cache {
         let mut change = channels_change + threads_change + members_change + roles_change;
 
         let bytes = SingleSerializer::guild(guild)?;
-        let mut conn = self.connection().await?;
-        let key = RedisKey::from(guild);
 
-        conn.set(key, bytes.as_slice())
+        self.backend
+            .set(RedisKey::from(guild), bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store guild bytes")?;
 
-        let guilds_added: isize = conn
-            .sadd(RedisKey::guilds(), guild.id.get())
+        let guilds_added = self
+            .backend
+            .set_add(RedisKey::guilds(), &[guild.id.get()])
             .await
             .wrap_err("Failed to add guild as guild id")?;
 
-        let unavailable_guilds_removed: isize = conn
-            .srem(RedisKey::unavailable_guilds(), guild.id.get())
+        let unavailable_guilds_removed = self
+            .backend
+            .set_remove(RedisKey::unavailable_guilds(), &[guild.id.get()])
             .await
             .wrap_err("Failed to remove guild as unavailable guild id")?;
 
-        change.guilds += guilds_added;
-        change.unavailable_guilds -= unavailable_guilds_removed;
+        change.guilds += guilds_added as isize;
+        change.unavailable_guilds -= unavailable_guilds_removed as isize;
 
         Ok(change)
     }
@@ -263,30 +265,33 @@ impl Cache {
         ) -> Result<CacheChange> {
             let user_bytes = serializer.user(user)?;
 
-            let mut conn = cache.connection().await?;
-
-            let items = &[
-                (RedisKey::member(guild, user.id), member_bytes.as_slice()),
-                (RedisKey::user(user.id), user_bytes.as_slice()),
+            let items = vec![
+                (RedisKey::member(guild, user.id), member_bytes),
+                (RedisKey::user(user.id), user_bytes),
             ];
 
-            conn.mset(items)
+            cache
+                .backend
+                .set_many(items)
                 .await
                 .wrap_err("Failed to store member or user bytes")?;
 
             let guild_key = RedisKey::guild_members(guild);
 
-            conn.sadd(guild_key, user.id.get())
+            cache
+                .backend
+                .set_add(guild_key, &[user.id.get()])
                 .await
                 .wrap_err("Failed to add user as guild member")?;
 
-            let added: isize = conn
-                .sadd(RedisKey::users(), user.id.get())
+            let added = cache
+                .backend
+                .set_add(RedisKey::users(), &[user.id.get()])
                 .await
                 .wrap_err("Failed to add user as user id")?;
 
             Ok(CacheChange {
-                users: added,
+                users: added as isize,
                 ..Default::default()
             })
         }
@@ -317,13 +322,11 @@ impl Cache {
 
                 let user = serializer
                     .user(&member.user)
-                    .map(|bytes| (RedisKey::from(&member.user), AlignedVecRedisArgs(bytes)));
+                    .map(|bytes| (RedisKey::from(&member.user), bytes));
 
-                let member = serializer.member(member).map(|bytes| {
-                    let key = RedisKey::member(guild, member.user.id);
-
-                    (key, AlignedVecRedisArgs(bytes))
-                });
+                let member = serializer
+                    .member(member)
+                    .map(|bytes| (RedisKey::member(guild, member.user.id), bytes));
 
                 match (member, user) {
                     (Ok(member), Ok(user)) => Ok(((member, user_id.get()), user)),
@@ -333,31 +336,33 @@ impl Cache {
             .collect::<Result<Zipped<Zipped<Vec<_>, Vec<_>>, Vec<_>>>>()?
             .into_parts();
 
-        let (members, member_ids) = zipped_members.into_parts();
-
-        let mut conn = self.connection().await?;
+        let (members, member_ids): (Vec<_>, Vec<_>) = zipped_members.into_parts();
 
-        conn.mset(&members)
+        self.backend
+            .set_many(members)
             .await
             .wrap_err("Failed to store members bytes")?;
 
-        conn.mset(&users)
+        self.backend
+            .set_many(users)
             .await
             .wrap_err("Failed to store users bytes")?;
 
         let guild_key = RedisKey::guild_members(guild);
 
-        conn.sadd(guild_key, &member_ids)
+        self.backend
+            .set_add(guild_key, &member_ids)
             .await
             .wrap_err("Failed to add users as guild members")?;
 
-        let added: isize = conn
-            .sadd(RedisKey::users(), &member_ids)
+        let added = self
+            .backend
+            .set_add(RedisKey::users(), &member_ids)
             .await
             .wrap_err("Failed to add users as user ids")?;
 
         Ok(CacheChange {
-            users: added,
+            users: added as isize,
             ..Default::default()
         })
     }
@@ -365,27 +370,27 @@ impl Cache {
     pub(crate) async fn cache_partial_guild(&self, guild: &PartialGuild) -> Result<CacheChange> {
         let mut change = self.cache_roles(guild.id, &guild.roles).await?;
 
-        let mut conn = self.connection().await?;
-
         let bytes = SingleSerializer::guild(guild)?;
-        let key = RedisKey::guild(guild.id);
 
-        conn.set(key, bytes.as_slice())
+        self.backend
+            .set(RedisKey::guild(guild.id), bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store guild bytes")?;
 
-        let guilds_added: isize = conn
-            .sadd(RedisKey::guilds(), guild.id.get())
+        let guilds_added = self
+            .backend
+            .set_add(RedisKey::guilds(), &[guild.id.get()])
             .await
             .wrap_err("Failed to add guild as guild id")?;
 
-        let unavailable_guilds_removed: isize = conn
-            .srem(RedisKey::unavailable_guilds(), guild.id.get())
+        let unavailable_guilds_removed = self
+            .backend
+            .set_remove(RedisKey::unavailable_guilds(), &[guild.id.get()])
             .await
             .wrap_err("Failed to remove guild as unavailable guild id")?;
 
-        change.guilds += guilds_added;
-        change.unavailable_guilds -= unavailable_guilds_removed;
+        change.guilds += guilds_added as isize;
+        change.unavailable_guilds -= unavailable_guilds_removed as isize;
 
         Ok(change)
     }
@@ -405,26 +410,27 @@ impl Cache {
         role: &Role,
     ) -> Result<CacheChange> {
         let bytes = SingleSerializer::role(role)?;
-        let mut conn = self.connection().await?;
-        let key = RedisKey::role(guild, role.id);
 
-        conn.set(key, bytes.as_slice())
+        self.backend
+            .set(RedisKey::role(guild, role.id), bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store role bytes")?;
 
         let guild_key = RedisKey::guild_roles(guild);
 
-        conn.sadd(guild_key, role.id.get())
+        self.backend
+            .set_add(guild_key, &[role.id.get()])
             .await
             .wrap_err("Failed to add role as guild role")?;
 
-        let added: isize = conn
-            .sadd(RedisKey::roles(), role.id.get())
+        let added = self
+            .backend
+            .set_add(RedisKey::roles(), &[role.id.get()])
             .await
             .wrap_err("Failed to add role as role id")?;
 
         Ok(CacheChange {
-            roles: added,
+            roles: added as isize,
             ..Default::default()
         })
     }
@@ -440,14 +446,12 @@ impl Cache {
         #[allow(clippy::default_constructed_unit_structs)]
         let mut serializer = MultiSerializer::default();
 
-        let (roles, role_ids) = roles
+        let (roles, role_ids): (Vec<_>, Vec<_>) = roles
             .into_iter()
             .map(|role| {
-                serializer.role(role).map(|bytes| {
-                    let key = RedisKey::role(guild, role.id);
-
-                    ((key, AlignedVecRedisArgs(bytes)), role.id.get())
-                })
+                serializer
+                    .role(role)
+                    .map(|bytes| ((RedisKey::role(guild, role.id), bytes), role.id.get()))
             })
             .collect::<Result<Zipped<Vec<_>, Vec<_>>, _>>()?
             .into_parts();
@@ -456,25 +460,26 @@ impl Cache {
             return Ok(CacheChange::default());
         }
 
-        let mut conn = self.connection().await?;
-
-        conn.mset(&roles)
+        self.backend
+            .set_many(roles)
             .await
             .wrap_err("Failed to store roles bytes")?;
 
         let guild_key = RedisKey::guild_roles(guild);
 
-        conn.sadd(guild_key, &role_ids)
+        self.backend
+            .set_add(guild_key, &role_ids)
             .await
             .wrap_err("Failed to add roles as guild roles")?;
 
-        let added: isize = conn
-            .sadd(RedisKey::roles(), &role_ids)
+        let added = self
+            .backend
+            .set_add(RedisKey::roles(), &role_ids)
             .await
             .wrap_err("Failed to add roles as role ids")?;
 
         Ok(CacheChange {
-            roles: added,
+            roles: added as isize,
             ..Default::default()
         })
     }
@@ -483,10 +488,9 @@ impl Cache {
         &self,
         guild: Id<GuildMarker>,
     ) -> Result<CacheChange> {
-        let mut conn = self.connection().await?;
-
-        let is_moved: bool = conn
-            .smove(
+        let is_moved = self
+            .backend
+            .set_move(
                 RedisKey::guilds(),
                 RedisKey::unavailable_guilds(),
                 guild.get(),
@@ -495,7 +499,8 @@ impl Cache {
             .wrap_err("Failed to move guild id")?;
 
         let change = if is_moved {
-            conn.del(RedisKey::guild(guild))
+            self.backend
+                .delete(vec![RedisKey::guild(guild)])
                 .await
                 .wrap_err("Failed to delete guild entry")?;
 
@@ -509,13 +514,14 @@ impl Cache {
 
             change
         } else {
-            let added: isize = conn
-                .sadd(RedisKey::unavailable_guilds(), guild.get())
+            let added = self
+                .backend
+                .set_add(RedisKey::unavailable_guilds(), &[guild.get()])
                 .await
                 .wrap_err("Failed to add guild to unavailable guilds")?;
 
             CacheChange {
-                unavailable_guilds: added,
+                unavailable_guilds: added as isize,
                 ..Default::default()
             }
         };
@@ -524,22 +530,21 @@ impl Cache {
     }
 
     pub(crate) async fn cache_user(&self, user: &User) -> Result<CacheChange> {
-        let mut conn = self.connection().await?;
-
         let bytes = SingleSerializer::user(user)?;
-        let key = RedisKey::from(user);
 
-        conn.set(key, bytes.as_slice())
+        self.backend
+            .set(RedisKey::from(user), bytes.as_slice(), None)
             .await
             .wrap_err("Failed to store user bytes")?;
 
-        let added: isize = conn
-            .sadd(RedisKey::users(), user.id.get())
+        let added = self
+            .backend
+            .set_add(RedisKey::users(), &[user.id.get()])
             .await
             .wrap_err("Failed to add user as user id")?;
 
         Ok(CacheChange {
-            users: added,
+            users: added as isize,
             ..Default::default()
         })
     }
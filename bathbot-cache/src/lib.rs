@@ -2,7 +2,9 @@ pub use self::{cache::Cache, key::ToCacheKey, serializer::CacheSerializer};
 
 pub mod model;
 
+mod backend;
 mod cache;
 mod key;
+mod metrics;
 mod serializer;
 mod util;
@@ -0,0 +1,38 @@
+use metrics::{counter, describe_counter};
+
+const LOCAL_CACHE_HITS: &str = "local_cache_hits";
+const LOCAL_CACHE_MISSES: &str = "local_cache_misses";
+const LOCAL_CACHE_EVICTIONS: &str = "local_cache_evictions";
+
+pub(crate) struct CacheMetrics;
+
+impl CacheMetrics {
+    pub(crate) fn init() {
+        describe_counter!(
+            LOCAL_CACHE_HITS,
+            "Number of times the in-memory cache contained a value"
+        );
+
+        describe_counter!(
+            LOCAL_CACHE_MISSES,
+            "Number of times a value had to be fetched from redis"
+        );
+
+        describe_counter!(
+            LOCAL_CACHE_EVICTIONS,
+            "Number of in-memory entries that were evicted due to size limits"
+        );
+    }
+
+    pub(crate) fn local_hit() {
+        counter!(LOCAL_CACHE_HITS).increment(1);
+    }
+
+    pub(crate) fn local_miss() {
+        counter!(LOCAL_CACHE_MISSES).increment(1);
+    }
+
+    pub(crate) fn local_eviction() {
+        counter!(LOCAL_CACHE_EVICTIONS).increment(1);
+    }
+}
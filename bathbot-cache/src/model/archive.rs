@@ -1,24 +1,33 @@
-use std::{marker::PhantomData, ops::Deref, pin::Pin};
+use std::{marker::PhantomData, ops::Deref, pin::Pin, sync::Arc};
 
-use bb8_redis::redis::{ErrorKind, FromRedisValue, RedisError, RedisResult, Value};
 use rkyv::{
     with::{ArchiveWith, DeserializeWith, With},
     AlignedVec, Archive, Archived, Deserialize, Infallible,
 };
 
+/// Bytes may be shared with the in-memory cache and are only copied when
+/// mutated.
 #[derive(Clone)]
 pub struct CachedArchive<T> {
-    bytes: AlignedVec,
+    bytes: Arc<AlignedVec>,
     phantom: PhantomData<T>,
 }
 
 impl<T> CachedArchive<T> {
     pub(crate) fn new(bytes: AlignedVec) -> Self {
+        Self::shared(Arc::new(bytes))
+    }
+
+    pub(crate) fn shared(bytes: Arc<AlignedVec>) -> Self {
         Self {
             bytes,
             phantom: PhantomData,
         }
     }
+
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
 }
 
 impl<T: Archive> Deref for CachedArchive<T> {
@@ -26,8 +35,8 @@ impl<T: Archive> Deref for CachedArchive<T> {
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        // SAFETY: Bytes originate from redis which only stores valid archived data
-        unsafe { rkyv::archived_root::<T>(&self.bytes) }
+        // SAFETY: Bytes originate from the cache which only stores valid archived data
+        unsafe { rkyv::archived_root::<T>(self.as_bytes()) }
     }
 }
 
@@ -43,8 +52,11 @@ impl<T: Archive> CachedArchive<T> {
     where
         F: FnOnce(Pin<&mut <T as Archive>::Archived>),
     {
-        // SAFETY: Bytes originate from redis which only stores valid archived data
-        let archived = unsafe { rkyv::archived_root_mut::<T>(Pin::new(&mut self.bytes)) };
+        // Copies the bytes if they're shared with the in-memory cache
+        let bytes = Arc::make_mut(&mut self.bytes).as_mut_slice();
+
+        // SAFETY: Bytes originate from the cache which only stores valid archived data
+        let archived = unsafe { rkyv::archived_root_mut::<T>(Pin::new(bytes)) };
         f(archived);
     }
 }
@@ -54,32 +66,9 @@ impl<T> CachedArchive<T> {
     where
         W: ArchiveWith<T> + DeserializeWith<<W as ArchiveWith<T>>::Archived, T, Infallible>,
     {
-        // SAFETY: Bytes originate from redis which only stores valid archived data
-        let archived = unsafe { rkyv::archived_root::<With<_, W>>(&self.bytes) };
+        // SAFETY: Bytes originate from the cache which only stores valid archived data
+        let archived = unsafe { rkyv::archived_root::<With<_, W>>(self.as_bytes()) };
 
         W::deserialize_with(archived, &mut Infallible).unwrap()
     }
 }
-
-impl<T> FromRedisValue for CachedArchive<T> {
-    #[inline]
-    fn from_redis_value(v: &Value) -> RedisResult<Self> {
-        match v {
-            Value::Data(data) => {
-                let mut bytes = AlignedVec::new();
-                bytes.reserve_exact(data.len());
-                bytes.extend_from_slice(data);
-
-                Ok(Self::new(bytes))
-            }
-            _ => Err(RedisError::from((
-                ErrorKind::TypeError,
-                "Response was of incompatible type",
-                format!(
-                    "Response type not byte list compatible. (response was {:?})",
-                    std::any::type_name::<T>()
-                ),
-            ))),
-        }
-    }
-}
@@ -1,4 +1,4 @@
-use bb8_redis::{bb8::PooledConnection, RedisConnectionManager};
+use crate::Cache;
 
-/// Provided by `Cache::fetch` to be later used in `Cache::cache_data`.
-pub struct CacheConnection<'c>(pub(crate) PooledConnection<'c, RedisConnectionManager>);
+/// Provided by `Cache::fetch` to be later used in `Cache::store`.
+pub struct CacheConnection<'c>(pub(crate) &'c Cache);
@@ -0,0 +1,347 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use rkyv::AlignedVec;
+
+use crate::metrics::CacheMetrics;
+
+/// Maximum amount of entries
+const MAX_ENTRIES: usize = 1024;
+
+/// Maximum amount of bytes across all entries
+const MAX_BYTES: usize = 64 * 1024 * 1024;
+
+/// Entries larger than this are only stored in redis
+const MAX_ENTRY_BYTES: usize = 4 * 1024 * 1024;
+
+/// Upper bound on how long an entry stays in memory.
+///
+/// Other processes sharing the same redis instance don't invalidate
+/// in-memory entries so this bounds their staleness.
+const MAX_TTL: Duration = Duration::from_secs(60);
+
+/// Bounded in-memory cache in front of redis.
+///
+/// Only entries of generic keys i.e. those going through [`Cache::fetch`],
+/// [`Cache::fetch_raw`], and the store methods are kept in here.
+///
+/// [`Cache::fetch`]: crate::Cache::fetch
+/// [`Cache::fetch_raw`]: crate::Cache::fetch_raw
+pub(crate) struct LocalCache {
+    inner: Mutex<LocalEntries>,
+}
+
+#[derive(Default)]
+struct LocalEntries {
+    entries: HashMap<Box<[u8]>, LocalEntry>,
+    /// Total amount of bytes across all entries
+    bytes: usize,
+    /// Incremented on each access to determine the least recently used entry
+    tick: u64,
+}
+
+struct LocalEntry {
+    /// Shared so that hits don't copy the bytes while holding the lock
+    bytes: Arc<AlignedVec>,
+    expires_at: Instant,
+    last_access: u64,
+}
+
+impl LocalCache {
+    pub(crate) fn new() -> Self {
+        Self {
+            inner: Mutex::new(LocalEntries::default()),
+        }
+    }
+
+    /// Returns the entry's bytes if it's present and not expired.
+    pub(crate) fn get(&self, key: &[u8]) -> Option<Arc<AlignedVec>> {
+        self.get_at(key, Instant::now())
+    }
+
+    fn get_at(&self, key: &[u8], now: Instant) -> Option<Arc<AlignedVec>> {
+        let mut unlocked = self.inner.lock().unwrap();
+        unlocked.tick += 1;
+        let tick = unlocked.tick;
+
+        let bytes = match unlocked.entries.get_mut(key) {
+            Some(entry) if entry.expires_at > now => {
+                entry.last_access = tick;
+
+                Some(Arc::clone(&entry.bytes))
+            }
+            Some(_) => {
+                unlocked.remove(key);
+
+                None
+            }
+            None => None,
+        };
+
+        match bytes {
+            Some(_) => CacheMetrics::local_hit(),
+            None => CacheMetrics::local_miss(),
+        }
+
+        bytes
+    }
+
+    /// Stores the bytes for the given key.
+    ///
+    /// The entry expires after the given ttl, capped at one minute. Oversized
+    /// entries are not stored and instead remove any previous entry.
+    pub(crate) fn insert(&self, key: &[u8], bytes: &[u8], ttl: Duration) {
+        self.insert_at(key, bytes, ttl, Instant::now());
+    }
+
+    fn insert_at(&self, key: &[u8], bytes: &[u8], ttl: Duration, now: Instant) {
+        let mut unlocked = self.inner.lock().unwrap();
+        unlocked.remove(key);
+
+        if ttl.is_zero() || bytes.len() > MAX_ENTRY_BYTES {
+            return;
+        }
+
+        unlocked.make_room(bytes.len(), now);
+
+        let mut aligned = AlignedVec::with_capacity(bytes.len());
+        aligned.extend_from_slice(bytes);
+
+        unlocked.tick += 1;
+
+        let entry = LocalEntry {
+            bytes: Arc::new(aligned),
+            expires_at: now + ttl.min(MAX_TTL),
+            last_access: unlocked.tick,
+        };
+
+        unlocked.bytes += entry.bytes.len();
+        unlocked.entries.insert(Box::from(key), entry);
+    }
+
+    pub(crate) fn remove(&self, key: &[u8]) {
+        self.inner.lock().unwrap().remove(key);
+    }
+
+    pub(crate) fn clear(&self) {
+        *self.inner.lock().unwrap() = LocalEntries::default();
+    }
+}
+
+impl LocalEntries {
+    fn remove(&mut self, key: &[u8]) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.bytes -= entry.bytes.len();
+        }
+    }
+
+    /// Removes expired entries and, if necessary, the least recently used
+    /// ones until an entry of the given size fits.
+    fn make_room(&mut self, len: usize, now: Instant) {
+        let fits = |this: &Self| this.entries.len() < MAX_ENTRIES && this.bytes + len <= MAX_BYTES;
+
+        if fits(self) {
+            return;
+        }
+
+        let mut removed = 0;
+
+        self.entries.retain(|_, entry| {
+            let keep = entry.expires_at > now;
+
+            if !keep {
+                removed += entry.bytes.len();
+            }
+
+            keep
+        });
+
+        self.bytes -= removed;
+
+        while !fits(self) {
+            let lru = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_access)
+                .map(|(key, _)| key.clone());
+
+            let Some(key) = lru else { break };
+
+            self.remove(&key);
+            CacheMetrics::local_eviction();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TTL: Duration = Duration::from_secs(30);
+
+    impl LocalCache {
+        fn len(&self) -> usize {
+            self.inner.lock().unwrap().entries.len()
+        }
+
+        fn bytes(&self) -> usize {
+            self.inner.lock().unwrap().bytes
+        }
+    }
+
+    fn key(i: usize) -> Vec<u8> {
+        i.to_be_bytes().to_vec()
+    }
+
+    #[test]
+    fn get_returns_inserted_bytes() {
+        let cache = LocalCache::new();
+        let now = Instant::now();
+        cache.insert_at(b"key", b"value", TTL, now);
+
+        let bytes = cache.get_at(b"key", now).unwrap();
+        assert_eq!(bytes.as_slice(), b"value");
+        assert!(cache.get_at(b"other", now).is_none());
+    }
+
+    #[test]
+    fn hits_share_bytes() {
+        let cache = LocalCache::new();
+        let now = Instant::now();
+        cache.insert_at(b"key", b"value", TTL, now);
+
+        let a = cache.get_at(b"key", now).unwrap();
+        let b = cache.get_at(b"key", now).unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+
+        // Replacing the entry keeps previously returned bytes intact
+        cache.insert_at(b"key", b"other", TTL, now);
+        assert_eq!(a.as_slice(), b"value");
+    }
+
+    #[test]
+    fn overwrite_replaces_byte_count() {
+        let cache = LocalCache::new();
+        let now = Instant::now();
+        cache.insert_at(b"key", &[0; 10], TTL, now);
+        cache.insert_at(b"key", &[0; 25], TTL, now);
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.bytes(), 25);
+
+        cache.insert_at(b"other", &[0; 5], TTL, now);
+        cache.remove(b"key");
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.bytes(), 5);
+
+        cache.clear();
+
+        assert_eq!(cache.len(), 0);
+        assert_eq!(cache.bytes(), 0);
+    }
+
+    #[test]
+    fn entries_expire_after_ttl() {
+        let cache = LocalCache::new();
+        let now = Instant::now();
+        cache.insert_at(b"key", b"value", Duration::from_secs(5), now);
+
+        assert!(cache.get_at(b"key", now + Duration::from_secs(4)).is_some());
+        assert!(cache.get_at(b"key", now + Duration::from_secs(5)).is_none());
+        assert_eq!(cache.bytes(), 0);
+    }
+
+    #[test]
+    fn ttl_is_capped() {
+        let cache = LocalCache::new();
+        let now = Instant::now();
+        cache.insert_at(b"key", b"value", Duration::MAX, now);
+
+        assert!(cache
+            .get_at(b"key", now + MAX_TTL - Duration::from_secs(1))
+            .is_some());
+        assert!(cache.get_at(b"key", now + MAX_TTL).is_none());
+    }
+
+    #[test]
+    fn skips_zero_ttl_and_oversized_entries() {
+        let cache = LocalCache::new();
+        let now = Instant::now();
+        cache.insert_at(b"key", b"value", TTL, now);
+        cache.insert_at(b"key", b"value", Duration::ZERO, now);
+
+        assert!(cache.get_at(b"key", now).is_none());
+
+        cache.insert_at(b"key", b"value", TTL, now);
+        cache.insert_at(b"key", &vec![0; MAX_ENTRY_BYTES + 1], TTL, now);
+
+        assert!(cache.get_at(b"key", now).is_none());
+        assert_eq!(cache.bytes(), 0);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_at_entry_cap() {
+        let cache = LocalCache::new();
+        let now = Instant::now();
+
+        for i in 0..MAX_ENTRIES {
+            cache.insert_at(&key(i), b"value", TTL, now);
+        }
+
+        // Access the oldest entry so the second oldest is evicted instead
+        assert!(cache.get_at(&key(0), now).is_some());
+
+        cache.insert_at(&key(MAX_ENTRIES), b"value", TTL, now);
+
+        assert_eq!(cache.len(), MAX_ENTRIES);
+        assert!(cache.get_at(&key(0), now).is_some());
+        assert!(cache.get_at(&key(1), now).is_none());
+        assert!(cache.get_at(&key(2), now).is_some());
+        assert!(cache.get_at(&key(MAX_ENTRIES), now).is_some());
+    }
+
+    #[test]
+    fn evicts_least_recently_used_at_byte_cap() {
+        let cache = LocalCache::new();
+        let now = Instant::now();
+        let bytes = vec![0; MAX_ENTRY_BYTES];
+        let count = MAX_BYTES / MAX_ENTRY_BYTES;
+
+        for i in 0..count {
+            cache.insert_at(&key(i), &bytes, TTL, now);
+        }
+
+        assert_eq!(cache.bytes(), MAX_BYTES);
+
+        cache.insert_at(&key(count), b"value", TTL, now);
+
+        assert_eq!(cache.len(), count);
+        assert_eq!(cache.bytes(), MAX_BYTES - MAX_ENTRY_BYTES + 5);
+        assert!(cache.get_at(&key(0), now).is_none());
+        assert!(cache.get_at(&key(1), now).is_some());
+    }
+
+    #[test]
+    fn evicts_expired_before_least_recently_used() {
+        let cache = LocalCache::new();
+        let now = Instant::now();
+
+        cache.insert_at(&key(0), b"value", TTL, now);
+        cache.insert_at(&key(1), b"value", Duration::from_secs(1), now);
+
+        for i in 2..MAX_ENTRIES {
+            cache.insert_at(&key(i), b"value", TTL, now);
+        }
+
+        let later = now + Duration::from_secs(2);
+        cache.insert_at(&key(MAX_ENTRIES), b"value", TTL, later);
+
+        assert_eq!(cache.len(), MAX_ENTRIES);
+        assert!(cache.get_at(&key(0), later).is_some());
+        assert!(cache.get_at(&key(1), later).is_none());
+    }
+}
@@ -1,10 +1,11 @@
-pub(crate) use self::stats::CacheStatsInternal;
 pub use self::{
     archive::CachedArchive,
     connection::CacheConnection,
     stats::{CacheChange, CacheStats},
 };
+pub(crate) use self::{local::LocalCache, stats::CacheStatsInternal};
 
 mod archive;
 mod connection;
+mod local;
 mod stats;
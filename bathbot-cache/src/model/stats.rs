@@ -3,10 +3,9 @@ use std::{
     sync::Mutex,
 };
 
-use bb8_redis::{bb8::Pool, redis::AsyncCommands, RedisConnectionManager};
 use eyre::{Result, WrapErr};
 
-use crate::key::RedisKey;
+use crate::{backend::CacheBackend, key::RedisKey};
 
 #[derive(Clone, Debug, Default)]
 pub struct CacheStats {
@@ -62,19 +61,14 @@ pub(crate) struct CacheStatsInternal {
 }
 
 impl CacheStatsInternal {
-    pub(crate) async fn new(redis: &Pool<RedisConnectionManager>) -> Result<Self> {
-        let mut conn = redis
-            .get()
-            .await
-            .wrap_err("Failed to get redis connection")?;
-
+    pub(crate) async fn new(backend: &dyn CacheBackend) -> Result<Self> {
         macro_rules! scard {
             ($name:ident) => {
-                conn.scard(RedisKey::$name()).await.wrap_err(concat!(
+                backend.set_len(RedisKey::$name()).await.wrap_err(concat!(
                     "Failed to get ",
                     stringify!($name),
                     " cardinality"
-                ))?
+                ))? as isize
             };
         }
 
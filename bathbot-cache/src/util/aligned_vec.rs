@@ -1,4 +1,6 @@
-use bb8_redis::redis::{RedisWrite, ToRedisArgs};
+use bb8_redis::redis::{
+    ErrorKind, FromRedisValue, RedisError, RedisResult, RedisWrite, ToRedisArgs, Value,
+};
 use rkyv::AlignedVec;
 
 pub(crate) struct AlignedVecRedisArgs(pub(crate) AlignedVec);
@@ -12,3 +14,26 @@ impl ToRedisArgs for AlignedVecRedisArgs {
         self.0.as_slice().write_redis_args(out)
     }
 }
+
+/// Bytes of a redis value copied into an [`AlignedVec`].
+pub(crate) struct AlignedVecRedisValue(pub(crate) AlignedVec);
+
+impl FromRedisValue for AlignedVecRedisValue {
+    #[inline]
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        match v {
+            Value::Data(data) => {
+                let mut bytes = AlignedVec::new();
+                bytes.reserve_exact(data.len());
+                bytes.extend_from_slice(data);
+
+                Ok(Self(bytes))
+            }
+            _ => Err(RedisError::from((
+                ErrorKind::TypeError,
+                "Response was of incompatible type",
+                format!("Response type not byte list compatible. (response was {v:?})"),
+            ))),
+        }
+    }
+}
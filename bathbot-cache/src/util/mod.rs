@@ -1,4 +1,34 @@
-pub(crate) use self::{aligned_vec::AlignedVecRedisArgs, zipped::Zipped};
+pub(crate) use self::{
+    aligned_vec::{AlignedVecRedisArgs, AlignedVecRedisValue},
+    zipped::Zipped,
+};
 
 mod aligned_vec;
 mod zipped;
+
+/// Drives a future that doesn't need a runtime, like those of the memory
+/// backend, to completion.
+#[cfg(test)]
+pub(crate) fn block_on<F: std::future::Future>(fut: F) -> F::Output {
+    use std::{
+        pin::pin,
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = pin!(fut);
+
+    loop {
+        if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use crate::{metrics::ClientMetrics, site::Site};
+
+/// Consecutive failures after which a circuit opens
+const FAILURE_THRESHOLD: u32 = 5;
+
+/// How long an open circuit rejects requests before letting a probe through
+const OPEN_DURATION: Duration = Duration::from_secs(30);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CircuitState {
+    /// Requests are sent as usual
+    Closed,
+    /// A single probe request is allowed to check whether the site recovered
+    HalfOpen,
+    /// Requests fail immediately
+    Open,
+}
+
+impl CircuitState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Closed => "closed",
+            Self::HalfOpen => "half-open",
+            Self::Open => "open",
+        }
+    }
+}
+
+/// State of a site's circuit breaker alongside statistics of recent requests.
+#[derive(Clone, Debug)]
+pub struct SiteStatus {
+    pub site: Site,
+    pub state: CircuitState,
+    /// Amount of recent requests
+    pub requests: usize,
+    /// Fraction of recent requests that failed
+    pub error_rate: f32,
+    /// Average latency of recent requests that succeeded
+    pub latency: Option<Duration>,
+}
+
+pub(crate) struct CircuitBreaker {
+    site: Site,
+    inner: Mutex<BreakerInner>,
+}
+
+struct BreakerInner {
+    state: BreakerState,
+    consecutive_failures: u32,
+    samples: VecDeque<Sample>,
+}
+
+enum BreakerState {
+    Closed,
+    /// The probe request is in flight
+    HalfOpen,
+    Open {
+        until: Instant,
+    },
+}
+
+struct Sample {
+    success: bool,
+    latency: Duration,
+}
+
+impl SiteStatus {
+    /// Amount of recent requests that error rate and latency are based on
+    pub const SAMPLE_COUNT: usize = 50;
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(site: Site) -> Self {
+        let inner = BreakerInner {
+            state: BreakerState::Closed,
+            consecutive_failures: 0,
+            samples: VecDeque::with_capacity(SiteStatus::SAMPLE_COUNT),
+        };
+
+        Self {
+            site,
+            inner: Mutex::new(inner),
+        }
+    }
+
+    /// Returns a permit if a request to the site may be sent.
+    pub(crate) fn acquire(&self) -> Option<BreakerPermit<'_>> {
+        let mut inner = self.lock();
+
+        match inner.state {
+            BreakerState::Closed => {}
+            BreakerState::Open { until } if until <= Instant::now() => {
+                inner.state = BreakerState::HalfOpen;
+                ClientMetrics::site_status(&inner.status(self.site));
+            }
+            BreakerState::HalfOpen | BreakerState::Open { .. } => {
+                ClientMetrics::circuit_rejection(self.site);
+
+                return None;
+            }
+        }
+
+        Some(BreakerPermit {
+            breaker: self,
+            start: Instant::now(),
+            finished: false,
+        })
+    }
+
+    pub(crate) fn status(&self) -> SiteStatus {
+        self.lock().status(self.site)
+    }
+
+    fn record(&self, success: bool, latency: Duration) {
+        let mut inner = self.lock();
+
+        if inner.samples.len() == SiteStatus::SAMPLE_COUNT {
+            inner.samples.pop_front();
+        }
+
+        inner.samples.push_back(Sample { success, latency });
+
+        if !self.site.has_circuit_breaker() {
+            ClientMetrics::site_status(&inner.status(self.site));
+
+            return;
+        }
+
+        let was_closed = matches!(inner.state, BreakerState::Closed);
+        let was_open = matches!(inner.state, BreakerState::Open { .. });
+
+        if success {
+            inner.consecutive_failures = 0;
+            inner.state = BreakerState::Closed;
+
+            if !was_closed {
+                info!(site = self.site.as_str(), "Circuit closed");
+            }
+        } else {
+            inner.consecutive_failures += 1;
+
+            let reopen = matches!(inner.state, BreakerState::HalfOpen)
+                || inner.consecutive_failures >= FAILURE_THRESHOLD;
+
+            if reopen {
+                inner.state = BreakerState::Open {
+                    until: Instant::now() + OPEN_DURATION,
+                };
+
+                if !was_open {
+                    warn!(
+                        site = self.site.as_str(),
+                        failures = inner.consecutive_failures,
+                        "Circuit opened"
+                    );
+                }
+            }
+        }
+
+        ClientMetrics::site_status(&inner.status(self.site));
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BreakerInner> {
+        self.inner.lock().unwrap()
+    }
+}
+
+impl BreakerInner {
+    fn status(&self, site: Site) -> SiteStatus {
+        let state = match self.state {
+            BreakerState::Closed => CircuitState::Closed,
+            BreakerState::HalfOpen => CircuitState::HalfOpen,
+            // The next request will be the probe
+            BreakerState::Open { until } if until <= Instant::now() => CircuitState::HalfOpen,
+            BreakerState::Open { .. } => CircuitState::Open,
+        };
+
+        let requests = self.samples.len();
+        let mut failures = 0;
+        let mut latency_sum = Duration::ZERO;
+
+        for sample in self.samples.iter() {
+            if sample.success {
+                latency_sum += sample.latency;
+            } else {
+                failures += 1;
+            }
+        }
+
+        let successes = requests - failures;
+
+        SiteStatus {
+            site,
+            state,
+            requests,
+            error_rate: if requests == 0 {
+                0.0
+            } else {
+                failures as f32 / requests as f32
+            },
+            latency: (successes > 0).then(|| latency_sum / successes as u32),
+        }
+    }
+}
+
+/// Records the outcome of a request.
+///
+/// Dropping the permit without finishing it, e.g. because the request timed
+/// out, counts as failure.
+pub(crate) struct BreakerPermit<'b> {
+    breaker: &'b CircuitBreaker,
+    start: Instant,
+    finished: bool,
+}
+
+impl BreakerPermit<'_> {
+    pub(crate) fn finish(mut self, success: bool) {
+        self.finished = true;
+        self.breaker.record(success, self.start.elapsed());
+    }
+}
+
+impl Drop for BreakerPermit<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.breaker.record(false, self.start.elapsed());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opens_after_repeated_failures() {
+        let breaker = CircuitBreaker::new(Site::Osekai);
+
+        for _ in 0..FAILURE_THRESHOLD {
+            breaker.acquire().unwrap().finish(false);
+        }
+
+        assert!(breaker.acquire().is_none());
+
+        let status = breaker.status();
+        assert_eq!(status.state, CircuitState::Open);
+        assert_eq!(status.requests, FAILURE_THRESHOLD as usize);
+        assert!(status.latency.is_none());
+
+        breaker.lock().state = BreakerState::Open {
+            until: Instant::now(),
+        };
+
+        let probe = breaker.acquire().unwrap();
+        assert!(breaker.acquire().is_none());
+
+        probe.finish(true);
+        assert_eq!(breaker.status().state, CircuitState::Closed);
+    }
+
+    #[test]
+    fn dropped_permit_counts_as_failure() {
+        let breaker = CircuitBreaker::new(Site::Huismetbenen);
+        drop(breaker.acquire());
+
+        assert_eq!(breaker.status().error_rate, 1.0);
+    }
+}
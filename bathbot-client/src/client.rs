@@ -4,27 +4,38 @@ use bytes::Bytes;
 use eyre::{Result, WrapErr};
 use http::{
     header::{AUTHORIZATION, CONTENT_LENGTH},
-    Response,
+    Response, StatusCode,
 };
 use hyper::{
     client::{connect::dns::GaiResolver, Client as HyperClient, HttpConnector},
     header::{CONTENT_TYPE, USER_AGENT},
-    Body, Error as HyperError, Method, Request,
+    Body, Method, Request,
 };
-use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use hyper_rustls::HttpsConnector;
 use leaky_bucket_lite::LeakyBucket;
 use tokio::time::Duration;
 
-use crate::{metrics::ClientMetrics, multipart::Multipart, ClientError, Site, MY_USER_AGENT};
+use crate::{
+    breaker::{BreakerPermit, CircuitBreaker, SiteStatus},
+    metrics::ClientMetrics,
+    multipart::Multipart,
+    transport::{FakeServer, Transport, TransportError, TransportMode},
+    webhook::PublicResolver,
+    ClientError, Site, MY_USER_AGENT,
+};
 
 pub(crate) type InnerClient = HyperClient<HttpsConnector<HttpConnector<GaiResolver>>, Body>;
 
+/// Only connects to public addresses, see [`PublicResolver`].
+pub(crate) type WebhookClient = HyperClient<HttpsConnector<HttpConnector<PublicResolver>>, Body>;
+
 pub struct Client {
-    pub(crate) client: InnerClient,
+    pub(crate) transport: Transport,
     #[cfg(feature = "twitch")]
     twitch: bathbot_model::TwitchData,
     github_auth: Box<str>,
-    ratelimiters: [LeakyBucket; 17],
+    ratelimiters: [LeakyBucket; 19],
+    breakers: [CircuitBreaker; 19],
 }
 
 impl Client {
@@ -32,21 +43,57 @@ impl Client {
         #[cfg(feature = "twitch")] (twitch_client_id, twitch_token): (&str, &str),
         github_token: &str,
     ) -> Result<Self> {
-        ClientMetrics::init();
+        Self::with_transport(
+            #[cfg(feature = "twitch")]
+            (twitch_client_id, twitch_token),
+            github_token,
+            TransportMode::Live,
+        )
+        .await
+    }
 
-        let connector = HttpsConnectorBuilder::new()
-            .with_webpki_roots()
-            .https_or_http()
-            .enable_http1()
-            .build();
+    /// Create a client whose requests are handled according to the given
+    /// [`TransportMode`].
+    pub async fn with_transport(
+        #[cfg(feature = "twitch")] (twitch_client_id, twitch_token): (&str, &str),
+        github_token: &str,
+        mode: TransportMode,
+    ) -> Result<Self> {
+        ClientMetrics::init();
 
-        let client = HyperClient::builder().build(connector);
+        let transport = Transport::new(mode);
 
         #[cfg(feature = "twitch")]
-        let twitch = Self::get_twitch_token(&client, twitch_client_id, twitch_token)
+        let twitch = Self::get_twitch_token(&transport, twitch_client_id, twitch_token)
             .await
             .wrap_err("failed to get twitch token")?;
 
+        Ok(Self::from_parts(
+            transport,
+            #[cfg(feature = "twitch")]
+            twitch,
+            github_token,
+        ))
+    }
+
+    /// Create a client that sends all requests to the given [`FakeServer`].
+    pub fn fake(server: FakeServer) -> Self {
+        Self::from_parts(
+            Transport::Fake(server),
+            #[cfg(feature = "twitch")]
+            bathbot_model::TwitchData {
+                client_id: http::HeaderValue::from_static("fake"),
+                oauth_token: Default::default(),
+            },
+            "fake",
+        )
+    }
+
+    fn from_parts(
+        transport: Transport,
+        #[cfg(feature = "twitch")] twitch: bathbot_model::TwitchData,
+        github_token: &str,
+    ) -> Self {
         let ratelimiter = |per_second| {
             LeakyBucket::builder()
                 .max(per_second)
@@ -76,20 +123,34 @@ impl Client {
                 .refill_interval(Duration::from_secs(7))
                 .refill_amount(1)
                 .build(),
-            ratelimiter(2), // OsuStats
-            ratelimiter(2), // OsuTrack
-            ratelimiter(2), // OsuWorld
-            ratelimiter(1), // Respektive
-            ratelimiter(5), // Twitch
+            ratelimiter(2),  // OsuStats
+            ratelimiter(2),  // OsuTrack
+            ratelimiter(5),  // OsuUserApi
+            ratelimiter(2),  // OsuWorld
+            ratelimiter(1),  // Respektive
+            ratelimiter(5),  // Twitch
+            ratelimiter(10), // Webhook
         ];
 
-        Ok(Self {
-            client,
+        let breakers = Site::ALL.map(CircuitBreaker::new);
+
+        for breaker in breakers.iter() {
+            ClientMetrics::site_status(&breaker.status());
+        }
+
+        Self {
+            transport,
             ratelimiters,
+            breakers,
             #[cfg(feature = "twitch")]
             twitch,
             github_auth,
-        })
+        }
+    }
+
+    /// Circuit breaker states and recent request statistics of all sites.
+    pub fn site_statuses(&self) -> Vec<SiteStatus> {
+        self.breakers.iter().map(CircuitBreaker::status).collect()
     }
 
     pub(crate) async fn ratelimit(&self, site: Site) {
@@ -130,18 +191,11 @@ impl Client {
             .body(Body::empty())
             .wrap_err("failed to build GET request")?;
 
-        let (response, start) = self
-            .send_request(req, site)
+        self.send_request(req, site)
+            .await
+            .wrap_err("failed to receive GET response")?
+            .bytes(url)
             .await
-            .wrap_err("failed to receive GET response")?;
-
-        let status = response.status();
-        let bytes_res = Self::error_for_status(response, url).await;
-
-        let latency = start.elapsed();
-        ClientMetrics::observe(site, status, latency);
-
-        bytes_res
     }
 
     pub(crate) async fn make_multipart_post_request(
@@ -167,18 +221,11 @@ impl Client {
 
         self.ratelimit(site).await;
 
-        let (response, start) = self
-            .send_request(req, site)
+        self.send_request(req, site)
+            .await
+            .wrap_err("Failed to receive POST multipart response")?
+            .bytes(url)
             .await
-            .wrap_err("Failed to receive POST multipart response")?;
-
-        let status = response.status();
-        let bytes_res = Self::error_for_status(response, url).await;
-
-        let latency = start.elapsed();
-        ClientMetrics::observe(site, status, latency);
-
-        bytes_res
     }
 
     pub(crate) async fn make_json_post_request(
@@ -207,18 +254,11 @@ impl Client {
 
         self.ratelimit(site).await;
 
-        let (response, start) = self
-            .send_request(req, site)
+        self.send_request(req, site)
+            .await
+            .wrap_err("Failed to receive POST response")?
+            .bytes(url)
             .await
-            .wrap_err("Failed to receive POST response")?;
-
-        let status = response.status();
-        let bytes_res = Self::error_for_status(response, url).await;
-
-        let latency = start.elapsed();
-        ClientMetrics::observe(site, status, latency);
-
-        bytes_res
     }
 
     pub(crate) async fn error_for_status(
@@ -233,25 +273,37 @@ impl Client {
                 .wrap_err("Failed to extract response bytes")
                 .map_err(ClientError::Report),
             400 => Err(ClientError::BadRequest),
+            401 => Err(ClientError::Unauthorized),
             404 => Err(ClientError::NotFound),
             429 => Err(ClientError::Ratelimited),
             _ => Err(eyre!("Failed with status code {status} when requesting url {url}").into()),
         }
     }
 
-    async fn send_request(
+    pub(crate) async fn send_request(
         &self,
         req: Request<Body>,
         site: Site,
-    ) -> Result<(Response<Body>, Instant), HyperError> {
+    ) -> Result<PendingResponse<'_>, TransportError> {
+        // Fail fast instead of waiting for a site that is known to be down
+        let Some(permit) = self.breakers[site as usize].acquire() else {
+            return Err(TransportError::Unavailable(site));
+        };
+
         self.ratelimit(site).await;
 
         let start = Instant::now();
-        let response_fut = self.client.request(req);
+        let response_fut = self.transport.request(req, site);
 
         match response_fut.await {
-            Ok(res) => Ok((res, start)),
+            Ok(response) => Ok(PendingResponse {
+                response,
+                site,
+                start,
+                permit,
+            }),
             Err(err) => {
+                permit.finish(false);
                 ClientMetrics::internal_error(site);
 
                 Err(err)
@@ -259,3 +311,42 @@ impl Client {
         }
     }
 }
+
+/// A response whose body has not been received yet.
+///
+/// Dropping it without receiving the body counts as failure for the site's
+/// circuit breaker.
+pub(crate) struct PendingResponse<'c> {
+    response: Response<Body>,
+    site: Site,
+    start: Instant,
+    permit: BreakerPermit<'c>,
+}
+
+impl PendingResponse<'_> {
+    pub(crate) fn status(&self) -> StatusCode {
+        self.response.status()
+    }
+
+    /// Receive the body of a successful response and record the outcome.
+    ///
+    /// The circuit breaker is only notified once the body arrived so that
+    /// failures and timeouts while receiving it count too.
+    pub(crate) async fn bytes(self, url: &str) -> Result<Bytes, ClientError> {
+        let Self {
+            response,
+            site,
+            start,
+            permit,
+        } = self;
+
+        let status = response.status();
+        let bytes_res = Client::error_for_status(response, url).await;
+
+        // Client errors are the request's fault rather than the site's
+        permit.finish(!status.is_server_error() && (bytes_res.is_ok() || !status.is_success()));
+        ClientMetrics::observe(site, status, start.elapsed());
+
+        bytes_res
+    }
+}
@@ -4,6 +4,7 @@ extern crate eyre;
 #[macro_use]
 extern crate tracing;
 
+mod breaker;
 mod client;
 mod discord;
 mod error;
@@ -13,15 +14,24 @@ mod miss_analyzer;
 mod multipart;
 mod osekai;
 mod osu;
+mod osu_oauth;
 mod osustats;
 mod osutrack;
 mod osuworld;
 mod respektive;
 mod site;
 mod snipe;
+mod transport;
 mod twitch;
+mod webhook;
 
-use self::site::Site;
-pub use self::{client::Client, error::ClientError};
+pub use self::{
+    breaker::{CircuitState, SiteStatus},
+    client::Client,
+    error::ClientError,
+    site::Site,
+    transport::{FakeRequest, FakeServer, TransportMode},
+    webhook::{validate_webhook_url, WebhookUrlError},
+};
 
 static MY_USER_AGENT: &str = env!("CARGO_PKG_NAME");
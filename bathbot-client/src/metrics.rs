@@ -1,12 +1,19 @@
 use std::time::Duration;
 
 use http::StatusCode;
-use metrics::{counter, describe_counter, describe_histogram, histogram};
+use metrics::{counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram};
 
-use crate::site::Site;
+use crate::{
+    breaker::{CircuitState, SiteStatus},
+    site::Site,
+};
 
 const CLIENT_RESPONSE_TIME: &str = "client_response_time";
 const CLIENT_INTERNAL_ERRORS: &str = "client_internal_errors";
+const CLIENT_CIRCUIT_STATE: &str = "client_circuit_state";
+const CLIENT_CIRCUIT_REJECTIONS: &str = "client_circuit_rejections";
+const CLIENT_ERROR_RATE: &str = "client_error_rate";
+const CLIENT_LATENCY: &str = "client_latency";
 
 pub(crate) struct ClientMetrics;
 
@@ -21,6 +28,26 @@ impl ClientMetrics {
             CLIENT_INTERNAL_ERRORS,
             "Number of times an internal error occurred"
         );
+
+        describe_gauge!(
+            CLIENT_CIRCUIT_STATE,
+            "Circuit breaker state per site; 0 = closed, 1 = half-open, 2 = open"
+        );
+
+        describe_counter!(
+            CLIENT_CIRCUIT_REJECTIONS,
+            "Number of requests rejected by an open circuit"
+        );
+
+        describe_gauge!(
+            CLIENT_ERROR_RATE,
+            "Fraction of recent requests per site that failed"
+        );
+
+        describe_gauge!(
+            CLIENT_LATENCY,
+            "Average latency of recent successful requests per site in seconds"
+        );
     }
 
     pub(crate) fn observe(site: Site, status: StatusCode, latency: Duration) {
@@ -35,4 +62,25 @@ impl ClientMetrics {
     pub(crate) fn internal_error(site: Site) {
         counter!(CLIENT_INTERNAL_ERRORS, "site" => site.as_str()).increment(1);
     }
+
+    pub(crate) fn site_status(status: &SiteStatus) {
+        let site = status.site.as_str();
+
+        let state = match status.state {
+            CircuitState::Closed => 0.0,
+            CircuitState::HalfOpen => 1.0,
+            CircuitState::Open => 2.0,
+        };
+
+        gauge!(CLIENT_CIRCUIT_STATE, "site" => site).set(state);
+        gauge!(CLIENT_ERROR_RATE, "site" => site).set(status.error_rate as f64);
+
+        if let Some(latency) = status.latency {
+            gauge!(CLIENT_LATENCY, "site" => site).set(latency);
+        }
+    }
+
+    pub(crate) fn circuit_rejection(site: Site) {
+        counter!(CLIENT_CIRCUIT_REJECTIONS, "site" => site.as_str()).increment(1);
+    }
 }
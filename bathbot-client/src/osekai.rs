@@ -109,3 +109,52 @@ impl Client {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use http::{Method, StatusCode};
+
+    use super::*;
+    use crate::{ClientError, FakeServer};
+
+    const COMMENTS_URL: &str = "https://osekai.net/global/api/comment_system.php";
+
+    #[tokio::test]
+    async fn medal_comments() {
+        let server = FakeServer::new();
+
+        server.post(
+            COMMENTS_URL,
+            r#"[{
+                "ID": 1,
+                "PostText": "Click the circles",
+                "Parent": 0,
+                "UserID": 2,
+                "Username": "peppy",
+                "VoteSum": "7"
+            }]"#,
+        );
+
+        let comments = Client::fake(server).get_osekai_comments(42).await.unwrap();
+
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].user_id, 2);
+        assert_eq!(comments[0].vote_sum, 7);
+    }
+
+    #[tokio::test]
+    async fn medal_comments_not_found() {
+        let server = FakeServer::new();
+        server.respond_with(Method::POST, COMMENTS_URL, StatusCode::NOT_FOUND, "");
+
+        let err = Client::fake(server)
+            .get_osekai_comments(42)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<ClientError>(),
+            Some(ClientError::NotFound)
+        ));
+    }
+}
@@ -0,0 +1,153 @@
+use bathbot_model::{OsuFriend, OsuUserToken};
+use bathbot_util::constants::{OSU_API_V2, OSU_OAUTH_TOKEN};
+use eyre::WrapErr;
+use http::{
+    header::{AUTHORIZATION, USER_AGENT},
+    Method, Request,
+};
+use hyper::Body;
+use rosu_v2::prelude::{GameMode, Score};
+use serde::{Deserialize, Serialize};
+
+use crate::{client::PendingResponse, Client, ClientError, Site, MY_USER_AGENT};
+
+/// Version of the osu!api whose response format `rosu-v2` expects.
+const OSU_API_VERSION: &str = "20220705";
+
+impl Client {
+    /// Exchange the refresh token of an osu! user for a new token.
+    ///
+    /// If the refresh token is no longer valid, e.g. because the user revoked
+    /// it on the osu! website, [`ClientError::BadRequest`] or
+    /// [`ClientError::Unauthorized`] is returned.
+    pub async fn refresh_osu_token(
+        &self,
+        client_id: u64,
+        client_secret: &str,
+        refresh_token: &str,
+    ) -> Result<OsuUserToken, ClientError> {
+        let body = RefreshTokenBody {
+            client_id,
+            client_secret,
+            grant_type: "refresh_token",
+            refresh_token,
+        };
+
+        let json = serde_json::to_vec(&body).wrap_err("Failed to serialize refresh body")?;
+
+        let bytes = self
+            .make_json_post_request(OSU_OAUTH_TOKEN, Site::OsuUserApi, json)
+            .await?;
+
+        // Don't include the body in the error since it contains tokens
+        let token = serde_json::from_slice(&bytes).wrap_err("Failed to deserialize osu! token")?;
+
+        Ok(token)
+    }
+
+    /// Revoke the osu! token so that it can no longer be used.
+    pub async fn revoke_osu_token(&self, access_token: &str) -> Result<(), ClientError> {
+        let url = format!("{OSU_API_V2}oauth/tokens/current");
+
+        self.osu_user_response(Method::DELETE, &url, access_token)
+            .await?
+            .bytes(&url)
+            .await
+            .map(|_| ())
+    }
+
+    /// Requires the `friends.read` scope.
+    pub async fn get_osu_friends(&self, access_token: &str) -> Result<Vec<OsuFriend>, ClientError> {
+        let url = format!("{OSU_API_V2}friends");
+
+        let bytes = self
+            .osu_user_response(Method::GET, &url, access_token)
+            .await?
+            .bytes(&url)
+            .await?;
+
+        let friends = serde_json::from_slice(&bytes).wrap_err_with(|| {
+            let body = String::from_utf8_lossy(&bytes);
+
+            format!("Failed to deserialize osu! friends: {body}")
+        })?;
+
+        Ok(friends)
+    }
+
+    /// Scores of the user's friends on a map.
+    ///
+    /// Returns `None` if osu! refuses to show the leaderboard which is the case
+    /// for users without osu!supporter.
+    pub async fn get_osu_friend_scores(
+        &self,
+        access_token: &str,
+        map_id: u32,
+        mode: GameMode,
+        legacy_only: bool,
+    ) -> Result<Option<Vec<Score>>, ClientError> {
+        let url = format!(
+            "{OSU_API_V2}beatmaps/{map_id}/scores?type=friend&mode={mode}&legacy_only={}",
+            legacy_only as u8
+        );
+
+        let response = self
+            .osu_user_response(Method::GET, &url, access_token)
+            .await?;
+
+        let status = response.status();
+        let bytes_res = response.bytes(&url).await;
+
+        if matches!(status.as_u16(), 403 | 422) {
+            return Ok(None);
+        }
+
+        let bytes = bytes_res?;
+
+        let scores: FriendScores = serde_json::from_slice(&bytes).wrap_err_with(|| {
+            let body = String::from_utf8_lossy(&bytes);
+
+            format!("Failed to deserialize friend scores: {body}")
+        })?;
+
+        Ok(Some(scores.scores))
+    }
+
+    async fn osu_user_response(
+        &self,
+        method: Method,
+        url: &str,
+        access_token: &str,
+    ) -> Result<PendingResponse<'_>, ClientError> {
+        trace!("{method} request with osu! user token to url {url}");
+
+        let req = Request::builder()
+            .uri(url)
+            .method(method)
+            .header(USER_AGENT, MY_USER_AGENT)
+            .header(AUTHORIZATION, format!("Bearer {access_token}"))
+            .header("x-api-version", OSU_API_VERSION)
+            .body(Body::empty())
+            .wrap_err("Failed to build osu! user request")?;
+
+        let response = self
+            .send_request(req, Site::OsuUserApi)
+            .await
+            .wrap_err("Failed to receive osu! user response")?;
+
+        Ok(response)
+    }
+}
+
+#[derive(Serialize)]
+struct RefreshTokenBody<'a> {
+    client_id: u64,
+    client_secret: &'a str,
+    grant_type: &'static str,
+    refresh_token: &'a str,
+}
+
+#[derive(Deserialize)]
+struct FriendScores {
+    scores: Vec<Score>,
+}
@@ -122,3 +122,40 @@ impl Client {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FakeServer;
+
+    #[tokio::test]
+    async fn country_globals() {
+        let server = FakeServer::new();
+
+        server.post(
+            "https://osustats.ppy.sh/api/getScoreRanking",
+            r#"[{"userId":2,"count":"42","osu_user":{"userName":"peppy"}}]"#,
+        );
+
+        let params = OsuStatsPlayersArgs {
+            mode: GameMode::Osu,
+            country: None,
+            page: 1,
+            min_rank: 1,
+            max_rank: 50,
+        };
+
+        let players = Client::fake(server.clone())
+            .get_country_globals(&params)
+            .await
+            .unwrap();
+
+        assert_eq!(players.len(), 1);
+        assert_eq!(players[0].user_id, 2);
+        assert_eq!(players[0].count, 42);
+        assert_eq!(players[0].username.as_str(), "peppy");
+
+        let body = String::from_utf8_lossy(&server.requests()[0].body).into_owned();
+        assert!(body.contains("name=\"rankMax\"\r\n\r\n50\r\n"));
+    }
+}
@@ -50,7 +50,9 @@ impl Client {
 
         let start = Instant::now();
 
-        let response = self.client.request(req).await.map_err(|err| {
+        let response_fut = self.transport.request(req, Site::OsuTrack);
+
+        let response = response_fut.await.map_err(|err| {
             ClientMetrics::internal_error(Site::OsuTrack);
 
             Report::new(err).wrap_err("Failed to receive POST response")
@@ -15,12 +15,45 @@ pub enum Site {
     OsuReplay,
     OsuStats,
     OsuTrack,
+    OsuUserApi,
     OsuWorld,
     Respektive,
     Twitch,
+    Webhook,
 }
 
 impl Site {
+    /// All sites in the order of their discriminants
+    pub const ALL: [Self; 19] = [
+        Self::DiscordAttachment,
+        Self::Flags,
+        Self::Github,
+        Self::Huismetbenen,
+        Self::KittenRoleplay,
+        Self::MissAnalyzer,
+        Self::Osekai,
+        Self::OsuAvatar,
+        Self::OsuBadge,
+        Self::OsuMapFile,
+        Self::OsuMapsetCover,
+        Self::OsuReplay,
+        Self::OsuStats,
+        Self::OsuTrack,
+        Self::OsuUserApi,
+        Self::OsuWorld,
+        Self::Respektive,
+        Self::Twitch,
+        Self::Webhook,
+    ];
+
+    /// Whether repeated failures should stop requests to the site.
+    ///
+    /// Webhooks point to arbitrary endpoints so one failing webhook must not
+    /// block all others.
+    pub fn has_circuit_breaker(self) -> bool {
+        self != Self::Webhook
+    }
+
     pub fn as_str(self) -> &'static str {
         match self {
             Self::DiscordAttachment => "DiscordAttachment",
@@ -37,9 +70,11 @@ impl Site {
             Self::OsuReplay => "OsuReplay",
             Self::OsuStats => "OsuStats",
             Self::OsuTrack => "OsuTrack",
+            Self::OsuUserApi => "OsuUserApi",
             Self::OsuWorld => "OsuWorld",
             Self::Respektive => "Respektive",
             Self::Twitch => "Twitch",
+            Self::Webhook => "Webhook",
         }
     }
 }
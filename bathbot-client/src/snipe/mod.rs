@@ -394,3 +394,45 @@ impl Client {
         Ok(countries)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FakeServer;
+
+    #[tokio::test]
+    async fn country_ranking() {
+        let server = FakeServer::new();
+
+        server.get(
+            "https://api.huismetbenen.nl/rankings/be/count",
+            r#"[{
+                "username": "player",
+                "user_id": 3,
+                "average_pp": null,
+                "average_sr": 5.5,
+                "weighted_pp": 1234.5,
+                "count_total": 10
+            }]"#,
+        );
+
+        let players = Client::fake(server)
+            .get_snipe_country("BE", SnipeCountryListOrder::Count, GameMode::Osu)
+            .await
+            .unwrap();
+
+        assert_eq!(players.len(), 1);
+        assert_eq!(players[0].user_id, 3);
+        assert_eq!(players[0].count_first, 10);
+        assert!(players[0].avg_pp.is_none());
+    }
+
+    #[tokio::test]
+    async fn missing_response_fails() {
+        let players = Client::fake(FakeServer::new())
+            .get_snipe_country("BE", SnipeCountryListOrder::Count, GameMode::Osu)
+            .await;
+
+        assert!(players.is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use bytes::Bytes;
+use http::{Method, Request, Response, StatusCode};
+use hyper::{body, Body};
+
+use super::TransportError;
+
+/// In-process stand-in for all sites the client talks to.
+///
+/// Responses are registered for a method and url and requests are answered
+/// without any network access. Requests without a registered response fail.
+#[derive(Clone, Debug, Default)]
+pub struct FakeServer {
+    inner: Arc<Mutex<FakeServerInner>>,
+}
+
+#[derive(Debug, Default)]
+struct FakeServerInner {
+    responses: HashMap<(Method, Box<str>), FakeResponse>,
+    requests: Vec<FakeRequest>,
+}
+
+#[derive(Clone, Debug)]
+struct FakeResponse {
+    status: StatusCode,
+    body: Bytes,
+}
+
+/// A request that was received by a [`FakeServer`].
+#[derive(Clone, Debug)]
+pub struct FakeRequest {
+    pub method: Method,
+    pub url: Box<str>,
+    pub body: Bytes,
+}
+
+impl FakeServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Respond to GET requests on the url with status code 200 and the body.
+    pub fn get(&self, url: impl Into<Box<str>>, body: impl Into<Bytes>) -> &Self {
+        self.respond_with(Method::GET, url, StatusCode::OK, body)
+    }
+
+    /// Respond to POST requests on the url with status code 200 and the body.
+    pub fn post(&self, url: impl Into<Box<str>>, body: impl Into<Bytes>) -> &Self {
+        self.respond_with(Method::POST, url, StatusCode::OK, body)
+    }
+
+    /// Respond to requests with the given method on the url with the status
+    /// code and body.
+    pub fn respond_with(
+        &self,
+        method: Method,
+        url: impl Into<Box<str>>,
+        status: StatusCode,
+        body: impl Into<Bytes>,
+    ) -> &Self {
+        let response = FakeResponse {
+            status,
+            body: body.into(),
+        };
+
+        self.lock().responses.insert((method, url.into()), response);
+
+        self
+    }
+
+    /// All requests that were received so far in the order of arrival.
+    pub fn requests(&self) -> Vec<FakeRequest> {
+        self.lock().requests.clone()
+    }
+
+    pub(super) async fn respond(
+        &self,
+        req: Request<Body>,
+    ) -> Result<Response<Body>, TransportError> {
+        let (parts, req_body) = req.into_parts();
+        let req_body = body::to_bytes(req_body).await?;
+        let url = parts.uri.to_string().into_boxed_str();
+
+        let mut inner = self.lock();
+
+        inner.requests.push(FakeRequest {
+            method: parts.method.clone(),
+            url: url.clone(),
+            body: req_body,
+        });
+
+        let Some(response) = inner.responses.get(&(parts.method.clone(), url.clone())) else {
+            return Err(TransportError::Unexpected {
+                method: parts.method,
+                url,
+            });
+        };
+
+        let mut res = Response::new(Body::from(response.body.clone()));
+        *res.status_mut() = response.status;
+
+        Ok(res)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, FakeServerInner> {
+        self.inner.lock().unwrap()
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    borrow::Cow,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bytes::Bytes;
+use eyre::{Report, WrapErr};
+use http::{
+    header::CONTENT_TYPE,
+    request::Parts as RequestParts,
+    response::{Parts as ResponseParts, Response},
+    Method,
+};
+use hyper::Body;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::TransportError;
+use crate::site::Site;
+
+/// Multipart boundaries are random so they're replaced by this placeholder
+/// before hashing a request body.
+const BOUNDARY_PLACEHOLDER: &[u8] = b"BOUNDARY";
+
+/// JSON fields whose values are replaced before a response is recorded.
+const SECRET_FIELDS: &[&str] = &["access_token", "refresh_token", "id_token", "client_secret"];
+
+const REDACTED: &str = "REDACTED";
+
+/// Identifies a request through its method, url, and body.
+pub(super) struct FixtureKey {
+    method: Method,
+    url: Box<str>,
+    hash: String,
+}
+
+impl FixtureKey {
+    pub(super) fn new(parts: &RequestParts, body: &[u8]) -> Self {
+        let url = parts.uri.to_string().into_boxed_str();
+
+        let mut hasher = Sha256::new();
+        hasher.update(parts.method.as_str());
+        hasher.update(b" ");
+        hasher.update(url.as_bytes());
+        hasher.update(b"\n");
+
+        match multipart_boundary(parts) {
+            Some(boundary) => {
+                let mut rest = body;
+
+                while let Some(idx) = rest
+                    .windows(boundary.len())
+                    .position(|window| window == boundary)
+                {
+                    hasher.update(&rest[..idx]);
+                    hasher.update(BOUNDARY_PLACEHOLDER);
+                    rest = &rest[idx + boundary.len()..];
+                }
+
+                hasher.update(rest);
+            }
+            None => hasher.update(body),
+        }
+
+        Self {
+            method: parts.method.clone(),
+            url,
+            hash: hex::encode(&hasher.finalize()[..8]),
+        }
+    }
+
+    pub(super) fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Whether the request targets an OAuth token endpoint.
+    fn is_token_request(&self) -> bool {
+        self.url.contains("/oauth") && self.url.ends_with("/token")
+    }
+
+    fn path(&self, dir: &Path, site: Site) -> PathBuf {
+        dir.join(site.as_str()).join(format!("{}.json", self.hash))
+    }
+}
+
+fn multipart_boundary(parts: &RequestParts) -> Option<&[u8]> {
+    const PREFIX: &[u8] = b"multipart/form-data; boundary=";
+
+    parts
+        .headers
+        .get(CONTENT_TYPE)?
+        .as_bytes()
+        .strip_prefix(PREFIX)
+        .filter(|boundary| !boundary.is_empty())
+}
+
+/// Replaces the values of [`SECRET_FIELDS`] within a JSON body.
+///
+/// Returns `None` if the body belongs to a token endpoint but is not JSON
+/// since it can't be checked for credentials.
+fn redact<'b>(key: &FixtureKey, body: &'b [u8]) -> Option<Cow<'b, [u8]>> {
+    fn redact_value(value: &mut Value) -> bool {
+        match value {
+            Value::Object(map) => {
+                let mut redacted = false;
+
+                for (field, value) in map.iter_mut() {
+                    if SECRET_FIELDS.contains(&field.as_str()) {
+                        *value = Value::String(REDACTED.to_owned());
+                        redacted = true;
+                    } else {
+                        redacted |= redact_value(value);
+                    }
+                }
+
+                redacted
+            }
+            Value::Array(values) => values
+                .iter_mut()
+                .fold(false, |redacted, value| redact_value(value) | redacted),
+            _ => false,
+        }
+    }
+
+    let mut value = match serde_json::from_slice::<Value>(body) {
+        Ok(value) => value,
+        Err(_) if key.is_token_request() => return None,
+        Err(_) => return Some(Cow::Borrowed(body)),
+    };
+
+    if redact_value(&mut value) {
+        serde_json::to_vec(&value).ok().map(Cow::Owned)
+    } else {
+        Some(Cow::Borrowed(body))
+    }
+}
+
+/// A recorded response.
+///
+/// Bodies that are valid UTF-8 are stored as text so that fixture files can
+/// be adjusted manually, other bodies are stored base64 encoded. Credentials
+/// within JSON bodies are redacted.
+#[derive(Deserialize, Serialize)]
+pub(super) struct Fixture {
+    method: Box<str>,
+    url: Box<str>,
+    status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_type: Option<Box<str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<Box<str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_base64: Option<Box<str>>,
+}
+
+impl Fixture {
+    /// Returns `None` if the response should not be recorded because it might
+    /// contain credentials.
+    pub(super) fn new(key: &FixtureKey, parts: &ResponseParts, body: &[u8]) -> Option<Self> {
+        let body = redact(key, body)?;
+
+        let content_type = parts
+            .headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(Box::from);
+
+        let (body, body_base64) = match std::str::from_utf8(&body) {
+            Ok(body) => (Some(Box::from(body)), None),
+            Err(_) => (None, Some(STANDARD.encode(&body).into_boxed_str())),
+        };
+
+        Some(Self {
+            method: Box::from(key.method.as_str()),
+            url: key.url.clone(),
+            status: parts.status.as_u16(),
+            content_type,
+            body,
+            body_base64,
+        })
+    }
+
+    pub(super) async fn read(
+        dir: &Path,
+        site: Site,
+        key: &FixtureKey,
+    ) -> Result<Self, TransportError> {
+        let path = key.path(dir, site);
+
+        let bytes = match tokio::fs::read(&path).await {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                return Err(TransportError::Unexpected {
+                    method: key.method.clone(),
+                    url: key.url.clone(),
+                })
+            }
+            Err(err) => {
+                let wrap = format!("Failed to read fixture at {}", path.display());
+
+                return Err(TransportError::Fixture(Report::new(err).wrap_err(wrap)));
+            }
+        };
+
+        serde_json::from_slice(&bytes)
+            .wrap_err_with(|| format!("Failed to deserialize fixture at {}", path.display()))
+            .map_err(TransportError::Fixture)
+    }
+
+    pub(super) async fn write(&self, dir: &Path, site: Site, key: &FixtureKey) -> eyre::Result<()> {
+        let path = key.path(dir, site);
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .wrap_err("Failed to create fixture directory")?;
+        }
+
+        let bytes = serde_json::to_vec_pretty(self).wrap_err("Failed to serialize fixture")?;
+
+        tokio::fs::write(&path, bytes)
+            .await
+            .wrap_err_with(|| format!("Failed to write fixture at {}", path.display()))
+    }
+
+    pub(super) fn into_response(self) -> Result<Response<Body>, TransportError> {
+        let body = match (self.body, self.body_base64) {
+            (Some(body), _) => Bytes::from(body.into_boxed_bytes()),
+            (None, Some(body)) => STANDARD
+                .decode(body.as_bytes())
+                .map(Bytes::from)
+                .wrap_err("Failed to decode fixture body")
+                .map_err(TransportError::Fixture)?,
+            (None, None) => Bytes::new(),
+        };
+
+        let mut response = Response::builder().status(self.status);
+
+        if let Some(content_type) = self.content_type {
+            response = response.header(CONTENT_TYPE, content_type.as_ref());
+        }
+
+        response
+            .body(Body::from(body))
+            .wrap_err("Failed to build fixture response")
+            .map_err(TransportError::Fixture)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Request;
+
+    use super::*;
+    use crate::multipart::Multipart;
+
+    fn multipart_key() -> FixtureKey {
+        let mut form = Multipart::new();
+        form.push_text("key", "value");
+
+        let (parts, _) = Request::builder()
+            .method(Method::POST)
+            .uri("https://osekai.net/medals/api/medals.php")
+            .header(CONTENT_TYPE, form.content_type())
+            .body(())
+            .unwrap()
+            .into_parts();
+
+        FixtureKey::new(&parts, &form.build())
+    }
+
+    fn key(url: &str) -> FixtureKey {
+        let (parts, _) = Request::builder()
+            .method(Method::POST)
+            .uri(url)
+            .body(())
+            .unwrap()
+            .into_parts();
+
+        FixtureKey::new(&parts, &[])
+    }
+
+    #[test]
+    fn multipart_boundary_is_ignored() {
+        assert_eq!(multipart_key().hash, multipart_key().hash);
+    }
+
+    #[test]
+    fn redacts_tokens() {
+        let key = key("https://osu.ppy.sh/oauth/token");
+        let body = br#"{"token_type":"Bearer","expires_in":86400,"access_token":"secret","refresh_token":"secret"}"#;
+
+        let redacted = redact(&key, body).unwrap();
+        let value: Value = serde_json::from_slice(&redacted).unwrap();
+
+        assert_eq!(value["access_token"], REDACTED);
+        assert_eq!(value["refresh_token"], REDACTED);
+        assert_eq!(value["expires_in"], 86400);
+    }
+
+    #[test]
+    fn redacts_nested_tokens() {
+        let key = key("https://example.com/api");
+        let body = br#"{"data":[{"access_token":"secret"}]}"#;
+
+        let redacted = redact(&key, body).unwrap();
+
+        assert!(!redacted.windows(6).any(|window| window == b"secret"));
+    }
+
+    #[test]
+    fn keeps_other_bodies() {
+        let key = key("https://example.com/api");
+        let body = br#"{"user_id":2}"#;
+
+        assert!(matches!(redact(&key, body), Some(Cow::Borrowed(_))));
+        assert!(matches!(redact(&key, b"not json"), Some(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn skips_unchecked_token_bodies() {
+        assert!(redact(&key("https://osu.ppy.sh/oauth/token"), b"not json").is_none());
+        assert!(redact(&key("https://id.twitch.tv/oauth2/token"), b"not json").is_none());
+    }
+}
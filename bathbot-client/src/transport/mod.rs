@@ -0,0 +1,154 @@
+use std::path::PathBuf;
+
+use eyre::Report;
+use http::{Method, Request, Response};
+use hyper::{
+    body,
+    client::{Client as HyperClient, HttpConnector},
+    Body, Error as HyperError,
+};
+use hyper_rustls::HttpsConnectorBuilder;
+use thiserror::Error;
+
+pub use self::fake::{FakeRequest, FakeServer};
+use self::fixture::{Fixture, FixtureKey};
+use crate::{
+    client::{InnerClient, WebhookClient},
+    site::Site,
+    webhook::PublicResolver,
+};
+
+mod fake;
+mod fixture;
+
+/// Determines where the client's requests end up.
+#[derive(Clone, Debug, Default)]
+pub enum TransportMode {
+    /// Send requests to the actual sites.
+    #[default]
+    Live,
+    /// Send requests to the actual sites and store every response as fixture
+    /// file in the given directory.
+    ///
+    /// Only the method and url of requests are stored. Token fields within
+    /// JSON response bodies are redacted and token responses that aren't JSON
+    /// are not recorded at all.
+    Record(PathBuf),
+    /// Don't send any requests and respond with the fixture files of the given
+    /// directory instead.
+    Replay(PathBuf),
+}
+
+pub(crate) enum Transport {
+    Live(HttpClients),
+    Record { clients: HttpClients, dir: PathBuf },
+    Replay(PathBuf),
+    Fake(FakeServer),
+}
+
+#[derive(Debug, Error)]
+pub(crate) enum TransportError {
+    #[error(transparent)]
+    Http(#[from] HyperError),
+    #[error("no recorded response for {method} {url}")]
+    Unexpected { method: Method, url: Box<str> },
+    #[error(transparent)]
+    Fixture(Report),
+    #[error("{} is temporarily unavailable", .0.as_str())]
+    Unavailable(Site),
+}
+
+impl Transport {
+    pub(crate) fn new(mode: TransportMode) -> Self {
+        match mode {
+            TransportMode::Live => Self::Live(HttpClients::new()),
+            TransportMode::Record(dir) => Self::Record {
+                clients: HttpClients::new(),
+                dir,
+            },
+            TransportMode::Replay(dir) => Self::Replay(dir),
+        }
+    }
+
+    pub(crate) async fn request(
+        &self,
+        req: Request<Body>,
+        site: Site,
+    ) -> Result<Response<Body>, TransportError> {
+        match self {
+            Self::Live(clients) => clients
+                .request(req, site)
+                .await
+                .map_err(TransportError::Http),
+            Self::Record { clients, dir } => {
+                let (parts, req_body) = req.into_parts();
+                let req_body = body::to_bytes(req_body).await?;
+                let key = FixtureKey::new(&parts, &req_body);
+
+                let response = clients
+                    .request(Request::from_parts(parts, Body::from(req_body)), site)
+                    .await?;
+
+                let (parts, res_body) = response.into_parts();
+                let res_body = body::to_bytes(res_body).await?;
+
+                match Fixture::new(&key, &parts, &res_body) {
+                    Some(fixture) => {
+                        if let Err(err) = fixture.write(dir, site, &key).await {
+                            warn!(?err, url = key.url(), "Failed to record response");
+                        }
+                    }
+                    None => warn!(url = key.url(), "Skipped recording token response"),
+                }
+
+                Ok(Response::from_parts(parts, Body::from(res_body)))
+            }
+            Self::Replay(dir) => {
+                let (parts, req_body) = req.into_parts();
+                let req_body = body::to_bytes(req_body).await?;
+                let key = FixtureKey::new(&parts, &req_body);
+
+                Fixture::read(dir, site, &key).await?.into_response()
+            }
+            Self::Fake(server) => server.respond(req).await,
+        }
+    }
+}
+
+/// Webhook urls are provided by users so they get a separate client that
+/// refuses to connect to internal addresses.
+pub(crate) struct HttpClients {
+    general: InnerClient,
+    webhook: WebhookClient,
+}
+
+impl HttpClients {
+    fn new() -> Self {
+        let general = HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_or_http()
+            .enable_http1()
+            .build();
+
+        let mut http = HttpConnector::new_with_resolver(PublicResolver::new());
+        http.enforce_http(false);
+
+        let webhook = HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_only()
+            .enable_http1()
+            .wrap_connector(http);
+
+        Self {
+            general: HyperClient::builder().build(general),
+            webhook: HyperClient::builder().build(webhook),
+        }
+    }
+
+    async fn request(&self, req: Request<Body>, site: Site) -> Result<Response<Body>, HyperError> {
+        match site {
+            Site::Webhook => self.webhook.request(req).await,
+            _ => self.general.request(req).await,
+        }
+    }
+}
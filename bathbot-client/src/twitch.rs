@@ -18,7 +18,7 @@ use crate::{Client, ClientError, Site};
 impl Client {
     #[cfg(feature = "twitch")]
     pub(crate) async fn get_twitch_token(
-        client: &crate::client::InnerClient,
+        transport: &crate::transport::Transport,
         client_id: &str,
         token: &str,
     ) -> Result<bathbot_model::TwitchData> {
@@ -52,7 +52,7 @@ impl Client {
             .body(Body::from(content))
             .wrap_err("Failed to build POST request")?;
 
-        let response = client.request(req).await?;
+        let response = transport.request(req, Site::Twitch).await?;
         let bytes = Self::error_for_status(response, TWITCH_OAUTH).await?;
 
         let oauth_token = serde_json::from_slice(&bytes).wrap_err_with(|| {
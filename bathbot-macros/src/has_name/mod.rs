@@ -75,11 +75,40 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
         return Err(Error::new_spanned(ident, message));
     }
 
+    // Optional field to choose one of the author's linked accounts
+    let has_account_field = fields
+        .named
+        .iter()
+        .any(|field| matches!(field.ident, Some(ref ident) if ident == "account"));
+
     let path = quote!(crate::commands::osu::);
 
+    // Prefix commands pass `account=<name>` through the `name` field
+    let skip_account_name = has_account_field.then(|| {
+        quote! {
+            if let Some(name) = self.name.as_deref() {
+                if #path prefix_account(name).is_some() {
+                    return #path UserIdResult::None;
+                }
+            }
+        }
+    });
+
+    let account_fn = has_account_field.then(|| {
+        quote! {
+            fn account(&self) -> Option<&str> {
+                self.account
+                    .as_deref()
+                    .or_else(|| self.name.as_deref().and_then(#path prefix_account))
+            }
+        }
+    });
+
     let tokens = quote! {
         impl #generics #path HasName for #ident #generics {
             fn user_id(&self) -> #path UserIdResult {
+                #skip_account_name
+
                 if let Some(name) = self.name.as_deref() {
                     #path UserIdResult::Id(rosu_v2::request::UserId::Name(name.into()))
                 } else if let Some(id) = self.discord {
@@ -96,6 +125,8 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
                     #path UserIdResult::None
                 }
             }
+
+            #account_fn
         }
     };
 
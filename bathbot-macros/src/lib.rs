@@ -17,7 +17,11 @@ mod util;
 /// Make sure there is a function in scope with the signature
 /// `async fn slash_{lowercased_name}(Arc<Context>, InteractionCommand) ->
 /// Result<()>`
-#[proc_macro_derive(SlashCommand, attributes(bucket, command, flags))]
+///
+/// Instead of `#[command(help = "...")]`, the help can be given through
+/// `#[help(...)]` as any `&str` expression, e.g. a const shared with the prefix
+/// command.
+#[proc_macro_derive(SlashCommand, attributes(bucket, command, flags, group, help))]
 pub fn slash_command(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
 
@@ -102,7 +106,7 @@ pub fn derive(input: TokenStream) -> TokenStream {
 /// Available attributes:
 /// - `desc`: string (required)
 /// - `group`: `PrefixCommandGroup` (required)
-/// - `help`: string
+/// - `help`: string or `&str` expression such as a const
 /// - `usage`: string
 /// - `aliases`: list of strings
 /// - `example`: list of strings
@@ -134,7 +138,25 @@ pub fn msg_command(attr: TokenStream, input: TokenStream) -> TokenStream {
     let attrs = parse_macro_input!(attr as message::CommandAttrs);
     let fun = parse_macro_input!(input as message::CommandFun);
 
-    match message::impl_cmd(attrs, fun) {
+    match message::impl_cmd(attrs, fun, message::CommandKind::Message) {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Create a static UserCommand `{uppercased_name}_USER`.
+///
+/// The function that's denoted with this attribute must have the signature
+/// `async fn(InteractionCommand) -> Result<()>`.
+///
+/// Must specify `name = "..."` and optionally `dm_permission = ...` and
+/// `flags(...)`.
+#[proc_macro_attribute]
+pub fn user_command(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let attrs = parse_macro_input!(attr as message::CommandAttrs);
+    let fun = parse_macro_input!(input as message::CommandFun);
+
+    match message::impl_cmd(attrs, fun, message::CommandKind::User) {
         Ok(result) => result.into(),
         Err(err) => err.to_compile_error().into(),
     }
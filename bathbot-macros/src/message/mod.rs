@@ -7,7 +7,14 @@ pub use self::{attrs::CommandAttrs, command::CommandFun};
 mod attrs;
 mod command;
 
-pub fn impl_cmd(attrs: CommandAttrs, fun: CommandFun) -> Result<TokenStream> {
+/// The kind of context menu command.
+#[derive(Copy, Clone)]
+pub enum CommandKind {
+    Message,
+    User,
+}
+
+pub fn impl_cmd(attrs: CommandAttrs, fun: CommandFun, kind: CommandKind) -> Result<TokenStream> {
     let CommandAttrs {
         name: attr_name,
         dm_permission,
@@ -23,19 +30,35 @@ pub fn impl_cmd(attrs: CommandAttrs, fun: CommandFun) -> Result<TokenStream> {
 
     let cmd_name_str = cmd_name.to_string();
 
+    let (suffix, slice, ty, command_type) = match kind {
+        CommandKind::Message => (
+            "MSG",
+            quote!(__MSG_COMMANDS),
+            quote!(MessageCommand),
+            quote!(Message),
+        ),
+        CommandKind::User => (
+            "USER",
+            quote!(__USER_COMMANDS),
+            quote!(UserCommand),
+            quote!(User),
+        ),
+    };
+
     let static_name = format_ident!(
-        "{}_MSG",
+        "{}_{}",
         cmd_name_str.to_uppercase(),
+        suffix,
         span = cmd_name.span()
     );
 
     let create = format_ident!("create_{cmd_name}__");
     let exec = format_ident!("exec_{cmd_name}__");
 
-    let path = quote!(crate::core::commands::interaction::MessageCommand);
+    let path = quote!(crate::core::commands::interaction::#ty);
 
     let tokens = quote! {
-        #[linkme::distributed_slice(crate::core::commands::interaction::__MSG_COMMANDS)]
+        #[linkme::distributed_slice(crate::core::commands::interaction::#slice)]
         pub static #static_name: #path = #path {
             create: #create,
             exec: #exec,
@@ -53,7 +76,7 @@ pub fn impl_cmd(attrs: CommandAttrs, fun: CommandFun) -> Result<TokenStream> {
                 dm_permission: #dm_permission,
                 guild_id: None,
                 id: None,
-                kind: ::twilight_model::application::command::CommandType::Message,
+                kind: ::twilight_model::application::command::CommandType::#command_type,
                 name: #attr_name.to_owned(),
                 name_localizations: None,
                 nsfw: None,
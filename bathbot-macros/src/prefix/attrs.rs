@@ -1,4 +1,4 @@
-use syn::{Attribute, Error, Ident, LitStr, Meta, MetaList, Result, Token};
+use syn::{Attribute, Error, Expr, Ident, LitStr, Meta, MetaList, Result, Token};
 
 use crate::{
     bucket::Bucket,
@@ -9,7 +9,7 @@ use crate::{
 pub struct CommandAttrs {
     pub aliases: Box<[LitStr]>,
     pub desc: Option<LitStr>,
-    pub help: AsOption<LitStr>,
+    pub help: AsOption<Expr>,
     pub usage: AsOption<LitStr>,
     pub examples: Box<[LitStr]>,
     pub bucket: AsOption<Bucket>,
@@ -51,7 +51,7 @@ impl CommandAttrs {
                 "alias" | "aliases" => aliases = Some(parse_all(meta_list)?.into_boxed_slice()),
                 "example" | "examples" => examples = Some(parse_all(meta_list)?.into_boxed_slice()),
                 "desc" => desc = parse_one(meta_list)?,
-                "help" => help = parse_one(meta_list)?,
+                "help" => help = Some(meta_list.parse_args()?),
                 "usage" => usage = parse_one(meta_list)?,
                 "bucket" => bucket = Some(meta_list.parse_args()?),
                 "flags" => flags = Some(meta_list.parse_args()?),
@@ -1,11 +1,13 @@
 use proc_macro2::Span;
-use syn::{Attribute, Error, LitBool, LitStr, Result};
+use syn::{Attribute, Error, Expr, Ident, LitBool, LitStr, Result};
 
 use crate::{bucket::Bucket, flags::Flags, util::AsOption};
 
 pub(super) struct CommandAttrs {
     pub(super) bucket: AsOption<Bucket>,
     pub(super) flags: Flags,
+    pub(super) group: Option<Ident>,
+    pub(super) help: Option<Expr>,
     pub(super) name_lit: LitStr,
 }
 
@@ -13,6 +15,8 @@ impl CommandAttrs {
     pub fn parse_attrs(attrs: &[Attribute]) -> Result<Self> {
         let mut bucket = None;
         let mut flags = None;
+        let mut group = None;
+        let mut help = None;
         let mut name_lit = None;
 
         for attr in attrs {
@@ -20,6 +24,10 @@ impl CommandAttrs {
                 bucket = Some(attr.parse_args()?);
             } else if attr.path().is_ident("flags") {
                 flags = Some(attr.parse_args()?);
+            } else if attr.path().is_ident("group") {
+                group = Some(attr.parse_args()?);
+            } else if attr.path().is_ident("help") {
+                help = Some(attr.parse_args()?);
             } else if attr.path().is_ident("command") {
                 attr.parse_nested_meta(|meta| {
                     if meta.path.is_ident("name") {
@@ -43,6 +51,8 @@ impl CommandAttrs {
         Ok(Self {
             bucket: AsOption(bucket),
             flags: flags.unwrap_or_default(),
+            group,
+            help,
             name_lit: name_lit.ok_or_else(|| {
                 Error::new(Span::call_site(), "missing #[command(name = \"...\")]")
             })?,
@@ -15,6 +15,8 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
     let CommandAttrs {
         bucket,
         flags,
+        group,
+        help,
         name_lit,
     } = CommandAttrs::parse_attrs(&input.attrs)?;
 
@@ -25,13 +27,39 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
     let exec = format_ident!("{slash_cmd}__", span = name.span());
     let path = quote!(crate::core::commands::interaction::SlashCommand);
 
+    let group = match group {
+        Some(group) => quote!(Some(crate::core::commands::prefix::PrefixCommandGroup::#group)),
+        None => quote!(None),
+    };
+
+    // A separate `#[help(...)]` overrides the help of `#[command(...)]` and,
+    // unlike it, accepts any `&str` expression such as a const
+    let (create, create_fn) = match help {
+        Some(help) => {
+            let create = format_ident!("{slash_cmd}__create", span = name.span());
+
+            let create_fn = quote! {
+                fn #create() -> twilight_interactions::command::ApplicationCommandData {
+                    let mut data = #name::create_command();
+                    data.help = Some(String::from(#help));
+
+                    data
+                }
+            };
+
+            (quote!(#create), create_fn)
+        }
+        None => (quote!(#name::create_command), TokenStream::new()),
+    };
+
     let tokens = quote! {
         #[linkme::distributed_slice(crate::core::commands::interaction::__SLASH_COMMANDS)]
         pub static #static_name: #path = #path {
             bucket: #bucket,
-            create: #name::create_command,
+            create: #create,
             exec: #exec,
             flags: #flags,
+            group: #group,
             name: #name_lit,
             id: std::sync::OnceLock::new(),
         };
@@ -41,6 +69,8 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
         ) -> crate::core::commands::interaction::CommandResult {
             Box::pin(#slash_cmd(command))
         }
+
+        #create_fn
     };
 
     Ok(tokens)
@@ -1,3 +1,5 @@
+use std::mem;
+
 use serde::{Deserialize, Serialize};
 
 use super::{SettingValue, Value};
@@ -28,6 +30,36 @@ pub struct ScoreEmbedSettings {
 }
 
 impl ScoreEmbedSettings {
+    /// Check the invariants that the score embed builder upholds, i.e. there
+    /// is at least one value, values are sorted by their row, and each kind of
+    /// value appears at most once.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.values.is_empty() {
+            return Err("must contain at least one value");
+        }
+
+        if !self
+            .values
+            .windows(2)
+            .all(|window| window[0].y <= window[1].y)
+        {
+            return Err("values must be sorted by their row");
+        }
+
+        for (i, value) in self.values.iter().enumerate() {
+            let kind = mem::discriminant(&value.inner);
+
+            if self.values[..i]
+                .iter()
+                .any(|prev| mem::discriminant(&prev.inner) == kind)
+            {
+                return Err("values must not appear multiple times");
+            }
+        }
+
+        Ok(())
+    }
+
     fn default_show_artist() -> bool {
         true
     }
@@ -170,3 +202,65 @@ impl Default for SettingsButtons {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(inner: Value, y: u8) -> SettingValue {
+        SettingValue { inner, y }
+    }
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(ScoreEmbedSettings::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_empty_values() {
+        let settings = ScoreEmbedSettings {
+            values: Vec::new(),
+            ..Default::default()
+        };
+
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn rejects_unsorted_values() {
+        let settings = ScoreEmbedSettings {
+            values: vec![value(Value::Grade, 1), value(Value::Mods, 0)],
+            ..Default::default()
+        };
+
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn rejects_duplicate_values() {
+        let settings = ScoreEmbedSettings {
+            values: vec![
+                value(Value::Pp(Default::default()), 0),
+                value(Value::Mods, 0),
+                value(Value::Pp(Default::default()), 1),
+            ],
+            ..Default::default()
+        };
+
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn accepts_footer_values() {
+        let settings = ScoreEmbedSettings {
+            values: vec![
+                value(Value::Grade, 0),
+                value(Value::Length, 1),
+                value(Value::MapRankedDate, SettingValue::FOOTER_Y),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(settings.validate(), Ok(()));
+    }
+}
@@ -6,6 +6,7 @@ mod github;
 mod huismetbenen;
 mod kittenroleplay;
 mod osekai;
+mod osu_oauth;
 mod osu_stats;
 mod osu_world;
 mod osutrack;
@@ -25,6 +26,6 @@ pub mod rkyv_util;
 
 pub use self::{
     country_code::*, deser::ModeAsSeed, either::Either, games::*, github::*, huismetbenen::*,
-    kittenroleplay::*, osekai::*, osu_stats::*, osu_world::*, osutrack::RankAccPeaks,
+    kittenroleplay::*, osekai::*, osu_oauth::*, osu_stats::*, osu_world::*, osutrack::RankAccPeaks,
     ranking_entries::*, respektive::*, score_slim::*, twitch::*, user_stats::*,
 };
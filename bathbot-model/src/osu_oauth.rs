@@ -0,0 +1,47 @@
+use rosu_v2::prelude::GameMode;
+use serde::Deserialize;
+
+/// OAuth token of an osu! user.
+///
+/// Intentionally not `Debug` so the token can't end up in logs.
+#[derive(Clone, Deserialize)]
+pub struct OsuUserToken {
+    pub access_token: Box<str>,
+    pub refresh_token: Box<str>,
+    /// Seconds until the access token expires
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OsuFriend {
+    pub id: u32,
+    pub username: Box<str>,
+    pub country_code: Box<str>,
+    #[serde(default)]
+    pub statistics_rulesets: OsuFriendRulesets,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct OsuFriendRulesets {
+    osu: Option<OsuFriendStatistics>,
+    taiko: Option<OsuFriendStatistics>,
+    fruits: Option<OsuFriendStatistics>,
+    mania: Option<OsuFriendStatistics>,
+}
+
+impl OsuFriendRulesets {
+    pub fn get(&self, mode: GameMode) -> Option<&OsuFriendStatistics> {
+        match mode {
+            GameMode::Osu => self.osu.as_ref(),
+            GameMode::Taiko => self.taiko.as_ref(),
+            GameMode::Catch => self.fruits.as_ref(),
+            GameMode::Mania => self.mania.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OsuFriendStatistics {
+    pub pp: f32,
+    pub global_rank: Option<u32>,
+}
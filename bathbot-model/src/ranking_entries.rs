@@ -189,6 +189,9 @@ pub enum RankingKind {
     Commands {
         bootup_time: OffsetDateTime,
     },
+    Friends {
+        mode: GameMode,
+    },
     HlScores {
         scores: Vec<HlGameScore>,
         version: HlVersion,
@@ -250,6 +253,11 @@ impl RankingKind {
 
                 EmbedHeader::Author(AuthorBuilder::new(text))
             }
+            Self::Friends { mode } => {
+                let text = format!("Friend Ranking for osu!{mode}", mode = mode_str(*mode));
+
+                EmbedHeader::Author(AuthorBuilder::new(text))
+            }
             Self::HlScores { version, .. } => {
                 let text = match version {
                     HlVersion::ScorePp => "Server leaderboard for Higherlower (Score PP)",
@@ -0,0 +1,14 @@
+use rkyv::Archive;
+use rkyv_with::{ArchiveWith, DeserializeWith};
+use rosu_v2::model::score::LegacyScoreStatistics as RosuLegacyScoreStatistics;
+
+#[derive(Archive, ArchiveWith, DeserializeWith)]
+#[archive_with(from(RosuLegacyScoreStatistics))]
+pub struct LegacyScoreStatistics {
+    pub count_geki: u32,
+    pub count_300: u32,
+    pub count_katu: u32,
+    pub count_100: u32,
+    pub count_50: u32,
+    pub count_miss: u32,
+}
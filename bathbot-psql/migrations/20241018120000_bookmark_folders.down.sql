@@ -0,0 +1,4 @@
+ALTER TABLE user_map_bookmarks DROP COLUMN note;
+ALTER TABLE user_map_bookmarks DROP COLUMN folder_id;
+
+DROP TABLE user_map_bookmark_folders;
\ No newline at end of file
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS user_map_bookmark_folders (
+    folder_id   SERIAL4 PRIMARY KEY,
+    user_id     INT8 NOT NULL,
+    folder_name VARCHAR(32) NOT NULL,
+    is_public   BOOLEAN NOT NULL DEFAULT FALSE,
+    UNIQUE (user_id, folder_name)
+);
+
+ALTER TABLE user_map_bookmarks ADD COLUMN folder_id INT4 REFERENCES user_map_bookmark_folders (folder_id) ON DELETE SET NULL;
+ALTER TABLE user_map_bookmarks ADD COLUMN note VARCHAR(200);
\ No newline at end of file
@@ -0,0 +1,5 @@
+DROP INDEX osu_map_difficulty_nomod_stars_index;
+DROP INDEX osu_maps_mapset_index;
+DROP INDEX osu_mapsets_search_index;
+
+ALTER TABLE osu_mapsets DROP COLUMN search_vector;
\ No newline at end of file
@@ -0,0 +1,10 @@
+ALTER TABLE osu_mapsets ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', artist), 'A') ||
+    setweight(to_tsvector('simple', title), 'A') ||
+    setweight(to_tsvector('simple', source), 'B') ||
+    setweight(to_tsvector('simple', tags), 'C')
+) STORED;
+
+CREATE INDEX osu_mapsets_search_index ON osu_mapsets USING GIN (search_vector);
+CREATE INDEX osu_maps_mapset_index ON osu_maps (mapset_id);
+CREATE INDEX osu_map_difficulty_nomod_stars_index ON osu_map_difficulty (map_id, stars) WHERE mods = 0;
\ No newline at end of file
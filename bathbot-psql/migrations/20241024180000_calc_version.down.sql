@@ -0,0 +1,5 @@
+ALTER TABLE osu_map_difficulty DROP COLUMN calc_version;
+ALTER TABLE osu_map_difficulty_taiko DROP COLUMN calc_version;
+ALTER TABLE osu_map_difficulty_catch DROP COLUMN calc_version;
+ALTER TABLE osu_map_difficulty_mania DROP COLUMN calc_version;
+ALTER TABLE osu_scores_performance DROP COLUMN calc_version;
\ No newline at end of file
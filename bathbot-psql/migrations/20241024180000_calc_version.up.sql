@@ -0,0 +1,7 @@
+-- Existing rows were calculated by the current calculator version so they're
+-- backfilled with version 1 instead of being considered stale.
+ALTER TABLE osu_map_difficulty ADD COLUMN calc_version INT2 NOT NULL DEFAULT 1;
+ALTER TABLE osu_map_difficulty_taiko ADD COLUMN calc_version INT2 NOT NULL DEFAULT 1;
+ALTER TABLE osu_map_difficulty_catch ADD COLUMN calc_version INT2 NOT NULL DEFAULT 1;
+ALTER TABLE osu_map_difficulty_mania ADD COLUMN calc_version INT2 NOT NULL DEFAULT 1;
+ALTER TABLE osu_scores_performance ADD COLUMN calc_version INT2 NOT NULL DEFAULT 1;
\ No newline at end of file
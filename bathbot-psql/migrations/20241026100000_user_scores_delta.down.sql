@@ -0,0 +1,2 @@
+DROP TABLE materialized_view_refreshes;
+DROP TABLE user_scores_delta;
\ No newline at end of file
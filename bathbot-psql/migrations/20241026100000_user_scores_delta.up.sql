@@ -0,0 +1,13 @@
+CREATE TABLE user_scores_delta (
+    score_id INT8 NOT NULL,
+    PRIMARY KEY (score_id)
+);
+
+CREATE TABLE materialized_view_refreshes (
+    view_name    VARCHAR(64) NOT NULL,
+    refreshed_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (view_name)
+);
+
+INSERT INTO materialized_view_refreshes (view_name, refreshed_at)
+VALUES ('user_scores', NOW());
\ No newline at end of file
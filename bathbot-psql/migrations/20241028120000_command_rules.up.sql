@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN command_rules BYTEA;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN bucket_overrides;
\ No newline at end of file
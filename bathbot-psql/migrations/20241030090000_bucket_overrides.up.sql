@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN bucket_overrides BYTEA;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN auto_embeds;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN auto_embeds SMALLINT;
\ No newline at end of file
@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS scheduled_jobs (
+    job_id     SERIAL4 PRIMARY KEY,
+    guild_id   INT8 NOT NULL,
+    channel_id INT8 NOT NULL,
+    user_id    INT8 NOT NULL,
+    schedule   VARCHAR(64) NOT NULL,
+    command    VARCHAR(32) NOT NULL,
+    args       VARCHAR(256) NOT NULL,
+    next_run   TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS scheduled_jobs_guild_id_index ON scheduled_jobs (guild_id);
+CREATE INDEX IF NOT EXISTS scheduled_jobs_next_run_index ON scheduled_jobs (next_run);
\ No newline at end of file
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS guild_command_aliases;
+DROP TABLE IF EXISTS user_command_aliases;
\ No newline at end of file
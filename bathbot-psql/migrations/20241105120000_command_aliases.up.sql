@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS guild_command_aliases (
+    guild_id  INT8 NOT NULL,
+    name      VARCHAR(32) NOT NULL,
+    expansion VARCHAR(256) NOT NULL,
+    PRIMARY KEY (guild_id, name)
+);
+
+CREATE TABLE IF NOT EXISTS user_command_aliases (
+    user_id   INT8 NOT NULL,
+    name      VARCHAR(32) NOT NULL,
+    expansion VARCHAR(256) NOT NULL,
+    PRIMARY KEY (user_id, name)
+);
\ No newline at end of file
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS user_linked_accounts;
+
+ALTER TABLE user_configs DROP COLUMN mode_accounts;
\ No newline at end of file
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS user_linked_accounts (
+    discord_id INT8 NOT NULL,
+    osu_id     INT4 NOT NULL,
+    PRIMARY KEY (discord_id, osu_id)
+);
+
+ALTER TABLE user_configs ADD COLUMN mode_accounts INT4[];
\ No newline at end of file
@@ -0,0 +1,2 @@
+ALTER TABLE user_configs DROP COLUMN locale;
+ALTER TABLE guild_configs DROP COLUMN locale;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN score_embed, DROP COLUMN score_embed_channels;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN score_embed JSONB, ADD COLUMN score_embed_channels INT8[];
\ No newline at end of file
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS api_keys (
+    key_hash   VARCHAR(64) NOT NULL PRIMARY KEY,
+    name       VARCHAR(32) NOT NULL UNIQUE,
+    owner_id   INT8,
+    rate_limit INT4 NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
\ No newline at end of file
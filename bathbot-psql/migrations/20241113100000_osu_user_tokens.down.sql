@@ -0,0 +1 @@
+DROP TABLE IF EXISTS osu_user_tokens;
\ No newline at end of file
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS osu_user_tokens (
+    discord_id    INT8 NOT NULL PRIMARY KEY,
+    osu_id        INT4 NOT NULL,
+    access_token  BYTEA NOT NULL,
+    refresh_token BYTEA NOT NULL,
+    expires_at    TIMESTAMPTZ NOT NULL,
+    updated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
\ No newline at end of file
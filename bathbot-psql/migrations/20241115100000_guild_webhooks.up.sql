@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS guild_webhooks (
+    webhook_id SERIAL NOT NULL PRIMARY KEY,
+    guild_id   INT8 NOT NULL,
+    url        VARCHAR(512) NOT NULL,
+    secret     VARCHAR(64) NOT NULL,
+    -- bitflags of the subscribed events
+    events     INT2 NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (guild_id, url)
+);
+
+CREATE TABLE IF NOT EXISTS webhook_dead_letters (
+    letter_id  SERIAL NOT NULL PRIMARY KEY,
+    webhook_id INT4 NOT NULL REFERENCES guild_webhooks (webhook_id) ON DELETE CASCADE,
+    event      VARCHAR(32) NOT NULL,
+    payload    JSONB NOT NULL,
+    error      TEXT NOT NULL,
+    attempts   INT2 NOT NULL,
+    failed_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS webhook_dead_letters_webhook_idx ON webhook_dead_letters (webhook_id);
\ No newline at end of file
@@ -1,4 +1,6 @@
-use eyre::Result;
+use std::time::Duration;
+
+use eyre::{Result, WrapErr};
 use futures::{future::BoxFuture, stream::BoxStream};
 use sqlx::{
     pool::PoolConnection,
@@ -14,14 +16,36 @@ pub struct Database {
 }
 
 impl Database {
-    pub fn new(uri: &str) -> Result<Self> {
+    /// Create a new database client that refreshes materialized views
+    /// periodically with the given interval.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `refresh_interval` is zero.
+    pub fn new(uri: &str, refresh_interval: Duration) -> Result<Self> {
+        assert!(
+            !refresh_interval.is_zero(),
+            "refresh interval must be non-zero"
+        );
+
         let pool = PgPoolOptions::new().connect_lazy(uri)?;
 
-        tokio::spawn(refresh_materialized_views(pool.clone()));
+        let refresh_psql = Self { pool: pool.clone() };
+        tokio::spawn(refresh_materialized_views(refresh_psql, refresh_interval));
 
         Ok(Self { pool })
     }
 
+    /// Checks whether the database is reachable.
+    pub async fn ping(&self) -> Result<()> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .wrap_err("failed to ping database")?;
+
+        Ok(())
+    }
+
     /// Retrieves a connection from the pool.
     pub(crate) async fn acquire(&self) -> Result<PoolConnection<Postgres>, SqlxError> {
         self.pool.acquire().await
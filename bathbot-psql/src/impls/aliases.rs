@@ -0,0 +1,232 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::{model::aliases::DbCommandAlias, Database};
+
+impl Database {
+    /// Names of all guild and user aliases.
+    pub async fn select_command_alias_names(&self) -> Result<Vec<String>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  name AS "name!" 
+FROM 
+  guild_command_aliases 
+UNION 
+SELECT 
+  name 
+FROM 
+  user_command_aliases"#
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.name).collect())
+    }
+
+    pub async fn select_guild_command_aliases(
+        &self,
+        guild: Id<GuildMarker>,
+    ) -> Result<Vec<DbCommandAlias>> {
+        let query = sqlx::query_as!(
+            DbCommandAlias,
+            r#"
+SELECT 
+  name, 
+  expansion 
+FROM 
+  guild_command_aliases 
+WHERE 
+  guild_id = $1 
+ORDER BY 
+  name"#,
+            guild.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn select_user_command_aliases(
+        &self,
+        user: Id<UserMarker>,
+    ) -> Result<Vec<DbCommandAlias>> {
+        let query = sqlx::query_as!(
+            DbCommandAlias,
+            r#"
+SELECT 
+  name, 
+  expansion 
+FROM 
+  user_command_aliases 
+WHERE 
+  user_id = $1 
+ORDER BY 
+  name"#,
+            user.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    /// Aliases of the user take precedence over aliases of the guild.
+    pub async fn select_command_alias(
+        &self,
+        user: Id<UserMarker>,
+        guild: Option<Id<GuildMarker>>,
+        name: &str,
+    ) -> Result<Option<DbCommandAlias>> {
+        let query = sqlx::query_as!(
+            DbCommandAlias,
+            r#"
+SELECT 
+  name, 
+  expansion 
+FROM 
+  user_command_aliases 
+WHERE 
+  user_id = $1 
+  AND name = $2"#,
+            user.get() as i64,
+            name,
+        );
+
+        let alias = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        let Some(guild) = guild.filter(|_| alias.is_none()) else {
+            return Ok(alias);
+        };
+
+        let query = sqlx::query_as!(
+            DbCommandAlias,
+            r#"
+SELECT 
+  name, 
+  expansion 
+FROM 
+  guild_command_aliases 
+WHERE 
+  guild_id = $1 
+  AND name = $2"#,
+            guild.get() as i64,
+            name,
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")
+    }
+
+    pub async fn upsert_guild_command_alias(
+        &self,
+        guild: Id<GuildMarker>,
+        name: &str,
+        expansion: &str,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO guild_command_aliases (guild_id, name, expansion) 
+VALUES 
+  ($1, $2, $3) ON CONFLICT (guild_id, name) DO 
+UPDATE 
+SET 
+  expansion = $3"#,
+            guild.get() as i64,
+            name,
+            expansion,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn upsert_user_command_alias(
+        &self,
+        user: Id<UserMarker>,
+        name: &str,
+        expansion: &str,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO user_command_aliases (user_id, name, expansion) 
+VALUES 
+  ($1, $2, $3) ON CONFLICT (user_id, name) DO 
+UPDATE 
+SET 
+  expansion = $3"#,
+            user.get() as i64,
+            name,
+            expansion,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_guild_command_alias(
+        &self,
+        guild: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  guild_command_aliases 
+WHERE 
+  guild_id = $1 
+  AND name = $2"#,
+            guild.get() as i64,
+            name,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_user_command_alias(
+        &self,
+        user: Id<UserMarker>,
+        name: &str,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  user_command_aliases 
+WHERE 
+  user_id = $1 
+  AND name = $2"#,
+            user.get() as i64,
+            name,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
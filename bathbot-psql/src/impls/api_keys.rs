@@ -0,0 +1,92 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{model::api_keys::DbApiKey, Database};
+
+impl Database {
+    pub async fn select_api_key(&self, key_hash: &str) -> Result<Option<DbApiKey>> {
+        let query = sqlx::query_as!(
+            DbApiKey,
+            r#"
+SELECT 
+  name, 
+  owner_id, 
+  rate_limit, 
+  created_at 
+FROM 
+  api_keys 
+WHERE 
+  key_hash = $1"#,
+            key_hash
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")
+    }
+
+    pub async fn select_api_keys(&self) -> Result<Vec<DbApiKey>> {
+        let query = sqlx::query_as!(
+            DbApiKey,
+            r#"
+SELECT 
+  name, 
+  owner_id, 
+  rate_limit, 
+  created_at 
+FROM 
+  api_keys 
+ORDER BY 
+  created_at"#
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    /// Returns `false` if a key with the same name already exists
+    pub async fn insert_api_key(
+        &self,
+        key_hash: &str,
+        name: &str,
+        owner: Option<Id<UserMarker>>,
+        rate_limit: u32,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO api_keys (key_hash, name, owner_id, rate_limit) 
+VALUES 
+  ($1, $2, $3, $4) ON CONFLICT (name) DO NOTHING"#,
+            key_hash,
+            name,
+            owner.map(|owner| owner.get() as i64),
+            rate_limit as i32,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_api_key(&self, name: &str) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  api_keys 
+WHERE 
+  name = $1"#,
+            name
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -1,9 +1,10 @@
 use eyre::{Result, WrapErr};
 use futures::StreamExt;
+use sqlx::{Postgres, Transaction};
 use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
-    model::osu::MapBookmark,
+    model::osu::{BookmarkFolder, MapBookmark},
     util::{parse_genre, parse_language, parse_mode, parse_status},
     Database,
 };
@@ -14,6 +15,8 @@ impl Database {
             r#"
 SELECT 
   bookmarks.insert_date, 
+  bookmarks.note AS "note?", 
+  folders.folder_name AS "folder_name?", 
   maps.map_id, 
   maps.mapset_id, 
   maps.user_id AS mapper_id, 
@@ -42,12 +45,15 @@ FROM
   (
     SELECT 
       map_id, 
-      insert_date 
+      insert_date, 
+      folder_id, 
+      note 
     FROM 
       user_map_bookmarks 
     WHERE 
       user_id = $1
   ) AS bookmarks 
+  LEFT JOIN user_map_bookmark_folders AS folders ON bookmarks.folder_id = folders.folder_id 
   JOIN (
     SELECT 
       map_id, 
@@ -120,6 +126,8 @@ ORDER BY
                 genre: parse_genre(row.genre_id),
                 language: parse_language(row.language_id),
                 cover_url: row.cover.into_boxed_str(),
+                folder: row.folder_name.map(String::into_boxed_str),
+                note: row.note.map(String::into_boxed_str),
             };
 
             bookmarks.push(bookmark);
@@ -147,6 +155,8 @@ VALUES
     }
 
     pub async fn delete_user_bookmark(&self, user_id: Id<UserMarker>, map_id: u32) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
+
         let query = sqlx::query!(
             r#"
 DELETE FROM 
@@ -158,6 +168,161 @@ WHERE
             map_id as i32
         );
 
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Self::delete_empty_bookmark_folders(&mut tx, user_id).await?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    pub async fn select_user_bookmark_folders(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Vec<BookmarkFolder>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  folder_id, 
+  folder_name, 
+  is_public 
+FROM 
+  user_map_bookmark_folders 
+WHERE 
+  user_id = $1 
+ORDER BY 
+  folder_name"#,
+            user_id.get() as i64
+        );
+
+        let mut rows = query.fetch(self);
+        let mut folders = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+
+            folders.push(BookmarkFolder {
+                folder_id: row.folder_id as u32,
+                name: row.folder_name.into_boxed_str(),
+                public: row.is_public,
+            });
+        }
+
+        Ok(folders)
+    }
+
+    pub async fn update_user_bookmark_note(
+        &self,
+        user_id: Id<UserMarker>,
+        map_id: u32,
+        note: Option<&str>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  user_map_bookmarks 
+SET 
+  note = $3 
+WHERE 
+  user_id = $1 
+  AND map_id = $2"#,
+            user_id.get() as i64,
+            map_id as i32,
+            note,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Moves the bookmark into the given folder, creating the folder if
+    /// necessary, and removes all folders of the user that are left empty.
+    pub async fn update_user_bookmark_folder(
+        &self,
+        user_id: Id<UserMarker>,
+        map_id: u32,
+        folder: Option<&str>,
+    ) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
+
+        let folder_id = match folder {
+            Some(folder_name) => {
+                let query = sqlx::query!(
+                    r#"
+INSERT INTO user_map_bookmark_folders (user_id, folder_name) 
+VALUES 
+  ($1, $2) ON CONFLICT (user_id, folder_name) DO 
+UPDATE 
+SET 
+  folder_name = EXCLUDED.folder_name RETURNING folder_id"#,
+                    user_id.get() as i64,
+                    folder_name,
+                );
+
+                let row = query
+                    .fetch_one(&mut *tx)
+                    .await
+                    .wrap_err("Failed to fetch one")?;
+
+                Some(row.folder_id)
+            }
+            None => None,
+        };
+
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  user_map_bookmarks 
+SET 
+  folder_id = $3 
+WHERE 
+  user_id = $1 
+  AND map_id = $2"#,
+            user_id.get() as i64,
+            map_id as i32,
+            folder_id,
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Self::delete_empty_bookmark_folders(&mut tx, user_id).await?;
+
+        tx.commit().await.wrap_err("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    pub async fn update_user_bookmark_folder_visibility(
+        &self,
+        user_id: Id<UserMarker>,
+        folder_id: u32,
+        public: bool,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  user_map_bookmark_folders 
+SET 
+  is_public = $3 
+WHERE 
+  user_id = $1 
+  AND folder_id = $2"#,
+            user_id.get() as i64,
+            folder_id as i32,
+            public,
+        );
+
         query
             .execute(self)
             .await
@@ -165,4 +330,33 @@ WHERE
 
         Ok(())
     }
+
+    async fn delete_empty_bookmark_folders(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: Id<UserMarker>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  user_map_bookmark_folders AS folders 
+WHERE 
+  user_id = $1 
+  AND NOT EXISTS (
+    SELECT 
+      1 
+    FROM 
+      user_map_bookmarks AS bookmarks 
+    WHERE 
+      bookmarks.folder_id = folders.folder_id
+  )"#,
+            user_id.get() as i64,
+        );
+
+        query
+            .execute(&mut **tx)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
 }
@@ -1,7 +1,9 @@
 use std::{collections::HashMap, hash::BuildHasher};
 
+use bathbot_model::embed_builder::ScoreEmbedSettings;
 use eyre::{Result, WrapErr};
 use futures::StreamExt;
+use sqlx::types::Json;
 use twilight_model::id::{marker::GuildMarker, Id};
 
 use crate::{
@@ -30,7 +32,13 @@ SELECT
   render_button, 
   allow_custom_skins, 
   hide_medal_solution, 
-  score_data 
+  score_data, 
+  command_rules, 
+  bucket_overrides, 
+  auto_embeds, 
+  locale, 
+  score_embed as "score_embed: Json<ScoreEmbedSettings>", 
+  score_embed_channels 
 FROM 
   guild_configs"#
         );
@@ -63,6 +71,12 @@ FROM
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            command_rules,
+            bucket_overrides,
+            auto_embeds,
+            locale,
+            score_embed,
+            score_embed_channels,
         } = config;
 
         let authorities =
@@ -71,18 +85,50 @@ FROM
         let prefixes =
             rkyv::to_bytes::<_, 32>(prefixes).wrap_err("failed to serialize prefixes")?;
 
+        let command_rules = if command_rules.is_empty() {
+            None
+        } else {
+            let bytes = rkyv::to_bytes::<_, 256>(command_rules)
+                .wrap_err("failed to serialize command rules")?;
+
+            Some(bytes)
+        };
+
+        let bucket_overrides = if bucket_overrides.is_empty() {
+            None
+        } else {
+            let bytes = rkyv::to_bytes::<_, 64>(bucket_overrides)
+                .wrap_err("failed to serialize bucket overrides")?;
+
+            Some(bytes)
+        };
+
+        let score_embed_channels = if score_embed_channels.is_empty() {
+            None
+        } else {
+            let channels: Vec<_> = score_embed_channels
+                .iter()
+                .map(|channel| channel.get() as i64)
+                .collect();
+
+            Some(channels)
+        };
+
         let query = sqlx::query!(
             r#"
 INSERT INTO guild_configs (
   guild_id, authorities, prefixes, allow_songs, 
   retries, osu_track_limit, list_size, 
   render_button, allow_custom_skins, 
-  hide_medal_solution, score_data
+  hide_medal_solution, score_data, 
+  command_rules, bucket_overrides, 
+  auto_embeds, locale, score_embed, 
+  score_embed_channels
 ) 
 VALUES 
   (
     $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 
-    $11
+    $11, $12, $13, $14, $15, $16, $17
   ) ON CONFLICT (guild_id) DO 
 UPDATE 
 SET 
@@ -95,7 +141,13 @@ SET
   render_button = $8, 
   allow_custom_skins = $9, 
   hide_medal_solution = $10, 
-  score_data = $11"#,
+  score_data = $11, 
+  command_rules = $12, 
+  bucket_overrides = $13, 
+  auto_embeds = $14, 
+  locale = $15, 
+  score_embed = $16, 
+  score_embed_channels = $17"#,
             guild_id.get() as i64,
             &authorities as &[u8],
             &prefixes as &[u8],
@@ -107,6 +159,12 @@ SET
             *allow_custom_skins,
             hide_medal_solution.map(i16::from),
             score_data.map(i16::from),
+            command_rules.as_deref(),
+            bucket_overrides.as_deref(),
+            (!auto_embeds.is_empty()).then(|| i16::from(*auto_embeds)),
+            locale.map(i16::from),
+            score_embed.as_ref().map(Json) as Option<Json<_>>,
+            score_embed_channels.as_deref(),
         );
 
         query
@@ -2,12 +2,12 @@ use bathbot_model::embed_builder::ScoreEmbedSettings;
 use eyre::{Result, WrapErr};
 use futures::StreamExt;
 use rosu_v2::prelude::GameMode;
-use sqlx::types::Json;
+use sqlx::{types::Json, Executor, Postgres};
 use time::UtcOffset;
 use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
-    model::configs::{DbSkinEntry, DbUserConfig, OsuUserId, SkinEntry, UserConfig},
+    model::configs::{DbSkinEntry, DbUserConfig, Locale, OsuUserId, SkinEntry, UserConfig},
     Database,
 };
 
@@ -28,7 +28,9 @@ SELECT
   twitch_id, 
   timezone_seconds, 
   render_button, 
-  score_data 
+  score_data, 
+  mode_accounts, 
+  locale 
 FROM 
   user_configs 
 WHERE 
@@ -252,6 +254,21 @@ FROM
         user_id: Id<UserMarker>,
         config: &UserConfig<OsuUserId>,
     ) -> Result<()> {
+        Self::upsert_user_config_(self, user_id, config).await?;
+
+        debug!(user_id = user_id.get(), "Inserted UserConfig into DB");
+
+        Ok(())
+    }
+
+    async fn upsert_user_config_<'e, E>(
+        executor: E,
+        user_id: Id<UserMarker>,
+        config: &UserConfig<OsuUserId>,
+    ) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
         let UserConfig {
             list_size,
             score_embed,
@@ -262,6 +279,8 @@ FROM
             timezone,
             render_button,
             score_data,
+            mode_accounts,
+            locale,
         } = config;
 
         let query = sqlx::query!(
@@ -269,10 +288,14 @@ FROM
 INSERT INTO user_configs (
   discord_id, osu_id, gamemode, twitch_id, 
   retries, score_embed, list_size, 
-  timezone_seconds, render_button, score_data
+  timezone_seconds, render_button, score_data, 
+  mode_accounts, locale
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT (discord_id) DO 
+  (
+    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 
+    $11, $12
+  ) ON CONFLICT (discord_id) DO 
 UPDATE 
 SET 
   osu_id = $2, 
@@ -283,7 +306,9 @@ SET
   list_size = $7, 
   timezone_seconds = $8, 
   render_button = $9, 
-  score_data = $10"#,
+  score_data = $10, 
+  mode_accounts = $11, 
+  locale = $12"#,
             user_id.get() as i64,
             osu.map(|id| id as i32),
             mode.map(|mode| mode as i16) as Option<i16>,
@@ -294,15 +319,15 @@ SET
             timezone.map(UtcOffset::whole_seconds),
             *render_button,
             score_data.map(i16::from),
+            mode_accounts.to_db().as_deref(),
+            locale.map(i16::from),
         );
 
         query
-            .execute(self)
+            .execute(executor)
             .await
             .wrap_err("failed to execute query")?;
 
-        debug!(user_id = user_id.get(), "Inserted UserConfig into DB");
-
         Ok(())
     }
 
@@ -402,4 +427,150 @@ WHERE
 
         Ok(row_opt.and_then(|row| row.gamemode.map(|mode| GameMode::from(mode as u8))))
     }
+
+    pub async fn select_user_locale(&self, user_id: Id<UserMarker>) -> Result<Option<Locale>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  locale 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        let locale = row_opt
+            .and_then(|row| row.locale)
+            .map(Locale::try_from)
+            .and_then(Result::ok);
+
+        Ok(locale)
+    }
+
+    /// Linked accounts besides the primary one
+    pub async fn select_linked_osu_ids(&self, user_id: Id<UserMarker>) -> Result<Vec<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  osu_id 
+FROM 
+  user_linked_accounts 
+WHERE 
+  discord_id = $1 
+ORDER BY 
+  osu_id"#,
+            user_id.get() as i64
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.osu_id as u32).collect())
+    }
+
+    /// Considers both the primary and all other linked accounts.
+    ///
+    /// Be sure wildcards (_, %) are escaped as required!
+    pub async fn select_linked_osu_id_by_name(
+        &self,
+        user_id: Id<UserMarker>,
+        username: &str,
+    ) -> Result<Option<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  user_id 
+FROM 
+  osu_user_names 
+WHERE 
+  username ILIKE $2 
+  AND user_id IN (
+    SELECT 
+      osu_id 
+    FROM 
+      user_configs 
+    WHERE 
+      discord_id = $1 
+      AND osu_id IS NOT NULL 
+    UNION 
+    SELECT 
+      osu_id 
+    FROM 
+      user_linked_accounts 
+    WHERE 
+      discord_id = $1
+  )"#,
+            user_id.get() as i64,
+            username
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| row.user_id as u32))
+    }
+
+    /// Store the config and update the linked accounts besides the primary
+    /// one within a single transaction.
+    pub async fn upsert_user_config_with_linked(
+        &self,
+        user_id: Id<UserMarker>,
+        config: &UserConfig<OsuUserId>,
+        unlink: Option<u32>,
+        link: Option<u32>,
+    ) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
+
+        if let Some(osu_id) = unlink {
+            let query = sqlx::query!(
+                r#"
+DELETE FROM 
+  user_linked_accounts 
+WHERE 
+  discord_id = $1 
+  AND osu_id = $2"#,
+                user_id.get() as i64,
+                osu_id as i32
+            );
+
+            query
+                .execute(&mut *tx)
+                .await
+                .wrap_err("failed to execute delete query")?;
+        }
+
+        if let Some(osu_id) = link {
+            let query = sqlx::query!(
+                r#"
+INSERT INTO user_linked_accounts (discord_id, osu_id) 
+VALUES 
+  ($1, $2) ON CONFLICT (discord_id, osu_id) DO NOTHING"#,
+                user_id.get() as i64,
+                osu_id as i32
+            );
+
+            query
+                .execute(&mut *tx)
+                .await
+                .wrap_err("failed to execute insert query")?;
+        }
+
+        Self::upsert_user_config_(&mut *tx, user_id, config).await?;
+
+        tx.commit().await.wrap_err("failed to commit transaction")?;
+
+        debug!(user_id = user_id.get(), "Inserted UserConfig into DB");
+
+        Ok(())
+    }
 }
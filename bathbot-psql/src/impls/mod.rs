@@ -1,5 +1,11 @@
+mod aliases;
+mod api_keys;
 mod bookmarks;
 mod configs;
 mod games;
 mod osu;
+mod osu_tokens;
+mod privacy;
+mod schedule;
 mod tracked_streams;
+mod webhooks;
@@ -13,6 +13,7 @@ use crate::{
     model::osu::{
         DbBeatmap, DbBeatmapset, DbCatchDifficultyAttributes, DbManiaDifficultyAttributes,
         DbMapFilename, DbOsuDifficultyAttributes, DbTaikoDifficultyAttributes, MapVersion,
+        PP_CALC_VERSION,
     },
     Database,
 };
@@ -256,9 +257,11 @@ FROM
   osu_map_difficulty 
 WHERE 
   map_id = $1 
-  AND mods = $2"#,
+  AND mods = $2 
+  AND calc_version >= $3"#,
                 map_id as i32,
-                mods as i32
+                mods as i32,
+                PP_CALC_VERSION
             )
             .fetch_optional(self)
             .await
@@ -281,9 +284,11 @@ FROM
   osu_map_difficulty_taiko 
 WHERE 
   map_id = $1 
-  AND mods = $2"#,
+  AND mods = $2 
+  AND calc_version >= $3"#,
                 map_id as i32,
-                mods as i32
+                mods as i32,
+                PP_CALC_VERSION
             )
             .fetch_optional(self)
             .await
@@ -304,9 +309,11 @@ FROM
   osu_map_difficulty_catch 
 WHERE 
   map_id = $1 
-  AND mods = $2"#,
+  AND mods = $2 
+  AND calc_version >= $3"#,
                 map_id as i32,
-                mods as i32
+                mods as i32,
+                PP_CALC_VERSION
             )
             .fetch_optional(self)
             .await
@@ -326,9 +333,11 @@ FROM
   osu_map_difficulty_mania 
 WHERE 
   map_id = $1 
-  AND mods = $2"#,
+  AND mods = $2 
+  AND calc_version >= $3"#,
                 map_id as i32,
-                mods as i32
+                mods as i32,
+                PP_CALC_VERSION
             )
             .fetch_optional(self)
             .await
@@ -616,12 +625,12 @@ INSERT INTO osu_map_difficulty (
   map_id, mods, aim, speed, flashlight, 
   slider_factor, speed_note_count, 
   ar, od, hp, n_circles, n_sliders, n_spinners, 
-  stars, max_combo
+  stars, max_combo, calc_version
 ) 
 VALUES 
   (
     $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 
-    $11, $12, $13, $14, $15
+    $11, $12, $13, $14, $15, $16
   ) ON CONFLICT (map_id, mods) DO 
 UPDATE 
 SET 
@@ -637,7 +646,8 @@ SET
   n_sliders = $12, 
   n_spinners = $13, 
   stars = $14, 
-  max_combo = $15"#,
+  max_combo = $15, 
+  calc_version = $16"#,
                 map_id as i32,
                 mods as i32,
                 aim,
@@ -652,7 +662,8 @@ SET
                 *n_sliders as i32,
                 *n_spinners as i32,
                 stars,
-                *max_combo as i32
+                *max_combo as i32,
+                PP_CALC_VERSION
             ),
             DifficultyAttributes::Taiko(TaikoDifficultyAttributes {
                 stamina,
@@ -667,10 +678,11 @@ SET
                 r#"
 INSERT INTO osu_map_difficulty_taiko (
   map_id, mods, stamina, rhythm, color, 
-  peak, hit_window, stars, max_combo, is_convert
+  peak, hit_window, stars, max_combo, is_convert, 
+  calc_version
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT (map_id, mods) DO 
+  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) ON CONFLICT (map_id, mods) DO 
 UPDATE 
 SET 
   stamina = $3, 
@@ -680,7 +692,8 @@ SET
   hit_window = $7, 
   stars = $8, 
   max_combo = $9, 
-  is_convert = $10"#,
+  is_convert = $10, 
+  calc_version = $11"#,
                 map_id as i32,
                 mods as i32,
                 stamina,
@@ -691,6 +704,7 @@ SET
                 stars,
                 *max_combo as i32,
                 is_convert,
+                PP_CALC_VERSION,
             ),
             DifficultyAttributes::Catch(CatchDifficultyAttributes {
                 stars,
@@ -703,10 +717,10 @@ SET
                 r#"
 INSERT INTO osu_map_difficulty_catch (
   map_id, mods, stars, ar, n_fruits, n_droplets, 
-  n_tiny_droplets, is_convert
+  n_tiny_droplets, is_convert, calc_version
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8) ON CONFLICT (map_id, mods) DO 
+  ($1, $2, $3, $4, $5, $6, $7, $8, $9) ON CONFLICT (map_id, mods) DO 
 UPDATE 
 SET 
   stars = $3, 
@@ -714,7 +728,8 @@ SET
   n_fruits = $5, 
   n_droplets = $6, 
   n_tiny_droplets = $7, 
-  is_convert = $8"#,
+  is_convert = $8, 
+  calc_version = $9"#,
                 map_id as i32,
                 mods as i32,
                 stars,
@@ -723,6 +738,7 @@ SET
                 *n_droplets as i32,
                 *n_tiny_droplets as i32,
                 is_convert,
+                PP_CALC_VERSION,
             ),
             DifficultyAttributes::Mania(ManiaDifficultyAttributes {
                 stars,
@@ -734,17 +750,18 @@ SET
                 r#"
 INSERT INTO osu_map_difficulty_mania (
   map_id, mods, stars, hit_window, n_objects, 
-  max_combo, is_convert
+  max_combo, is_convert, calc_version
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7) ON CONFLICT (map_id, mods) DO 
+  ($1, $2, $3, $4, $5, $6, $7, $8) ON CONFLICT (map_id, mods) DO 
 UPDATE 
 SET 
   stars = $3, 
   hit_window = $4, 
   n_objects = $5, 
   max_combo = $6, 
-  is_convert = $7"#,
+  is_convert = $7, 
+  calc_version = $8"#,
                 map_id as i32,
                 mods as i32,
                 stars,
@@ -752,6 +769,7 @@ SET
                 *n_objects as i32,
                 *max_combo as i32,
                 is_convert,
+                PP_CALC_VERSION,
             ),
         };
 
@@ -1,9 +1,11 @@
 use eyre::{Result, WrapErr};
+use futures::StreamExt;
 use rosu_v2::prelude::{Beatmapset, BeatmapsetExtended};
 use sqlx::{Executor, Postgres};
 
 use crate::{
-    model::osu::{ArtistTitle, DbBeatmapset},
+    model::osu::{ArtistTitle, DbBeatmapset, MapsetSearchEntry, MapsetSearchParams},
+    util::{parse_mode, parse_status},
     Database,
 };
 
@@ -55,6 +57,189 @@ WHERE
             .wrap_err("failed to fetch optional")
     }
 
+    /// Search through all stored mapsets.
+    ///
+    /// Mapsets are ordered by full-text relevance and only mapsets that have
+    /// at least one map matching all map-specific filters are included.
+    pub async fn search_mapsets(
+        &self,
+        params: &MapsetSearchParams<'_>,
+    ) -> Result<Vec<MapsetSearchEntry>> {
+        let stars = params.stars.inclusive();
+        let ar = params.ar.inclusive();
+        let cs = params.cs.inclusive();
+        let hp = params.hp.inclusive();
+        let od = params.od.inclusive();
+        let bpm = params.bpm.inclusive();
+        let length = params.length.inclusive();
+        let keys = params.keys.inclusive();
+
+        let statuses = params.statuses.map(|statuses| {
+            statuses
+                .iter()
+                .map(|&status| status as i16)
+                .collect::<Vec<_>>()
+        });
+
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  mapsets.mapset_id, 
+  mapsets.user_id, 
+  mapsets.artist, 
+  mapsets.title, 
+  mapsets.creator, 
+  mapsets.rank_status, 
+  mapsets.ranked_date, 
+  mapsets.bpm, 
+  maps.map_count AS "map_count!", 
+  maps.modes AS "modes!", 
+  maps.max_stars, 
+  COALESCE(
+    ts_rank(
+      mapsets.search_vector, 
+      plainto_tsquery('simple', $1)
+    ), 
+    0
+  ) AS "relevance!" 
+FROM 
+  osu_mapsets AS mapsets 
+  JOIN (
+    SELECT 
+      maps.mapset_id, 
+      COUNT(*) AS map_count, 
+      ARRAY_AGG(DISTINCT maps.gamemode) AS modes, 
+      MAX(
+        COALESCE(
+          osu.stars, taiko.stars, catch.stars, 
+          mania.stars
+        )
+      ):: FLOAT4 AS max_stars 
+    FROM 
+      osu_maps AS maps 
+      LEFT JOIN osu_map_difficulty AS osu ON maps.gamemode = 0 
+      AND osu.map_id = maps.map_id 
+      AND osu.mods = 0 
+      LEFT JOIN osu_map_difficulty_taiko AS taiko ON maps.gamemode = 1 
+      AND taiko.map_id = maps.map_id 
+      AND taiko.mods = 0 
+      LEFT JOIN osu_map_difficulty_catch AS catch ON maps.gamemode = 2 
+      AND catch.map_id = maps.map_id 
+      AND catch.mods = 0 
+      LEFT JOIN osu_map_difficulty_mania AS mania ON maps.gamemode = 3 
+      AND mania.map_id = maps.map_id 
+      AND mania.mods = 0 
+    WHERE 
+      ($2 :: INT2 IS NULL OR maps.gamemode = $2) 
+      AND (
+        $3 :: FLOAT4 IS NULL 
+        OR COALESCE(
+          osu.stars, taiko.stars, catch.stars, 
+          mania.stars
+        ) >= $3
+      ) 
+      AND (
+        $4 :: FLOAT4 IS NULL 
+        OR COALESCE(
+          osu.stars, taiko.stars, catch.stars, 
+          mania.stars
+        ) <= $4
+      ) 
+      AND ($5 :: FLOAT4 IS NULL OR maps.ar >= $5) 
+      AND ($6 :: FLOAT4 IS NULL OR maps.ar <= $6) 
+      AND ($7 :: FLOAT4 IS NULL OR maps.cs >= $7) 
+      AND ($8 :: FLOAT4 IS NULL OR maps.cs <= $8) 
+      AND ($9 :: FLOAT4 IS NULL OR maps.hp >= $9) 
+      AND ($10 :: FLOAT4 IS NULL OR maps.hp <= $10) 
+      AND ($11 :: FLOAT4 IS NULL OR maps.od >= $11) 
+      AND ($12 :: FLOAT4 IS NULL OR maps.od <= $12) 
+      AND ($13 :: FLOAT4 IS NULL OR maps.bpm >= $13) 
+      AND ($14 :: FLOAT4 IS NULL OR maps.bpm <= $14) 
+      AND ($15 :: FLOAT4 IS NULL OR maps.seconds_drain >= $15) 
+      AND ($16 :: FLOAT4 IS NULL OR maps.seconds_drain <= $16) 
+      AND (
+        $17 :: FLOAT4 IS NULL 
+        OR (maps.gamemode = 3 AND maps.cs >= $17)
+      ) 
+      AND (
+        $18 :: FLOAT4 IS NULL 
+        OR (maps.gamemode = 3 AND maps.cs <= $18)
+      ) 
+    GROUP BY 
+      maps.mapset_id
+  ) AS maps ON mapsets.mapset_id = maps.mapset_id 
+WHERE 
+  (
+    $1 :: TEXT IS NULL 
+    OR mapsets.search_vector @@ plainto_tsquery('simple', $1)
+  ) 
+  AND ($19 :: INT2[] IS NULL OR mapsets.rank_status = ANY($19)) 
+  AND ($20 :: INT2 IS NULL OR mapsets.genre_id = $20) 
+  AND ($21 :: INT2 IS NULL OR mapsets.language_id = $21) 
+  AND ($22 :: BOOL IS NULL OR mapsets.video = $22) 
+  AND ($23 :: BOOL IS NULL OR mapsets.storyboard = $23) 
+  AND ($24 :: TEXT IS NULL OR LOWER(mapsets.artist) = $24) 
+  AND ($25 :: TEXT IS NULL OR LOWER(mapsets.title) = $25) 
+  AND ($26 :: TEXT IS NULL OR LOWER(mapsets.creator) = $26) 
+ORDER BY 
+  "relevance!" DESC, 
+  mapsets.ranked_date DESC NULLS LAST 
+LIMIT 
+  $27"#,
+            params.query,
+            params.mode.map(|mode| mode as i16),
+            stars.0,
+            stars.1,
+            ar.0,
+            ar.1,
+            cs.0,
+            cs.1,
+            hp.0,
+            hp.1,
+            od.0,
+            od.1,
+            bpm.0,
+            bpm.1,
+            length.0,
+            length.1,
+            keys.0,
+            keys.1,
+            statuses.as_deref(),
+            params.genre.map(|genre| genre as i16),
+            params.language.map(|language| language as i16),
+            params.video,
+            params.storyboard,
+            params.artist,
+            params.title,
+            params.creator,
+            params.limit as i64,
+        );
+
+        let mut rows = query.fetch(self);
+        let mut entries = Vec::with_capacity(params.limit.min(100));
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+
+            entries.push(MapsetSearchEntry {
+                mapset_id: row.mapset_id as u32,
+                creator_id: row.user_id as u32,
+                artist: row.artist.into_boxed_str(),
+                title: row.title.into_boxed_str(),
+                creator_name: row.creator.into_boxed_str(),
+                status: parse_status(row.rank_status),
+                ranked_date: row.ranked_date,
+                bpm: row.bpm,
+                map_count: row.map_count as usize,
+                modes: row.modes.into_iter().map(parse_mode).collect(),
+                max_stars: row.max_stars,
+                relevance: row.relevance,
+            });
+        }
+
+        Ok(entries)
+    }
+
     pub async fn upsert_beatmapset(&self, mapset: &BeatmapsetExtended) -> Result<()> {
         let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
 
@@ -2,6 +2,7 @@ pub mod map;
 pub mod mapset;
 pub mod name;
 pub mod rank_pp;
+pub mod recalc;
 pub mod render;
 pub mod score;
 pub mod tracked_users;
@@ -0,0 +1,240 @@
+use eyre::{Result, WrapErr};
+use futures::StreamExt;
+
+use crate::{
+    model::osu::{DbScore, DbScoreAny, DbStaleDifficulty, StaleCounts, StaleDifficulty},
+    Database,
+};
+
+impl Database {
+    pub async fn count_stale_pp_rows(&self, version: i16) -> Result<StaleCounts> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  (
+    SELECT 
+      COUNT(*) 
+    FROM 
+      osu_map_difficulty 
+    WHERE 
+      calc_version < $1
+  ) + (
+    SELECT 
+      COUNT(*) 
+    FROM 
+      osu_map_difficulty_taiko 
+    WHERE 
+      calc_version < $1
+  ) + (
+    SELECT 
+      COUNT(*) 
+    FROM 
+      osu_map_difficulty_catch 
+    WHERE 
+      calc_version < $1
+  ) + (
+    SELECT 
+      COUNT(*) 
+    FROM 
+      osu_map_difficulty_mania 
+    WHERE 
+      calc_version < $1
+  ) AS "difficulties!", 
+  (
+    SELECT 
+      COUNT(*) 
+    FROM 
+      osu_scores_performance 
+    WHERE 
+      calc_version < $1
+  ) AS "scores!""#,
+            version
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("Failed to fetch stale counts")?;
+
+        Ok(StaleCounts {
+            difficulties: row.difficulties as usize,
+            scores: row.scores as usize,
+        })
+    }
+
+    /// Select all stale difficulty attributes of the next `map_count` maps
+    /// whose map id is larger than `after_map_id`.
+    pub async fn select_stale_difficulties(
+        &self,
+        version: i16,
+        after_map_id: u32,
+        map_count: usize,
+    ) -> Result<Vec<StaleDifficulty>> {
+        let query = sqlx::query_as!(
+            DbStaleDifficulty,
+            r#"
+WITH stale AS (
+  SELECT 
+    map_id, 
+    mods, 
+    0 :: INT2 AS gamemode 
+  FROM 
+    osu_map_difficulty 
+  WHERE 
+    calc_version < $1 
+    AND map_id > $2 
+  UNION ALL 
+  SELECT 
+    map_id, 
+    mods, 
+    1 :: INT2 AS gamemode 
+  FROM 
+    osu_map_difficulty_taiko 
+  WHERE 
+    calc_version < $1 
+    AND map_id > $2 
+  UNION ALL 
+  SELECT 
+    map_id, 
+    mods, 
+    2 :: INT2 AS gamemode 
+  FROM 
+    osu_map_difficulty_catch 
+  WHERE 
+    calc_version < $1 
+    AND map_id > $2 
+  UNION ALL 
+  SELECT 
+    map_id, 
+    mods, 
+    3 :: INT2 AS gamemode 
+  FROM 
+    osu_map_difficulty_mania 
+  WHERE 
+    calc_version < $1 
+    AND map_id > $2
+), 
+maps AS (
+  SELECT 
+    DISTINCT map_id 
+  FROM 
+    stale 
+  ORDER BY 
+    map_id 
+  LIMIT 
+    $3
+) 
+SELECT 
+  stale.map_id AS "map_id!", 
+  stale.mods AS "mods!", 
+  stale.gamemode AS "gamemode!" 
+FROM 
+  stale 
+  JOIN maps ON stale.map_id = maps.map_id 
+ORDER BY 
+  stale.map_id"#,
+            version,
+            after_map_id as i32,
+            map_count as i64,
+        );
+
+        let mut rows = query.fetch(self);
+        let mut stale = Vec::with_capacity(map_count);
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next stale difficulty")?;
+            stale.push(row.into());
+        }
+
+        Ok(stale)
+    }
+
+    /// Select the next `limit` scores with stale pp whose score id is larger
+    /// than `after_score_id`.
+    pub async fn select_stale_scores(
+        &self,
+        version: i16,
+        after_score_id: u64,
+        limit: usize,
+    ) -> Result<Vec<DbScore>> {
+        let query = sqlx::query_as!(
+            DbScoreAny,
+            r#"
+SELECT 
+  scores.score_id, 
+  scores.user_id, 
+  scores.map_id, 
+  scores.gamemode, 
+  scores.mods, 
+  scores.score, 
+  scores.maxcombo, 
+  scores.grade, 
+  scores.count50, 
+  scores.count100, 
+  scores.count300, 
+  scores.countgeki, 
+  scores.countkatu, 
+  scores.countmiss, 
+  scores.ended_at, 
+  pp.pp :: FLOAT4, 
+  NULL :: FLOAT4 AS "stars_osu?", 
+  NULL :: FLOAT4 AS "stars_taiko?", 
+  NULL :: FLOAT4 AS "stars_catch?", 
+  NULL :: FLOAT4 AS "stars_mania?" 
+FROM 
+  osu_scores_performance AS pp 
+  JOIN osu_scores AS scores ON pp.score_id = scores.score_id 
+WHERE 
+  pp.calc_version < $1 
+  AND pp.score_id > $2 
+ORDER BY 
+  pp.score_id 
+LIMIT 
+  $3"#,
+            version,
+            after_score_id as i64,
+            limit as i64,
+        );
+
+        let mut rows = query.fetch(self);
+        let mut scores = Vec::with_capacity(limit);
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next stale score")?;
+            scores.push(row.into());
+        }
+
+        Ok(scores)
+    }
+
+    /// Store recalculated pp values and tag them with the given version.
+    pub async fn update_scores_pp(
+        &self,
+        score_ids: &[i64],
+        pps: &[f64],
+        version: i16,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  osu_scores_performance AS perf 
+SET 
+  pp = data.pp, 
+  calc_version = $3 
+FROM 
+  UNNEST($1 :: INT8[], $2 :: FLOAT8[]) AS data(score_id, pp) 
+WHERE 
+  perf.score_id = data.score_id"#,
+            score_ids,
+            pps,
+            version
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
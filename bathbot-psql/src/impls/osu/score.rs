@@ -10,7 +10,7 @@ use crate::{
     model::osu::{
         DbScore, DbScoreAny, DbScoreBeatmapRaw, DbScoreBeatmapsetRaw, DbScoreCatch, DbScoreMania,
         DbScoreOsu, DbScoreTaiko, DbScoreUserRaw, DbScores, DbScoresBuilder, DbTopScore,
-        DbTopScoreRaw, DbTopScores,
+        DbTopScoreRaw, DbTopScores, PP_CALC_VERSION,
     },
 };
 
@@ -912,7 +912,62 @@ FROM
         SELECT 
           * 
         FROM 
-          user_scores 
+          (
+            SELECT 
+              score_id, 
+              user_id, 
+              map_id, 
+              gamemode, 
+              mods, 
+              score, 
+              maxcombo, 
+              grade, 
+              count50, 
+              count100, 
+              count300, 
+              countmiss, 
+              countgeki, 
+              countkatu, 
+              ended_at, 
+              country_code, 
+              pp 
+            FROM 
+              user_scores 
+            UNION ALL 
+              -- scores inserted since the last refresh
+            SELECT 
+              osu_scores.score_id, 
+              osu_scores.user_id, 
+              osu_scores.map_id, 
+              osu_scores.gamemode, 
+              osu_scores.mods, 
+              osu_scores.score, 
+              osu_scores.maxcombo, 
+              osu_scores.grade, 
+              osu_scores.count50, 
+              osu_scores.count100, 
+              osu_scores.count300, 
+              osu_scores.countmiss, 
+              osu_scores.countgeki, 
+              osu_scores.countkatu, 
+              osu_scores.ended_at, 
+              osu_user_stats.country_code, 
+              osu_scores_performance.pp 
+            FROM 
+              user_scores_delta 
+              JOIN osu_scores USING (score_id) 
+              JOIN osu_user_stats USING (user_id) 
+              JOIN osu_scores_performance USING (score_id) 
+            WHERE 
+              NOT EXISTS (
+                SELECT 
+                  1 
+                FROM 
+                  user_scores 
+                WHERE 
+                  user_scores.score_id = user_scores_delta.score_id
+              )
+          ) AS merged_scores 
         WHERE 
           gamemode = $1 
           AND (
@@ -1063,10 +1118,16 @@ FROM
             }
         }
 
+        let refreshed_at = self
+            .select_user_scores_refreshed_at()
+            .await
+            .wrap_err("Failed to fetch refresh timestamp")?;
+
         Ok(DbTopScores {
             scores,
             maps,
             mapsets,
+            refreshed_at,
         })
     }
 
@@ -1191,8 +1252,11 @@ FROM
                 }
             }
 
+            // Newly inserted scores are also added to the delta table so that
+            // they show up in `user_scores` before its next refresh
             let query = sqlx::query!(
                 r#"
+WITH inserted AS (
 INSERT INTO osu_scores (
 score_id, user_id, map_id, gamemode, 
 mods, score, maxcombo, grade, count50, 
@@ -1207,7 +1271,13 @@ FROM
   $5::INT4[], $6::INT4[], $7::INT4[], $8::INT2[], 
   $9::INT4[], $10::INT4[], $11::INT4[], $12::INT4[], 
   $13::INT4[], $14::INT4[], $15::BOOL[], $16::TIMESTAMPTZ[]
-) ON CONFLICT (score_id) DO NOTHING"#,
+) ON CONFLICT (score_id) DO NOTHING RETURNING score_id
+) 
+INSERT INTO user_scores_delta (score_id) 
+SELECT
+  score_id
+FROM
+  inserted ON CONFLICT (score_id) DO NOTHING"#,
                 &vec_score_id,
                 &vec_user_id,
                 &vec_map_id,
@@ -1234,13 +1304,14 @@ FROM
             if !vec_pp.is_empty() {
                 let query = sqlx::query!(
                     r#"
-INSERT INTO osu_scores_performance (score_id, pp) 
+INSERT INTO osu_scores_performance (score_id, pp, calc_version) 
 SELECT
-  *
+  *, $3
 FROM
   UNNEST($1::INT8[], $2::FLOAT8[]) ON CONFLICT (score_id) DO NOTHING"#,
                     &vec_pp_score_id,
                     &vec_pp,
+                    PP_CALC_VERSION,
                 );
 
                 query
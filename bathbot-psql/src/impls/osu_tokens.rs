@@ -0,0 +1,89 @@
+use eyre::{Result, WrapErr};
+use time::OffsetDateTime;
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{model::osu_tokens::DbOsuUserToken, Database};
+
+impl Database {
+    pub async fn select_osu_user_token(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<DbOsuUserToken>> {
+        let query = sqlx::query_as!(
+            DbOsuUserToken,
+            r#"
+SELECT 
+  osu_id, 
+  access_token, 
+  refresh_token, 
+  expires_at 
+FROM 
+  osu_user_tokens 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")
+    }
+
+    pub async fn upsert_osu_user_token(
+        &self,
+        user_id: Id<UserMarker>,
+        osu_id: u32,
+        access_token: &[u8],
+        refresh_token: &[u8],
+        expires_at: OffsetDateTime,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_user_tokens (
+  discord_id, osu_id, access_token, 
+  refresh_token, expires_at
+) 
+VALUES 
+  ($1, $2, $3, $4, $5) ON CONFLICT (discord_id) DO 
+UPDATE 
+SET 
+  osu_id = $2, 
+  access_token = $3, 
+  refresh_token = $4, 
+  expires_at = $5, 
+  updated_at = NOW()"#,
+            user_id.get() as i64,
+            osu_id as i32,
+            access_token,
+            refresh_token,
+            expires_at,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_osu_user_token(&self, user_id: Id<UserMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  osu_user_tokens 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,254 @@
+use eyre::{Result, WrapErr};
+use sqlx::types::JsonValue;
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::Database;
+
+impl Database {
+    /// Collect all data stored for the discord user into a json object.
+    pub async fn select_user_data(&self, user_id: Id<UserMarker>) -> Result<JsonValue> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  jsonb_build_object(
+    'user_config', 
+    (
+      SELECT 
+        to_jsonb(config) - 'discord_id' 
+      FROM 
+        user_configs AS config 
+      WHERE 
+        discord_id = $1
+    ), 
+    'linked_osu_accounts', 
+    (
+      SELECT 
+        COALESCE(
+          jsonb_agg(osu_id), 
+          '[]'
+        ) 
+      FROM 
+        user_linked_accounts 
+      WHERE 
+        discord_id = $1
+    ), 
+    'render_settings', 
+    (
+      SELECT 
+        to_jsonb(settings) - 'discord_id' 
+      FROM 
+        user_render_settings AS settings 
+      WHERE 
+        discord_id = $1
+    ), 
+    'bookmarks', 
+    (
+      SELECT 
+        COALESCE(
+          jsonb_agg(
+            to_jsonb(bookmark) - 'user_id'
+          ), 
+          '[]'
+        ) 
+      FROM 
+        user_map_bookmarks AS bookmark 
+      WHERE 
+        user_id = $1
+    ), 
+    'bookmark_folders', 
+    (
+      SELECT 
+        COALESCE(
+          jsonb_agg(
+            to_jsonb(folder) - 'user_id'
+          ), 
+          '[]'
+        ) 
+      FROM 
+        user_map_bookmark_folders AS folder 
+      WHERE 
+        user_id = $1
+    ), 
+    'bggame_score', 
+    (
+      SELECT 
+        score 
+      FROM 
+        bggame_scores 
+      WHERE 
+        discord_id = $1
+    ), 
+    'higherlower_scores', 
+    (
+      SELECT 
+        COALESCE(
+          jsonb_agg(
+            to_jsonb(game) - 'discord_id'
+          ), 
+          '[]'
+        ) 
+      FROM 
+        higherlower_scores AS game 
+      WHERE 
+        discord_id = $1
+    ), 
+    'command_aliases', 
+    (
+      SELECT 
+        COALESCE(
+          jsonb_agg(
+            to_jsonb(alias) - 'user_id'
+          ), 
+          '[]'
+        ) 
+      FROM 
+        user_command_aliases AS alias 
+      WHERE 
+        user_id = $1
+    ), 
+    'scheduled_jobs', 
+    (
+      SELECT 
+        COALESCE(
+          jsonb_agg(
+            to_jsonb(job) - 'user_id'
+          ), 
+          '[]'
+        ) 
+      FROM 
+        scheduled_jobs AS job 
+      WHERE 
+        user_id = $1
+    ), 
+    'osu_authorization', 
+    (
+      SELECT 
+        to_jsonb(token) - 'discord_id' - 'access_token' - 'refresh_token' 
+      FROM 
+        osu_user_tokens AS token 
+      WHERE 
+        discord_id = $1
+    ), 
+    'api_keys', 
+    (
+      SELECT 
+        COALESCE(
+          jsonb_agg(
+            jsonb_build_object(
+              'name', api_key.name, 'created_at', api_key.created_at, 
+              'rate_limit', api_key.rate_limit
+            )
+          ), 
+          '[]'
+        ) 
+      FROM 
+        api_keys AS api_key 
+      WHERE 
+        owner_id = $1
+    )
+  ) AS "data!""#,
+            user_id.get() as i64
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("failed to fetch one")?;
+
+        Ok(row.data)
+    }
+
+    /// Delete all data stored for the discord user.
+    ///
+    /// Returns the ids of all osu! accounts that were linked to the user.
+    pub async fn delete_user_data(&self, user_id: Id<UserMarker>) -> Result<Vec<u32>> {
+        let query = sqlx::query!(
+            r#"
+WITH configs AS (
+  DELETE FROM 
+    user_configs 
+  WHERE 
+    discord_id = $1 RETURNING osu_id
+), 
+linked AS (
+  DELETE FROM 
+    user_linked_accounts 
+  WHERE 
+    discord_id = $1 RETURNING osu_id
+), 
+render_settings AS (
+  DELETE FROM 
+    user_render_settings 
+  WHERE 
+    discord_id = $1
+), 
+bookmarks AS (
+  DELETE FROM 
+    user_map_bookmarks 
+  WHERE 
+    user_id = $1
+), 
+bookmark_folders AS (
+  DELETE FROM 
+    user_map_bookmark_folders 
+  WHERE 
+    user_id = $1
+), 
+bggame AS (
+  DELETE FROM 
+    bggame_scores 
+  WHERE 
+    discord_id = $1
+), 
+higherlower AS (
+  DELETE FROM 
+    higherlower_scores 
+  WHERE 
+    discord_id = $1
+), 
+aliases AS (
+  DELETE FROM 
+    user_command_aliases 
+  WHERE 
+    user_id = $1
+), 
+jobs AS (
+  DELETE FROM 
+    scheduled_jobs 
+  WHERE 
+    user_id = $1
+), 
+osu_tokens AS (
+  DELETE FROM 
+    osu_user_tokens 
+  WHERE 
+    discord_id = $1
+), 
+keys AS (
+  DELETE FROM 
+    api_keys 
+  WHERE 
+    owner_id = $1
+) 
+SELECT 
+  osu_id AS "osu_id!" 
+FROM 
+  configs 
+WHERE 
+  osu_id IS NOT NULL 
+UNION 
+SELECT 
+  osu_id 
+FROM 
+  linked"#,
+            user_id.get() as i64
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.osu_id as u32).collect())
+    }
+}
@@ -0,0 +1,155 @@
+use eyre::{Result, WrapErr};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::{model::schedule::DbScheduledJob, Database};
+
+impl Database {
+    pub async fn select_guild_scheduled_jobs(
+        &self,
+        guild: Id<GuildMarker>,
+    ) -> Result<Vec<DbScheduledJob>> {
+        let query = sqlx::query_as!(
+            DbScheduledJob,
+            r#"
+SELECT 
+  job_id, 
+  guild_id, 
+  channel_id, 
+  user_id, 
+  schedule, 
+  command, 
+  args, 
+  next_run 
+FROM 
+  scheduled_jobs 
+WHERE 
+  guild_id = $1 
+ORDER BY 
+  job_id"#,
+            guild.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    /// Jobs whose next run is not after the given time
+    pub async fn select_due_scheduled_jobs(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<DbScheduledJob>> {
+        let query = sqlx::query_as!(
+            DbScheduledJob,
+            r#"
+SELECT 
+  job_id, 
+  guild_id, 
+  channel_id, 
+  user_id, 
+  schedule, 
+  command, 
+  args, 
+  next_run 
+FROM 
+  scheduled_jobs 
+WHERE 
+  next_run <= $1"#,
+            now
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    /// Returns the id of the new job
+    #[allow(clippy::too_many_arguments)]
+    pub async fn insert_scheduled_job(
+        &self,
+        guild: Id<GuildMarker>,
+        channel: Id<ChannelMarker>,
+        user: Id<UserMarker>,
+        schedule: &str,
+        command: &str,
+        args: &str,
+        next_run: OffsetDateTime,
+    ) -> Result<i32> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO scheduled_jobs (
+  guild_id, channel_id, user_id, schedule, 
+  command, args, next_run
+) 
+VALUES 
+  ($1, $2, $3, $4, $5, $6, $7) RETURNING job_id"#,
+            guild.get() as i64,
+            channel.get() as i64,
+            user.get() as i64,
+            schedule,
+            command,
+            args,
+            next_run,
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("failed to fetch one")?;
+
+        Ok(row.job_id)
+    }
+
+    /// Moves the job's next run from `prev` to `next`.
+    ///
+    /// Returns `false` if the job's next run was no longer `prev` i.e. the job
+    /// has already been claimed elsewhere or was removed.
+    pub async fn update_scheduled_job_next_run(
+        &self,
+        job_id: i32,
+        prev: OffsetDateTime,
+        next: OffsetDateTime,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  scheduled_jobs 
+SET 
+  next_run = $3 
+WHERE 
+  job_id = $1 
+  AND next_run = $2"#,
+            job_id,
+            prev,
+            next,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_scheduled_job(&self, guild: Id<GuildMarker>, job_id: i32) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  scheduled_jobs 
+WHERE 
+  guild_id = $1 
+  AND job_id = $2"#,
+            guild.get() as i64,
+            job_id,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
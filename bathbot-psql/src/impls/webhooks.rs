@@ -0,0 +1,164 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{
+    model::webhooks::{DbGuildWebhook, DbWebhookDeadLetter},
+    Database,
+};
+
+impl Database {
+    pub async fn select_guild_webhooks(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DbGuildWebhook>> {
+        let query = sqlx::query_as!(
+            DbGuildWebhook,
+            r#"
+SELECT 
+  webhook_id, 
+  guild_id, 
+  url, 
+  secret, 
+  events 
+FROM 
+  guild_webhooks 
+WHERE 
+  guild_id = $1 
+ORDER BY 
+  created_at"#,
+            guild_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    /// Webhooks of all guilds that subscribed to any of the given events
+    pub async fn select_webhooks_by_events(&self, events: i16) -> Result<Vec<DbGuildWebhook>> {
+        let query = sqlx::query_as!(
+            DbGuildWebhook,
+            r#"
+SELECT 
+  webhook_id, 
+  guild_id, 
+  url, 
+  secret, 
+  events 
+FROM 
+  guild_webhooks 
+WHERE 
+  events & $1 != 0"#,
+            events
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    /// Returns `false` if the guild already has a webhook with that url
+    pub async fn insert_guild_webhook(
+        &self,
+        guild_id: Id<GuildMarker>,
+        url: &str,
+        secret: &str,
+        events: i16,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO guild_webhooks (guild_id, url, secret, events) 
+VALUES 
+  ($1, $2, $3, $4) ON CONFLICT (guild_id, url) DO NOTHING"#,
+            guild_id.get() as i64,
+            url,
+            secret,
+            events,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_guild_webhook(&self, guild_id: Id<GuildMarker>, url: &str) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  guild_webhooks 
+WHERE 
+  guild_id = $1 
+  AND url = $2"#,
+            guild_id.get() as i64,
+            url
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    pub async fn insert_webhook_dead_letter(
+        &self,
+        webhook_id: i32,
+        event: &str,
+        payload: &str,
+        error: &str,
+        attempts: u8,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO webhook_dead_letters (
+  webhook_id, event, payload, error, attempts
+) 
+VALUES 
+  ($1, $2, $3::TEXT::JSONB, $4, $5)"#,
+            webhook_id,
+            event,
+            payload,
+            error,
+            attempts as i16,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// The most recent dead letters of the guild's webhooks
+    pub async fn select_webhook_dead_letters(
+        &self,
+        guild_id: Id<GuildMarker>,
+        limit: i64,
+    ) -> Result<Vec<DbWebhookDeadLetter>> {
+        let query = sqlx::query_as!(
+            DbWebhookDeadLetter,
+            r#"
+SELECT 
+  hooks.url, 
+  letters.event, 
+  letters.error, 
+  letters.attempts, 
+  letters.failed_at 
+FROM 
+  webhook_dead_letters AS letters 
+  JOIN guild_webhooks AS hooks ON letters.webhook_id = hooks.webhook_id 
+WHERE 
+  hooks.guild_id = $1 
+ORDER BY 
+  letters.failed_at DESC 
+LIMIT 
+  $2"#,
+            guild_id.get() as i64,
+            limit
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+}
@@ -0,0 +1,7 @@
+/// A custom command name that expands into a command with preset arguments.
+pub struct DbCommandAlias {
+    pub name: String,
+    /// Either a prefix command without prefix e.g. `top -m taiko sort=date`
+    /// or a slash command e.g. `/top sort:pp`
+    pub expansion: String,
+}
@@ -0,0 +1,13 @@
+use time::OffsetDateTime;
+
+/// A key to access the public api.
+///
+/// Only the SHA-256 hash of the key is stored.
+pub struct DbApiKey {
+    pub name: String,
+    /// Discord user id of the key's owner
+    pub owner_id: Option<i64>,
+    /// Maximum amount of requests per minute
+    pub rate_limit: i32,
+    pub created_at: OffsetDateTime,
+}
@@ -0,0 +1,47 @@
+/// Kinds of osu! links that are automatically embedded when posted in a
+/// guild.
+///
+/// Empty by default i.e. auto-embeds are opt-in.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct AutoEmbeds(u8);
+
+impl AutoEmbeds {
+    pub const MAP: Self = Self(1 << 0);
+    pub const SCORE: Self = Self(1 << 1);
+    pub const USER: Self = Self(1 << 2);
+    pub const MATCH: Self = Self(1 << 3);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.0 |= other.0;
+        } else {
+            self.0 &= !other.0;
+        }
+    }
+}
+
+impl From<AutoEmbeds> for i16 {
+    #[inline]
+    fn from(auto_embeds: AutoEmbeds) -> Self {
+        auto_embeds.0 as Self
+    }
+}
+
+impl From<i16> for AutoEmbeds {
+    #[inline]
+    fn from(value: i16) -> Self {
+        Self(value as u8)
+    }
+}
@@ -0,0 +1,51 @@
+use rkyv::{Archive, Deserialize, Infallible, Serialize};
+
+/// Guild-specific ratelimits that replace the default limits of buckets.
+#[derive(Archive, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BucketOverrides {
+    overrides: Vec<BucketOverride>,
+}
+
+impl BucketOverrides {
+    pub fn overrides(&self) -> &[BucketOverride] {
+        &self.overrides
+    }
+
+    pub fn get(&self, bucket: &str) -> Option<&BucketOverride> {
+        self.overrides.iter().find(|entry| entry.bucket == bucket)
+    }
+
+    /// Replace the override of a bucket or remove it if `None` is given.
+    pub fn set(&mut self, bucket: &str, entry: Option<BucketOverride>) {
+        self.overrides.retain(|entry| entry.bucket != bucket);
+
+        if let Some(entry) = entry {
+            self.overrides.push(entry);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.overrides.is_empty()
+    }
+
+    /// # Safety
+    ///
+    /// The caller must ensure that the provided bytes are valid archived
+    /// bucket overrides
+    pub(crate) unsafe fn deserialize(bytes: &[u8]) -> Self {
+        let archived_overrides = rkyv::archived_root::<Self>(bytes);
+
+        archived_overrides.deserialize(&mut Infallible).unwrap()
+    }
+}
+
+#[derive(Archive, Clone, Debug, Deserialize, Serialize)]
+pub struct BucketOverride {
+    /// Name of the bucket
+    pub bucket: String,
+    /// Seconds between two uses
+    pub delay: u32,
+    /// Seconds in which at most `limit` uses are allowed
+    pub time_span: u32,
+    pub limit: u32,
+}
@@ -0,0 +1,162 @@
+use rkyv::{Archive, Deserialize, Infallible, Serialize};
+use rosu_v2::prelude::GameMode;
+use twilight_model::id::{
+    marker::{ChannelMarker, RoleMarker},
+    Id,
+};
+
+/// Prefix of rule targets that refer to a command group instead of a single
+/// command.
+pub const GROUP_TARGET_PREFIX: &str = "group:";
+
+/// Restrictions and defaults for commands within a guild.
+#[derive(Archive, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CommandRules {
+    rules: Vec<CommandRule>,
+    channel_modes: Vec<ChannelMode>,
+}
+
+impl CommandRules {
+    pub const MAX_RULES: usize = 50;
+
+    pub fn rules(&self) -> &[CommandRule] {
+        &self.rules
+    }
+
+    pub fn rule(&self, target: &str) -> Option<&CommandRule> {
+        self.rules.iter().find(|rule| rule.target == target)
+    }
+
+    /// Get the rule for the target or insert an empty one.
+    ///
+    /// Returns `None` if there is no rule for the target yet and the maximum
+    /// amount of rules has been reached.
+    pub fn rule_mut(&mut self, target: &str) -> Option<&mut CommandRule> {
+        match self.rules.iter().position(|rule| rule.target == target) {
+            Some(idx) => Some(&mut self.rules[idx]),
+            None if self.rules.len() >= Self::MAX_RULES => None,
+            None => {
+                self.rules.push(CommandRule::new(target.to_owned()));
+
+                self.rules.last_mut()
+            }
+        }
+    }
+
+    /// Remove the rule for the given target.
+    ///
+    /// Returns whether a rule was removed.
+    pub fn remove_rule(&mut self, target: &str) -> bool {
+        let len = self.rules.len();
+        self.rules.retain(|rule| rule.target != target);
+
+        self.rules.len() != len
+    }
+
+    /// Remove all rules that no longer restrict anything.
+    pub fn remove_empty(&mut self) {
+        self.rules.retain(|rule| !rule.is_empty());
+    }
+
+    pub fn channel_modes(&self) -> &[ChannelMode] {
+        &self.channel_modes
+    }
+
+    pub fn channel_mode(&self, channel: Id<ChannelMarker>) -> Option<GameMode> {
+        self.channel_modes
+            .iter()
+            .find(|entry| entry.channel == channel.get())
+            .map(|entry| GameMode::from(entry.mode))
+    }
+
+    pub fn set_channel_mode(&mut self, channel: Id<ChannelMarker>, mode: Option<GameMode>) {
+        let channel = channel.get();
+        self.channel_modes.retain(|entry| entry.channel != channel);
+
+        if let Some(mode) = mode {
+            self.channel_modes.push(ChannelMode {
+                channel,
+                mode: mode as u8,
+            });
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty() && self.channel_modes.is_empty()
+    }
+
+    /// # Safety
+    ///
+    /// The caller must ensure that the provided bytes are valid archived
+    /// command rules
+    pub(crate) unsafe fn deserialize(bytes: &[u8]) -> Self {
+        let archived_rules = rkyv::archived_root::<Self>(bytes);
+
+        archived_rules.deserialize(&mut Infallible).unwrap()
+    }
+}
+
+/// Restrictions for either a single command or a command group.
+#[derive(Archive, Clone, Debug, Deserialize, Serialize)]
+pub struct CommandRule {
+    /// Either a command name or a group name prefixed with
+    /// [`GROUP_TARGET_PREFIX`]
+    pub target: String,
+    /// Whether the target is disabled in the whole guild
+    pub disabled: bool,
+    /// If not empty, the target can only be used in these channels
+    pub allowed_channels: Vec<u64>,
+    /// Channels in which the target is disabled
+    pub disabled_channels: Vec<u64>,
+    /// If not empty, only members with any of these roles can use the target
+    pub roles: Vec<u64>,
+}
+
+impl CommandRule {
+    fn new(target: String) -> Self {
+        Self {
+            target,
+            disabled: false,
+            allowed_channels: Vec::new(),
+            disabled_channels: Vec::new(),
+            roles: Vec::new(),
+        }
+    }
+
+    pub fn group(&self) -> Option<&str> {
+        self.target.strip_prefix(GROUP_TARGET_PREFIX)
+    }
+
+    pub fn allowed_channels(&self) -> impl Iterator<Item = Id<ChannelMarker>> + '_ {
+        self.allowed_channels.iter().copied().map(Id::new)
+    }
+
+    pub fn disabled_channels(&self) -> impl Iterator<Item = Id<ChannelMarker>> + '_ {
+        self.disabled_channels.iter().copied().map(Id::new)
+    }
+
+    pub fn roles(&self) -> impl Iterator<Item = Id<RoleMarker>> + '_ {
+        self.roles.iter().copied().map(Id::new)
+    }
+
+    pub fn is_disabled_in(&self, channel: Id<ChannelMarker>) -> bool {
+        self.disabled || self.disabled_channels.contains(&channel.get())
+    }
+
+    pub fn is_allowed_in(&self, channel: Id<ChannelMarker>) -> bool {
+        self.allowed_channels.is_empty() || self.allowed_channels.contains(&channel.get())
+    }
+
+    fn is_empty(&self) -> bool {
+        !self.disabled
+            && self.allowed_channels.is_empty()
+            && self.disabled_channels.is_empty()
+            && self.roles.is_empty()
+    }
+}
+
+#[derive(Archive, Copy, Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelMode {
+    pub channel: u64,
+    pub mode: u8,
+}
@@ -1,4 +1,11 @@
-use super::{list_size::ListSize, Authorities, HideSolutions, Prefixes, Retries, ScoreData};
+use bathbot_model::embed_builder::ScoreEmbedSettings;
+use sqlx::types::Json;
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use super::{
+    list_size::ListSize, Authorities, AutoEmbeds, BucketOverrides, CommandRules, HideSolutions,
+    Locale, Prefixes, Retries, ScoreData,
+};
 
 pub struct DbGuildConfig {
     pub guild_id: i64,
@@ -12,6 +19,12 @@ pub struct DbGuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<i16>,
     pub score_data: Option<i16>,
+    pub command_rules: Option<Vec<u8>>,
+    pub bucket_overrides: Option<Vec<u8>>,
+    pub auto_embeds: Option<i16>,
+    pub locale: Option<i16>,
+    pub score_embed: Option<Json<ScoreEmbedSettings>>,
+    pub score_embed_channels: Option<Vec<i64>>,
 }
 
 #[derive(Clone, Default)]
@@ -26,6 +39,13 @@ pub struct GuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<HideSolutions>,
     pub score_data: Option<ScoreData>,
+    pub command_rules: CommandRules,
+    pub bucket_overrides: BucketOverrides,
+    pub auto_embeds: AutoEmbeds,
+    pub locale: Option<Locale>,
+    pub score_embed: Option<ScoreEmbedSettings>,
+    /// Channels in which `score_embed` overrides the members' settings
+    pub score_embed_channels: Vec<Id<ChannelMarker>>,
 }
 
 impl From<DbGuildConfig> for GuildConfig {
@@ -43,12 +63,24 @@ impl From<DbGuildConfig> for GuildConfig {
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            command_rules,
+            bucket_overrides,
+            auto_embeds,
+            locale,
+            score_embed,
+            score_embed_channels,
         } = config;
 
         // SAFETY: The bytes originate from the DB which only provides valid archived
         // data
         let authorities = unsafe { Authorities::deserialize(&authorities) };
         let prefixes = unsafe { Prefixes::deserialize(&prefixes) };
+        let command_rules = command_rules
+            .map(|bytes| unsafe { CommandRules::deserialize(&bytes) })
+            .unwrap_or_default();
+        let bucket_overrides = bucket_overrides
+            .map(|bytes| unsafe { BucketOverrides::deserialize(&bytes) })
+            .unwrap_or_default();
 
         Self {
             authorities,
@@ -63,6 +95,16 @@ impl From<DbGuildConfig> for GuildConfig {
                 .map(HideSolutions::try_from)
                 .and_then(Result::ok),
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            command_rules,
+            bucket_overrides,
+            auto_embeds: auto_embeds.map(AutoEmbeds::from).unwrap_or_default(),
+            locale: locale.map(Locale::try_from).and_then(Result::ok),
+            score_embed: score_embed.map(|Json(score_embed)| score_embed),
+            score_embed_channels: score_embed_channels
+                .unwrap_or_default()
+                .into_iter()
+                .map(|channel| Id::new(channel as u64))
+                .collect(),
         }
     }
 }
@@ -0,0 +1,61 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+/// Language of command responses.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum Locale {
+    #[default]
+    #[option(name = "English", value = "en")]
+    English = 0,
+    #[option(name = "Deutsch", value = "de")]
+    German = 1,
+    #[option(name = "Polski", value = "pl")]
+    Polish = 2,
+    #[option(name = "Português", value = "pt")]
+    Portuguese = 3,
+}
+
+impl Locale {
+    pub const ALL: [Self; 4] = [Self::English, Self::German, Self::Polish, Self::Portuguese];
+
+    /// The locale's code as used by discord.
+    pub fn discord_code(self) -> &'static str {
+        match self {
+            Self::English => "en-US",
+            Self::German => "de",
+            Self::Polish => "pl",
+            Self::Portuguese => "pt-BR",
+        }
+    }
+
+    /// Parse a locale code as provided by discord.
+    pub fn from_discord(code: &str) -> Option<Self> {
+        match code {
+            "en-US" | "en-GB" => Some(Self::English),
+            "de" => Some(Self::German),
+            "pl" => Some(Self::Polish),
+            "pt-BR" => Some(Self::Portuguese),
+            _ => None,
+        }
+    }
+}
+
+impl From<Locale> for i16 {
+    fn from(locale: Locale) -> Self {
+        locale as Self
+    }
+}
+
+impl TryFrom<i16> for Locale {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::English),
+            1 => Ok(Self::German),
+            2 => Ok(Self::Polish),
+            3 => Ok(Self::Portuguese),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,19 +1,27 @@
 pub use self::{
     authorities::{Authorities, Authority},
+    auto_embeds::AutoEmbeds,
+    bucket_overrides::{BucketOverride, BucketOverrides},
+    command_rules::{ChannelMode, CommandRule, CommandRules, GROUP_TARGET_PREFIX},
     guild::{DbGuildConfig, GuildConfig},
     hide_solutions::HideSolutions,
     list_size::ListSize,
+    locale::Locale,
     prefixes::{Prefix, Prefixes, DEFAULT_PREFIX},
     retries::Retries,
     score_data::ScoreData,
     skin::{DbSkinEntry, SkinEntry},
-    user::{DbUserConfig, OsuId, OsuUserId, OsuUsername, UserConfig},
+    user::{DbUserConfig, ModeAccounts, OsuId, OsuUserId, OsuUsername, UserConfig},
 };
 
 mod authorities;
+mod auto_embeds;
+mod bucket_overrides;
+mod command_rules;
 mod guild;
 mod hide_solutions;
 mod list_size;
+mod locale;
 mod prefixes;
 mod retries;
 mod score_data;
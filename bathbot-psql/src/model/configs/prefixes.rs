@@ -41,6 +41,11 @@ impl Prefixes {
         self.inner.retain(f)
     }
 
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
     #[inline]
     pub fn dedup(&mut self) {
         for i in (1..self.inner.len()).rev() {
@@ -3,7 +3,7 @@ use rosu_v2::prelude::{GameMode, Username};
 use sqlx::types::Json;
 use time::UtcOffset;
 
-use super::{list_size::ListSize, Retries, ScoreData};
+use super::{list_size::ListSize, Locale, Retries, ScoreData};
 
 pub struct DbUserConfig {
     pub list_size: Option<i16>,
@@ -15,6 +15,8 @@ pub struct DbUserConfig {
     pub timezone_seconds: Option<i32>,
     pub render_button: Option<bool>,
     pub score_data: Option<i16>,
+    pub mode_accounts: Option<Vec<i32>>,
+    pub locale: Option<i16>,
 }
 
 pub trait OsuId {
@@ -46,6 +48,19 @@ pub struct UserConfig<O: OsuId> {
     pub timezone: Option<UtcOffset>,
     pub render_button: Option<bool>,
     pub score_data: Option<ScoreData>,
+    pub mode_accounts: ModeAccounts,
+    pub locale: Option<Locale>,
+}
+
+impl UserConfig<OsuUserId> {
+    /// The linked account to use for the mode.
+    ///
+    /// That's the account specifically set for the mode or the primary
+    /// account otherwise.
+    pub fn osu_for_mode(&self, mode: Option<GameMode>) -> Option<u32> {
+        mode.and_then(|mode| self.mode_accounts.get(mode))
+            .or(self.osu)
+    }
 }
 
 impl<O: OsuId> Default for UserConfig<O> {
@@ -61,6 +76,8 @@ impl<O: OsuId> Default for UserConfig<O> {
             timezone: None,
             render_button: None,
             score_data: None,
+            mode_accounts: ModeAccounts::default(),
+            locale: None,
         }
     }
 }
@@ -78,6 +95,8 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
             timezone_seconds,
             render_button,
             score_data,
+            mode_accounts,
+            locale,
         } = config;
 
         Self {
@@ -92,6 +111,63 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
                 .map(Result::unwrap),
             render_button,
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            mode_accounts: mode_accounts.map(ModeAccounts::from_db).unwrap_or_default(),
+            locale: locale.map(Locale::try_from).and_then(Result::ok),
+        }
+    }
+}
+
+/// Linked osu! accounts that are used instead of the primary account for
+/// specific modes.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ModeAccounts([Option<u32>; 4]);
+
+impl ModeAccounts {
+    pub fn get(&self, mode: GameMode) -> Option<u32> {
+        self.0[mode as usize]
+    }
+
+    pub fn set(&mut self, mode: GameMode, user_id: Option<u32>) {
+        self.0[mode as usize] = user_id;
+    }
+
+    /// Unset the account for all modes.
+    pub fn remove(&mut self, user_id: u32) {
+        for entry in self.0.iter_mut() {
+            if *entry == Some(user_id) {
+                *entry = None;
+            }
         }
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = (GameMode, u32)> + '_ {
+        self.0
+            .iter()
+            .enumerate()
+            .filter_map(|(mode, user_id)| Some((GameMode::from(mode as u8), (*user_id)?)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(Option::is_none)
+    }
+
+    /// The DB stores a `0` for modes without account.
+    fn from_db(user_ids: Vec<i32>) -> Self {
+        let mut accounts = Self::default();
+
+        for (entry, user_id) in accounts.0.iter_mut().zip(user_ids) {
+            *entry = (user_id > 0).then_some(user_id as u32);
+        }
+
+        accounts
+    }
+
+    pub(crate) fn to_db(self) -> Option<Vec<i32>> {
+        (!self.is_empty()).then(|| {
+            self.0
+                .iter()
+                .map(|user_id| user_id.map_or(0, |id| id as i32))
+                .collect()
+        })
+    }
 }
@@ -1,4 +1,9 @@
+pub mod aliases;
+pub mod api_keys;
 pub mod configs;
 pub mod games;
 pub mod osu;
+pub mod osu_tokens;
 pub mod render;
+pub mod schedule;
+pub mod webhooks;
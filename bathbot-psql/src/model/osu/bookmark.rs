@@ -27,4 +27,12 @@ pub struct MapBookmark {
     pub genre: Genre,
     pub language: Language,
     pub cover_url: Box<str>,
+    pub folder: Option<Box<str>>,
+    pub note: Option<Box<str>>,
+}
+
+pub struct BookmarkFolder {
+    pub folder_id: u32,
+    pub name: Box<str>,
+    pub public: bool,
 }
@@ -1,3 +1,6 @@
+use std::ops::Bound;
+
+use rosu_v2::prelude::{GameMode, Genre, Language, RankStatus};
 use time::OffsetDateTime;
 
 #[derive(Clone)]
@@ -17,3 +20,111 @@ pub struct ArtistTitle {
     pub artist: String,
     pub title: String,
 }
+
+/// Filters for searching through stored mapsets.
+///
+/// Every `None` value and every unbounded range end is ignored.
+#[derive(Default)]
+pub struct MapsetSearchParams<'a> {
+    /// Full-text query matched against artist, title, source, and tags
+    pub query: Option<&'a str>,
+    pub mode: Option<GameMode>,
+    pub statuses: Option<&'a [RankStatus]>,
+    pub genre: Option<Genre>,
+    pub language: Option<Language>,
+    pub video: Option<bool>,
+    pub storyboard: Option<bool>,
+    pub artist: Option<&'a str>,
+    pub title: Option<&'a str>,
+    pub creator: Option<&'a str>,
+    pub stars: SearchRange,
+    pub ar: SearchRange,
+    pub cs: SearchRange,
+    pub hp: SearchRange,
+    pub od: SearchRange,
+    pub bpm: SearchRange,
+    pub length: SearchRange,
+    pub keys: SearchRange,
+    pub limit: usize,
+}
+
+/// Lower and upper bound of a range filter in [`MapsetSearchParams`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SearchRange {
+    pub lower: Bound<f32>,
+    pub upper: Bound<f32>,
+}
+
+impl SearchRange {
+    /// Inclusive bounds to be used in queries.
+    ///
+    /// Exclusive bounds are moved to the next representable value so that
+    /// e.g. `stars>5` does not match maps with exactly 5 stars.
+    pub fn inclusive(self) -> (Option<f32>, Option<f32>) {
+        let lower = match self.lower {
+            Bound::Included(value) => Some(value),
+            Bound::Excluded(value) => Some(value.next_up()),
+            Bound::Unbounded => None,
+        };
+
+        let upper = match self.upper {
+            Bound::Included(value) => Some(value),
+            Bound::Excluded(value) => Some(value.next_down()),
+            Bound::Unbounded => None,
+        };
+
+        (lower, upper)
+    }
+}
+
+impl Default for SearchRange {
+    fn default() -> Self {
+        Self {
+            lower: Bound::Unbounded,
+            upper: Bound::Unbounded,
+        }
+    }
+}
+
+impl From<(Bound<f32>, Bound<f32>)> for SearchRange {
+    fn from((lower, upper): (Bound<f32>, Bound<f32>)) -> Self {
+        Self { lower, upper }
+    }
+}
+
+pub struct MapsetSearchEntry {
+    pub mapset_id: u32,
+    pub creator_id: u32,
+    pub artist: Box<str>,
+    pub title: Box<str>,
+    pub creator_name: Box<str>,
+    pub status: RankStatus,
+    pub ranked_date: Option<OffsetDateTime>,
+    pub bpm: f32,
+    /// Amount of maps that matched the filters
+    pub map_count: usize,
+    /// Modes of the maps that matched the filters
+    pub modes: Vec<GameMode>,
+    /// Highest nomod star rating of matching maps, if available
+    pub max_stars: Option<f32>,
+    /// Full-text search relevance
+    pub relevance: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_range_inclusive() {
+        let range = SearchRange::from((Bound::Included(5.0), Bound::Included(6.0)));
+        assert_eq!(range.inclusive(), (Some(5.0), Some(6.0)));
+
+        let range = SearchRange::from((Bound::Excluded(5.0), Bound::Excluded(6.0)));
+        let (lower, upper) = range.inclusive();
+        assert!(lower.unwrap() > 5.0);
+        assert!(upper.unwrap() < 6.0);
+
+        assert_eq!(SearchRange::default().inclusive(), (None, None));
+    }
+}
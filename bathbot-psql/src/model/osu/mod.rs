@@ -1,8 +1,9 @@
-pub use self::{bookmark::*, map::*, mapset::*, score::*, tracked_user::*, user::*};
+pub use self::{bookmark::*, map::*, mapset::*, recalc::*, score::*, tracked_user::*, user::*};
 
 mod bookmark;
 mod map;
 mod mapset;
+mod recalc;
 mod score;
 mod tracked_user;
 mod user;
@@ -0,0 +1,42 @@
+use rosu_v2::prelude::GameMode;
+
+use crate::util::parse_mode;
+
+/// Version of the performance calculator that stored difficulty attributes and
+/// score pp are tagged with.
+///
+/// Must be incremented whenever `rosu-pp` changes its output so that the
+/// recalculation job picks up all stale rows.
+pub const PP_CALC_VERSION: i16 = 1;
+
+/// Difficulty attributes that were calculated with an outdated calculator
+/// version.
+pub struct StaleDifficulty {
+    pub map_id: u32,
+    pub mods: u32,
+    pub mode: GameMode,
+}
+
+pub(crate) struct DbStaleDifficulty {
+    pub map_id: i32,
+    pub mods: i32,
+    pub gamemode: i16,
+}
+
+impl From<DbStaleDifficulty> for StaleDifficulty {
+    #[inline]
+    fn from(row: DbStaleDifficulty) -> Self {
+        Self {
+            map_id: row.map_id as u32,
+            mods: row.mods as u32,
+            mode: parse_mode(row.gamemode),
+        }
+    }
+}
+
+/// Amount of stored rows that have yet to be recalculated.
+#[derive(Copy, Clone, Default)]
+pub struct StaleCounts {
+    pub difficulties: usize,
+    pub scores: usize,
+}
@@ -485,6 +485,7 @@ pub struct DbTopScores<S> {
     pub(crate) scores: Vec<DbTopScore>,
     pub(crate) maps: Maps<S>,
     pub(crate) mapsets: Mapsets<S>,
+    pub(crate) refreshed_at: Option<OffsetDateTime>,
 }
 
 impl<S> DbTopScores<S> {
@@ -519,6 +520,13 @@ impl<S> DbTopScores<S> {
     pub fn mapsets(&self) -> Iter<'_, u32, DbScoreBeatmapset> {
         self.mapsets.iter()
     }
+
+    /// Last time the underlying materialized view was fully refreshed.
+    ///
+    /// Scores inserted since then are included regardless.
+    pub fn refreshed_at(&self) -> Option<OffsetDateTime> {
+        self.refreshed_at
+    }
 }
 
 impl<S: BuildHasher> DbTopScores<S> {
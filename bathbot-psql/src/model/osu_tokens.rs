@@ -0,0 +1,11 @@
+use time::OffsetDateTime;
+
+/// OAuth token of a user's osu! account.
+///
+/// Both tokens are stored encrypted.
+pub struct DbOsuUserToken {
+    pub osu_id: i32,
+    pub access_token: Vec<u8>,
+    pub refresh_token: Vec<u8>,
+    pub expires_at: OffsetDateTime,
+}
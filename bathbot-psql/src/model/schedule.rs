@@ -0,0 +1,16 @@
+use time::OffsetDateTime;
+
+/// A command that is executed periodically on behalf of a user.
+pub struct DbScheduledJob {
+    pub job_id: i32,
+    pub guild_id: i64,
+    pub channel_id: i64,
+    pub user_id: i64,
+    /// Cron-like schedule
+    pub schedule: String,
+    /// Name of the slash command
+    pub command: String,
+    /// Subcommands and options of the command in their textual form
+    pub args: String,
+    pub next_run: OffsetDateTime,
+}
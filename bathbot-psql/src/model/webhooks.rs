@@ -0,0 +1,21 @@
+use time::OffsetDateTime;
+
+#[derive(Clone)]
+pub struct DbGuildWebhook {
+    pub webhook_id: i32,
+    pub guild_id: i64,
+    pub url: String,
+    /// Key to sign payloads with
+    pub secret: String,
+    /// Bitflags of the subscribed events
+    pub events: i16,
+}
+
+/// A payload that could not be delivered after all retries.
+pub struct DbWebhookDeadLetter {
+    pub url: String,
+    pub event: String,
+    pub error: String,
+    pub attempts: i16,
+    pub failed_at: OffsetDateTime,
+}
@@ -1,35 +1,91 @@
 use std::time::Duration;
 
-use eyre::Report;
-use sqlx::PgPool;
+use eyre::{Result, WrapErr};
+use time::OffsetDateTime;
 
-pub(crate) async fn refresh_materialized_views(pool: PgPool) {
-    const DAY: Duration = Duration::from_secs(24 * 3600);
+use crate::Database;
 
-    let mut interval = tokio::time::interval(DAY);
+impl Database {
+    /// Refresh the `user_scores` materialized view and clear the scores from
+    /// its delta table that are now contained in the view.
+    pub async fn refresh_user_scores(&self) -> Result<()> {
+        let mut conn = self
+            .acquire()
+            .await
+            .wrap_err("Failed to acquire connection")?;
 
-    loop {
-        interval.tick().await;
+        sqlx::query!(r#"REFRESH MATERIALIZED VIEW CONCURRENTLY user_scores"#)
+            .execute(&mut *conn)
+            .await
+            .wrap_err("Failed to refresh user_scores materialized view")?;
 
-        info!("Refreshing materialized views...");
+        let delta_query = sqlx::query!(
+            r#"
+DELETE FROM 
+  user_scores_delta 
+WHERE 
+  score_id IN (
+    SELECT 
+      score_id 
+    FROM 
+      user_scores
+  )"#
+        );
+
+        delta_query
+            .execute(&mut *conn)
+            .await
+            .wrap_err("Failed to clear user_scores delta")?;
+
+        let timestamp_query = sqlx::query!(
+            r#"
+INSERT INTO materialized_view_refreshes (view_name, refreshed_at) 
+VALUES 
+  ('user_scores', NOW()) ON CONFLICT (view_name) DO 
+UPDATE 
+SET 
+  refreshed_at = NOW()"#
+        );
+
+        timestamp_query
+            .execute(&mut *conn)
+            .await
+            .wrap_err("Failed to update refresh timestamp")?;
+
+        Ok(())
+    }
 
-        let mut conn = match pool.acquire().await.map_err(Report::new) {
-            Ok(conn) => conn,
-            Err(err) => {
-                warn!(
-                    ?err,
-                    "Failed to acquire connection to refresh materialized views"
-                );
+    /// Last time the `user_scores` materialized view was fully refreshed.
+    pub async fn select_user_scores_refreshed_at(&self) -> Result<Option<OffsetDateTime>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  refreshed_at 
+FROM 
+  materialized_view_refreshes 
+WHERE 
+  view_name = 'user_scores'"#
+        );
 
-                continue;
-            }
-        };
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")?;
 
-        let user_scores_query =
-            sqlx::query!(r#"REFRESH MATERIALIZED VIEW CONCURRENTLY user_scores"#);
+        Ok(row_opt.map(|row| row.refreshed_at))
+    }
+}
+
+pub(crate) async fn refresh_materialized_views(psql: Database, interval: Duration) {
+    let mut interval = tokio::time::interval(interval);
+
+    loop {
+        interval.tick().await;
+
+        info!("Refreshing materialized views...");
 
-        if let Err(err) = user_scores_query.execute(&mut *conn).await {
-            warn!(err = ?Report::new(err), "Failed to refresh user_scores materialized view");
+        if let Err(err) = psql.refresh_user_scores().await {
+            warn!(?err, "Failed to refresh user_scores materialized view");
         }
 
         info!("Finished refreshing materialized views");
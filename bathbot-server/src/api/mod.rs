@@ -0,0 +1,102 @@
+use std::fmt::Write;
+
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::GameMode;
+use sha2::{Digest, Sha256};
+
+pub use self::model::*;
+
+mod model;
+
+/// Provides the data for the public api.
+///
+/// The server itself has no access to the database or the bot's managers so
+/// the bot implements this trait and hands it to the server on startup.
+pub trait ApiProvider: Send + Sync + 'static {
+    /// Whether the provider is ready to handle requests.
+    fn is_ready(&self) -> bool;
+
+    /// Lookup an api key by the hash of the key, see [`hash_api_key`].
+    fn api_key<'a>(&'a self, key_hash: &'a str) -> BoxFuture<'a, Result<Option<ApiKey>>>;
+
+    /// Difficulty attributes and pp of a map. Returns `None` if the map was
+    /// not found.
+    fn map_attributes(&self, params: MapParams) -> BoxFuture<'_, Result<Option<MapAttributes>>>;
+
+    /// Top scores of a user. Returns `None` if the user was not found.
+    fn user_top(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        limit: usize,
+    ) -> BoxFuture<'_, Result<Option<Vec<TopScore>>>>;
+
+    /// Match costs of a multiplayer match. Returns `None` if the match was not
+    /// found or is private.
+    fn match_costs(
+        &self,
+        match_id: u32,
+        warmups: usize,
+    ) -> BoxFuture<'_, Result<Option<MatchCosts>>>;
+
+    /// Bookmarks of a discord user.
+    ///
+    /// Only bookmarks in public folders are included unless the requester is
+    /// the user themselves.
+    fn bookmarks(
+        &self,
+        discord_id: u64,
+        requester: Option<u64>,
+    ) -> BoxFuture<'_, Result<Vec<Bookmark>>>;
+}
+
+/// An authenticated api key.
+#[derive(Clone, Debug)]
+pub struct ApiKey {
+    pub name: Box<str>,
+    /// Discord user id of the key's owner
+    pub owner: Option<u64>,
+    /// Maximum amount of requests per minute
+    pub rate_limit: u32,
+}
+
+/// Arguments for [`ApiProvider::map_attributes`].
+pub struct MapParams {
+    pub map_id: u32,
+    pub mode: Option<GameMode>,
+    pub mods: u32,
+    pub accuracy: Option<f32>,
+    pub combo: Option<u32>,
+    pub misses: Option<u32>,
+}
+
+/// Api keys are only stored as hex-encoded SHA-256 hash.
+pub fn hash_api_key(key: &str) -> String {
+    let digest = Sha256::digest(key.as_bytes());
+
+    digest
+        .iter()
+        .fold(String::with_capacity(64), |mut hash, byte| {
+            let _ = write!(hash, "{byte:02x}");
+
+            hash
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_key_hash() {
+        assert_eq!(
+            hash_api_key(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hash_api_key("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
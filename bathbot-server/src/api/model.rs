@@ -0,0 +1,127 @@
+use rosu_v2::prelude::GameMode;
+use serde::{Serialize, Serializer};
+
+#[derive(Serialize)]
+pub struct MapAttributes {
+    pub map_id: u32,
+    pub mapset_id: u32,
+    #[serde(serialize_with = "serialize_mode")]
+    pub mode: GameMode,
+    pub artist: Box<str>,
+    pub title: Box<str>,
+    pub version: Box<str>,
+    pub creator: Box<str>,
+    pub mods: u32,
+    pub stars: f64,
+    pub max_combo: u32,
+    pub ar: f64,
+    pub cs: f64,
+    pub hp: f64,
+    pub od: f64,
+    pub bpm: f32,
+    pub seconds_drain: u32,
+    pub accuracy: f32,
+    pub pp: f64,
+    pub max_pp: f64,
+}
+
+#[derive(Serialize)]
+pub struct TopScore {
+    /// Position in the user's top scores, starting at 1
+    pub position: usize,
+    pub score_id: u64,
+    pub map_id: u32,
+    pub mapset_id: u32,
+    #[serde(serialize_with = "serialize_mode")]
+    pub mode: GameMode,
+    pub artist: Box<str>,
+    pub title: Box<str>,
+    pub version: Box<str>,
+    pub mods: Box<str>,
+    pub grade: Box<str>,
+    pub score: u32,
+    pub accuracy: f32,
+    pub combo: u32,
+    pub max_combo: u32,
+    pub count_300: u32,
+    pub count_100: u32,
+    pub count_50: u32,
+    pub count_miss: u32,
+    pub stars: f32,
+    pub pp: f32,
+    pub max_pp: f32,
+    /// The pp if the score had been a full combo, `None` if it already is one
+    pub if_fc_pp: Option<f32>,
+    /// Unix timestamp in seconds
+    pub ended_at: i64,
+}
+
+#[derive(Serialize)]
+pub struct MatchCosts {
+    pub match_id: u32,
+    pub name: Box<str>,
+    pub finished: bool,
+    pub games: usize,
+    #[serde(flatten)]
+    pub result: MatchCostsResult,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MatchCostsResult {
+    TeamVs {
+        blue: MatchCostsTeam,
+        red: MatchCostsTeam,
+    },
+    HeadToHead {
+        players: Vec<MatchCostsPlayer>,
+    },
+    NoGames,
+}
+
+#[derive(Serialize)]
+pub struct MatchCostsTeam {
+    pub win_count: u32,
+    pub players: Vec<MatchCostsPlayer>,
+}
+
+#[derive(Serialize)]
+pub struct MatchCostsPlayer {
+    pub user_id: u32,
+    pub username: Box<str>,
+    pub match_cost: f32,
+    pub avg_score: u32,
+    pub performance_cost: f32,
+    pub participation_bonus_factor: f32,
+    pub mods_bonus_factor: f32,
+    pub tiebreaker_bonus: f32,
+}
+
+#[derive(Serialize)]
+pub struct Bookmark {
+    pub map_id: u32,
+    pub mapset_id: u32,
+    #[serde(serialize_with = "serialize_mode")]
+    pub mode: GameMode,
+    pub artist: Box<str>,
+    pub title: Box<str>,
+    pub version: Box<str>,
+    pub creator: Box<str>,
+    pub folder: Option<Box<str>>,
+    pub note: Option<Box<str>>,
+    /// Unix timestamp in seconds
+    pub inserted_at: i64,
+}
+
+fn serialize_mode<S: Serializer>(mode: &GameMode, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_str(mode_str(*mode))
+}
+
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "fruits",
+        GameMode::Mania => "mania",
+    }
+}
@@ -0,0 +1,68 @@
+use bathbot_model::embed_builder::ScoreEmbedSettings;
+use eyre::Result;
+use futures::future::BoxFuture;
+use serde::Serialize;
+
+/// Reads and writes the configs that are editable through the dashboard.
+///
+/// Implemented by the bot so that changes go through its config managers.
+pub trait DashboardProvider: Send + Sync + 'static {
+    /// Whether the provider is ready to handle requests.
+    fn is_ready(&self) -> bool;
+
+    fn user_settings(&self, user: u64) -> BoxFuture<'_, Result<UserSettings>>;
+
+    fn update_user_settings(&self, user: u64, settings: UserSettings) -> BoxFuture<'_, Result<()>>;
+
+    /// Returns the guild's name if the bot is in the guild and the user is
+    /// allowed to configure it.
+    fn manageable_guild(&self, user: u64, guild: u64) -> BoxFuture<'_, Result<Option<Box<str>>>>;
+
+    fn guild_settings(&self, guild: u64) -> BoxFuture<'_, Result<GuildSettings>>;
+
+    /// Returns the first prefix that is not allowed, in which case nothing is
+    /// updated.
+    fn update_guild_settings(
+        &self,
+        guild: u64,
+        settings: GuildSettings,
+    ) -> BoxFuture<'_, Result<Option<Box<str>>>>;
+
+    /// Stop all osu! and twitch tracking in the channel.
+    ///
+    /// Returns `None` if the channel does not belong to the guild, otherwise
+    /// the amount of removed osu! tracking entries.
+    fn untrack_channel(&self, guild: u64, channel: u64) -> BoxFuture<'_, Result<Option<usize>>>;
+}
+
+/// Editable parts of a user config.
+///
+/// Enum-like values are represented by their database value.
+#[derive(Default, Serialize)]
+pub struct UserSettings {
+    pub mode: Option<u8>,
+    pub list_size: Option<i16>,
+    pub retries: Option<i16>,
+    pub render_button: Option<bool>,
+    pub score_data: Option<i16>,
+    pub locale: Option<i16>,
+    pub score_embed: Option<ScoreEmbedSettings>,
+}
+
+/// Editable parts of a guild config.
+///
+/// Enum-like values are represented by their database value.
+#[derive(Default, Serialize)]
+pub struct GuildSettings {
+    pub prefixes: Vec<Box<str>>,
+    pub authorities: Vec<u64>,
+    pub list_size: Option<i16>,
+    pub retries: Option<i16>,
+    pub score_data: Option<i16>,
+    pub locale: Option<i16>,
+    pub hide_medal_solution: Option<i16>,
+    pub track_limit: Option<u8>,
+    pub allow_songs: Option<bool>,
+    pub render_button: Option<bool>,
+    pub allow_custom_skins: Option<bool>,
+}
@@ -0,0 +1,63 @@
+use futures::future::BoxFuture;
+use serde::Serialize;
+
+/// Reports the health of the bot's subsystems.
+pub trait HealthProvider: Send + Sync + 'static {
+    /// Whether the bot finished starting up.
+    fn is_ready(&self) -> bool;
+
+    /// Check all components. Checks should time out on their own so that the
+    /// endpoint stays responsive.
+    fn components(&self) -> BoxFuture<'_, Vec<ComponentHealth>>;
+}
+
+#[derive(Serialize)]
+pub struct ComponentHealth {
+    pub name: Box<str>,
+    pub status: ComponentStatus,
+    /// Unix timestamp of the last time the component was known to work
+    pub last_success: Option<i64>,
+    /// Whether the bot can't function without this component
+    pub critical: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<Box<str>>,
+}
+
+impl ComponentHealth {
+    pub fn new(name: impl Into<Box<str>>, status: ComponentStatus) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            last_success: None,
+            critical: false,
+            details: None,
+        }
+    }
+
+    pub fn last_success(mut self, last_success: Option<i64>) -> Self {
+        self.last_success = last_success;
+
+        self
+    }
+
+    pub fn critical(mut self) -> Self {
+        self.critical = true;
+
+        self
+    }
+
+    pub fn details(mut self, details: impl Into<Box<str>>) -> Self {
+        self.details = Some(details.into());
+
+        self
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentStatus {
+    Up,
+    Down,
+    /// The component is turned off, e.g. through a feature or config
+    Disabled,
+}
@@ -0,0 +1,29 @@
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::GameMode;
+
+/// Renders images of osu! users so they can be embedded outside of discord.
+///
+/// Rendering is expensive so implementors should cache the resulting PNGs.
+/// The server assumes that images are up to an hour old.
+pub trait ImageProvider: Send + Sync + 'static {
+    /// Whether the provider is ready to handle requests.
+    fn is_ready(&self) -> bool;
+
+    /// PNG bytes of a user's card. Returns `None` if the user was not found or
+    /// has no top scores.
+    fn card(&self, user_id: u32, mode: GameMode) -> BoxFuture<'_, Result<Option<Vec<u8>>>>;
+
+    /// PNG bytes of a user's rank history graph. Returns `None` if the user
+    /// was not found or has no rank history.
+    fn rank_graph(&self, user_id: u32, mode: GameMode) -> BoxFuture<'_, Result<Option<Vec<u8>>>>;
+}
+
+/// Returned by an [`ImageProvider`] if too many images are already waiting to
+/// be rendered.
+///
+/// The server responds with `503 Service Unavailable` instead of an internal
+/// error.
+#[derive(Debug, thiserror::Error)]
+#[error("too many pending image renders")]
+pub struct RenderQueueFull;
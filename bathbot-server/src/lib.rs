@@ -1,14 +1,20 @@
 #[macro_use]
 extern crate tracing;
 
+pub mod api;
+pub mod dashboard;
+pub mod health;
+pub mod images;
+
 mod middleware;
 mod routes;
 mod server;
+mod session;
 mod standby;
 mod state;
 
 pub use self::{
     server::Server,
-    standby::{AuthenticationStandby, AuthenticationStandbyError},
+    standby::{AuthenticationStandby, AuthenticationStandbyError, OsuAuthorization},
     state::AppStateBuilder,
 };
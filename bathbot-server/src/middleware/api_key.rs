@@ -0,0 +1,210 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::State,
+    http::header::AUTHORIZATION,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use hyper::Request;
+
+use crate::{api::hash_api_key, routes::api::ApiError, state::AppState};
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Authenticates requests through the `Authorization: Bearer <key>` or
+/// `X-Api-Key: <key>` header and applies the key's ratelimit.
+pub async fn authenticate<B>(
+    State(state): State<Arc<AppState>>,
+    mut req: Request<B>,
+    next: Next<B>,
+) -> Response {
+    if !state.api.is_ready() {
+        return ApiError::Unavailable.into_response();
+    }
+
+    let headers = req.headers();
+
+    let key = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .or_else(|| {
+            headers
+                .get("x-api-key")
+                .and_then(|value| value.to_str().ok())
+        });
+
+    let Some(key) = key else {
+        return ApiError::MissingKey.into_response();
+    };
+
+    let key = match state.api.api_key(&hash_api_key(key.trim())).await {
+        Ok(Some(key)) => key,
+        Ok(None) => return ApiError::InvalidKey.into_response(),
+        Err(err) => {
+            error!(?err, "Failed to get api key");
+
+            return ApiError::Internal.into_response();
+        }
+    };
+
+    if let Err(retry_after) = state.api_ratelimiter.try_acquire(&key.name, key.rate_limit) {
+        return ApiError::Ratelimited { retry_after }.into_response();
+    }
+
+    req.extensions_mut().insert(key);
+
+    next.run(req).await
+}
+
+/// Fixed-window ratelimiter, counting requests per key and minute.
+///
+/// Also used to limit image requests per IP address.
+#[derive(Default)]
+pub struct ApiRatelimiter {
+    windows: Mutex<RatelimitWindows>,
+}
+
+#[derive(Default)]
+struct RatelimitWindows {
+    by_key: HashMap<Box<str>, RatelimitWindow>,
+    /// Start of each window in the order they were started so that expired
+    /// windows can be dropped without iterating over all of them.
+    expiry: VecDeque<(Instant, Box<str>)>,
+}
+
+struct RatelimitWindow {
+    start: Instant,
+    count: u32,
+}
+
+impl RatelimitWindows {
+    /// Drop all windows that expired. Each window is queued exactly once so
+    /// only the expired ones are visited.
+    fn prune(&mut self, now: Instant) {
+        while let Some((start, _)) = self.expiry.front() {
+            if now.duration_since(*start) < WINDOW {
+                break;
+            }
+
+            if let Some((_, key)) = self.expiry.pop_front() {
+                self.by_key.remove(&key);
+            }
+        }
+    }
+}
+
+impl ApiRatelimiter {
+    /// On failure, returns the amount of seconds until the window resets.
+    pub(crate) fn try_acquire(&self, key: &str, limit: u32) -> Result<(), u64> {
+        self.try_acquire_at(key, limit, Instant::now())
+    }
+
+    fn try_acquire_at(&self, key: &str, limit: u32, now: Instant) -> Result<(), u64> {
+        let mut windows = self.windows.lock().unwrap();
+        windows.prune(now);
+
+        let RatelimitWindows { by_key, expiry } = &mut *windows;
+
+        let window = by_key.entry(Box::from(key)).or_insert_with(|| {
+            expiry.push_back((now, Box::from(key)));
+
+            RatelimitWindow {
+                start: now,
+                count: 0,
+            }
+        });
+
+        if window.count >= limit {
+            let elapsed = now.duration_since(window.start);
+
+            return Err(WINDOW.saturating_sub(elapsed).as_secs().max(1));
+        }
+
+        window.count += 1;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limit_per_window() {
+        let ratelimiter = ApiRatelimiter::default();
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            assert_eq!(ratelimiter.try_acquire_at("key", 3, start), Ok(()));
+        }
+
+        assert_eq!(ratelimiter.try_acquire_at("key", 3, start), Err(60));
+
+        let later = start + Duration::from_secs(45);
+        assert_eq!(ratelimiter.try_acquire_at("key", 3, later), Err(15));
+
+        // Less than a second remaining still requires waiting a second
+        let almost = start + WINDOW - Duration::from_millis(1);
+        assert_eq!(ratelimiter.try_acquire_at("key", 3, almost), Err(1));
+    }
+
+    #[test]
+    fn window_resets() {
+        let ratelimiter = ApiRatelimiter::default();
+        let start = Instant::now();
+
+        assert_eq!(ratelimiter.try_acquire_at("key", 1, start), Ok(()));
+        assert!(ratelimiter.try_acquire_at("key", 1, start).is_err());
+
+        let next = start + WINDOW;
+        assert_eq!(ratelimiter.try_acquire_at("key", 1, next), Ok(()));
+        assert!(ratelimiter.try_acquire_at("key", 1, next).is_err());
+    }
+
+    #[test]
+    fn keys_are_independent() {
+        let ratelimiter = ApiRatelimiter::default();
+        let now = Instant::now();
+
+        assert_eq!(ratelimiter.try_acquire_at("a", 1, now), Ok(()));
+        assert!(ratelimiter.try_acquire_at("a", 1, now).is_err());
+        assert_eq!(ratelimiter.try_acquire_at("b", 1, now), Ok(()));
+    }
+
+    #[test]
+    fn zero_limit_rejects() {
+        let ratelimiter = ApiRatelimiter::default();
+
+        assert!(ratelimiter
+            .try_acquire_at("key", 0, Instant::now())
+            .is_err());
+    }
+
+    #[test]
+    fn expired_windows_are_dropped() {
+        let ratelimiter = ApiRatelimiter::default();
+        let start = Instant::now();
+
+        for i in 0..3 {
+            let _ = ratelimiter.try_acquire_at(&i.to_string(), 1, start);
+        }
+
+        let later = start + Duration::from_secs(30);
+        let _ = ratelimiter.try_acquire_at("key", 1, later);
+
+        assert_eq!(ratelimiter.windows.lock().unwrap().by_key.len(), 4);
+
+        let _ = ratelimiter.try_acquire_at("key", 1, start + WINDOW);
+
+        let windows = ratelimiter.windows.lock().unwrap();
+        assert_eq!(windows.by_key.len(), 1);
+        assert_eq!(windows.expiry.len(), 1);
+    }
+}
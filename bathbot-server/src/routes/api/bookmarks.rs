@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+
+use super::ApiError;
+use crate::{
+    api::{ApiKey, Bookmark},
+    state::AppState,
+};
+
+pub async fn get_bookmarks(
+    Path(discord_id): Path<u64>,
+    Extension(key): Extension<ApiKey>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<Bookmark>>, ApiError> {
+    match state.api.bookmarks(discord_id, key.owner).await {
+        Ok(bookmarks) => Ok(Json(bookmarks)),
+        Err(err) => {
+            error!(?err, discord_id, "Failed to get bookmarks");
+
+            Err(ApiError::Internal)
+        }
+    }
+}
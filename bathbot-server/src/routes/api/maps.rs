@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{rejection::QueryRejection, Path, Query, State},
+    Json,
+};
+use rosu_v2::prelude::GameModsIntermode;
+use serde::Deserialize;
+
+use super::{parse_mode, ApiError};
+use crate::{
+    api::{MapAttributes, MapParams},
+    state::AppState,
+};
+
+#[derive(Deserialize)]
+pub struct MapQuery {
+    mode: Option<String>,
+    /// Either acronyms like `HDDT` or the bitflags
+    mods: Option<String>,
+    acc: Option<f32>,
+    combo: Option<u32>,
+    misses: Option<u32>,
+}
+
+pub async fn get_map(
+    Path(map_id): Path<u32>,
+    query: Result<Query<MapQuery>, QueryRejection>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<MapAttributes>, ApiError> {
+    let Ok(Query(query)) = query else {
+        return Err(ApiError::BadRequest("Invalid query"));
+    };
+
+    let mode = query.mode.as_deref().map(parse_mode).transpose()?;
+
+    let mods = match query.mods.as_deref() {
+        None => 0,
+        Some(mods) => match mods.parse() {
+            Ok(bits) => bits,
+            Err(_) => match GameModsIntermode::try_from_acronyms(mods) {
+                Some(mods) => mods.bits(),
+                None => return Err(ApiError::BadRequest("Invalid mods")),
+            },
+        },
+    };
+
+    if query.acc.is_some_and(|acc| !(0.0..=100.0).contains(&acc)) {
+        return Err(ApiError::BadRequest("Accuracy must be between 0 and 100"));
+    }
+
+    let params = MapParams {
+        map_id,
+        mode,
+        mods,
+        accuracy: query.acc,
+        combo: query.combo,
+        misses: query.misses,
+    };
+
+    match state.api.map_attributes(params).await {
+        Ok(Some(attrs)) => Ok(Json(attrs)),
+        Ok(None) => Err(ApiError::NotFound("Map not found")),
+        Err(err) => {
+            error!(?err, map_id, "Failed to get map attributes");
+
+            Err(ApiError::Internal)
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{rejection::QueryRejection, Path, Query, State},
+    Json,
+};
+use serde::Deserialize;
+
+use super::ApiError;
+use crate::{api::MatchCosts, state::AppState};
+
+#[derive(Deserialize)]
+pub struct MatchCostsQuery {
+    warmups: Option<usize>,
+}
+
+pub async fn get_match_costs(
+    Path(match_id): Path<u32>,
+    query: Result<Query<MatchCostsQuery>, QueryRejection>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<MatchCosts>, ApiError> {
+    let Ok(Query(query)) = query else {
+        return Err(ApiError::BadRequest("Invalid query"));
+    };
+
+    let warmups = query.warmups.unwrap_or(0);
+
+    match state.api.match_costs(match_id, warmups).await {
+        Ok(Some(costs)) => Ok(Json(costs)),
+        Ok(None) => Err(ApiError::NotFound("Match not found or private")),
+        Err(err) => {
+            error!(?err, match_id, "Failed to get match costs");
+
+            Err(ApiError::Internal)
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use axum::{
+    http::{header::RETRY_AFTER, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use rosu_v2::prelude::GameMode;
+use serde::Serialize;
+
+pub mod bookmarks;
+pub mod maps;
+pub mod matches;
+pub mod openapi;
+pub mod users;
+
+pub enum ApiError {
+    BadRequest(&'static str),
+    Internal,
+    MissingKey,
+    InvalidKey,
+    NotFound(&'static str),
+    Ratelimited { retry_after: u64 },
+    Unavailable,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: &'static str,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error) = match self {
+            Self::BadRequest(error) => (StatusCode::BAD_REQUEST, error),
+            Self::Internal => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
+            Self::MissingKey => (StatusCode::UNAUTHORIZED, "Missing api key"),
+            Self::InvalidKey => (StatusCode::UNAUTHORIZED, "Invalid api key"),
+            Self::NotFound(error) => (StatusCode::NOT_FOUND, error),
+            Self::Ratelimited { retry_after } => {
+                let body = ErrorBody {
+                    error: "Ratelimit exceeded",
+                };
+
+                let mut res = (StatusCode::TOO_MANY_REQUESTS, Json(body)).into_response();
+                res.headers_mut()
+                    .insert(RETRY_AFTER, HeaderValue::from(retry_after));
+
+                return res;
+            }
+            Self::Unavailable => (StatusCode::SERVICE_UNAVAILABLE, "Not ready yet"),
+        };
+
+        (status, Json(ErrorBody { error })).into_response()
+    }
+}
+
+fn parse_mode(mode: &str) -> Result<GameMode, ApiError> {
+    match mode {
+        "0" | "osu" => Ok(GameMode::Osu),
+        "1" | "taiko" => Ok(GameMode::Taiko),
+        "2" | "fruits" | "catch" => Ok(GameMode::Catch),
+        "3" | "mania" => Ok(GameMode::Mania),
+        _ => Err(ApiError::BadRequest(
+            "Mode must be one of `osu`, `taiko`, `fruits`, or `mania`",
+        )),
+    }
+}
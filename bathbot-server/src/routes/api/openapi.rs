@@ -0,0 +1,7 @@
+use axum::http::{header::CONTENT_TYPE, HeaderName};
+
+static OPENAPI: &str = include_str!("openapi.json");
+
+pub async fn get_openapi() -> ([(HeaderName, &'static str); 1], &'static str) {
+    ([(CONTENT_TYPE, "application/json")], OPENAPI)
+}
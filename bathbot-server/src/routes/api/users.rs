@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{rejection::QueryRejection, Path, Query, State},
+    Json,
+};
+use rosu_v2::prelude::GameMode;
+use serde::Deserialize;
+
+use super::{parse_mode, ApiError};
+use crate::{api::TopScore, state::AppState};
+
+#[derive(Deserialize)]
+pub struct TopQuery {
+    mode: Option<String>,
+    limit: Option<usize>,
+}
+
+pub async fn get_user_top(
+    Path(user_id): Path<u32>,
+    query: Result<Query<TopQuery>, QueryRejection>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<TopScore>>, ApiError> {
+    let Ok(Query(query)) = query else {
+        return Err(ApiError::BadRequest("Invalid query"));
+    };
+
+    let mode = match query.mode.as_deref() {
+        Some(mode) => parse_mode(mode)?,
+        None => GameMode::Osu,
+    };
+
+    let limit = query.limit.unwrap_or(100);
+
+    if !(1..=100).contains(&limit) {
+        return Err(ApiError::BadRequest("Limit must be between 1 and 100"));
+    }
+
+    match state.api.user_top(user_id, mode, limit).await {
+        Ok(Some(scores)) => Ok(Json(scores)),
+        Ok(None) => Err(ApiError::NotFound("User not found")),
+        Err(err) => {
+            error!(?err, user_id, "Failed to get top scores");
+
+            Err(ApiError::Internal)
+        }
+    }
+}
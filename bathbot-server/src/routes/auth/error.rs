@@ -1,13 +1,16 @@
 use axum::extract::rejection::QueryRejection;
 use handlebars::RenderError;
 use hyper::StatusCode;
-use rosu_v2::prelude::OsuError;
 
 #[derive(Debug, thiserror::Error)]
 #[error("authentication error")]
 pub enum AuthError {
     #[error("bad auth params")]
     BadAuthParams(#[from] QueryRejection),
+    #[error("failed to build request")]
+    BuildRequest(#[from] axum::http::Error),
+    #[error("failed to deserialize osu response")]
+    DeserializeOsu(#[source] serde_json::Error),
     #[error("failed to deserialize twitch response")]
     DeserializeTwitch(serde_json::Error),
     #[error("failed to render page")]
@@ -16,22 +19,25 @@ pub enum AuthError {
     EmptyStandby,
     #[error("received empty twitch data")]
     EmptyTwitchData,
-    #[error("osu api error")]
-    OsuApi(#[source] OsuError),
-    #[error("failed to build authenticated osu client")]
-    OsuAuthClient(#[source] OsuError),
+    #[error("failed to receive osu response")]
+    OsuResponse(#[source] hyper::Error),
     #[error("failed to await response bytes")]
     ResponseBytes(#[source] hyper::Error),
-    #[error("failed to build twitch request")]
-    TwitchRequest(#[from] axum::http::Error),
     #[error("failed to receive twitch response")]
     TwitchResponse(#[source] hyper::Error),
+    #[error("failed to encode form")]
+    Urlencode(#[from] serde_urlencoded::ser::Error),
 }
 
 impl AuthError {
     pub fn response(&self) -> (StatusCode, &'static str) {
         match self {
             Self::BadAuthParams(_) => (StatusCode::BAD_REQUEST, "Insufficient query"),
+            Self::BuildRequest(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
+            Self::DeserializeOsu(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to authenticate user",
+            ),
             Self::DeserializeTwitch(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Unexpected response from twitch API",
@@ -42,14 +48,10 @@ impl AuthError {
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Received empty twitch response",
             ),
-            Self::OsuApi(_) => (StatusCode::INTERNAL_SERVER_ERROR, "osu! API error"),
-            Self::OsuAuthClient(_) => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to authenticate user",
-            ),
+            Self::OsuResponse(_) => (StatusCode::INTERNAL_SERVER_ERROR, "osu! API error"),
             Self::ResponseBytes(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
-            Self::TwitchRequest(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
             Self::TwitchResponse(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
+            Self::Urlencode(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
         }
     }
 }
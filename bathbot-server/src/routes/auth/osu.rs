@@ -5,11 +5,18 @@ use axum::{
     http::StatusCode,
     response::Html,
 };
+use bathbot_model::OsuUserToken;
+use bathbot_util::constants::{OSU_API_V2, OSU_OAUTH_TOKEN};
 use eyre::Report;
-use rosu_v2::Osu;
+use hyper::{
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    Body, Request,
+};
+use rosu_v2::prelude::UserExtended;
+use serde::de::DeserializeOwned;
 
 use super::{AuthError, Params, RenderData, RenderDataKind, RenderDataStatus};
-use crate::state::AppState;
+use crate::{standby::OsuAuthorization, state::AppState};
 
 pub async fn auth_osu(
     query: Result<Query<Params>, QueryRejection>,
@@ -48,18 +55,30 @@ async fn auth(
         return Err(AuthError::EmptyStandby);
     }
 
-    let mut redirect = state.redirect_base.to_string();
-    redirect.push_str("/auth/osu");
+    let client_id = state.osu_client_id.to_string();
+    let redirect_uri = format!("{}/auth/osu", state.redirect_base);
 
-    let osu = Osu::builder()
-        .client_id(state.osu_client_id)
-        .client_secret(&*state.osu_client_secret)
-        .with_authorization(params.code, &*redirect)
-        .build()
-        .await
-        .map_err(AuthError::OsuAuthClient)?;
+    let form = [
+        ("client_id", client_id.as_str()),
+        ("client_secret", &*state.osu_client_secret),
+        ("grant_type", "authorization_code"),
+        ("code", params.code.as_str()),
+        ("redirect_uri", redirect_uri.as_str()),
+    ];
+
+    // Exchanging the code manually instead of through rosu-v2 so that the
+    // token can be handed to the bot
+    let token_req = Request::post(OSU_OAUTH_TOKEN)
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(serde_urlencoded::to_string(form)?))?;
 
-    let user = osu.own_data().await.map_err(AuthError::OsuApi)?;
+    let token: OsuUserToken = osu_request(state, token_req).await?;
+
+    let user_req = Request::get(format!("{OSU_API_V2}me"))
+        .header(AUTHORIZATION, format!("Bearer {}", token.access_token))
+        .body(Body::empty())?;
+
+    let user: UserExtended = osu_request(state, user_req).await?;
 
     let render_data = RenderData {
         status: RenderDataStatus::Success {
@@ -75,7 +94,26 @@ async fn auth(
         "Successful osu! authorization"
     );
 
-    state.standby.process_osu(user, params.state);
+    state
+        .standby
+        .process_osu(OsuAuthorization { user, token }, params.state);
 
     Ok(page)
 }
+
+async fn osu_request<T: DeserializeOwned>(
+    state: &AppState,
+    req: Request<Body>,
+) -> Result<T, AuthError> {
+    let response = state
+        .client
+        .request(req)
+        .await
+        .map_err(AuthError::OsuResponse)?;
+
+    let bytes = hyper::body::to_bytes(response.into_body())
+        .await
+        .map_err(AuthError::ResponseBytes)?;
+
+    serde_json::from_slice(&bytes).map_err(AuthError::DeserializeOsu)
+}
@@ -0,0 +1,68 @@
+use axum::extract::rejection::QueryRejection;
+use handlebars::RenderError;
+use hyper::StatusCode;
+
+#[derive(Debug, thiserror::Error)]
+#[error("dashboard error")]
+pub enum DashboardError {
+    #[error("bad callback params")]
+    BadCallbackParams(#[from] QueryRejection),
+    #[error("failed to deserialize discord response")]
+    DeserializeDiscord(#[source] serde_json::Error),
+    #[error("failed to build discord request")]
+    DiscordRequest(#[from] axum::http::Error),
+    #[error("failed to receive discord response")]
+    DiscordResponse(#[source] hyper::Error),
+    #[error("not allowed to configure the guild")]
+    Forbidden,
+    #[error("internal error")]
+    Internal,
+    #[error("csrf token mismatch")]
+    InvalidCsrf,
+    #[error("oauth state mismatch")]
+    InvalidState,
+    #[error("failed to render page")]
+    Render(#[from] RenderError),
+    #[error("failed to await response bytes")]
+    ResponseBytes(#[source] hyper::Error),
+    #[error("provider not ready yet")]
+    Unavailable,
+    #[error("failed to urlencode")]
+    Urlencode(#[from] serde_urlencoded::ser::Error),
+}
+
+impl DashboardError {
+    pub fn response(&self) -> (StatusCode, &'static str) {
+        match self {
+            Self::BadCallbackParams(_) => (StatusCode::BAD_REQUEST, "Insufficient query"),
+            Self::DeserializeDiscord(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected response from discord",
+            ),
+            Self::DiscordRequest(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
+            Self::DiscordResponse(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "Failed to reach discord")
+            }
+            Self::Forbidden => (
+                StatusCode::FORBIDDEN,
+                "You are not allowed to configure this server",
+            ),
+            Self::Internal => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
+            Self::InvalidCsrf => (
+                StatusCode::FORBIDDEN,
+                "Invalid form submission, please reload the page and try again",
+            ),
+            Self::InvalidState => (
+                StatusCode::BAD_REQUEST,
+                "Invalid login attempt, please try again",
+            ),
+            Self::Render(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
+            Self::ResponseBytes(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
+            Self::Unavailable => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "The bot is still starting up, please try again shortly",
+            ),
+            Self::Urlencode(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{Html, IntoResponse, Redirect, Response},
+    Form,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    bad_request, error::DashboardError, error_page, parse_bool, parse_choice, Choice,
+    HIDE_SOLUTIONS, LIST_SIZES, LOCALES, RETRIES, SCORE_DATA,
+};
+use crate::{dashboard::GuildSettings, session::DashboardSession, state::AppState};
+
+const MAX_PREFIXES: usize = 5;
+const MAX_AUTHORITIES: usize = 10;
+
+pub async fn get_guild(
+    headers: HeaderMap,
+    Path(guild_id): Path<u64>,
+    State(state): State<Arc<AppState>>,
+) -> Response {
+    let (session, guild_name) = match authorize(&headers, guild_id, &state).await {
+        Ok(Some(tuple)) => tuple,
+        Ok(None) => return Redirect::to("/dashboard").into_response(),
+        Err(err) => return error_page(&state, err),
+    };
+
+    render_current(&state, session, guild_id, guild_name, None, None).await
+}
+
+#[derive(Deserialize)]
+pub struct GuildForm {
+    #[serde(default)]
+    prefixes: String,
+    #[serde(default)]
+    authorities: String,
+    #[serde(default)]
+    list_size: String,
+    #[serde(default)]
+    retries: String,
+    #[serde(default)]
+    score_data: String,
+    #[serde(default)]
+    locale: String,
+    #[serde(default)]
+    hide_medal_solution: String,
+    #[serde(default)]
+    track_limit: String,
+    #[serde(default)]
+    allow_songs: String,
+    #[serde(default)]
+    render_button: String,
+    #[serde(default)]
+    allow_custom_skins: String,
+    #[serde(default)]
+    csrf: String,
+}
+
+impl TryFrom<GuildForm> for GuildSettings {
+    type Error = String;
+
+    fn try_from(form: GuildForm) -> Result<Self, Self::Error> {
+        let prefixes: Vec<Box<str>> = form.prefixes.split_whitespace().map(Box::from).collect();
+
+        if prefixes.is_empty() || prefixes.len() > MAX_PREFIXES {
+            return Err(format!(
+                "There must be between one and {MAX_PREFIXES} prefixes"
+            ));
+        }
+
+        let authorities = form
+            .authorities
+            .split_whitespace()
+            .map(|role| role.trim_start_matches("<@&").trim_end_matches('>').parse())
+            .collect::<Result<Vec<u64>, _>>()
+            .map_err(|_| "Authorities must be role ids".to_owned())?;
+
+        if authorities.len() > MAX_AUTHORITIES {
+            return Err(format!(
+                "There can be at most {MAX_AUTHORITIES} authority roles"
+            ));
+        }
+
+        let track_limit = match form.track_limit.trim() {
+            "" => None,
+            limit => match limit.parse() {
+                Ok(limit @ 1..=100) => Some(limit),
+                _ => return Err("The tracking limit must be between 1 and 100".to_owned()),
+            },
+        };
+
+        Ok(Self {
+            prefixes,
+            authorities,
+            list_size: parse_choice(&form.list_size, &LIST_SIZES, "list size")?,
+            retries: parse_choice(&form.retries, &RETRIES, "retries")?,
+            score_data: parse_choice(&form.score_data, &SCORE_DATA, "score data")?,
+            locale: parse_choice(&form.locale, &LOCALES, "language")?,
+            hide_medal_solution: parse_choice(
+                &form.hide_medal_solution,
+                &HIDE_SOLUTIONS,
+                "medal solutions",
+            )?,
+            track_limit,
+            allow_songs: parse_bool(&form.allow_songs, "song commands")?,
+            render_button: parse_bool(&form.render_button, "render button")?,
+            allow_custom_skins: parse_bool(&form.allow_custom_skins, "custom skins")?,
+        })
+    }
+}
+
+pub async fn post_guild(
+    headers: HeaderMap,
+    Path(guild_id): Path<u64>,
+    State(state): State<Arc<AppState>>,
+    Form(form): Form<GuildForm>,
+) -> Response {
+    let (session, guild_name) = match authorize(&headers, guild_id, &state).await {
+        Ok(Some(tuple)) => tuple,
+        Ok(None) => return Redirect::to("/dashboard").into_response(),
+        Err(err) => return error_page(&state, err),
+    };
+
+    if !session.verify_csrf(&form.csrf) {
+        return error_page(&state, DashboardError::InvalidCsrf);
+    }
+
+    let settings = match GuildSettings::try_from(form) {
+        Ok(settings) => settings,
+        Err(content) => {
+            let res =
+                render_current(&state, session, guild_id, guild_name, None, Some(content)).await;
+
+            return bad_request(res);
+        }
+    };
+
+    match state
+        .dashboard
+        .update_guild_settings(guild_id, settings)
+        .await
+    {
+        Ok(None) => {}
+        Ok(Some(prefix)) => {
+            let content = format!("The prefix `{prefix}` is not allowed");
+            let res =
+                render_current(&state, session, guild_id, guild_name, None, Some(content)).await;
+
+            return bad_request(res);
+        }
+        Err(err) => {
+            error!(?err, guild_id, "Failed to update guild settings");
+
+            return error_page(&state, DashboardError::Internal);
+        }
+    }
+
+    info!(
+        guild_id,
+        user = session.user_id,
+        "Updated guild config via dashboard"
+    );
+
+    let notice = Some("Saved".to_owned());
+
+    render_current(&state, session, guild_id, guild_name, notice, None).await
+}
+
+#[derive(Deserialize)]
+pub struct UntrackForm {
+    #[serde(default)]
+    channel: String,
+    #[serde(default)]
+    csrf: String,
+}
+
+pub async fn post_untrack(
+    headers: HeaderMap,
+    Path(guild_id): Path<u64>,
+    State(state): State<Arc<AppState>>,
+    Form(form): Form<UntrackForm>,
+) -> Response {
+    let (session, guild_name) = match authorize(&headers, guild_id, &state).await {
+        Ok(Some(tuple)) => tuple,
+        Ok(None) => return Redirect::to("/dashboard").into_response(),
+        Err(err) => return error_page(&state, err),
+    };
+
+    if !session.verify_csrf(&form.csrf) {
+        return error_page(&state, DashboardError::InvalidCsrf);
+    }
+
+    let channel = form.channel.trim();
+    let channel = channel.trim_start_matches("<#").trim_end_matches('>');
+
+    let Ok(channel_id) = channel.parse() else {
+        let content = "The channel must be a channel id".to_owned();
+        let res = render_current(&state, session, guild_id, guild_name, None, Some(content)).await;
+
+        return bad_request(res);
+    };
+
+    let (notice, error) = match state.dashboard.untrack_channel(guild_id, channel_id).await {
+        Ok(Some(count)) => {
+            let notice = format!("Stopped tracking in the channel, removed {count} osu! entries");
+
+            (Some(notice), None)
+        }
+        Ok(None) => (
+            None,
+            Some("The channel is not part of this server".to_owned()),
+        ),
+        Err(err) => {
+            error!(?err, guild_id, channel_id, "Failed to untrack channel");
+
+            return error_page(&state, DashboardError::Internal);
+        }
+    };
+
+    render_current(&state, session, guild_id, guild_name, notice, error).await
+}
+
+/// Returns `None` if there is no session.
+async fn authorize(
+    headers: &HeaderMap,
+    guild_id: u64,
+    state: &AppState,
+) -> Result<Option<(DashboardSession, Box<str>)>, DashboardError> {
+    let Some(session) = state.sessions.from_headers(headers) else {
+        return Ok(None);
+    };
+
+    if !state.dashboard.is_ready() {
+        return Err(DashboardError::Unavailable);
+    }
+
+    // Permissions might have changed since the login so check again
+    match state
+        .dashboard
+        .manageable_guild(session.user_id, guild_id)
+        .await
+    {
+        Ok(Some(guild_name)) => Ok(Some((session, guild_name))),
+        Ok(None) => Err(DashboardError::Forbidden),
+        Err(err) => {
+            error!(?err, guild_id, "Failed to check guild permissions");
+
+            Err(DashboardError::Internal)
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct GuildData<'c> {
+    session: DashboardSession,
+    guild_id: u64,
+    guild_name: Box<str>,
+    prefixes: String,
+    authorities: String,
+    settings: GuildSettings,
+    notice: Option<String>,
+    error: Option<String>,
+    list_sizes: &'c [Choice],
+    retries: &'c [Choice],
+    score_data: &'c [Choice],
+    locales: &'c [Choice],
+    hide_solutions: &'c [Choice],
+}
+
+/// Render the page with the guild's current settings.
+async fn render_current(
+    state: &AppState,
+    session: DashboardSession,
+    guild_id: u64,
+    guild_name: Box<str>,
+    notice: Option<String>,
+    error: Option<String>,
+) -> Response {
+    let settings = match state.dashboard.guild_settings(guild_id).await {
+        Ok(settings) => settings,
+        Err(err) => {
+            error!(?err, guild_id, "Failed to get guild settings");
+
+            return error_page(state, DashboardError::Internal);
+        }
+    };
+
+    let prefixes = settings.prefixes.join(" ");
+
+    let authorities = settings
+        .authorities
+        .iter()
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let data = GuildData {
+        session,
+        guild_id,
+        guild_name,
+        prefixes,
+        authorities,
+        settings,
+        notice,
+        error,
+        list_sizes: &LIST_SIZES,
+        retries: &RETRIES,
+        score_data: &SCORE_DATA,
+        locales: &LOCALES,
+        hide_solutions: &HIDE_SOLUTIONS,
+    };
+
+    match state.handlebars.render("dashboard_guild", &data) {
+        Ok(page) => Html(page).into_response(),
+        Err(err) => error_page(state, DashboardError::Render(err)),
+    }
+}
@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{rejection::QueryRejection, Query, State},
+    http::{header::SET_COOKIE, HeaderMap},
+    response::{AppendHeaders, IntoResponse, Redirect, Response},
+};
+use hyper::{
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    Body, Request,
+};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+
+use super::{error::DashboardError, error_page};
+use crate::{
+    session::{
+        cookie, random_token, DashboardSession, SessionGuild, SESSION_COOKIE, SESSION_DURATION,
+    },
+    state::AppState,
+};
+
+const DISCORD_AUTHORIZE: &str = "https://discord.com/oauth2/authorize";
+const DISCORD_TOKEN: &str = "https://discord.com/api/v10/oauth2/token";
+const DISCORD_USER: &str = "https://discord.com/api/v10/users/@me";
+const DISCORD_GUILDS: &str = "https://discord.com/api/v10/users/@me/guilds";
+
+const STATE_COOKIE: &str = "bathbot_oauth_state";
+
+pub async fn get_login(State(state): State<Arc<AppState>>) -> Response {
+    let oauth_state = random_token();
+    let client_id = state.discord_client_id.to_string();
+    let redirect_uri = format!("{}/dashboard/callback", state.redirect_base);
+
+    let params = [
+        ("client_id", client_id.as_str()),
+        ("response_type", "code"),
+        ("scope", "identify guilds"),
+        ("redirect_uri", redirect_uri.as_str()),
+        ("state", oauth_state.as_str()),
+    ];
+
+    let query = match serde_urlencoded::to_string(params) {
+        Ok(query) => query,
+        Err(err) => return error_page(&state, DashboardError::Urlencode(err)),
+    };
+
+    let location = format!("{DISCORD_AUTHORIZE}?{query}");
+    let cookie = state.cookie(STATE_COOKIE, &oauth_state, 600);
+
+    (
+        AppendHeaders([(SET_COOKIE, cookie)]),
+        Redirect::to(&location),
+    )
+        .into_response()
+}
+
+#[derive(Deserialize)]
+pub struct CallbackParams {
+    code: String,
+    state: String,
+}
+
+pub async fn get_callback(
+    query: Result<Query<CallbackParams>, QueryRejection>,
+    headers: HeaderMap,
+    State(state): State<Arc<AppState>>,
+) -> Response {
+    match callback(query, &headers, &state).await {
+        Ok(session_id) => {
+            let session_cookie =
+                state.cookie(SESSION_COOKIE, &session_id, SESSION_DURATION.as_secs());
+            let state_cookie = state.cookie(STATE_COOKIE, "", 0);
+
+            let cookies = AppendHeaders([(SET_COOKIE, session_cookie), (SET_COOKIE, state_cookie)]);
+
+            (cookies, Redirect::to("/dashboard")).into_response()
+        }
+        Err(err) => error_page(&state, err),
+    }
+}
+
+async fn callback(
+    query: Result<Query<CallbackParams>, QueryRejection>,
+    headers: &HeaderMap,
+    state: &AppState,
+) -> Result<String, DashboardError> {
+    let Query(params) = query?;
+
+    if cookie(headers, STATE_COOKIE) != Some(params.state.as_str()) {
+        return Err(DashboardError::InvalidState);
+    }
+
+    if !state.dashboard.is_ready() {
+        return Err(DashboardError::Unavailable);
+    }
+
+    let client_id = state.discord_client_id.to_string();
+    let redirect_uri = format!("{}/dashboard/callback", state.redirect_base);
+
+    let form = [
+        ("client_id", client_id.as_str()),
+        ("client_secret", &*state.discord_client_secret),
+        ("grant_type", "authorization_code"),
+        ("code", params.code.as_str()),
+        ("redirect_uri", redirect_uri.as_str()),
+    ];
+
+    let token_req = Request::post(DISCORD_TOKEN)
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(serde_urlencoded::to_string(form)?))?;
+
+    let token: DiscordToken = discord_request(state, token_req).await?;
+    let auth = format!("Bearer {}", token.access_token);
+
+    let user_req = Request::get(DISCORD_USER)
+        .header(AUTHORIZATION, &auth)
+        .body(Body::empty())?;
+
+    let user: DiscordUser = discord_request(state, user_req).await?;
+
+    let guilds_req = Request::get(DISCORD_GUILDS)
+        .header(AUTHORIZATION, &auth)
+        .body(Body::empty())?;
+
+    let discord_guilds: Vec<DiscordGuild> = discord_request(state, guilds_req).await?;
+    let mut guilds = Vec::new();
+
+    for guild in discord_guilds {
+        match state.dashboard.manageable_guild(user.id, guild.id).await {
+            Ok(Some(name)) => guilds.push(SessionGuild { id: guild.id, name }),
+            Ok(None) => {}
+            Err(err) => warn!(?err, guild = guild.id, "Failed to check guild permissions"),
+        }
+    }
+
+    let username = user.global_name.unwrap_or(user.username);
+
+    info!(user = user.id, "Successful dashboard login");
+
+    let session = DashboardSession::new(user.id, username, guilds);
+
+    Ok(state.sessions.insert(session))
+}
+
+pub async fn get_logout(headers: HeaderMap, State(state): State<Arc<AppState>>) -> Response {
+    if let Some(id) = cookie(&headers, SESSION_COOKIE) {
+        state.sessions.remove(id);
+    }
+
+    let cookie = state.cookie(SESSION_COOKIE, "", 0);
+
+    (
+        AppendHeaders([(SET_COOKIE, cookie)]),
+        Redirect::to("/dashboard"),
+    )
+        .into_response()
+}
+
+async fn discord_request<T: DeserializeOwned>(
+    state: &AppState,
+    req: Request<Body>,
+) -> Result<T, DashboardError> {
+    let response = state
+        .client
+        .request(req)
+        .await
+        .map_err(DashboardError::DiscordResponse)?;
+
+    let bytes = hyper::body::to_bytes(response.into_body())
+        .await
+        .map_err(DashboardError::ResponseBytes)?;
+
+    serde_json::from_slice(&bytes).map_err(DashboardError::DeserializeDiscord)
+}
+
+#[derive(Deserialize)]
+struct DiscordToken {
+    access_token: Box<str>,
+}
+
+#[derive(Deserialize)]
+struct DiscordUser {
+    #[serde(deserialize_with = "deserialize_id")]
+    id: u64,
+    username: Box<str>,
+    global_name: Option<Box<str>>,
+}
+
+#[derive(Deserialize)]
+struct DiscordGuild {
+    #[serde(deserialize_with = "deserialize_id")]
+    id: u64,
+}
+
+/// Discord sends ids as strings.
+fn deserialize_id<'de, D: Deserializer<'de>>(d: D) -> Result<u64, D::Error> {
+    let id = <&str as Deserialize>::deserialize(d)?;
+
+    id.parse().map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{Html, IntoResponse, Response},
+};
+use eyre::Report;
+use serde::Serialize;
+
+use self::error::DashboardError;
+use crate::{session::DashboardSession, state::AppState};
+
+pub mod error;
+pub mod guild;
+pub mod login;
+pub mod user;
+
+/// Templates of the dashboard's pages, registered on startup.
+pub const TEMPLATES: [(&str, &str); 4] = [
+    (
+        "dashboard",
+        include_str!("../../../templates/dashboard/index.hbs"),
+    ),
+    (
+        "dashboard_error",
+        include_str!("../../../templates/dashboard/error.hbs"),
+    ),
+    (
+        "dashboard_guild",
+        include_str!("../../../templates/dashboard/guild.hbs"),
+    ),
+    (
+        "dashboard_user",
+        include_str!("../../../templates/dashboard/user.hbs"),
+    ),
+];
+
+/// A value of a select input. The value is the one stored in the database.
+#[derive(Serialize)]
+struct Choice {
+    value: i16,
+    name: &'static str,
+}
+
+const fn choice(value: i16, name: &'static str) -> Choice {
+    Choice { value, name }
+}
+
+const MODES: [Choice; 4] = [
+    choice(0, "osu!"),
+    choice(1, "Taiko"),
+    choice(2, "Catch"),
+    choice(3, "Mania"),
+];
+
+const LIST_SIZES: [Choice; 3] = [
+    choice(0, "Condensed"),
+    choice(1, "Detailed"),
+    choice(2, "Single"),
+];
+
+const RETRIES: [Choice; 3] = [
+    choice(0, "Hide"),
+    choice(1, "Consider mods"),
+    choice(2, "Ignore mods"),
+];
+
+const SCORE_DATA: [Choice; 3] = [
+    choice(1, "Lazer"),
+    choice(0, "Stable"),
+    choice(2, "Lazer (Classic scoring)"),
+];
+
+const LOCALES: [Choice; 4] = [
+    choice(0, "English"),
+    choice(1, "Deutsch"),
+    choice(2, "Polski"),
+    choice(3, "Português (Brasil)"),
+];
+
+const HIDE_SOLUTIONS: [Choice; 3] = [
+    choice(0, "Show all solutions"),
+    choice(1, "Hide Hush-Hush solutions"),
+    choice(2, "Hide all solutions"),
+];
+
+#[derive(Serialize)]
+struct OverviewData {
+    session: Option<DashboardSession>,
+}
+
+pub async fn get_dashboard(headers: HeaderMap, State(state): State<Arc<AppState>>) -> Response {
+    let data = OverviewData {
+        session: state.sessions.from_headers(&headers),
+    };
+
+    match state.handlebars.render("dashboard", &data) {
+        Ok(page) => Html(page).into_response(),
+        Err(err) => error_page(&state, DashboardError::Render(err)),
+    }
+}
+
+fn error_page(state: &AppState, err: DashboardError) -> Response {
+    #[derive(Serialize)]
+    struct ErrorData {
+        error: &'static str,
+    }
+
+    let (status_code, error) = err.response();
+    warn!("{:?}", Report::new(err));
+
+    match state
+        .handlebars
+        .render("dashboard_error", &ErrorData { error })
+    {
+        Ok(page) => (status_code, Html(page)).into_response(),
+        Err(err) => {
+            error!(?err, "Failed to render error page");
+
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Parse the value of a select input, empty values are `None`.
+fn parse_choice(value: &str, choices: &[Choice], field: &str) -> Result<Option<i16>, String> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    value
+        .parse()
+        .ok()
+        .filter(|value| choices.iter().any(|choice| choice.value == *value))
+        .map(Some)
+        .ok_or_else(|| format!("Invalid value for {field}"))
+}
+
+fn parse_bool(value: &str, field: &str) -> Result<Option<bool>, String> {
+    match value {
+        "" => Ok(None),
+        "true" => Ok(Some(true)),
+        "false" => Ok(Some(false)),
+        _ => Err(format!("Invalid value for {field}")),
+    }
+}
+
+/// Turn the re-rendered page of a rejected form into a `400 Bad Request`.
+fn bad_request(mut res: Response) -> Response {
+    if res.status().is_success() {
+        *res.status_mut() = StatusCode::BAD_REQUEST;
+    }
+
+    res
+}
@@ -0,0 +1,183 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::HeaderMap,
+    response::{Html, IntoResponse, Redirect, Response},
+    Form,
+};
+use bathbot_model::embed_builder::ScoreEmbedSettings;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    bad_request, error::DashboardError, error_page, parse_bool, parse_choice, Choice, LIST_SIZES,
+    LOCALES, MODES, RETRIES, SCORE_DATA,
+};
+use crate::{dashboard::UserSettings, session::DashboardSession, state::AppState};
+
+pub async fn get_user(headers: HeaderMap, State(state): State<Arc<AppState>>) -> Response {
+    let Some(session) = state.sessions.from_headers(&headers) else {
+        return Redirect::to("/dashboard").into_response();
+    };
+
+    if !state.dashboard.is_ready() {
+        return error_page(&state, DashboardError::Unavailable);
+    }
+
+    match state.dashboard.user_settings(session.user_id).await {
+        Ok(settings) => render_user(&state, session, settings, None, None),
+        Err(err) => {
+            error!(?err, "Failed to get user settings");
+
+            error_page(&state, DashboardError::Internal)
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct UserForm {
+    #[serde(default)]
+    mode: String,
+    #[serde(default)]
+    list_size: String,
+    #[serde(default)]
+    retries: String,
+    #[serde(default)]
+    score_data: String,
+    #[serde(default)]
+    locale: String,
+    #[serde(default)]
+    render_button: String,
+    #[serde(default)]
+    score_embed: String,
+    #[serde(default)]
+    csrf: String,
+}
+
+impl TryFrom<UserForm> for UserSettings {
+    type Error = String;
+
+    fn try_from(form: UserForm) -> Result<Self, Self::Error> {
+        let score_embed = match form.score_embed.trim() {
+            "" => None,
+            json => match serde_json::from_str::<ScoreEmbedSettings>(json) {
+                Ok(settings) => match settings.validate() {
+                    Ok(()) => Some(settings),
+                    Err(err) => return Err(format!("Invalid score embed layout: {err}")),
+                },
+                Err(err) => return Err(format!("Invalid score embed layout: {err}")),
+            },
+        };
+
+        Ok(Self {
+            mode: parse_choice(&form.mode, &MODES, "mode")?.map(|mode| mode as u8),
+            list_size: parse_choice(&form.list_size, &LIST_SIZES, "list size")?,
+            retries: parse_choice(&form.retries, &RETRIES, "retries")?,
+            render_button: parse_bool(&form.render_button, "render button")?,
+            score_data: parse_choice(&form.score_data, &SCORE_DATA, "score data")?,
+            locale: parse_choice(&form.locale, &LOCALES, "language")?,
+            score_embed,
+        })
+    }
+}
+
+pub async fn post_user(
+    headers: HeaderMap,
+    State(state): State<Arc<AppState>>,
+    Form(form): Form<UserForm>,
+) -> Response {
+    let Some(session) = state.sessions.from_headers(&headers) else {
+        return Redirect::to("/dashboard").into_response();
+    };
+
+    if !state.dashboard.is_ready() {
+        return error_page(&state, DashboardError::Unavailable);
+    }
+
+    if !session.verify_csrf(&form.csrf) {
+        return error_page(&state, DashboardError::InvalidCsrf);
+    }
+
+    let settings = match UserSettings::try_from(form) {
+        Ok(settings) => settings,
+        Err(content) => {
+            return match state.dashboard.user_settings(session.user_id).await {
+                Ok(settings) => {
+                    bad_request(render_user(&state, session, settings, None, Some(content)))
+                }
+                Err(err) => {
+                    error!(?err, "Failed to get user settings");
+
+                    error_page(&state, DashboardError::Internal)
+                }
+            };
+        }
+    };
+
+    let user_id = session.user_id;
+
+    if let Err(err) = state
+        .dashboard
+        .update_user_settings(user_id, settings)
+        .await
+    {
+        error!(?err, "Failed to update user settings");
+
+        return error_page(&state, DashboardError::Internal);
+    }
+
+    match state.dashboard.user_settings(user_id).await {
+        Ok(settings) => render_user(&state, session, settings, Some("Saved"), None),
+        Err(err) => {
+            error!(?err, "Failed to get user settings");
+
+            error_page(&state, DashboardError::Internal)
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct UserData<'c> {
+    session: DashboardSession,
+    settings: UserSettings,
+    notice: Option<&'static str>,
+    error: Option<String>,
+    score_embed: String,
+    modes: &'c [Choice],
+    list_sizes: &'c [Choice],
+    retries: &'c [Choice],
+    score_data: &'c [Choice],
+    locales: &'c [Choice],
+}
+
+fn render_user(
+    state: &AppState,
+    session: DashboardSession,
+    settings: UserSettings,
+    notice: Option<&'static str>,
+    error: Option<String>,
+) -> Response {
+    let score_embed = settings
+        .score_embed
+        .as_ref()
+        .and_then(|settings| serde_json::to_string_pretty(settings).ok())
+        .unwrap_or_default();
+
+    let data = UserData {
+        session,
+        settings,
+        notice,
+        error,
+        score_embed,
+        modes: &MODES,
+        list_sizes: &LIST_SIZES,
+        retries: &RETRIES,
+        score_data: &SCORE_DATA,
+        locales: &LOCALES,
+    };
+
+    match state.handlebars.render("dashboard_user", &data) {
+        Ok(page) => Html(page).into_response(),
+        Err(err) => error_page(state, DashboardError::Render(err)),
+    }
+}
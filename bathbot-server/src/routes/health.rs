@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, Json};
+use serde::Serialize;
+
+use crate::{
+    health::{ComponentHealth, ComponentStatus},
+    state::AppState,
+};
+
+#[derive(Serialize)]
+pub struct HealthReport {
+    status: OverallStatus,
+    components: Vec<ComponentHealth>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum OverallStatus {
+    Ok,
+    /// Only non-critical components are down
+    Degraded,
+    Down,
+}
+
+impl OverallStatus {
+    fn new(components: &[ComponentHealth]) -> Self {
+        let mut status = Self::Ok;
+
+        for component in components {
+            if component.status != ComponentStatus::Down {
+                continue;
+            } else if component.critical {
+                return Self::Down;
+            }
+
+            status = Self::Degraded;
+        }
+
+        status
+    }
+}
+
+/// Health of every component. Responds with 503 if a critical component is
+/// down.
+pub async fn get_health(State(state): State<Arc<AppState>>) -> (StatusCode, Json<HealthReport>) {
+    if !state.health.is_ready() {
+        return not_ready();
+    }
+
+    let components = state.health.components().await;
+    let status = OverallStatus::new(&components);
+
+    let status_code = match status {
+        OverallStatus::Ok | OverallStatus::Degraded => StatusCode::OK,
+        OverallStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+    };
+
+    (status_code, Json(HealthReport { status, components }))
+}
+
+/// Whether the bot started up and all critical components are up. Responds
+/// with 503 otherwise.
+pub async fn get_ready(State(state): State<Arc<AppState>>) -> (StatusCode, Json<HealthReport>) {
+    if !state.health.is_ready() {
+        return not_ready();
+    }
+
+    let mut components = state.health.components().await;
+    components.retain(|component| component.critical);
+    let status = OverallStatus::new(&components);
+
+    let status_code = match status {
+        OverallStatus::Ok => StatusCode::OK,
+        OverallStatus::Degraded | OverallStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+    };
+
+    (status_code, Json(HealthReport { status, components }))
+}
+
+fn not_ready() -> (StatusCode, Json<HealthReport>) {
+    let report = HealthReport {
+        status: OverallStatus::Down,
+        components: Vec::new(),
+    };
+
+    (StatusCode::SERVICE_UNAVAILABLE, Json(report))
+}
@@ -0,0 +1,263 @@
+use std::{
+    fmt::Write,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use axum::{
+    extract::{ConnectInfo, Path, State},
+    http::{
+        header::{CACHE_CONTROL, CONTENT_TYPE, ETAG, IF_NONE_MATCH, RETRY_AFTER},
+        HeaderMap, HeaderValue, StatusCode,
+    },
+    response::{IntoResponse, Response},
+};
+use eyre::Result;
+use rosu_v2::prelude::GameMode;
+use sha2::{Digest, Sha256};
+
+use crate::{images::RenderQueueFull, state::AppState};
+
+/// Matches the duration for which the provider caches images.
+const CACHE_CONTROL_VALUE: &str = "public, max-age=3600";
+
+/// Image requests per IP address and minute.
+const IMAGE_RATELIMIT: u32 = 60;
+
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+pub async fn get_card(
+    Path((user_id, mode)): Path<(u32, String)>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    State(state): State<Arc<AppState>>,
+) -> Response {
+    let Some(mode) = parse_png_mode(&mode) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    if let Err(res) = check_ratelimit(&state, addr, &headers) {
+        return res;
+    }
+
+    if !state.images.is_ready() {
+        return StatusCode::SERVICE_UNAVAILABLE.into_response();
+    }
+
+    let res = state.images.card(user_id, mode).await;
+
+    image_response(res, &headers, user_id, "card")
+}
+
+pub async fn get_rank_graph(
+    Path((user_id, mode)): Path<(u32, String)>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    State(state): State<Arc<AppState>>,
+) -> Response {
+    let Some(mode) = parse_png_mode(&mode) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    if let Err(res) = check_ratelimit(&state, addr, &headers) {
+        return res;
+    }
+
+    if !state.images.is_ready() {
+        return StatusCode::SERVICE_UNAVAILABLE.into_response();
+    }
+
+    let res = state.images.rank_graph(user_id, mode).await;
+
+    image_response(res, &headers, user_id, "rank graph")
+}
+
+fn check_ratelimit(
+    state: &AppState,
+    addr: SocketAddr,
+    headers: &HeaderMap,
+) -> Result<(), Response> {
+    let ip = client_ip(addr.ip(), headers, &state.trusted_proxies);
+    let key = ratelimit_key(ip);
+
+    state
+        .image_ratelimiter
+        .try_acquire(&key, IMAGE_RATELIMIT)
+        .map_err(|retry_after| {
+            let headers = [(RETRY_AFTER, HeaderValue::from(retry_after))];
+
+            (StatusCode::TOO_MANY_REQUESTS, headers).into_response()
+        })
+}
+
+/// The peer address is the client unless it's a trusted proxy. In that case
+/// the last address in `X-Forwarded-For` that is not a trusted proxy is the
+/// client since earlier entries can be spoofed by the client itself.
+fn client_ip(peer: IpAddr, headers: &HeaderMap, trusted_proxies: &[IpAddr]) -> IpAddr {
+    if !trusted_proxies.contains(&peer) {
+        return peer;
+    }
+
+    headers
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|ip| ip.trim().parse::<IpAddr>().ok())
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .find(|ip| !trusted_proxies.contains(ip))
+        .unwrap_or(peer)
+}
+
+/// IPv6 clients usually get a whole /64 prefix assigned so they share a
+/// ratelimit with their prefix instead of getting one per address.
+fn ratelimit_key(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V4(ip) => ip.to_string(),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => ip.to_string(),
+            None => {
+                let [a, b, c, d, ..] = ip.segments();
+
+                format!("{a:x}:{b:x}:{c:x}:{d:x}::/64")
+            }
+        },
+    }
+}
+
+/// Routes can't contain a file extension after a parameter so the mode
+/// parameter includes the `.png` suffix.
+fn parse_png_mode(mode: &str) -> Option<GameMode> {
+    match mode.strip_suffix(".png")? {
+        "osu" => Some(GameMode::Osu),
+        "taiko" => Some(GameMode::Taiko),
+        "fruits" | "catch" => Some(GameMode::Catch),
+        "mania" => Some(GameMode::Mania),
+        _ => None,
+    }
+}
+
+fn image_response(
+    res: Result<Option<Vec<u8>>>,
+    headers: &HeaderMap,
+    user_id: u32,
+    kind: &str,
+) -> Response {
+    let bytes = match res {
+        Ok(Some(bytes)) => bytes,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(err) if err.is::<RenderQueueFull>() => {
+            warn!(user_id, "Render queue full, rejecting {kind}");
+
+            return StatusCode::SERVICE_UNAVAILABLE.into_response();
+        }
+        Err(err) => {
+            error!(?err, user_id, "Failed to render {kind}");
+
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let etag = etag(&bytes);
+
+    let etag_matches = headers
+        .get(IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.split(',').any(|tag| tag.trim() == etag));
+
+    let Ok(etag) = HeaderValue::from_str(&etag) else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+
+    if etag_matches {
+        let headers = [
+            (ETAG, etag),
+            (CACHE_CONTROL, HeaderValue::from_static(CACHE_CONTROL_VALUE)),
+        ];
+
+        return (StatusCode::NOT_MODIFIED, headers).into_response();
+    }
+
+    let headers = [
+        (CONTENT_TYPE, HeaderValue::from_static("image/png")),
+        (ETAG, etag),
+        (CACHE_CONTROL, HeaderValue::from_static(CACHE_CONTROL_VALUE)),
+    ];
+
+    (headers, bytes).into_response()
+}
+
+/// Quoted hex-encoded prefix of the SHA-256 hash of the bytes.
+fn etag(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+
+    let mut etag = String::with_capacity(34);
+    etag.push('"');
+
+    for byte in &digest[..16] {
+        let _ = write!(etag, "{byte:02x}");
+    }
+
+    etag.push('"');
+
+    etag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROXY: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1));
+
+    fn forwarded(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(X_FORWARDED_FOR, HeaderValue::from_static(value));
+
+        headers
+    }
+
+    #[test]
+    fn untrusted_peer_ignores_forwarded_for() {
+        let peer: IpAddr = "203.0.113.7".parse().unwrap();
+        let headers = forwarded("198.51.100.1");
+
+        assert_eq!(client_ip(peer, &headers, &[PROXY]), peer);
+        assert_eq!(client_ip(peer, &headers, &[]), peer);
+    }
+
+    #[test]
+    fn trusted_proxy_uses_last_untrusted_address() {
+        // The first entry was added by the client and must not be trusted
+        let headers = forwarded("192.0.2.1, 198.51.100.1, 10.0.0.1");
+        let client: IpAddr = "198.51.100.1".parse().unwrap();
+
+        assert_eq!(client_ip(PROXY, &headers, &[PROXY]), client);
+    }
+
+    #[test]
+    fn trusted_proxy_without_header_uses_peer() {
+        assert_eq!(client_ip(PROXY, &HeaderMap::new(), &[PROXY]), PROXY);
+    }
+
+    #[test]
+    fn ipv6_is_keyed_by_prefix() {
+        let a: IpAddr = "2001:db8:1:2:aaaa::1".parse().unwrap();
+        let b: IpAddr = "2001:db8:1:2:bbbb::2".parse().unwrap();
+        let c: IpAddr = "2001:db8:1:3::1".parse().unwrap();
+
+        assert_eq!(ratelimit_key(a), "2001:db8:1:2::/64");
+        assert_eq!(ratelimit_key(a), ratelimit_key(b));
+        assert_ne!(ratelimit_key(a), ratelimit_key(c));
+    }
+
+    #[test]
+    fn ipv4_is_keyed_by_address() {
+        let ip: IpAddr = "203.0.113.7".parse().unwrap();
+        let mapped: IpAddr = "::ffff:203.0.113.7".parse().unwrap();
+
+        assert_eq!(ratelimit_key(ip), "203.0.113.7");
+        assert_eq!(ratelimit_key(mapped), "203.0.113.7");
+    }
+}
@@ -1,4 +1,8 @@
+pub mod api;
 pub mod auth;
+pub mod dashboard;
 pub mod guild_count;
+pub mod health;
+pub mod images;
 pub mod metrics;
 pub mod osudirect;
@@ -1,10 +1,10 @@
-use std::{path::PathBuf, sync::Arc, time::Duration};
+use std::{net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
 
 use axum::{
     http::StatusCode,
     middleware,
     response::Response,
-    routing::{get, get_service},
+    routing::{get, get_service, post, MethodRouter},
     Router,
 };
 use eyre::Result;
@@ -14,10 +14,22 @@ use tower_http::{services::ServeDir, trace::TraceLayer};
 use tracing::Span;
 
 use crate::{
-    middleware::metrics::track_metrics,
+    middleware::{api_key::authenticate, metrics::track_metrics},
     routes::{
+        api::{
+            bookmarks::get_bookmarks, maps::get_map, matches::get_match_costs,
+            openapi::get_openapi, users::get_user_top,
+        },
         auth::{osu::auth_osu, twitch::auth_twitch},
+        dashboard::{
+            get_dashboard,
+            guild::{get_guild, post_guild, post_untrack},
+            login::{get_callback, get_login, get_logout},
+            user::{get_user, post_user},
+        },
         guild_count::get_guild_count,
+        health::{get_health, get_ready},
+        images::{get_card, get_rank_graph},
         metrics::get_metrics,
         osudirect::redirect_osudirect,
     },
@@ -58,7 +70,10 @@ impl Server {
         let app = Self::bathbot_app(website_path, Arc::clone(&state));
 
         let server = axum::Server::bind(&([0, 0, 0, 0], port).into())
-            .serve(app.with_state(state).into_make_service())
+            .serve(
+                app.with_state(state)
+                    .into_make_service_with_connect_info::<SocketAddr>(),
+            )
             .with_graceful_shutdown(async {
                 let _ = shutdown_rx.await;
             });
@@ -86,12 +101,51 @@ impl Server {
         Router::new()
             .route("/metrics", get(get_metrics))
             .route("/guild_count", get(get_guild_count))
+            .route("/health", get(get_health))
+            .route("/ready", get(get_ready))
             .nest("/auth", Self::auth_app(website_path))
             .route("/osudirect/:mapset_id", get(redirect_osudirect))
+            .route("/card/:user_id/:mode", get(get_card))
+            .route("/graph/rank/:user_id/:mode", get(get_rank_graph))
+            .nest("/api/v1", Self::api_app(Arc::clone(&state)))
+            .nest("/dashboard", Self::dashboard_app())
             .layer(middleware::from_fn_with_state(state, track_metrics))
             .layer(trace)
     }
 
+    /// Api routes that require an api key. Their paths must match those
+    /// described in `openapi.json`.
+    fn api_routes() -> [(&'static str, MethodRouter<Arc<AppState>>); 4] {
+        [
+            ("/maps/:map_id", get(get_map)),
+            ("/users/:user_id/top", get(get_user_top)),
+            ("/matches/:match_id/costs", get(get_match_costs)),
+            ("/discord/:discord_id/bookmarks", get(get_bookmarks)),
+        ]
+    }
+
+    fn api_app(state: Arc<AppState>) -> Router<Arc<AppState>> {
+        Self::api_routes()
+            .into_iter()
+            .fold(Router::new(), |router, (path, route)| {
+                router.route(path, route)
+            })
+            .layer(middleware::from_fn_with_state(state, authenticate))
+            // The description is accessible without api key
+            .route("/openapi.json", get(get_openapi))
+    }
+
+    fn dashboard_app() -> Router<Arc<AppState>> {
+        Router::new()
+            .route("/", get(get_dashboard))
+            .route("/login", get(get_login))
+            .route("/callback", get(get_callback))
+            .route("/logout", get(get_logout))
+            .route("/user", get(get_user).post(post_user))
+            .route("/guilds/:guild_id", get(get_guild).post(post_guild))
+            .route("/guilds/:guild_id/untrack", post(post_untrack))
+    }
+
     fn auth_app(website_path: PathBuf) -> Router<Arc<AppState>> {
         let mut auth_assets = website_path;
         auth_assets.push("assets/auth");
@@ -110,3 +164,39 @@ impl Server {
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn openapi_paths_match_routes() {
+        let openapi: serde_json::Value =
+            serde_json::from_str(include_str!("routes/api/openapi.json")).unwrap();
+
+        let documented: BTreeSet<_> = openapi["paths"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect();
+
+        // Convert axum's `:param` segments to openapi's `{param}`
+        let routed: BTreeSet<_> = Server::api_routes()
+            .into_iter()
+            .map(|(path, _)| {
+                path.split('/')
+                    .map(|segment| match segment.strip_prefix(':') {
+                        Some(param) => format!("{{{param}}}"),
+                        None => segment.to_owned(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+            .collect();
+
+        assert_eq!(documented, routed);
+    }
+}
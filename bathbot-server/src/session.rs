@@ -0,0 +1,128 @@
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use axum::http::{header::COOKIE, HeaderMap};
+use rand::{thread_rng, RngCore};
+use serde::Serialize;
+
+pub const SESSION_COOKIE: &str = "bathbot_session";
+pub const SESSION_DURATION: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// A discord user that logged into the dashboard.
+#[derive(Clone, Serialize)]
+pub struct DashboardSession {
+    pub user_id: u64,
+    pub username: Box<str>,
+    /// Guilds that the user may configure
+    pub guilds: Vec<SessionGuild>,
+    /// Token that must be included in submitted forms
+    pub csrf_token: Box<str>,
+    #[serde(skip)]
+    created_at: Instant,
+}
+
+#[derive(Clone, Serialize)]
+pub struct SessionGuild {
+    pub id: u64,
+    pub name: Box<str>,
+}
+
+impl DashboardSession {
+    pub fn new(user_id: u64, username: Box<str>, guilds: Vec<SessionGuild>) -> Self {
+        Self {
+            user_id,
+            username,
+            guilds,
+            csrf_token: random_token().into_boxed_str(),
+            created_at: Instant::now(),
+        }
+    }
+
+    /// Whether the token matches the session's csrf token.
+    ///
+    /// Compares in constant time so the token can't be guessed byte by byte.
+    pub fn verify_csrf(&self, token: &str) -> bool {
+        let expected = self.csrf_token.as_bytes();
+        let token = token.as_bytes();
+
+        expected.len() == token.len()
+            && expected
+                .iter()
+                .zip(token)
+                .fold(0, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+
+    fn is_expired(&self) -> bool {
+        self.created_at.elapsed() >= SESSION_DURATION
+    }
+}
+
+/// In-memory storage of dashboard sessions.
+///
+/// Sessions don't survive restarts which is fine since logging in again only
+/// takes a click.
+#[derive(Default)]
+pub struct Sessions {
+    inner: Mutex<HashMap<Box<str>, DashboardSession>>,
+}
+
+impl Sessions {
+    /// Store the session and return its id.
+    pub fn insert(&self, session: DashboardSession) -> String {
+        let id = random_token();
+
+        let mut sessions = self.inner.lock().unwrap();
+        sessions.retain(|_, session| !session.is_expired());
+        sessions.insert(Box::from(id.as_str()), session);
+
+        id
+    }
+
+    pub fn get(&self, id: &str) -> Option<DashboardSession> {
+        let sessions = self.inner.lock().unwrap();
+
+        sessions
+            .get(id)
+            .filter(|session| !session.is_expired())
+            .cloned()
+    }
+
+    /// Get the session of the request's session cookie.
+    pub fn from_headers(&self, headers: &HeaderMap) -> Option<DashboardSession> {
+        cookie(headers, SESSION_COOKIE).and_then(|id| self.get(id))
+    }
+
+    pub fn remove(&self, id: &str) {
+        self.inner.lock().unwrap().remove(id);
+    }
+}
+
+/// Value of a cookie in the request headers.
+pub fn cookie<'h>(headers: &'h HeaderMap, name: &str) -> Option<&'h str> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find_map(|(key, value)| (key == name).then_some(value))
+}
+
+/// Hex-encoded random 32 bytes.
+pub fn random_token() -> String {
+    let mut bytes = [0; 32];
+    thread_rng().fill_bytes(&mut bytes);
+
+    bytes
+        .iter()
+        .fold(String::with_capacity(64), |mut token, byte| {
+            let _ = write!(token, "{byte:02x}");
+
+            token
+        })
+}
@@ -6,7 +6,7 @@ use std::{
     time::Duration,
 };
 
-use bathbot_model::TwitchUser;
+use bathbot_model::{OsuUserToken, TwitchUser};
 use bathbot_util::IntHasher;
 use flexmap::std::StdMutexMap;
 use futures::future::FutureExt;
@@ -23,10 +23,16 @@ pub enum AuthenticationStandbyError {
     Timeout,
 }
 
+/// An authenticated osu! user alongside their token.
+pub struct OsuAuthorization {
+    pub user: UserExtended,
+    pub token: OsuUserToken,
+}
+
 pub struct AuthenticationStandby {
     // u8 is sufficient for 256 concurrent authorization awaitings within two minutes
     current_state: AtomicU8,
-    osu: StdMutexMap<u8, Sender<UserExtended>, IntHasher>,
+    osu: StdMutexMap<u8, Sender<OsuAuthorization>, IntHasher>,
     twitch: StdMutexMap<u8, Sender<TwitchUser>, IntHasher>,
 }
 
@@ -46,7 +52,7 @@ impl AuthenticationStandby {
         Self::default()
     }
 
-    /// Wait for an osu! username to be authenticated.
+    /// Wait for an osu! user to be authenticated.
     pub fn wait_for_osu(&self) -> WaitForOsuAuth {
         let (tx, rx) = oneshot::channel();
         let state = self.generate_state();
@@ -78,9 +84,9 @@ impl AuthenticationStandby {
         self.current_state.fetch_add(1, Ordering::SeqCst)
     }
 
-    pub(super) fn process_osu(&self, user: UserExtended, id: u8) {
+    pub(super) fn process_osu(&self, auth: OsuAuthorization, id: u8) {
         if let Some(tx) = self.osu.lock(&id).remove() {
-            let _ = tx.send(user);
+            let _ = tx.send(auth);
         }
     }
 
@@ -93,11 +99,11 @@ impl AuthenticationStandby {
 
 pub struct WaitForOsuAuth {
     pub state: u8,
-    fut: Pin<Box<Timeout<Receiver<UserExtended>>>>,
+    fut: Pin<Box<Timeout<Receiver<OsuAuthorization>>>>,
 }
 
 impl Future for WaitForOsuAuth {
-    type Output = Result<UserExtended, AuthenticationStandbyError>;
+    type Output = Result<OsuAuthorization, AuthenticationStandbyError>;
 
     #[inline]
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
@@ -1,4 +1,4 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{net::IpAddr, path::PathBuf, sync::Arc};
 
 use bathbot_util::MetricsReader;
 use eyre::{Result, WrapErr};
@@ -11,7 +11,11 @@ use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
 use metrics::describe_histogram;
 use metrics_exporter_prometheus::PrometheusHandle;
 
-use crate::standby::AuthenticationStandby;
+use crate::{
+    api::ApiProvider, dashboard::DashboardProvider, health::HealthProvider, images::ImageProvider,
+    middleware::api_key::ApiRatelimiter, routes::dashboard::TEMPLATES, session::Sessions,
+    standby::AuthenticationStandby,
+};
 
 pub struct AppState {
     pub client: Client<HttpsConnector<HttpConnector<GaiResolver>>, Body>,
@@ -22,8 +26,33 @@ pub struct AppState {
     pub osu_client_secret: Box<str>,
     pub twitch_client_id: Box<str>,
     pub twitch_token: Box<str>,
+    pub discord_client_id: u64,
+    pub discord_client_secret: Box<str>,
     pub redirect_base: Box<str>,
+    pub trusted_proxies: Box<[IpAddr]>,
     pub standby: Arc<AuthenticationStandby>,
+    pub api: Arc<dyn ApiProvider>,
+    pub api_ratelimiter: ApiRatelimiter,
+    pub dashboard: Arc<dyn DashboardProvider>,
+    pub sessions: Sessions,
+    pub images: Arc<dyn ImageProvider>,
+    pub image_ratelimiter: ApiRatelimiter,
+    pub health: Arc<dyn HealthProvider>,
+}
+
+impl AppState {
+    /// `Set-Cookie` value for a cookie of the dashboard.
+    pub fn cookie(&self, name: &str, value: &str, max_age: u64) -> String {
+        let secure = if self.redirect_base.starts_with("https") {
+            "; Secure"
+        } else {
+            ""
+        };
+
+        format!(
+            "{name}={value}; Path=/dashboard; Max-Age={max_age}; HttpOnly; SameSite=Lax{secure}"
+        )
+    }
 }
 
 pub struct AppStateBuilder {
@@ -34,7 +63,17 @@ pub struct AppStateBuilder {
     pub osu_client_secret: String,
     pub twitch_client_id: String,
     pub twitch_token: String,
+    pub discord_client_id: u64,
+    pub discord_client_secret: String,
     pub redirect_base: String,
+    /// Reverse proxies whose `X-Forwarded-For` header is trusted to contain
+    /// the client's address. If empty, the peer address is used as is so the
+    /// server must be exposed directly.
+    pub trusted_proxies: Vec<IpAddr>,
+    pub api: Arc<dyn ApiProvider>,
+    pub dashboard: Arc<dyn DashboardProvider>,
+    pub images: Arc<dyn ImageProvider>,
+    pub health: Arc<dyn HealthProvider>,
 }
 
 impl AppStateBuilder {
@@ -47,7 +86,14 @@ impl AppStateBuilder {
             osu_client_secret,
             twitch_client_id,
             twitch_token,
+            discord_client_id,
+            discord_client_secret,
             redirect_base,
+            trusted_proxies,
+            api,
+            dashboard,
+            images,
+            health,
         } = self;
 
         let connector = HttpsConnectorBuilder::new()
@@ -66,6 +112,12 @@ impl AppStateBuilder {
                 format!("Failed to register auth template at `{path:?}` to handlebars")
             })?;
 
+        for (name, template) in TEMPLATES {
+            handlebars
+                .register_template_string(name, template)
+                .wrap_err_with(|| format!("Failed to register template `{name}` to handlebars"))?;
+        }
+
         describe_histogram!(
             "server_response_time",
             "Response time for requests to the server"
@@ -80,8 +132,18 @@ impl AppStateBuilder {
             osu_client_secret: osu_client_secret.into_boxed_str(),
             twitch_client_id: twitch_client_id.into_boxed_str(),
             twitch_token: twitch_token.into_boxed_str(),
+            discord_client_id,
+            discord_client_secret: discord_client_secret.into_boxed_str(),
             redirect_base: redirect_base.into_boxed_str(),
+            trusted_proxies: trusted_proxies.into_boxed_slice(),
             standby,
+            api,
+            api_ratelimiter: ApiRatelimiter::default(),
+            dashboard,
+            sessions: Sessions::default(),
+            images,
+            image_ratelimiter: ApiRatelimiter::default(),
+            health,
         };
 
         Ok((state, website_path))
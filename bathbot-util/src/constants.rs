@@ -13,6 +13,8 @@ pub const OSU_BASE: &str = "https://osu.ppy.sh/";
 /// FIXME: Endpoint is sometimes wrong so avoid using it, see issue #426
 pub const MAP_THUMB_URL: &str = "https://b.ppy.sh/thumb/";
 pub const AVATAR_URL: &str = "https://a.ppy.sh/";
+pub const OSU_API_V2: &str = "https://osu.ppy.sh/api/v2/";
+pub const OSU_OAUTH_TOKEN: &str = "https://osu.ppy.sh/oauth/token";
 pub const HUISMETBENEN: &str = "https://api.huismetbenen.nl/";
 
 // twitch
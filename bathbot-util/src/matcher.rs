@@ -48,7 +48,6 @@ fn get_mention(mention_type: MentionType, msg: &str) -> Option<u64> {
         .and_then(|c| c.as_str().parse().ok())
 }
 
-#[allow(dead_code)]
 pub fn get_osu_user_id(msg: &str) -> Option<OsuUserId> {
     OSU_URL_USER_MATCHER.get().captures(msg).and_then(|c| {
         c.get(1)
@@ -15,6 +15,14 @@ impl MessageOrigin {
     pub fn new(guild: Option<Id<GuildMarker>>, channel: Id<ChannelMarker>) -> Self {
         Self { guild, channel }
     }
+
+    pub fn guild(&self) -> Option<Id<GuildMarker>> {
+        self.guild
+    }
+
+    pub fn channel(&self) -> Id<ChannelMarker> {
+        self.channel
+    }
 }
 
 impl Display for MessageOrigin {
@@ -0,0 +1,36 @@
+## Common errors
+
+general-issue = Etwas ist schiefgelaufen, gib bade die Schuld
+osu-api-issue = Ein Problem mit der osu!-API, gib bade die Schuld
+ordr-issue = Ein Problem mit der o!rdr-API, gib bade die Schuld
+osekai-issue = Ein Problem mit der osekai-API, gib bade die Schuld
+osustats-api-issue = Ein Problem mit der osustats-API, gib bade die Schuld
+twitch-api-issue = Ein Problem mit der Twitch-API, gib bade die Schuld
+threads-unavailable = Hier kann kein neuer Thread gestartet werden
+
+## Command processing
+
+only-owner = Dieser Befehl kann nur vom Besitzer des Bots verwendet werden
+on-cooldown = Befehl ist noch gesperrt, versuche es in { $seconds } Sekunden erneut
+authority-check-issue = Fehler beim Überprüfen des Autoritätsstatus
+rules-check-issue = Fehler beim Überprüfen der Befehlseinschränkungen
+
+## osu! accounts
+
+require-link = Gib entweder einen osu!-Benutzernamen an oder verknüpfe dich über { $link } mit einem osu!-Profil
+not-linked-account = `{ $account }` ist keiner deiner verknüpften osu!-Accounts
+
+## Slash commands
+
+cmd-config = Passe deine Standardkonfiguration für Befehle an
+cmd-config-language = Lege die Sprache der Antworten des Bots fest
+cmd-serverconfig = Passe Konfigurationen oder Autoritätsrollen für diesen Server an
+cmd-link = Verwalte deine verknüpften osu!-Accounts
+cmd-link-add = Verknüpfe einen weiteren osu!-Account
+cmd-link-remove = Entferne einen verknüpften osu!-Account
+cmd-link-set-primary = Lege deinen primären osu!-Account fest
+cmd-link-mode = Lege den Account fest, der für einen Modus verwendet wird
+cmd-link-list = Zeige alle verknüpften osu!-Accounts an
+cmd-privacy = Exportiere oder lösche die über dich gespeicherten Daten
+cmd-privacy-export = Erhalte alle über dich gespeicherten Daten per DM
+cmd-privacy-delete = Lösche alle über dich gespeicherten Daten
@@ -0,0 +1,21 @@
+## Common errors
+
+general-issue = Something went wrong, blame bade
+osu-api-issue = Some issue with the osu api, blame bade
+ordr-issue = Some issue with the o!rdr api, blame bade
+osekai-issue = Some issue with the osekai api, blame bade
+osustats-api-issue = Some issue with the osustats api, blame bade
+twitch-api-issue = Some issue with the twitch api, blame bade
+threads-unavailable = Cannot start new thread from here
+
+## Command processing
+
+only-owner = That command can only be used by the bot owner
+on-cooldown = Command on cooldown, try again in { $seconds } seconds
+authority-check-issue = Error while checking authority status
+rules-check-issue = Error while checking command restrictions
+
+## osu! accounts
+
+require-link = Either specify an osu! username or link yourself to an osu! profile via { $link }
+not-linked-account = `{ $account }` is not one of your linked osu! accounts
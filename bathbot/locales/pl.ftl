@@ -0,0 +1,36 @@
+## Common errors
+
+general-issue = Coś poszło nie tak, wiń bade
+osu-api-issue = Problem z API osu!, wiń bade
+ordr-issue = Problem z API o!rdr, wiń bade
+osekai-issue = Problem z API osekai, wiń bade
+osustats-api-issue = Problem z API osustats, wiń bade
+twitch-api-issue = Problem z API Twitcha, wiń bade
+threads-unavailable = Nie można tutaj utworzyć nowego wątku
+
+## Command processing
+
+only-owner = Tej komendy może użyć tylko właściciel bota
+on-cooldown = Komenda jest chwilowo niedostępna, spróbuj ponownie za { $seconds } s
+authority-check-issue = Błąd podczas sprawdzania uprawnień
+rules-check-issue = Błąd podczas sprawdzania ograniczeń komend
+
+## osu! accounts
+
+require-link = Podaj nazwę użytkownika osu! albo połącz się z profilem osu! przez { $link }
+not-linked-account = `{ $account }` nie jest jednym z twoich połączonych kont osu!
+
+## Slash commands
+
+cmd-config = Dostosuj swoją domyślną konfigurację komend
+cmd-config-language = Ustaw język odpowiedzi bota
+cmd-serverconfig = Dostosuj konfigurację lub role uprawnień tego serwera
+cmd-link = Zarządzaj połączonymi kontami osu!
+cmd-link-add = Połącz kolejne konto osu!
+cmd-link-remove = Usuń połączone konto osu!
+cmd-link-set-primary = Ustaw swoje główne konto osu!
+cmd-link-mode = Ustaw konto używane dla danego trybu
+cmd-link-list = Wyświetl wszystkie połączone konta osu!
+cmd-privacy = Eksportuj lub usuń dane przechowywane o tobie
+cmd-privacy-export = Otrzymaj wszystkie dane przechowywane o tobie w wiadomości prywatnej
+cmd-privacy-delete = Usuń wszystkie dane przechowywane o tobie
@@ -0,0 +1,36 @@
+## Common errors
+
+general-issue = Algo deu errado, culpe o bade
+osu-api-issue = Algum problema com a API do osu!, culpe o bade
+ordr-issue = Algum problema com a API do o!rdr, culpe o bade
+osekai-issue = Algum problema com a API do osekai, culpe o bade
+osustats-api-issue = Algum problema com a API do osustats, culpe o bade
+twitch-api-issue = Algum problema com a API da Twitch, culpe o bade
+threads-unavailable = Não é possível iniciar um novo tópico aqui
+
+## Command processing
+
+only-owner = Esse comando só pode ser usado pelo dono do bot
+on-cooldown = Comando em espera, tente novamente em { $seconds } segundos
+authority-check-issue = Erro ao verificar o status de autoridade
+rules-check-issue = Erro ao verificar as restrições de comandos
+
+## osu! accounts
+
+require-link = Especifique um nome de usuário do osu! ou vincule-se a um perfil do osu! via { $link }
+not-linked-account = `{ $account }` não é uma das suas contas do osu! vinculadas
+
+## Slash commands
+
+cmd-config = Ajuste sua configuração padrão para comandos
+cmd-config-language = Defina o idioma das respostas do bot
+cmd-serverconfig = Ajuste configurações ou cargos de autoridade deste servidor
+cmd-link = Gerencie suas contas do osu! vinculadas
+cmd-link-add = Vincule outra conta do osu!
+cmd-link-remove = Remova uma conta do osu! vinculada
+cmd-link-set-primary = Defina sua conta principal do osu!
+cmd-link-mode = Defina a conta usada para um modo
+cmd-link-list = Mostre todas as contas do osu! vinculadas
+cmd-privacy = Exporte ou exclua os dados armazenados sobre você
+cmd-privacy-export = Receba todos os dados armazenados sobre você por DM
+cmd-privacy-delete = Exclua todos os dados armazenados sobre você
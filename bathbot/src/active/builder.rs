@@ -87,11 +87,13 @@ impl ActiveMessagesBuilder {
             let (activity_tx, activity_rx) = watch::channel(());
 
             if let Some(until_timeout) = active_msg.until_timeout() {
-                ActiveMessagesBuilder::spawn_timeout(activity_rx, response, until_timeout);
+                ActiveMessagesBuilder::spawn_timeout(activity_rx, response.clone(), until_timeout);
 
                 let full = FullActiveMessage {
                     active_msg,
                     activity_tx,
+                    response,
+                    persisted: false,
                 };
 
                 Context::get().active_msgs.insert(msg, full).await;
@@ -114,7 +116,11 @@ impl ActiveMessagesBuilder {
         }
     }
 
-    fn spawn_timeout(mut rx: Receiver<()>, response: ActiveResponse, until_timeout: Duration) {
+    pub(super) fn spawn_timeout(
+        mut rx: Receiver<()>,
+        response: ActiveResponse,
+        until_timeout: Duration,
+    ) {
         tokio::spawn(async move {
             loop {
                 tokio::select! {
@@ -8,11 +8,12 @@ use std::{
 };
 
 use bathbot_macros::PaginationBuilder;
-use bathbot_psql::model::osu::MapBookmark;
+use bathbot_psql::model::osu::{BookmarkFolder, MapBookmark};
 use bathbot_util::{
     constants::{AVATAR_URL, OSU_BASE},
     datetime::SecToMinSec,
     fields,
+    modal::{ModalBuilder, TextInputBuilder},
     numbers::round,
     EmbedBuilder, FooterBuilder, IntHasher, MessageOrigin,
 };
@@ -22,7 +23,7 @@ use rosu_pp::{Beatmap, Difficulty, Performance};
 use rosu_v2::prelude::{GameMode, Username};
 use twilight_model::{
     channel::message::{
-        component::{ActionRow, Button, ButtonStyle},
+        component::{ActionRow, Button, ButtonStyle, SelectMenu, SelectMenuOption, TextInputStyle},
         Component,
     },
     id::{marker::UserMarker, Id},
@@ -34,19 +35,27 @@ use crate::{
         response::ActiveResponse,
         BuildPage, ComponentResult, IActiveMessage,
     },
+    commands::osu::BookmarksFilter,
     core::Context,
     manager::redis::{osu::UserArgs, RedisData},
-    util::{interaction::InteractionComponent, Authored, ComponentExt, Emote},
+    util::{
+        interaction::{InteractionComponent, InteractionModal},
+        Authored, ComponentExt, Emote, ModalExt,
+    },
 };
 
 #[derive(PaginationBuilder)]
 pub struct BookmarksPagination {
     #[pagination(per_page = 1)]
     bookmarks: Vec<MapBookmark>,
+    folders: Vec<BookmarkFolder>,
+    folder: Option<Box<str>>,
+    readonly: Option<bool>,
     origin: MessageOrigin,
     cached_entries: CachedBookmarkEntries,
     defer_next: bool,
     filtered_maps: Option<bool>,
+    filter: Option<BookmarksFilter>,
     confirm_remove: Option<bool>,
     token: String,
     msg_owner: Id<UserMarker>,
@@ -233,11 +242,20 @@ impl BookmarksPagination {
             None => (map.creator_name.as_ref(), map.creator_id),
         };
 
-        let footer_text = format!(
-            "Page {page}/{pages} • {status:?} map of {mapper_name}",
+        let mut footer_text = format!(
+            "Page {page}/{pages} • ",
             page = self.pages.curr_page(),
             pages = self.pages.last_page(),
-            status = map.status,
+        );
+
+        if let Some(ref folder) = map.folder {
+            let _ = write!(footer_text, "Folder: {folder} • ");
+        }
+
+        let _ = write!(
+            footer_text,
+            "{status:?} map of {mapper_name}",
+            status = map.status
         );
 
         let footer = FooterBuilder::new(footer_text).icon_url(format!("{AVATAR_URL}{mapper_id}"));
@@ -267,6 +285,10 @@ impl BookmarksPagination {
             GameMode::Catch => {}
         }
 
+        if let Some(ref note) = map.note {
+            let _ = write!(description, "\n:pencil: {note}");
+        }
+
         let embed = EmbedBuilder::new()
             .description(description)
             .fields(fields)
@@ -284,7 +306,7 @@ impl BookmarksPagination {
             Err(err) => return ComponentResult::Err(err),
         };
 
-        if owner != self.msg_owner {
+        if owner != self.msg_owner || self.readonly() {
             return ComponentResult::Ignore;
         }
 
@@ -299,8 +321,15 @@ impl BookmarksPagination {
             return ComponentResult::Err(err);
         }
 
-        self.pages = Pages::new(1, self.bookmarks.len());
-        self.pages.set_index(idx);
+        // Removing the last bookmark of a folder also deletes the folder
+        if bookmark.folder.is_some() {
+            match Context::bookmarks().folders(owner).await {
+                Ok(folders) => self.folders = folders,
+                Err(err) => return ComponentResult::Err(err),
+            }
+        }
+
+        self.reset_pages(idx);
         self.defer_next = true;
 
         debug!(user = %self.msg_owner, map = bookmark.map_id, "Removed bookmarked map");
@@ -308,19 +337,291 @@ impl BookmarksPagination {
         ComponentResult::BuildPage
     }
 
+    async fn handle_publish(&mut self, component: &InteractionComponent) -> ComponentResult {
+        let owner = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if owner != self.msg_owner || self.readonly() {
+            return ComponentResult::Ignore;
+        }
+
+        let Some(folder) = self.current_folder_mut() else {
+            return ComponentResult::Ignore;
+        };
+
+        let public = !folder.public;
+        let folder_id = folder.folder_id;
+
+        if let Err(err) = component.defer().await {
+            return ComponentResult::Err(Report::new(err).wrap_err("Failed to defer component"));
+        }
+
+        let publish_fut = Context::bookmarks().set_folder_public(owner, folder_id, public);
+
+        if let Err(err) = publish_fut.await {
+            return ComponentResult::Err(err);
+        }
+
+        if let Some(folder) = self.current_folder_mut() {
+            folder.public = public;
+        }
+
+        self.defer_next = true;
+
+        debug!(user = %owner, folder_id, public, "Changed bookmark folder visibility");
+
+        ComponentResult::BuildPage
+    }
+
+    async fn handle_folder_menu(&mut self, component: &InteractionComponent) -> ComponentResult {
+        let owner = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if owner != self.msg_owner || self.readonly() {
+            return ComponentResult::Ignore;
+        }
+
+        let Some(value) = component.data.values.first() else {
+            return ComponentResult::Err(eyre!("Missing value for bookmark folder menu"));
+        };
+
+        let folder = match value.as_str() {
+            ALL_BOOKMARKS => None,
+            value => {
+                let folder_opt = value
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(|id| self.folders.iter().find(|folder| folder.folder_id == id));
+
+                match folder_opt {
+                    Some(folder) => Some(folder.name.clone()),
+                    None => {
+                        return ComponentResult::Err(eyre!("Unknown bookmark folder `{value}`"))
+                    }
+                }
+            }
+        };
+
+        if let Err(err) = component.defer().await {
+            return ComponentResult::Err(Report::new(err).wrap_err("Failed to defer component"));
+        }
+
+        let mut bookmarks = match Context::bookmarks().get(owner).await {
+            Ok(bookmarks) => bookmarks,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if let Some(ref folder) = folder {
+            bookmarks.retain(|bookmark| bookmark.folder.as_ref() == Some(folder));
+        }
+
+        // Keep the query, mode, and order the list was created with
+        let filtering = match self.filter {
+            Some(ref filter) => {
+                filter.apply(&mut bookmarks);
+                self.content = filter.content(folder.as_deref(), None);
+
+                filter.is_filtering()
+            }
+            None => {
+                self.content = match folder {
+                    Some(ref folder) => format!("`Folder: {folder}`"),
+                    None => String::new(),
+                };
+
+                false
+            }
+        };
+
+        self.filtered_maps = Some(filtering || folder.is_some());
+        self.folder = folder;
+        self.pages = Pages::new(1, bookmarks.len());
+        self.bookmarks = bookmarks;
+        self.defer_next = true;
+
+        ComponentResult::BuildPage
+    }
+
+    async fn async_handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
+        let owner = modal.user_id()?;
+
+        if owner != self.msg_owner || self.readonly() {
+            return Ok(());
+        }
+
+        let input = modal
+            .data
+            .components
+            .first()
+            .and_then(|row| row.components.first())
+            .wrap_err("Missing bookmark modal input")?
+            .value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+
+        let idx = self.pages.index();
+
+        let Some(bookmark) = self.bookmarks.get_mut(idx) else {
+            return Ok(());
+        };
+
+        let map_id = bookmark.map_id;
+
+        match modal.data.custom_id.as_str() {
+            "bookmarks_note" => {
+                Context::bookmarks().set_note(owner, map_id, input).await?;
+                bookmark.note = input.map(Box::from);
+
+                debug!(user = %owner, map = map_id, "Updated bookmark note");
+            }
+            "bookmarks_folder" => {
+                // Re-use the casing of an existing folder with the same name
+                let folder = input.map(|name| {
+                    self.folders
+                        .iter()
+                        .find(|folder| folder.name.eq_ignore_ascii_case(name))
+                        .map_or_else(|| Box::from(name), |folder| folder.name.clone())
+                });
+
+                let folder_fut = Context::bookmarks().set_folder(owner, map_id, folder.as_deref());
+                folder_fut.await?;
+                bookmark.folder = folder;
+
+                self.folders = Context::bookmarks().folders(owner).await?;
+
+                // Remove the bookmark from the list if it no longer belongs to
+                // the currently shown folder
+                if self.folder.is_some() && self.folder != self.bookmarks[idx].folder {
+                    self.bookmarks.remove(idx);
+                    self.reset_pages(idx);
+                }
+
+                debug!(user = %owner, map = map_id, "Updated bookmark folder");
+            }
+            other => {
+                warn!(name = %other, ?modal, "Unknown bookmarks modal");
+
+                return Ok(());
+            }
+        }
+
+        modal.defer().await.wrap_err("Failed to defer modal")?;
+        self.defer_next = true;
+
+        Ok(())
+    }
+
+    /// Recalculate pages after the amount of bookmarks changed and move to
+    /// the given index or the last page if the index is out of bounds.
+    fn reset_pages(&mut self, idx: usize) {
+        self.pages = Pages::new(1, self.bookmarks.len());
+        self.pages
+            .set_index(idx.min(self.bookmarks.len().saturating_sub(1)));
+    }
+
+    fn readonly(&self) -> bool {
+        self.readonly.unwrap_or(false)
+    }
+
+    fn current_folder_mut(&mut self) -> Option<&mut BookmarkFolder> {
+        let name = self.folder.as_ref()?;
+
+        self.folders.iter_mut().find(|folder| &folder.name == name)
+    }
+
+    fn note_modal(&self) -> ModalBuilder {
+        let mut input = TextInputBuilder::new("bookmark_note", "Note")
+            .placeholder("Leave empty to remove the note")
+            .style(TextInputStyle::Paragraph)
+            .max_len(200)
+            .required(false);
+
+        if let Some(note) = self
+            .bookmarks
+            .get(self.pages.index())
+            .and_then(|bookmark| bookmark.note.as_deref())
+        {
+            input = input.value(note);
+        }
+
+        ModalBuilder::new("bookmarks_note", "Edit the bookmark note").input(input)
+    }
+
+    fn folder_modal(&self) -> ModalBuilder {
+        let mut input = TextInputBuilder::new("bookmark_folder", "Folder name")
+            .placeholder("Leave empty to remove the bookmark from its folder")
+            .max_len(32)
+            .required(false);
+
+        if let Some(folder) = self
+            .bookmarks
+            .get(self.pages.index())
+            .and_then(|bookmark| bookmark.folder.as_deref())
+        {
+            input = input.value(folder);
+        }
+
+        ModalBuilder::new("bookmarks_folder", "Move the bookmark into a folder").input(input)
+    }
+
+    fn folder_menu(&self) -> Component {
+        let all = SelectMenuOption {
+            default: self.folder.is_none(),
+            description: None,
+            emoji: None,
+            label: "All bookmarks".to_owned(),
+            value: ALL_BOOKMARKS.to_owned(),
+        };
+
+        let folders = self.folders.iter().take(24).map(|folder| SelectMenuOption {
+            default: self.folder.as_ref() == Some(&folder.name),
+            description: folder.public.then(|| "Published".to_owned()),
+            emoji: None,
+            label: folder.name.to_string(),
+            value: folder.folder_id.to_string(),
+        });
+
+        let menu = SelectMenu {
+            custom_id: "bookmarks_folder_menu".to_owned(),
+            disabled: false,
+            max_values: None,
+            min_values: None,
+            options: [all].into_iter().chain(folders).collect(),
+            placeholder: Some("Choose a folder".to_owned()),
+        };
+
+        Component::ActionRow(ActionRow {
+            components: vec![Component::SelectMenu(menu)],
+        })
+    }
+
     pub fn set_index(&mut self, index: usize) {
         self.pages.set_index(index);
     }
 }
 
+/// Select menu value for showing bookmarks of all folders.
+const ALL_BOOKMARKS: &str = "all";
+
 impl IActiveMessage for BookmarksPagination {
     fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
         Box::pin(self.async_build_page())
     }
 
     fn build_components(&self) -> Vec<Component> {
+        let readonly = self.readonly();
+
         if self.bookmarks.is_empty() {
-            return Vec::new();
+            if readonly || self.folders.is_empty() {
+                return Vec::new();
+            }
+
+            return vec![self.folder_menu()];
         }
 
         let jump_start = Button {
@@ -379,6 +680,17 @@ impl IActiveMessage for BookmarksPagination {
             url: None,
         };
 
+        if readonly {
+            let components = vec![
+                Component::Button(jump_start),
+                Component::Button(single_step_back),
+                Component::Button(single_step),
+                Component::Button(jump_end),
+            ];
+
+            return vec![Component::ActionRow(ActionRow { components })];
+        }
+
         let components = vec![
             Component::Button(jump_start),
             Component::Button(single_step_back),
@@ -387,7 +699,62 @@ impl IActiveMessage for BookmarksPagination {
             Component::Button(jump_end),
         ];
 
-        vec![Component::ActionRow(ActionRow { components })]
+        let note = Button {
+            custom_id: Some("bookmarks_note".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Note".to_owned()),
+            style: ButtonStyle::Secondary,
+            url: None,
+        };
+
+        let folder = Button {
+            custom_id: Some("bookmarks_folder".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Folder".to_owned()),
+            style: ButtonStyle::Secondary,
+            url: None,
+        };
+
+        let mut edit_components = vec![Component::Button(note), Component::Button(folder)];
+
+        let curr_folder = self
+            .folder
+            .as_ref()
+            .and_then(|name| self.folders.iter().find(|folder| &folder.name == name));
+
+        if let Some(curr_folder) = curr_folder {
+            let (label, style) = if curr_folder.public {
+                ("Unpublish folder", ButtonStyle::Secondary)
+            } else {
+                ("Publish folder", ButtonStyle::Primary)
+            };
+
+            let publish = Button {
+                custom_id: Some("bookmarks_publish".to_owned()),
+                disabled: false,
+                emoji: None,
+                label: Some(label.to_owned()),
+                style,
+                url: None,
+            };
+
+            edit_components.push(Component::Button(publish));
+        }
+
+        let mut components = vec![
+            Component::ActionRow(ActionRow { components }),
+            Component::ActionRow(ActionRow {
+                components: edit_components,
+            }),
+        ];
+
+        if !self.folders.is_empty() {
+            components.push(self.folder_menu());
+        }
+
+        components
     }
 
     fn handle_component<'a>(
@@ -403,6 +770,25 @@ impl IActiveMessage for BookmarksPagination {
                 Box::pin(ready(ComponentResult::BuildPage))
             }
             "bookmarks_confirm_remove" => Box::pin(self.handle_remove(component)),
+            "bookmarks_note" | "bookmarks_folder" => {
+                let is_owner = component
+                    .user_id()
+                    .is_ok_and(|user_id| user_id == self.msg_owner);
+
+                if !is_owner || self.readonly() {
+                    return ComponentResult::Ignore.boxed();
+                }
+
+                let modal = if component.data.custom_id == "bookmarks_note" {
+                    self.note_modal()
+                } else {
+                    self.folder_modal()
+                };
+
+                ComponentResult::CreateModal(modal).boxed()
+            }
+            "bookmarks_publish" => Box::pin(self.handle_publish(component)),
+            "bookmarks_folder_menu" => Box::pin(self.handle_folder_menu(component)),
             _ => {
                 self.defer_next = true;
 
@@ -411,8 +797,17 @@ impl IActiveMessage for BookmarksPagination {
         }
     }
 
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(self.async_handle_modal(modal))
+    }
+
     fn until_timeout(&self) -> Option<Duration> {
-        (!self.bookmarks.is_empty()).then_some(Duration::from_secs(60))
+        let active = !self.bookmarks.is_empty() || (!self.readonly() && !self.folders.is_empty());
+
+        active.then_some(Duration::from_secs(60))
     }
 
     fn on_timeout(&mut self, _: ActiveResponse) -> BoxFuture<'_, Result<()>> {
@@ -9,23 +9,32 @@ use bathbot_model::{
         SettingValue, SettingsButtons, SettingsImage, Value,
     },
     rosu_v2::user::User,
+    twilight_model::id::IdRkyv,
 };
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::MessageBuilder;
-use eyre::{Result, WrapErr};
+use eyre::{Report, Result, WrapErr};
 use futures::future::BoxFuture;
+use rkyv::{Archive, Deserialize, Serialize};
 use twilight_model::{
     channel::message::{
         component::{ActionRow, Button, ButtonStyle, SelectMenu, SelectMenuOption},
         Component, ReactionType,
     },
-    id::{marker::UserMarker, Id},
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
 };
 
 use super::{single_score::MarkIndex, SingleScoreContent, SingleScorePagination};
 use crate::{
-    active::{response::ActiveResponse, BuildPage, ComponentResult, IActiveMessage},
-    commands::utility::ScoreEmbedDataWrap,
+    active::{
+        persist::{PersistedKind, PersistedState},
+        response::ActiveResponse,
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    commands::utility::{builder_data, ScoreEmbedDataWrap},
     core::Context,
     manager::redis::RedisData,
     util::{interaction::InteractionComponent, Authored, Emote},
@@ -37,6 +46,57 @@ pub struct ScoreEmbedBuilderActive {
     section: EmbedSection,
     value_kind: ValueKind,
     msg_owner: Id<UserMarker>,
+    target: ScoreEmbedTarget,
+}
+
+/// Whose score embed settings are being built.
+#[derive(Archive, Copy, Clone, Deserialize, Serialize)]
+pub enum ScoreEmbedTarget {
+    User,
+    Guild(#[with(IdRkyv)] Id<GuildMarker>),
+}
+
+/// Persisted state of a [`ScoreEmbedBuilderActive`].
+///
+/// The preview score is fetched again on restore so only the settings and
+/// the builder's navigation state are stored.
+#[derive(Archive, Deserialize, Serialize)]
+pub struct PersistedScoreEmbedBuilder {
+    /// JSON of the [`ScoreEmbedSettings`] since they're not archivable
+    settings: Box<str>,
+    score_data: i16,
+    content: ContentStatus,
+    section: EmbedSection,
+    value_kind: ValueKind,
+    #[with(IdRkyv)]
+    msg_owner: Id<UserMarker>,
+    target: ScoreEmbedTarget,
+}
+
+impl PersistedScoreEmbedBuilder {
+    pub async fn restore(self) -> Result<ScoreEmbedBuilderActive> {
+        let settings: ScoreEmbedSettings =
+            serde_json::from_str(&self.settings).wrap_err("Failed to deserialize settings")?;
+
+        let score_data = ScoreData::try_from(self.score_data).unwrap_or_default();
+
+        let (user, data) = builder_data(score_data.is_legacy()).await?;
+
+        let mut active_msg = ScoreEmbedBuilderActive::new(
+            &user,
+            data,
+            settings,
+            score_data,
+            self.msg_owner,
+            self.target,
+        );
+
+        active_msg.content = self.content;
+        active_msg.section = self.section;
+        active_msg.value_kind = self.value_kind;
+
+        Ok(active_msg)
+    }
 }
 
 impl ScoreEmbedBuilderActive {
@@ -46,6 +106,7 @@ impl ScoreEmbedBuilderActive {
         settings: ScoreEmbedSettings,
         score_data: ScoreData,
         msg_owner: Id<UserMarker>,
+        target: ScoreEmbedTarget,
     ) -> Self {
         let inner = SingleScorePagination::new(
             user,
@@ -62,6 +123,7 @@ impl ScoreEmbedBuilderActive {
             section: EmbedSection::None,
             value_kind: ValueKind::None,
             msg_owner,
+            target,
         }
     }
 
@@ -673,10 +735,22 @@ impl ScoreEmbedBuilderActive {
             debug!(values = ?self.inner.settings.values, "Wrong setting values order");
         }
 
-        let store_fut =
-            Context::user_config().store_score_embed_settings(self.msg_owner, &self.inner.settings);
+        let store_res = match self.target {
+            ScoreEmbedTarget::User => {
+                Context::user_config()
+                    .store_score_embed_settings(self.msg_owner, &self.inner.settings)
+                    .await
+            }
+            ScoreEmbedTarget::Guild(guild_id) => {
+                let settings = self.inner.settings.clone();
 
-        match store_fut.await {
+                Context::guild_config()
+                    .update(guild_id, |config| config.score_embed = Some(settings))
+                    .await
+            }
+        };
+
+        match store_res {
             Ok(_) => self.content = ContentStatus::Preview,
             Err(err) => {
                 self.content = ContentStatus::Error;
@@ -1480,6 +1554,32 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
         Box::pin(self.async_handle_component(component))
     }
 
+    fn persist(&self) -> Option<Result<PersistedState>> {
+        let settings = match serde_json::to_string(&self.inner.settings) {
+            Ok(settings) => settings.into_boxed_str(),
+            Err(err) => {
+                return Some(Err(
+                    Report::new(err).wrap_err("Failed to serialize settings")
+                ))
+            }
+        };
+
+        let persisted = PersistedScoreEmbedBuilder {
+            settings,
+            score_data: self.inner.score_data().into(),
+            content: self.content,
+            section: self.section,
+            value_kind: self.value_kind,
+            msg_owner: self.msg_owner,
+            target: self.target,
+        };
+
+        Some(PersistedState::new(
+            PersistedKind::ScoreEmbedBuilder,
+            &persisted,
+        ))
+    }
+
     fn on_timeout(&mut self, response: ActiveResponse) -> BoxFuture<'_, Result<()>> {
         let content = match self.content {
             ContentStatus::Preview => "Settings saved successfully ✅",
@@ -1507,7 +1607,7 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Archive, Copy, Clone, Deserialize, Serialize)]
 enum ContentStatus {
     Preview,
     Error,
@@ -1522,7 +1622,7 @@ impl ContentStatus {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Archive, Copy, Clone, Deserialize, Serialize)]
 pub enum EmbedSection {
     None,
     ScoreData,
@@ -1530,7 +1630,7 @@ pub enum EmbedSection {
     Buttons,
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Archive, Copy, Clone, Deserialize, PartialEq, Eq, Serialize)]
 pub enum ValueKind {
     None,
     Artist,
@@ -311,6 +311,11 @@ impl CommandIter {
                         InteractionCommandKind::Message(command) => {
                             let Command { name: name_, .. } = (command.create)();
 
+                            (name_, Vec::new())
+                        }
+                        InteractionCommandKind::User(command) => {
+                            let Command { name: name_, .. } = (command.create)();
+
                             (name_, Vec::new())
                         }
                     };
@@ -381,6 +386,15 @@ impl From<InteractionCommandKind> for CommandParts {
             InteractionCommandKind::Message(command) => {
                 let Command { description, .. } = (command.create)();
 
+                Self {
+                    help: description,
+                    root: true,
+                    options: Vec::new(),
+                }
+            }
+            InteractionCommandKind::User(command) => {
+                let Command { description, .. } = (command.create)();
+
                 Self {
                     help: description,
                     root: true,
@@ -250,8 +250,8 @@ impl HelpPrefixMenu {
         );
 
         let link = InteractionCommands::get_command("link").map_or_else(
-            || "`/link`".to_owned(),
-            |cmd| cmd.mention("link").to_string(),
+            || "`/link add`".to_owned(),
+            |cmd| cmd.mention("link add").to_string(),
         );
 
         let description = format!(
@@ -263,7 +263,8 @@ impl HelpPrefixMenu {
             - If you want to specify an argument, e.g. a username, that contains \
             spaces, you must encapsulate it with `\"` i.e. `\"nathan on osu\"`.\n\
             - If you've used the {link} command to connect to an osu! account, \
-            you can omit the username for any command that needs one.\n\
+            you can omit the username for any command that needs one. \
+            To use another one of your linked accounts, specify `account=name` instead of a username.\n\
             - If you have questions, complains, or suggestions for the bot, feel free to join its \
             [discord server]({BATHBOT_WORKSHOP}) and let Badewanne3 know.\n\
             [This roadmap]({BATHBOT_ROADMAP}) shows already suggested features and known bugs.\n\n\
@@ -3,10 +3,11 @@ use std::{
     time::Duration,
 };
 
-use bathbot_model::HlVersion;
+use bathbot_model::{twilight_model::id::IdRkyv, HlVersion};
 use bathbot_util::{AuthorBuilder, EmbedBuilder, MessageBuilder};
 use eyre::{Result, WrapErr};
 use futures::future::BoxFuture;
+use rkyv::{with::Skip, Archive, Deserialize, Serialize};
 use rosu_v2::prelude::GameMode;
 use time::OffsetDateTime;
 use tokio::sync::oneshot::Receiver;
@@ -21,7 +22,11 @@ use twilight_model::{
 
 use self::state::{ButtonState, HigherLowerState};
 use crate::{
-    active::{response::ActiveResponse, BuildPage, ComponentResult, IActiveMessage},
+    active::{
+        persist::{PersistedKind, PersistedState},
+        response::ActiveResponse,
+        BuildPage, ComponentResult, IActiveMessage,
+    },
     core::Context,
     util::{interaction::InteractionComponent, Authored, ComponentExt, Emote},
 };
@@ -29,13 +34,18 @@ use crate::{
 mod score_pp;
 mod state;
 
+/// When persisted, the pending image receiver is skipped and the image is
+/// created again once the page is built next.
+#[derive(Archive, Deserialize, Serialize)]
 pub struct HigherLowerGame {
     state: HigherLowerState,
     revealed: bool,
+    #[with(Skip)]
     img_url_rx: Option<Receiver<String>>,
     current_score: u32,
     highscore: u32,
     buttons: ButtonState,
+    #[with(IdRkyv)]
     msg_owner: Id<UserMarker>,
 }
 
@@ -96,6 +106,10 @@ impl IActiveMessage for HigherLowerGame {
             ButtonState::TryAgain { .. } => Some(Duration::from_secs(30)),
         }
     }
+
+    fn persist(&self) -> Option<Result<PersistedState>> {
+        Some(PersistedState::new(PersistedKind::HigherLower, self))
+    }
 }
 
 impl HigherLowerGame {
@@ -133,7 +147,11 @@ impl HigherLowerGame {
                         Ok(url) => embed = embed.image(url),
                         Err(err) => warn!(?err, "Failed to receive image url"),
                     },
-                    None => warn!("Tried to await image rx after it's already been used"),
+                    // The receiver does not survive persisting the game
+                    None => match self.state.image().await {
+                        Ok(url) => embed = embed.image(url),
+                        Err(err) => warn!(?err, "Failed to receive image url"),
+                    },
                 }
 
                 true
@@ -395,7 +413,7 @@ impl HigherLowerGame {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Archive, Copy, Clone, Deserialize, Serialize)]
 enum HlGuess {
     Higher,
     Lower,
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use bathbot_model::rosu_v2::ranking::ArchivedRankingsUser;
+use bathbot_model::rosu_v2::{grade::GradeRkyv, ranking::ArchivedRankingsUser};
 use bathbot_util::{
     constants::OSU_BASE,
     numbers::{round, WithComma},
@@ -9,6 +9,7 @@ use bathbot_util::{
 use eyre::{Result, WrapErr};
 use image::{GenericImageView, ImageBuffer};
 use rand::Rng;
+use rkyv::{Archive, Deserialize, Serialize};
 use rosu_v2::prelude::{CountryCode, GameMode, GameMods, Grade, Score, User as UserRosu, Username};
 use twilight_model::channel::message::embed::EmbedField;
 
@@ -21,6 +22,7 @@ use crate::{
 
 const ALPHA_THRESHOLD: u8 = 20;
 
+#[derive(Archive, Deserialize, Serialize)]
 pub(super) struct ScorePp {
     pub pp: f32,
     pub avatar_url: Box<str>,
@@ -35,6 +37,7 @@ pub(super) struct ScorePp {
     score: u32,
     acc: f32,
     miss_count: u32,
+    #[with(GradeRkyv)]
     grade: Grade,
 }
 
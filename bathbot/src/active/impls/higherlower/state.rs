@@ -4,6 +4,7 @@ use bathbot_model::HlVersion;
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{ContextCompat, Result, WrapErr};
 use image::{codecs::png::PngEncoder, ColorType, ImageEncoder};
+use rkyv::{Archive, Deserialize, Serialize};
 use rosu_v2::prelude::GameMode;
 use tokio::sync::oneshot::{self, Receiver};
 
@@ -13,6 +14,7 @@ use crate::{core::BotConfig, util::ChannelExt};
 pub(super) const W: u32 = 900;
 pub(super) const H: u32 = 250;
 
+#[derive(Archive, Deserialize, Serialize)]
 pub(super) enum ButtonState {
     HigherLower,
     Next {
@@ -27,6 +29,7 @@ pub(super) enum ButtonState {
 
 // seems to be a false alarm by clippy
 #[allow(clippy::large_enum_variant)]
+#[derive(Archive, Deserialize, Serialize)]
 pub(super) enum HigherLowerState {
     ScorePp {
         mode: GameMode,
@@ -107,35 +110,46 @@ impl HigherLowerState {
 
                 ScorePp::log(&*previous, &*next);
 
-                let pfp1 = mem::take(&mut previous.avatar_url);
+                Self::spawn_image(previous, next)
+            }
+        };
 
-                // Clone this since it's needed in the next round
-                let pfp2 = next.avatar_url.clone();
+        Ok(rx)
+    }
 
-                let mapset_id1 = previous.mapset_id;
-                let mapset_id2 = next.mapset_id;
+    /// Create the image for the current round again, e.g. after restoring
+    /// a persisted game whose image receiver did not survive.
+    pub(super) fn image(&self) -> Receiver<String> {
+        match self {
+            Self::ScorePp { previous, next, .. } => Self::spawn_image(previous, next),
+        }
+    }
 
-                let (tx, rx) = oneshot::channel();
+    fn spawn_image(previous: &ScorePp, next: &ScorePp) -> Receiver<String> {
+        // Clone these since they're needed to recreate the image after a restore
+        let pfp1 = previous.avatar_url.clone();
+        let pfp2 = next.avatar_url.clone();
 
-                // Create the image in the background so it's available when needed later
-                tokio::spawn(async move {
-                    let url = match ScorePp::image(&pfp1, &pfp2, mapset_id1, mapset_id2).await {
-                        Ok(url) => url,
-                        Err(err) => {
-                            warn!(?err, "Failed to create image");
+        let mapset_id1 = previous.mapset_id;
+        let mapset_id2 = next.mapset_id;
 
-                            String::new()
-                        }
-                    };
+        let (tx, rx) = oneshot::channel();
 
-                    let _ = tx.send(url);
-                });
+        // Create the image in the background so it's available when needed later
+        tokio::spawn(async move {
+            let url = match ScorePp::image(&pfp1, &pfp2, mapset_id1, mapset_id2).await {
+                Ok(url) => url,
+                Err(err) => {
+                    warn!(?err, "Failed to create image");
 
-                rx
-            }
-        };
+                    String::new()
+                }
+            };
 
-        Ok(rx)
+            let _ = tx.send(url);
+        });
+
+        rx
     }
 
     pub(super) async fn upload_image(img: &[u8], content: String) -> Result<String> {
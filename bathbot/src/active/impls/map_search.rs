@@ -1,5 +1,9 @@
-use std::{collections::BTreeMap, fmt::Write};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+};
 
+use bathbot_psql::model::osu::MapsetSearchEntry;
 use bathbot_util::{
     constants::OSU_BASE,
     numbers::{last_multiple, round},
@@ -7,7 +11,9 @@ use bathbot_util::{
 };
 use eyre::{Report, Result};
 use futures::future::BoxFuture;
-use rosu_v2::prelude::{BeatmapsetExtended, BeatmapsetSearchResult, GameMode, Genre, Language};
+use rosu_v2::prelude::{
+    BeatmapsetExtended, BeatmapsetSearchResult, GameMode, Genre, Language, RankStatus,
+};
 use twilight_model::{
     channel::message::{
         component::{ActionRow, Button, ButtonStyle},
@@ -24,13 +30,32 @@ use crate::{
 };
 
 pub struct MapSearchPagination {
-    maps: BTreeMap<usize, BeatmapsetExtended>,
-    search_result: BeatmapsetSearchResult,
+    entries: MapSearchEntries,
     args: Search,
+    content: Option<&'static str>,
     msg_owner: Id<UserMarker>,
     pages: MapSearchPages,
 }
 
+enum MapSearchEntries {
+    /// Results of the osu! API, requested lazily
+    Api {
+        maps: BTreeMap<usize, BeatmapsetExtended>,
+        search_result: BeatmapsetSearchResult,
+    },
+    /// Results of searching through stored mapsets
+    Local(Vec<MapsetSearchEntry>),
+}
+
+impl MapSearchEntries {
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::Api { maps, .. } => maps.is_empty(),
+            Self::Local(entries) => entries.is_empty(),
+        }
+    }
+}
+
 impl IActiveMessage for MapSearchPagination {
     fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
         Box::pin(self.async_build_page())
@@ -58,9 +83,32 @@ impl MapSearchPagination {
         let pages = MapSearchPages::new(search_result.total as usize);
 
         Self {
-            maps,
-            search_result,
+            entries: MapSearchEntries::Api {
+                maps,
+                search_result,
+            },
             args,
+            content: None,
+            msg_owner,
+            pages,
+        }
+    }
+
+    pub fn new_local(
+        entries: Vec<MapsetSearchEntry>,
+        args: Search,
+        content: Option<&'static str>,
+        msg_owner: Id<UserMarker>,
+    ) -> Self {
+        let mut pages = MapSearchPages::new(entries.len());
+
+        // All entries are available from the start
+        pages.reached_end = true;
+
+        Self {
+            entries: MapSearchEntries::Local(entries),
+            args,
+            content,
             msg_owner,
             pages,
         }
@@ -69,36 +117,63 @@ impl MapSearchPagination {
     fn available_entries_in_page(&self) -> usize {
         let pages = &self.pages;
 
-        self.maps
-            .range(pages.index()..pages.index() + pages.per_page())
-            .count()
+        match self.entries {
+            MapSearchEntries::Api { ref maps, .. } => maps
+                .range(pages.index()..pages.index() + pages.per_page())
+                .count(),
+            MapSearchEntries::Local(ref entries) => entries
+                .len()
+                .saturating_sub(pages.index())
+                .min(pages.per_page()),
+        }
     }
 
     fn defer(&self) -> bool {
-        self.available_entries_in_page() < self.pages.per_page()
+        matches!(self.entries, MapSearchEntries::Api { .. })
+            && self.available_entries_in_page() < self.pages.per_page()
     }
 
     async fn async_build_page(&mut self) -> Result<BuildPage> {
         let should_request_more = self.defer();
 
-        if should_request_more {
-            let next_fut = self.search_result.get_next(Context::osu());
-
-            if let Some(mut next_search_result) = next_fut.await.transpose()? {
-                let idx = self.pages.index();
-
-                let iter = next_search_result
-                    .mapsets
-                    .drain(..)
-                    .enumerate()
-                    .map(|(i, s)| (idx + i, s));
-
-                self.maps.extend(iter);
-                self.search_result = next_search_result;
+        if let MapSearchEntries::Api {
+            ref mut maps,
+            ref mut search_result,
+        } = self.entries
+        {
+            if should_request_more {
+                let next_fut = search_result.get_next(Context::osu());
+
+                match next_fut.await.transpose() {
+                    Ok(Some(mut next_search_result)) => {
+                        let idx = self.pages.index();
+
+                        let iter = next_search_result
+                            .mapsets
+                            .drain(..)
+                            .enumerate()
+                            .map(|(i, s)| (idx + i, s));
+
+                        maps.extend(iter);
+                        *search_result = next_search_result;
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        warn!(?err, "Failed to get next search results");
+
+                        self.content = Some(
+                            "The osu! API is currently unavailable so \
+                            no further results could be loaded",
+                        );
+                    }
+                }
             }
         }
 
-        let mut title = "Mapset results".to_owned();
+        let mut title = match self.entries {
+            MapSearchEntries::Api { .. } => "Mapset results".to_owned(),
+            MapSearchEntries::Local(_) => "Local mapset results".to_owned(),
+        };
         let sort = self.args.sort.unwrap_or_default();
 
         let non_empty_args = self.args.query.is_some()
@@ -204,66 +279,65 @@ impl MapSearchPagination {
             title.push('`');
         }
 
-        if self.maps.is_empty() {
+        if self.entries.is_empty() {
             let embed = EmbedBuilder::new()
                 .description("No maps found for the query")
                 .footer(FooterBuilder::new("Page 1/1"))
                 .title(title);
 
-            return Ok(BuildPage::new(embed, should_request_more));
-        }
-
-        let index = self.pages.index();
-        let entries = self.maps.range(index..index + 10);
-        let mut description = String::with_capacity(512);
-
-        for (&i, mapset) in entries {
-            let mut mode = String::with_capacity(4);
-            let maps = mapset.maps.as_ref().unwrap();
+            let mut build = BuildPage::new(embed, should_request_more);
 
-            if maps.iter().any(|map| map.mode == GameMode::Osu) {
-                mode.push_str("osu!");
+            if let Some(content) = self.content {
+                build = build.content(content);
             }
 
-            if maps.iter().any(|map| map.mode == GameMode::Mania) {
-                if !mode.is_empty() {
-                    mode.push_str(", ");
-                }
+            return Ok(build);
+        }
 
-                mode.push_str("mania");
-            }
+        let index = self.pages.index();
+        let mut description = String::with_capacity(512);
 
-            if maps.iter().any(|map| map.mode == GameMode::Taiko) {
-                if !mode.is_empty() {
-                    mode.push_str(", ");
+        match self.entries {
+            MapSearchEntries::Api { ref maps, .. } => {
+                for (&i, mapset) in maps.range(index..index + 10) {
+                    let maps = mapset.maps.as_ref().unwrap();
+
+                    let entry = EntryLine {
+                        idx: i + 1,
+                        artist: &mapset.artist,
+                        title: &mapset.title,
+                        mapset_id: mapset.mapset_id,
+                        map_count: maps.len(),
+                        creator_name: &mapset.creator_name,
+                        creator_id: mapset.creator_id,
+                        status: mapset.status,
+                        bpm: mapset.bpm,
+                        modes: ModeList::new(|mode| maps.iter().any(|map| map.mode == mode)),
+                    };
+
+                    let _ = writeln!(description, "{entry}");
                 }
-
-                mode.push_str("taiko");
             }
-
-            if maps.iter().any(|map| map.mode == GameMode::Catch) {
-                if !mode.is_empty() {
-                    mode.push_str(", ");
+            MapSearchEntries::Local(ref entries) => {
+                let end = entries.len().min(index + 10);
+
+                for (mapset, i) in entries[index..end].iter().zip(index..) {
+                    let entry = EntryLine {
+                        idx: i + 1,
+                        artist: &mapset.artist,
+                        title: &mapset.title,
+                        mapset_id: mapset.mapset_id,
+                        map_count: mapset.map_count,
+                        creator_name: &mapset.creator_name,
+                        creator_id: mapset.creator_id,
+                        status: mapset.status,
+                        bpm: mapset.bpm,
+                        modes: ModeList::new(|mode| mapset.modes.contains(&mode)),
+                    };
+
+                    let _ = writeln!(description, "{entry}");
                 }
-
-                mode.push_str("ctb");
             }
-
-            let _ = writeln!(
-                description,
-                "**#{idx} [{artist} - {title}]({OSU_BASE}s/{set_id})** [{count} map{plural}]\n\
-                Creator: [{creator}]({OSU_BASE}u/{creator_id}) ({status:?}) • BPM: {bpm} • Mode: {mode}",
-                idx = i + 1,
-                artist = mapset.artist.cow_escape_markdown(),
-                title = mapset.title.cow_escape_markdown(),
-                set_id = mapset.mapset_id,
-                count = maps.len(),
-                plural = if maps.len() != 1 { "s" } else { "" },
-                creator = mapset.creator_name.cow_escape_markdown(),
-                creator_id = mapset.creator_id,
-                status = mapset.status,
-                bpm = round(mapset.bpm),
-            );
         }
 
         let page = self.pages.curr_page();
@@ -275,7 +349,13 @@ impl MapSearchPagination {
             .footer(FooterBuilder::new(footer_text))
             .title(title);
 
-        Ok(BuildPage::new(embed, should_request_more))
+        let mut build = BuildPage::new(embed, should_request_more);
+
+        if let Some(content) = self.content {
+            build = build.content(content);
+        }
+
+        Ok(build)
     }
 
     async fn async_handle_component(
@@ -341,6 +421,84 @@ impl MapSearchPagination {
     }
 }
 
+struct EntryLine<'a> {
+    idx: usize,
+    artist: &'a str,
+    title: &'a str,
+    mapset_id: u32,
+    map_count: usize,
+    creator_name: &'a str,
+    creator_id: u32,
+    status: RankStatus,
+    bpm: f32,
+    modes: ModeList,
+}
+
+impl Display for EntryLine<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "**#{idx} [{artist} - {title}]({OSU_BASE}s/{set_id})** [{count} map{plural}]\n\
+            Creator: [{creator}]({OSU_BASE}u/{creator_id}) ({status:?}) • BPM: {bpm} • Mode: {mode}",
+            idx = self.idx,
+            artist = self.artist.cow_escape_markdown(),
+            title = self.title.cow_escape_markdown(),
+            set_id = self.mapset_id,
+            count = self.map_count,
+            plural = if self.map_count != 1 { "s" } else { "" },
+            creator = self.creator_name.cow_escape_markdown(),
+            creator_id = self.creator_id,
+            status = self.status,
+            bpm = round(self.bpm),
+            mode = self.modes,
+        )
+    }
+}
+
+#[derive(Copy, Clone)]
+struct ModeList {
+    osu: bool,
+    taiko: bool,
+    catch: bool,
+    mania: bool,
+}
+
+impl ModeList {
+    fn new(has_mode: impl Fn(GameMode) -> bool) -> Self {
+        Self {
+            osu: has_mode(GameMode::Osu),
+            taiko: has_mode(GameMode::Taiko),
+            catch: has_mode(GameMode::Catch),
+            mania: has_mode(GameMode::Mania),
+        }
+    }
+}
+
+impl Display for ModeList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let modes = [
+            (self.osu, "osu!"),
+            (self.mania, "mania"),
+            (self.taiko, "taiko"),
+            (self.catch, "ctb"),
+        ];
+
+        let mut iter = modes
+            .into_iter()
+            .filter_map(|(has_mode, name)| has_mode.then_some(name));
+
+        if let Some(first) = iter.next() {
+            f.write_str(first)?;
+
+            for name in iter {
+                write!(f, ", {name}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 pub struct MapSearchPages {
     index: usize,
     last_index: usize,
@@ -7,7 +7,7 @@ pub use self::{
     bookmarks::BookmarksPagination,
     changelog::ChangelogPagination,
     compare::{CompareMostPlayedPagination, CompareScoresPagination, CompareTopPagination},
-    embed_builder::ScoreEmbedBuilderActive,
+    embed_builder::{PersistedScoreEmbedBuilder, ScoreEmbedBuilderActive, ScoreEmbedTarget},
     help::{HelpInteractionCommand, HelpPrefixMenu},
     higherlower::HigherLowerGame,
     leaderboard::LeaderboardPagination,
@@ -23,7 +23,8 @@ pub use self::{
     nochoke::NoChokePagination,
     osekai::{MedalCountPagination, MedalRarityPagination},
     osustats::{OsuStatsBestPagination, OsuStatsPlayersPagination, OsuStatsScoresPagination},
-    profile::ProfileMenu,
+    privacy::PrivacyDeletion,
+    profile::{PersistedProfileMenu, ProfileMenu},
     ranking::RankingPagination,
     ranking_countries::RankingCountriesPagination,
     recent_list::RecentListPagination,
@@ -35,7 +36,7 @@ pub use self::{
     skins::SkinsPagination,
     slash_commands::SlashCommandsPagination,
     snipe::{SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination},
-    top::TopPagination,
+    top::{PersistedTopPagination, TopPagination},
     top_if::TopIfPagination,
 };
 
@@ -57,6 +58,7 @@ mod most_played;
 mod nochoke;
 mod osekai;
 mod osustats;
+mod privacy;
 mod profile;
 mod ranking;
 mod ranking_countries;
@@ -1,10 +1,11 @@
 use std::fmt::Write;
 
 use bathbot_macros::PaginationBuilder;
-use bathbot_model::{OsekaiMedal, OsekaiUserEntry};
+use bathbot_model::{twilight_model::id::IdRkyv, OsekaiMedal, OsekaiUserEntry};
 use bathbot_util::{constants::OSU_BASE, numbers::round, CowUtils, EmbedBuilder, FooterBuilder};
 use eyre::Result;
 use futures::future::BoxFuture;
+use rkyv::{Archive, Deserialize, Serialize};
 use twilight_model::{
     channel::message::Component,
     id::{marker::UserMarker, Id},
@@ -13,16 +14,18 @@ use twilight_model::{
 use crate::{
     active::{
         pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        persist::{PersistedKind, PersistedState},
         BuildPage, ComponentResult, IActiveMessage,
     },
     util::interaction::{InteractionComponent, InteractionModal},
 };
 
-#[derive(PaginationBuilder)]
+#[derive(Archive, Deserialize, PaginationBuilder, Serialize)]
 pub struct MedalCountPagination {
     #[pagination(per_page = 10)]
     ranking: Box<[OsekaiUserEntry]>,
     author_idx: Option<usize>,
+    #[with(IdRkyv)]
     msg_owner: Id<UserMarker>,
     pages: Pages,
 }
@@ -103,4 +106,8 @@ impl IActiveMessage for MedalCountPagination {
     ) -> BoxFuture<'a, Result<()>> {
         handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
     }
+
+    fn persist(&self) -> Option<Result<PersistedState>> {
+        Some(PersistedState::new(PersistedKind::MedalCount, self))
+    }
 }
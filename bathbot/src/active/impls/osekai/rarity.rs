@@ -1,10 +1,11 @@
 use std::fmt::Write;
 
 use bathbot_macros::PaginationBuilder;
-use bathbot_model::{OsekaiMedal, OsekaiRarityEntry};
+use bathbot_model::{twilight_model::id::IdRkyv, OsekaiMedal, OsekaiRarityEntry};
 use bathbot_util::{numbers::round, EmbedBuilder, FooterBuilder};
 use eyre::Result;
 use futures::future::BoxFuture;
+use rkyv::{Archive, Deserialize, Serialize};
 use twilight_model::{
     channel::message::Component,
     id::{marker::UserMarker, Id},
@@ -13,15 +14,17 @@ use twilight_model::{
 use crate::{
     active::{
         pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        persist::{PersistedKind, PersistedState},
         BuildPage, ComponentResult, IActiveMessage,
     },
     util::interaction::{InteractionComponent, InteractionModal},
 };
 
-#[derive(PaginationBuilder)]
+#[derive(Archive, Deserialize, PaginationBuilder, Serialize)]
 pub struct MedalRarityPagination {
     #[pagination(per_page = 15)]
     ranking: Box<[OsekaiRarityEntry]>,
+    #[with(IdRkyv)]
     msg_owner: Id<UserMarker>,
     pages: Pages,
 }
@@ -90,4 +93,8 @@ impl IActiveMessage for MedalRarityPagination {
     ) -> BoxFuture<'a, Result<()>> {
         handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
     }
+
+    fn persist(&self) -> Option<Result<PersistedState>> {
+        Some(PersistedState::new(PersistedKind::MedalRarity, self))
+    }
 }
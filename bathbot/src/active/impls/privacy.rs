@@ -0,0 +1,154 @@
+use bathbot_util::EmbedBuilder;
+use eyre::{Report, Result};
+use futures::future::BoxFuture;
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle},
+        Component,
+    },
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{BuildPage, ComponentResult, IActiveMessage},
+    core::Context,
+    util::{interaction::InteractionComponent, Authored, ComponentExt},
+};
+
+pub struct PrivacyDeletion {
+    msg_owner: Id<UserMarker>,
+    status: DeleteStatus,
+}
+
+#[derive(Copy, Clone)]
+enum DeleteStatus {
+    Pending,
+    Cancelled,
+    Deleted,
+}
+
+impl PrivacyDeletion {
+    pub fn new(msg_owner: Id<UserMarker>) -> Self {
+        Self {
+            msg_owner,
+            status: DeleteStatus::Pending,
+        }
+    }
+
+    async fn async_handle_component(
+        &mut self,
+        component: &InteractionComponent,
+    ) -> ComponentResult {
+        let owner = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if owner != self.msg_owner || !matches!(self.status, DeleteStatus::Pending) {
+            return ComponentResult::Ignore;
+        }
+
+        match component.data.custom_id.as_str() {
+            "privacy_delete" => {
+                if let Err(err) = component.defer().await {
+                    return ComponentResult::Err(
+                        Report::new(err).wrap_err("Failed to defer component"),
+                    );
+                }
+
+                if let Err(err) = Context::user_config().delete_data(owner).await {
+                    return ComponentResult::Err(err);
+                }
+
+                info!(user = %owner, "Deleted all user data");
+
+                self.status = DeleteStatus::Deleted;
+            }
+            "privacy_cancel" => {
+                if let Err(err) = component.defer().await {
+                    return ComponentResult::Err(
+                        Report::new(err).wrap_err("Failed to defer component"),
+                    );
+                }
+
+                self.status = DeleteStatus::Cancelled;
+            }
+            other => {
+                warn!(name = %other, ?component, "Unknown privacy component");
+
+                return ComponentResult::Ignore;
+            }
+        }
+
+        ComponentResult::BuildPage
+    }
+}
+
+impl IActiveMessage for PrivacyDeletion {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let (embed, defer) = match self.status {
+            DeleteStatus::Pending => {
+                let description = "This will permanently delete all data stored about you \
+                    i.e. your configuration, linked osu! and twitch accounts, render settings, \
+                    bookmarks, game scores, aliases, scheduled commands, and api keys.\n\
+                    Are you sure?";
+
+                let embed = EmbedBuilder::new()
+                    .color_red()
+                    .title("Delete all your data")
+                    .description(description);
+
+                (embed, false)
+            }
+            DeleteStatus::Cancelled => {
+                let embed = EmbedBuilder::new().description("Deletion cancelled");
+
+                (embed, true)
+            }
+            DeleteStatus::Deleted => {
+                let embed = EmbedBuilder::new().description("All your data has been deleted");
+
+                (embed, true)
+            }
+        };
+
+        BuildPage::new(embed, defer).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if !matches!(self.status, DeleteStatus::Pending) {
+            return Vec::new();
+        }
+
+        let delete = Button {
+            custom_id: Some("privacy_delete".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Delete everything".to_owned()),
+            style: ButtonStyle::Danger,
+            url: None,
+        };
+
+        let cancel = Button {
+            custom_id: Some("privacy_cancel".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Cancel".to_owned()),
+            style: ButtonStyle::Secondary,
+            url: None,
+        };
+
+        let row = ActionRow {
+            components: vec![Component::Button(delete), Component::Button(cancel)],
+        };
+
+        vec![Component::ActionRow(row)]
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        Box::pin(self.async_handle_component(component))
+    }
+}
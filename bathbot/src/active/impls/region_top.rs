@@ -8,7 +8,7 @@ use bathbot_util::{
     datetime::SecToMinSec,
     numbers::{round, WithComma},
     osu::flag_url,
-    CowUtils, EmbedBuilder, FooterBuilder, IntHasher,
+    CowUtils, EmbedBuilder, IntHasher,
 };
 use eyre::Result;
 use futures::future::BoxFuture;
@@ -27,6 +27,7 @@ use crate::{
         BuildPage, ComponentResult, IActiveMessage,
     },
     commands::osu::{RegionTopKind, ScoresOrder},
+    embeds::footer_data_as_of,
     util::{
         interaction::{InteractionComponent, InteractionModal},
         osu::GradeFormatter,
@@ -62,8 +63,6 @@ impl IActiveMessage for RegionTopPagination {
             footer_text += " • Region data provided by https://osuworld.octo.moe";
         }
 
-        let footer = FooterBuilder::new(footer_text);
-
         let (title, thumbnail) = match &self.kind {
             RegionTopKind::Global => ("Global top 100 scores:".to_owned(), None),
             RegionTopKind::Country { country_code } => {
@@ -121,10 +120,8 @@ impl IActiveMessage for RegionTopPagination {
             );
         }
 
-        let mut embed = EmbedBuilder::new()
-            .description(description)
-            .footer(footer)
-            .title(title);
+        let embed = EmbedBuilder::new().description(description).title(title);
+        let mut embed = footer_data_as_of(embed, footer_text, data.refreshed_at());
 
         if let Some(thumbnail) = thumbnail {
             embed = embed.thumbnail(thumbnail);
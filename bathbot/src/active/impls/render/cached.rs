@@ -119,7 +119,9 @@ impl CachedRender {
     async fn render_anyway(&mut self, component: &mut InteractionComponent) -> Result<()> {
         let owner = component.user_id()?;
 
-        if let Some(cooldown) = Context::check_ratelimit(owner, BucketName::Render) {
+        if let Some(cooldown) =
+            Context::check_ratelimit(owner, component.guild_id, BucketName::Render).await
+        {
             let content = format!(
                 "Rendering is on cooldown for you <@{owner}>, try again in {cooldown} seconds"
             );
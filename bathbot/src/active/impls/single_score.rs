@@ -96,6 +96,10 @@ impl SingleScorePagination {
         self.pages.set_index(idx);
     }
 
+    pub fn score_data(&self) -> ScoreData {
+        self.score_data
+    }
+
     pub async fn async_build_page(
         &mut self,
         content: Box<str>,
@@ -255,7 +259,9 @@ impl SingleScorePagination {
             Err(err) => warn!(?err),
         }
 
-        if let Some(cooldown) = Context::check_ratelimit(owner, BucketName::Render) {
+        if let Some(cooldown) =
+            Context::check_ratelimit(owner, component.guild_id, BucketName::Render).await
+        {
             // Put the replay back so that the button can still be used
             data.replay = Some(replay);
 
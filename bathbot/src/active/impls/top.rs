@@ -1,6 +1,6 @@
 use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 
-use bathbot_model::rosu_v2::user::User;
+use bathbot_model::{rosu_v2::user::User, twilight_model::id::IdRkyv};
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     constants::OSU_BASE,
@@ -8,8 +8,9 @@ use bathbot_util::{
     numbers::{round, WithComma},
     CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter, ScoreExt,
 };
-use eyre::Result;
+use eyre::{Result, WrapErr};
 use futures::future::BoxFuture;
+use rkyv::{Archive, Deserialize, Serialize};
 use rosu_v2::prelude::GameMode;
 use time::OffsetDateTime;
 use twilight_model::{
@@ -20,14 +21,19 @@ use twilight_model::{
 use crate::{
     active::{
         pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        persist::{PersistedKind, PersistedState},
         BuildPage, ComponentResult, IActiveMessage,
     },
     commands::{
         osu::TopScoreOrder,
-        utility::{ScoreEmbedDataHalf, ScoreEmbedDataWrap},
+        utility::{PersistedScoreEmbedDataHalf, ScoreEmbedDataHalf, ScoreEmbedDataWrap},
     },
+    core::Context,
     embeds::{ComboFormatter, HitResultFormatter, PpFormatter},
-    manager::{redis::RedisData, OsuMap},
+    manager::{
+        redis::{osu::UserArgsSlim, RedisData},
+        OsuMap,
+    },
     util::{
         interaction::{InteractionComponent, InteractionModal},
         osu::{GradeFormatter, ScoreFormatter},
@@ -262,6 +268,75 @@ impl IActiveMessage for TopPagination {
     ) -> BoxFuture<'a, Result<()>> {
         handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
     }
+
+    fn persist(&self) -> Option<Result<PersistedState>> {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| entry.try_get_half().map(PersistedScoreEmbedDataHalf::new))
+            .collect::<Option<Vec<_>>>()?;
+
+        let persisted = PersistedTopPagination {
+            user_id: self.user.user_id(),
+            mode: self.mode,
+            entries,
+            sort_by: self.sort_by,
+            condensed_list: self.condensed_list,
+            score_data: self.score_data.into(),
+            content: self.content.clone(),
+            msg_owner: self.msg_owner,
+            pages: self.pages.clone(),
+        };
+
+        Some(PersistedState::new(PersistedKind::Top, &persisted))
+    }
+}
+
+/// Persisted state of a [`TopPagination`].
+///
+/// Instead of the user and maps, only their ids are stored so that they can be
+/// fetched again on restore.
+#[derive(Archive, Deserialize, Serialize)]
+pub struct PersistedTopPagination {
+    user_id: u32,
+    mode: GameMode,
+    entries: Vec<PersistedScoreEmbedDataHalf>,
+    sort_by: TopScoreOrder,
+    condensed_list: bool,
+    score_data: i16,
+    content: Box<str>,
+    #[with(IdRkyv)]
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl PersistedTopPagination {
+    pub async fn restore(self) -> Result<TopPagination> {
+        let user_args = UserArgsSlim::user_id(self.user_id).mode(self.mode);
+
+        let user = Context::redis()
+            .osu_user_from_args(user_args)
+            .await
+            .wrap_err("Failed to get user")?;
+
+        let entries = PersistedScoreEmbedDataHalf::restore(self.entries).await?;
+
+        // Entries whose map is no longer available have been skipped
+        let mut pages = Pages::new(self.pages.per_page(), entries.len());
+        pages.set_index(self.pages.index());
+
+        Ok(TopPagination {
+            user,
+            mode: self.mode,
+            entries,
+            sort_by: self.sort_by,
+            condensed_list: self.condensed_list,
+            score_data: ScoreData::try_from(self.score_data).unwrap_or_default(),
+            content: self.content,
+            msg_owner: self.msg_owner,
+            pages,
+        })
+    }
 }
 
 pub struct TopPaginationBuilder {
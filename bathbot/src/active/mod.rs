@@ -8,10 +8,14 @@ use enum_dispatch::enum_dispatch;
 use eyre::{Report, Result, WrapErr};
 use flexmap::tokio::TokioMutexMap;
 use futures::future::BoxFuture;
-use tokio::sync::watch::Sender;
+use tokio::sync::watch::{self, Sender};
 use twilight_model::{
-    channel::message::Component,
-    id::{marker::MessageMarker, Id},
+    channel::message::{Component, MessageFlags},
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+    id::{
+        marker::{InteractionMarker, MessageMarker},
+        Id,
+    },
 };
 
 pub use self::origin::ActiveMessageOriginError;
@@ -25,13 +29,14 @@ use self::{
         MatchCostPagination, MedalCountPagination, MedalRarityPagination, MedalsCommonPagination,
         MedalsListPagination, MedalsMissingPagination, MedalsRecentPagination,
         MostPlayedPagination, NoChokePagination, OsuStatsBestPagination, OsuStatsPlayersPagination,
-        OsuStatsScoresPagination, ProfileMenu, RankingCountriesPagination, RankingPagination,
-        RecentListPagination, RegionTopPagination, RenderSettingsActive, ScoreEmbedBuilderActive,
-        ScoresMapPagination, ScoresServerPagination, ScoresUserPagination, SettingsImport,
-        SimulateComponents, SingleScorePagination, SkinsPagination, SlashCommandsPagination,
-        SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination,
-        TopIfPagination, TopPagination,
+        OsuStatsScoresPagination, PrivacyDeletion, ProfileMenu, RankingCountriesPagination,
+        RankingPagination, RecentListPagination, RegionTopPagination, RenderSettingsActive,
+        ScoreEmbedBuilderActive, ScoresMapPagination, ScoresServerPagination, ScoresUserPagination,
+        SettingsImport, SimulateComponents, SingleScorePagination, SkinsPagination,
+        SlashCommandsPagination, SnipeCountryListPagination, SnipeDifferencePagination,
+        SnipePlayerListPagination, TopIfPagination, TopPagination,
     },
+    persist::{PersistedActiveMessage, PersistedState},
     response::ActiveResponse,
 };
 use crate::{
@@ -47,6 +52,7 @@ pub mod impls;
 mod builder;
 mod origin;
 mod pagination;
+mod persist;
 mod response;
 
 #[enum_dispatch(IActiveMessage)]
@@ -78,6 +84,7 @@ pub enum ActiveMessage {
     OsuStatsBestPagination,
     OsuStatsPlayersPagination,
     OsuStatsScoresPagination,
+    PrivacyDeletion,
     ProfileMenu,
     RankingPagination,
     RankingCountriesPagination,
@@ -103,6 +110,38 @@ pub enum ActiveMessage {
 struct FullActiveMessage {
     active_msg: ActiveMessage,
     activity_tx: Sender<()>,
+    response: ActiveResponse,
+    /// Whether the state has been stored in redis
+    persisted: bool,
+}
+
+impl FullActiveMessage {
+    /// Store the current state in redis so that it can be restored after a
+    /// restart.
+    async fn persist(&mut self) {
+        let Some(state_res) = self.active_msg.persist() else {
+            return;
+        };
+
+        let Some(until_timeout) = self.active_msg.until_timeout() else {
+            return;
+        };
+
+        let persisted = match state_res {
+            Ok(state) => PersistedActiveMessage::new(state, &self.response),
+            Err(err) => return warn!(?err, "Failed to persist active message"),
+        };
+
+        let key = PersistedActiveMessage::key(self.response.msg);
+        let expire = until_timeout.as_secs().max(1);
+
+        let store_fut = Context::cache().store_new::<_, _, 4096>(&key, &persisted, expire);
+
+        match store_fut.await {
+            Ok(_) => self.persisted = true,
+            Err(err) => warn!(?err, "Failed to store active message"),
+        }
+    }
 }
 
 pub struct ActiveMessages {
@@ -136,16 +175,28 @@ impl ActiveMessages {
         let msg_id = component.message.id;
         let mut guard = Context::get().active_msgs.inner.lock(&msg_id).await;
 
-        let Some(FullActiveMessage {
-            active_msg,
-            activity_tx,
-        }) = guard.get_mut()
-        else {
-            return error!(
-                name = %component.data.custom_id,
-                ?component,
-                "Unknown message component",
-            );
+        if guard.get_mut().is_none() {
+            match ActiveMessages::rehydrate(msg_id).await {
+                Some(full) => {
+                    guard.insert(full);
+                }
+                None => {
+                    debug!(
+                        name = %component.data.custom_id,
+                        "Component for unknown or expired message",
+                    );
+
+                    if let Err(err) = respond_expired(component.id, &component.token).await {
+                        warn!(?err, "Failed to respond to expired component");
+                    }
+
+                    return;
+                }
+            }
+        }
+
+        let Some(full) = guard.get_mut() else {
+            return;
         };
 
         async fn handle_component_inner(
@@ -230,7 +281,8 @@ impl ActiveMessages {
             }
         }
 
-        handle_component_inner(active_msg, activity_tx, &mut component).await;
+        handle_component_inner(&mut full.active_msg, &full.activity_tx, &mut component).await;
+        full.persist().await;
 
         let elapsed = start.elapsed();
         BotMetrics::observe_command("component", component.data.custom_id, elapsed);
@@ -241,17 +293,31 @@ impl ActiveMessages {
 
         EventKind::Modal.log(&modal, &modal.data.custom_id).await;
 
-        let mut guard = match modal.message {
-            Some(ref msg) => Context::get().active_msgs.inner.own(msg.id).await,
-            None => return warn!("Received modal without message"),
+        let Some(msg_id) = modal.message.as_ref().map(|msg| msg.id) else {
+            return warn!("Received modal without message");
         };
 
-        let Some(FullActiveMessage {
-            active_msg,
-            activity_tx,
-        }) = guard.get_mut()
-        else {
-            return error!(name = %modal.data.custom_id, ?modal, "Unknown modal");
+        let mut guard = Context::get().active_msgs.inner.own(msg_id).await;
+
+        if guard.get_mut().is_none() {
+            match ActiveMessages::rehydrate(msg_id).await {
+                Some(full) => {
+                    guard.insert(full);
+                }
+                None => {
+                    debug!(name = %modal.data.custom_id, "Modal for unknown or expired message");
+
+                    if let Err(err) = respond_expired(modal.id, &modal.token).await {
+                        warn!(?err, "Failed to respond to expired modal");
+                    }
+
+                    return;
+                }
+            }
+        }
+
+        let Some(full) = guard.get_mut() else {
+            return;
         };
 
         async fn handle_modal_inner(
@@ -309,7 +375,8 @@ impl ActiveMessages {
             }
         }
 
-        handle_modal_inner(active_msg, activity_tx, &mut modal).await;
+        handle_modal_inner(&mut full.active_msg, &full.activity_tx, &mut modal).await;
+        full.persist().await;
 
         let elapsed = start.elapsed();
         BotMetrics::observe_command("modal", modal.data.custom_id, elapsed);
@@ -324,12 +391,74 @@ impl ActiveMessages {
     }
 
     async fn remove_full(&self, msg: Id<MessageMarker>) -> Option<FullActiveMessage> {
-        self.inner.lock(&msg).await.remove()
+        let full = self.inner.lock(&msg).await.remove();
+
+        if full.as_ref().is_some_and(|full| full.persisted) {
+            let key = PersistedActiveMessage::key(msg);
+
+            if let Err(err) = Context::cache().delete(&key).await {
+                warn!(?err, "Failed to delete persisted active message");
+            }
+        }
+
+        full
     }
 
-    async fn insert(&self, msg: Id<MessageMarker>, active_msg: FullActiveMessage) {
+    async fn insert(&self, msg: Id<MessageMarker>, mut active_msg: FullActiveMessage) {
+        active_msg.persist().await;
         self.inner.own(msg).await.insert(active_msg);
     }
+
+    /// Restore an active message that was persisted before a restart.
+    ///
+    /// Returns `None` if there is no persisted state or it is no longer
+    /// compatible.
+    async fn rehydrate(msg: Id<MessageMarker>) -> Option<FullActiveMessage> {
+        let key = PersistedActiveMessage::key(msg);
+
+        let persisted = match Context::cache()
+            .fetch::<_, PersistedActiveMessage>(&key)
+            .await
+        {
+            Ok(Ok(persisted)) => persisted,
+            Ok(Err(_)) => return None,
+            Err(err) => {
+                warn!(?err, "Failed to fetch persisted active message");
+
+                return None;
+            }
+        };
+
+        let Some((state, response)) = persisted.unpack(msg) else {
+            if let Err(err) = Context::cache().delete(&key).await {
+                warn!(?err, "Failed to delete incompatible active message");
+            }
+
+            return None;
+        };
+
+        let active_msg = match state.rehydrate().await {
+            Ok(active_msg) => active_msg,
+            Err(err) => {
+                warn!(?err, "Failed to rehydrate active message");
+
+                return None;
+            }
+        };
+
+        let until_timeout = active_msg.until_timeout()?;
+        let (activity_tx, activity_rx) = watch::channel(());
+        ActiveMessagesBuilder::spawn_timeout(activity_rx, response.clone(), until_timeout);
+
+        BotMetrics::inc_redis_hit("Active message");
+
+        Some(FullActiveMessage {
+            active_msg,
+            activity_tx,
+            response,
+            persisted: true,
+        })
+    }
 }
 
 #[enum_dispatch]
@@ -391,6 +520,14 @@ pub trait IActiveMessage {
         }
     }
 
+    /// The state to store in redis so that the message remains functional
+    /// across restarts.
+    ///
+    /// Defaults to `None` i.e. the message can not be persisted.
+    fn persist(&self) -> Option<Result<PersistedState>> {
+        None
+    }
+
     /// Duration until the message is no longer active.
     /// On `None` the message will immediatly be considered as inactive.
     ///
@@ -446,3 +583,24 @@ impl ComponentResult {
         Box::pin(ready(self))
     }
 }
+
+/// Let the user know that the message can no longer be interacted with.
+async fn respond_expired(interaction_id: Id<InteractionMarker>, token: &str) -> Result<()> {
+    let data = InteractionResponseData {
+        content: Some("This message has expired, please use the command again".to_owned()),
+        flags: Some(MessageFlags::EPHEMERAL),
+        ..Default::default()
+    };
+
+    let response = InteractionResponse {
+        kind: InteractionResponseType::ChannelMessageWithSource,
+        data: Some(data),
+    };
+
+    Context::interaction()
+        .create_response(interaction_id, token, &response)
+        .await
+        .wrap_err("Failed to create response")?;
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use eyre::{Result, WrapErr};
+use rkyv::{
+    ser::serializers::AllocSerializer, AlignedVec, Archive, Deserialize, Infallible, Serialize,
+};
+use sha2::{Digest, Sha256};
+use twilight_model::id::{marker::MessageMarker, Id};
+
+use super::{
+    impls::{
+        HigherLowerGame, MedalCountPagination, MedalRarityPagination, PersistedProfileMenu,
+        PersistedScoreEmbedBuilder, PersistedTopPagination,
+    },
+    response::{ActiveResponse, ActiveResponseInner},
+    ActiveMessage,
+};
+
+/// Must be incremented whenever the archived layout of a persisted active
+/// message changes so that stale entries are considered expired instead of
+/// being misinterpreted.
+const PERSIST_VERSION: u16 = 4;
+
+/// Active messages whose state can be stored in redis to survive restarts.
+///
+/// Active messages whose state consists of plain data are archived as is.
+/// Those that hold API models, e.g. top score paginations or profile menus,
+/// only store ids and fetch the models again when they're restored.
+#[derive(Copy, Clone, Debug)]
+#[repr(u8)]
+pub enum PersistedKind {
+    MedalCount = 0,
+    MedalRarity = 1,
+    Top = 2,
+    Profile = 3,
+    ScoreEmbedBuilder = 4,
+    HigherLower = 5,
+}
+
+impl PersistedKind {
+    fn from_u8(kind: u8) -> Option<Self> {
+        match kind {
+            0 => Some(Self::MedalCount),
+            1 => Some(Self::MedalRarity),
+            2 => Some(Self::Top),
+            3 => Some(Self::Profile),
+            4 => Some(Self::ScoreEmbedBuilder),
+            5 => Some(Self::HigherLower),
+            _ => None,
+        }
+    }
+}
+
+/// Serialized state of an active message.
+pub struct PersistedState {
+    kind: PersistedKind,
+    bytes: AlignedVec,
+}
+
+impl PersistedState {
+    pub fn new<T>(kind: PersistedKind, active_msg: &T) -> Result<Self>
+    where
+        T: Serialize<AllocSerializer<4096>>,
+    {
+        let bytes = rkyv::to_bytes(active_msg).wrap_err("Failed to serialize active message")?;
+
+        Ok(Self { kind, bytes })
+    }
+}
+
+/// The entry that is stored in redis for each persisted active message.
+#[derive(Archive, Serialize)]
+pub struct PersistedActiveMessage {
+    version: u16,
+    kind: u8,
+    response: PersistedResponse,
+    state: Vec<u8>,
+    /// SHA-256 of `state` to detect corrupted entries before deserializing
+    checksum: [u8; 32],
+}
+
+#[derive(Archive, Serialize)]
+enum PersistedResponse {
+    Message { channel: u64 },
+    Interaction { token: Box<str> },
+}
+
+impl PersistedActiveMessage {
+    pub fn new(state: PersistedState, response: &ActiveResponse) -> Self {
+        let PersistedState { kind, bytes } = state;
+
+        let response = match response.inner {
+            ActiveResponseInner::Message { channel } => PersistedResponse::Message {
+                channel: channel.get(),
+            },
+            ActiveResponseInner::Interaction { ref token } => PersistedResponse::Interaction {
+                token: token.clone(),
+            },
+        };
+
+        let state = bytes.into_vec();
+
+        Self {
+            version: PERSIST_VERSION,
+            kind: kind as u8,
+            response,
+            checksum: checksum(&state),
+            state,
+        }
+    }
+
+    pub fn key(msg: Id<MessageMarker>) -> String {
+        format!("active_msg_{msg}")
+    }
+}
+
+impl ArchivedPersistedActiveMessage {
+    /// Validate the entry and unpack its state and response.
+    ///
+    /// Returns `None` if the entry originates from an incompatible version or
+    /// its state is corrupted.
+    pub fn unpack(&self, msg: Id<MessageMarker>) -> Option<(PersistedState, ActiveResponse)> {
+        if self.version != PERSIST_VERSION || self.checksum != checksum(&self.state) {
+            return None;
+        }
+
+        let kind = PersistedKind::from_u8(self.kind)?;
+
+        let mut bytes = AlignedVec::with_capacity(self.state.len());
+        bytes.extend_from_slice(&self.state);
+
+        let inner = match self.response {
+            ArchivedPersistedResponse::Message { channel } => ActiveResponseInner::Message {
+                channel: Id::new(channel),
+            },
+            ArchivedPersistedResponse::Interaction { ref token } => {
+                ActiveResponseInner::Interaction {
+                    token: token.as_ref().into(),
+                }
+            }
+        };
+
+        Some((
+            PersistedState { kind, bytes },
+            ActiveResponse { msg, inner },
+        ))
+    }
+}
+
+impl PersistedState {
+    /// Restore the active message.
+    ///
+    /// Active messages that only persisted ids fetch their models again.
+    pub async fn rehydrate(self) -> Result<ActiveMessage> {
+        let Self { kind, bytes } = self;
+
+        // SAFETY: Persisted entries are only written by `PersistedActiveMessage::new`
+        // into the bot's own redis instance which is trusted just like for every
+        // other cache entry that is accessed without validation. The bytes were
+        // serialized from the type corresponding to `kind` and the version
+        // guarantees that its layout did not change since. The checksum only
+        // catches accidental corruption such as truncated writes; it does not
+        // make accessing bytes from an untrusted source sound.
+        let active_msg = match kind {
+            PersistedKind::MedalCount => {
+                unsafe { deserialize::<MedalCountPagination>(&bytes) }.into()
+            }
+            PersistedKind::MedalRarity => {
+                unsafe { deserialize::<MedalRarityPagination>(&bytes) }.into()
+            }
+            PersistedKind::Top => unsafe { deserialize::<PersistedTopPagination>(&bytes) }
+                .restore()
+                .await?
+                .into(),
+            PersistedKind::Profile => unsafe { deserialize::<PersistedProfileMenu>(&bytes) }
+                .restore()
+                .await?
+                .into(),
+            PersistedKind::ScoreEmbedBuilder => {
+                unsafe { deserialize::<PersistedScoreEmbedBuilder>(&bytes) }
+                    .restore()
+                    .await?
+                    .into()
+            }
+            PersistedKind::HigherLower => unsafe { deserialize::<HigherLowerGame>(&bytes) }.into(),
+        };
+
+        Ok(active_msg)
+    }
+}
+
+/// # Safety
+///
+/// The bytes must be a valid archive of `T`.
+unsafe fn deserialize<T>(bytes: &[u8]) -> T
+where
+    T: Archive,
+    T::Archived: Deserialize<T, Infallible>,
+{
+    rkyv::archived_root::<T>(bytes)
+        .deserialize(&mut Infallible)
+        .unwrap()
+}
+
+/// Unlike [`DefaultHasher`](std::hash::DefaultHasher), the output is stable
+/// across Rust releases so toolchain updates don't invalidate persisted state.
+fn checksum(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
+}
@@ -14,11 +14,13 @@ use crate::{
     util::{InteractionToken, MessageExt},
 };
 
+#[derive(Clone)]
 pub struct ActiveResponse {
     pub msg: Id<MessageMarker>,
     pub inner: ActiveResponseInner,
 }
 
+#[derive(Clone)]
 pub enum ActiveResponseInner {
     Message { channel: Id<ChannelMarker> },
     Interaction { token: Box<str> },
@@ -28,7 +30,8 @@ impl ActiveResponse {
     pub fn new(orig: &ActiveMessageOrigin, response: &Message) -> Self {
         let inner = match orig {
             ActiveMessageOrigin::Channel(_)
-            | ActiveMessageOrigin::Command(CommandOrigin::Message { .. }) => {
+            | ActiveMessageOrigin::Command(CommandOrigin::Message { .. })
+            | ActiveMessageOrigin::Command(CommandOrigin::Scheduled { .. }) => {
                 ActiveResponseInner::Message {
                     channel: response.channel_id,
                 }
@@ -1,11 +1,17 @@
-use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::Result;
 use twilight_model::{channel::Message, guild::Permissions};
 
-use crate::{core::buckets::BucketName, util::ChannelExt, Context};
+use crate::{
+    core::{buckets::BucketName, locale::Issue},
+    util::ChannelExt,
+    Context,
+};
 
 pub async fn bigger(msg: &Message, permissions: Option<Permissions>) -> Result<()> {
-    if let Some(cooldown) = Context::check_ratelimit(msg.author.id, BucketName::BgBigger) {
+    if let Some(cooldown) =
+        Context::check_ratelimit(msg.author.id, msg.guild_id, BucketName::BgBigger).await
+    {
         trace!(
             "Ratelimiting user {} on bucket `BgBigger` for {cooldown} seconds",
             msg.author.id
@@ -37,7 +43,7 @@ pub async fn bigger(msg: &Message, permissions: Option<Permissions>) -> Result<(
                 msg.create_message(builder, permissions).await?;
             }
             Err(err) => {
-                let _ = msg.error(GENERAL_ISSUE).await;
+                let _ = msg.error(Issue::General).await;
 
                 return Err(err.wrap_err("Failed to get subimage"));
             }
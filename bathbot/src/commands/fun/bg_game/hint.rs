@@ -1,11 +1,15 @@
-use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::Result;
 use twilight_model::{channel::Message, guild::Permissions};
 
-use crate::{core::buckets::BucketName, util::ChannelExt, Context};
+use crate::{
+    core::{buckets::BucketName, locale::Issue},
+    util::ChannelExt,
+    Context,
+};
 
 pub async fn hint(msg: &Message, permissions: Option<Permissions>) -> Result<()> {
-    let ratelimit = Context::check_ratelimit(msg.author.id, BucketName::BgHint);
+    let ratelimit = Context::check_ratelimit(msg.author.id, msg.guild_id, BucketName::BgHint).await;
 
     if let Some(cooldown) = ratelimit {
         trace!(
@@ -23,7 +27,7 @@ pub async fn hint(msg: &Message, permissions: Option<Permissions>) -> Result<()>
                 msg.create_message(builder, permissions).await?;
             }
             Err(err) => {
-                let _ = msg.error(GENERAL_ISSUE).await;
+                let _ = msg.error(Issue::General).await;
 
                 return Err(err.wrap_err("Failed to get hint"));
             }
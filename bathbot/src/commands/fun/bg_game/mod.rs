@@ -2,7 +2,7 @@ use bathbot_macros::{command, SlashCommand};
 use bathbot_model::{command_fields::ThreadChannel, Effects};
 use bathbot_psql::model::games::DbMapTagsParams;
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, INVALID_ACTION_FOR_CHANNEL_TYPE, THREADS_UNAVAILABLE},
+    constants::{INVALID_ACTION_FOR_CHANNEL_TYPE, THREADS_UNAVAILABLE},
     CowUtils, MessageBuilder,
 };
 use eyre::{Report, Result};
@@ -20,6 +20,7 @@ use crate::{
         impls::{BackgroundGame, BackgroundGameSetup},
         ActiveMessages,
     },
+    core::locale::Issue,
     util::{
         interaction::InteractionCommand, Authored, ChannelExt, CheckPermissions,
         InteractionCommandExt,
@@ -110,6 +111,7 @@ pub async fn prefix_backgroundgame(
     I will only show members of this server."
 )]
 #[flags(SKIP_DEFER)]
+#[group(Games)]
 pub struct Bg {
     #[command(desc = "Specify a gamemode")]
     mode: Option<BgGameMode>,
@@ -190,7 +192,7 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
 
     if let Some(ThreadChannel::Thread) = thread {
         if command.guild_id.is_none() {
-            command.error_callback(THREADS_UNAVAILABLE).await?;
+            command.error_callback(Issue::ThreadsUnavailable).await?;
 
             return Ok(());
         }
@@ -244,7 +246,7 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
                         return Ok(());
                     }
                     None => {
-                        let _ = command.error_callback(GENERAL_ISSUE).await;
+                        let _ = command.error_callback(Issue::General).await;
 
                         return Err(Report::new(err).wrap_err("failed to create thread"));
                     }
@@ -291,7 +293,7 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
             let entries = match Context::games().bggame_tags(params).await {
                 Ok(entries) => entries,
                 Err(err) => {
-                    let _ = command.error(GENERAL_ISSUE).await;
+                    let _ = command.error(Issue::General).await;
 
                     return Err(err.wrap_err("failed to get all tagged mania mapsets"));
                 }
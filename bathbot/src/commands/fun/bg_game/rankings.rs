@@ -1,13 +1,14 @@
 use std::collections::BTreeMap;
 
 use bathbot_model::{RankingEntries, RankingEntry, RankingKind};
-use bathbot_util::{constants::GENERAL_ISSUE, IntHasher};
+use bathbot_util::IntHasher;
 use eyre::Result;
 use hashbrown::HashSet;
 use twilight_model::{channel::Message, id::Id};
 
 use crate::{
     active::{impls::RankingPagination, ActiveMessages},
+    core::locale::Issue,
     util::ChannelExt,
     Context,
 };
@@ -18,7 +19,7 @@ pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
     let mut scores = match Context::games().bggame_leaderboard().await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = msg.error(GENERAL_ISSUE).await;
+            let _ = msg.error(Issue::General).await;
 
             return Err(err.wrap_err("failed to get bggame scores"));
         }
@@ -1,11 +1,17 @@
-use bathbot_util::constants::{GENERAL_ISSUE, INVITE_LINK};
+use bathbot_util::constants::INVITE_LINK;
 use eyre::Result;
 use twilight_model::channel::Message;
 
-use crate::{core::buckets::BucketName, util::ChannelExt, Context};
+use crate::{
+    core::{buckets::BucketName, locale::Issue},
+    util::ChannelExt,
+    Context,
+};
 
 pub async fn skip(msg: &Message) -> Result<()> {
-    if let Some(cooldown) = Context::check_ratelimit(msg.author.id, BucketName::BgSkip) {
+    if let Some(cooldown) =
+        Context::check_ratelimit(msg.author.id, msg.guild_id, BucketName::BgSkip).await
+    {
         trace!(
             "Ratelimiting user {} on bucket `BgSkip` for {cooldown} seconds",
             msg.author.id
@@ -23,7 +29,7 @@ pub async fn skip(msg: &Message) -> Result<()> {
         Some(game) => match game.restart() {
             Ok(_) => {}
             Err(err) => {
-                let _ = msg.error(GENERAL_ISSUE).await;
+                let _ = msg.error(Issue::General).await;
 
                 return Err(err.wrap_err("Failed to restart game"));
             }
@@ -9,12 +9,13 @@ use twilight_model::{channel::ReactionType, gateway::event::Event, http::attachm
 
 use super::ReactionWrapper;
 use crate::{
+    core::locale::Issue,
     database::MapsetTagWrapper,
     games::bg::MapsetTags,
     util::{
         constants::{
             common_literals::{MANIA, OSU},
-            GENERAL_ISSUE, OSU_BASE, OWNER_USER_ID,
+            OSU_BASE, OWNER_USER_ID,
         },
         send_reaction, CowUtils, Emote,
     },
@@ -123,7 +124,7 @@ async fn bgtagsmanual(data: CommandData) -> Result<()> {
             msg.create_message(builder).await?;
         }
         Err(err) => {
-            let _ = msg.error(GENERAL_ISSUE).await;
+            let _ = msg.error(Issue::General).await;
 
             return Err(err);
         }
@@ -169,7 +170,7 @@ async fn bgtags(data: CommandData) -> Result<()> {
     let mut untagged = match ctx.psql().get_all_tags_mapset(mode).await {
         Ok(tags) => tags.iter().any(|tag| tag.untagged()),
         Err(err) => {
-            let _ = msg.error(GENERAL_ISSUE).await;
+            let _ = msg.error(Issue::General).await;
 
             return Err(err);
         }
@@ -215,7 +216,7 @@ async fn bgtags(data: CommandData) -> Result<()> {
                 tags
             }
             Err(err) => {
-                let _ = msg.error(GENERAL_ISSUE).await;
+                let _ = msg.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -364,7 +365,7 @@ async fn bgtags(data: CommandData) -> Result<()> {
                 }
             }
             Err(err) => {
-                let _ = msg.error(GENERAL_ISSUE).await;
+                let _ = msg.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -4,7 +4,7 @@ use bathbot_macros::SlashCommand;
 use bathbot_model::{
     command_fields::GameModeOption, HlVersion, RankingEntries, RankingEntry, RankingKind,
 };
-use bathbot_util::{constants::GENERAL_ISSUE, IntHasher};
+use bathbot_util::IntHasher;
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -15,12 +15,14 @@ use crate::{
         impls::{HigherLowerGame, RankingPagination},
         ActiveMessages,
     },
+    core::locale::Issue,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "higherlower", desc = "Play a game of osu! themed higher lower")]
+#[group(Games)]
 pub enum HigherLower {
     #[command(name = "pp")]
     ScorePp(HigherLowerScorePp),
@@ -78,7 +80,7 @@ async fn slash_higherlower(mut command: InteractionCommand) -> Result<()> {
                 .await
         }
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             Err(err)
         }
@@ -102,7 +104,7 @@ async fn higherlower_leaderboard(
     let mut scores = match Context::games().higherlower_leaderboard(version).await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
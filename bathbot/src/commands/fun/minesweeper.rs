@@ -19,6 +19,7 @@ use crate::{
     In case you don't know how it works: Each number indicates the amount of neighboring bombs."
 )]
 #[flags(SKIP_DEFER)]
+#[group(Games)]
 pub struct Minesweeper {
     #[command(desc = "Choose a difficulty")]
     difficulty: Difficulty,
@@ -2,10 +2,7 @@ use std::borrow::Cow;
 
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
-    matcher,
-    osu::flag_url,
-    AuthorBuilder, EmbedBuilder, MessageBuilder,
+    constants::OSU_BASE, matcher, osu::flag_url, AuthorBuilder, EmbedBuilder, MessageBuilder,
 };
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
@@ -14,7 +11,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use super::{require_link, user_not_found};
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, InteractionCommandExt},
     Context,
@@ -32,6 +32,8 @@ pub struct Avatar<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 pub async fn slash_avatar(mut command: InteractionCommand) -> Result<()> {
@@ -62,7 +64,11 @@ impl<'m> Avatar<'m> {
             }
         }
 
-        Self { name, discord }
+        Self {
+            name,
+            discord,
+            account: None,
+        }
     }
 }
 
@@ -73,7 +79,7 @@ async fn avatar(orig: CommandOrigin<'_>, args: Avatar<'_>) -> Result<()> {
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -90,7 +96,7 @@ async fn avatar(orig: CommandOrigin<'_>, args: Avatar<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
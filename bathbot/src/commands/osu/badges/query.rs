@@ -1,11 +1,7 @@
 use std::{cmp::Ordering, collections::BTreeMap, fmt::Write};
 
 use bathbot_model::OsekaiBadge;
-use bathbot_util::{
-    constants::{AVATAR_URL, OSEKAI_ISSUE},
-    string_cmp::levenshtein_similarity,
-    CowUtils,
-};
+use bathbot_util::{constants::AVATAR_URL, string_cmp::levenshtein_similarity, CowUtils};
 use eyre::{Result, WrapErr};
 use rkyv::{Deserialize, Infallible};
 use twilight_interactions::command::AutocompleteValue;
@@ -14,7 +10,7 @@ use twilight_model::application::command::{CommandOptionChoice, CommandOptionCho
 use super::BadgesQuery_;
 use crate::{
     active::{impls::BadgesPagination, ActiveMessages},
-    core::Context,
+    core::{locale::Issue, Context},
     manager::redis::RedisData,
     util::{
         interaction::InteractionCommand, osu::get_combined_thumbnail, Authored,
@@ -34,7 +30,7 @@ pub(super) async fn query(mut command: InteractionCommand, args: BadgesQuery_) -
     let badges = match Context::redis().badges().await {
         Ok(badges) => badges,
         Err(err) => {
-            let _ = command.error(OSEKAI_ISSUE).await;
+            let _ = command.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached badges"));
         }
@@ -105,7 +101,7 @@ pub(super) async fn query(mut command: InteractionCommand, args: BadgesQuery_) -
         match owners_fut.await {
             Ok(owners) => owners,
             Err(err) => {
-                let _ = command.error(OSEKAI_ISSUE).await;
+                let _ = command.error(Issue::Osekai).await;
 
                 return Err(err.wrap_err("Failed to get badge owners"));
             }
@@ -154,7 +150,7 @@ async fn no_badge_found(command: &InteractionCommand, name: &str) -> Result<()>
     let badges = match Context::redis().badges().await {
         Ok(badges) => badges,
         Err(err) => {
-            let _ = command.error(OSEKAI_ISSUE).await;
+            let _ = command.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached badges"));
         }
@@ -1,9 +1,6 @@
 use std::collections::BTreeMap;
 
-use bathbot_util::{
-    constants::{AVATAR_URL, GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::{constants::AVATAR_URL, MessageBuilder};
 use eyre::{Report, Result};
 use rkyv::{Deserialize, Infallible};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
@@ -12,7 +9,7 @@ use super::BadgesUser;
 use crate::{
     active::{impls::BadgesPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::Issue, Context},
     manager::redis::{osu::UserArgs, RedisData},
     util::osu::get_combined_thumbnail,
 };
@@ -26,7 +23,7 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser) -> Result<()
             Ok(Some(id)) => UserId::Id(id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err.wrap_err("failed to get user id"));
             }
@@ -47,7 +44,7 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser) -> Result<()
             return orig.error(content).await;
         }
         UserArgs::Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -57,7 +54,7 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser) -> Result<()
     let badges = match badges_res {
         Ok(badges) => badges,
         Err(err) => {
-            let _ = orig.error(OSEKAI_ISSUE).await;
+            let _ = orig.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get badges"));
         }
@@ -84,7 +81,7 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser) -> Result<()
         match owners_fut.await {
             Ok(owners) => owners,
             Err(err) => {
-                let _ = orig.error(OSEKAI_ISSUE).await;
+                let _ = orig.error(Issue::Osekai).await;
 
                 return Err(err.wrap_err("failed to get badge owners"));
             }
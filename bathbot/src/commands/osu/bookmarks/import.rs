@@ -0,0 +1,140 @@
+use std::{collections::HashSet, fmt::Write};
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{IntHasher, MessageBuilder};
+use eyre::{Report, Result};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    commands::osu::require_link,
+    core::{commands::CommandOrigin, locale::Issue, Context},
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+};
+
+/// Name of the folder that imported favourites are put into
+const FOLDER: &str = "Favourites";
+
+/// Amount of mapsets per request
+const LIMIT: usize = 100;
+
+/// Upper bound for imported mapsets so a single import can't spam requests
+const MAX_MAPSETS: usize = 1000;
+
+#[derive(CreateCommand, CommandModel, SlashCommand)]
+#[command(
+    name = "importfavourites",
+    desc = "Bookmark the favourite mapsets of your osu! account",
+    help = "Import the favourite mapsets of your linked osu! account as bookmarks.\n\
+    For each mapset its hardest difficulty is bookmarked and put into the `Favourites` folder. \
+    Maps that you already bookmarked are left untouched.\n\
+    Use `/bookmarks folder:Favourites` to view them afterwards."
+)]
+#[flags(EPHEMERAL)]
+pub struct ImportFavourites;
+
+pub async fn slash_importfavourites(mut command: InteractionCommand) -> Result<()> {
+    let owner = command.user_id()?;
+
+    let user_id = match Context::user_config().osu_id(owner).await {
+        Ok(Some(user_id)) => user_id,
+        Ok(None) => return require_link(&CommandOrigin::from(&mut command)).await,
+        Err(err) => {
+            let _ = command.error(Issue::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let bookmarked: HashSet<_, IntHasher> = match Context::bookmarks().get(owner).await {
+        Ok(bookmarks) => bookmarks
+            .into_iter()
+            .map(|bookmark| bookmark.map_id)
+            .collect(),
+        Err(err) => {
+            let _ = command.error(Issue::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let mut mapsets = Vec::new();
+
+    loop {
+        let mapsets_fut = Context::osu()
+            .user_beatmapsets(user_id)
+            .favourite()
+            .limit(LIMIT)
+            .offset(mapsets.len());
+
+        match mapsets_fut.await {
+            Ok(page) => {
+                let len = page.len();
+                mapsets.extend(page);
+
+                if len < LIMIT || mapsets.len() >= MAX_MAPSETS {
+                    break;
+                }
+            }
+            Err(err) => {
+                let _ = command.error(Issue::OsuApi).await;
+
+                return Err(Report::new(err).wrap_err("Failed to get favourite mapsets"));
+            }
+        }
+    }
+
+    if mapsets.is_empty() {
+        let content = "Your linked osu! account has no favourite mapsets";
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let mut imported = 0;
+    let mut skipped = 0;
+
+    for mapset in mapsets {
+        let hardest = mapset.maps.as_deref().and_then(|maps| {
+            maps.iter()
+                .max_by(|a, b| a.stars.total_cmp(&b.stars))
+                .map(|map| map.map_id)
+        });
+
+        let Some(map_id) = hardest else { continue };
+
+        if bookmarked.contains(&map_id) {
+            skipped += 1;
+
+            continue;
+        }
+
+        // Bookmarks require the map to be stored
+        Context::osu_map().store(&mapset).await;
+
+        let manager = Context::bookmarks();
+
+        let res = async {
+            manager.add(owner, map_id).await?;
+            manager.set_folder(owner, map_id, Some(FOLDER)).await
+        };
+
+        if let Err(err) = res.await {
+            let _ = command.error(Issue::General).await;
+
+            return Err(err.wrap_err("Failed to import favourite"));
+        }
+
+        imported += 1;
+    }
+
+    let mut content = format!("Bookmarked {imported} favourite mapsets into the `{FOLDER}` folder");
+
+    if skipped > 0 {
+        let _ = write!(content, ", {skipped} were already bookmarked");
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
@@ -4,14 +4,14 @@ use std::{
 };
 
 use bathbot_macros::msg_command;
-use bathbot_util::{constants::GENERAL_ISSUE, osu::MapIdType, MessageOrigin};
+use bathbot_util::{osu::MapIdType, MessageOrigin};
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
 use twilight_model::channel::Message;
 
 use crate::{
     active::{impls::BookmarksPagination, ActiveMessages},
-    core::Context,
+    core::{locale::Issue, Context},
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
 };
 
@@ -24,7 +24,7 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
         .and_then(|resolved| resolved.messages.values().next());
 
     let Some(msg) = msg_opt else {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error(Issue::General).await;
 
         return Err(eyre!("Missing resolved message"));
     };
@@ -71,7 +71,7 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(Report::new(err).wrap_err("Failed to get mapset"));
         }
@@ -102,7 +102,7 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
     let bookmarks = match Context::bookmarks().get(user_id).await {
         Ok(bookmarks) => bookmarks,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await?;
+            let _ = command.error(Issue::General).await?;
 
             return Err(err);
         }
@@ -112,7 +112,7 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
         Some(index) => index,
         None => {
             if let Err(err) = Context::bookmarks().add(user_id, map_id).await {
-                let _ = command.error(GENERAL_ISSUE).await;
+                let _ = command.error(Issue::General).await;
 
                 return Err(err);
             } else {
@@ -123,10 +123,13 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
 
     debug!(user = %user_id, map = map_id, "Added bookmarked map");
 
-    let bookmarks = match Context::bookmarks().get(user_id).await {
-        Ok(bookmarks) => bookmarks,
+    let bookmarks_fut = Context::bookmarks().get(user_id);
+    let folders_fut = Context::bookmarks().folders(user_id);
+
+    let (bookmarks, folders) = match tokio::try_join!(bookmarks_fut, folders_fut) {
+        Ok(tuple) => tuple,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await?;
+            let _ = command.error(Issue::General).await?;
 
             return Err(err);
         }
@@ -142,6 +145,7 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
 
     let mut pagination = BookmarksPagination::builder()
         .bookmarks(bookmarks)
+        .folders(folders)
         .origin(origin)
         .cached_entries(HashMap::default())
         .defer_next(false)
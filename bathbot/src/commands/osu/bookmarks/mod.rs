@@ -1,2 +1,5 @@
+mod import;
 mod message;
 mod slash;
+
+pub use self::slash::BookmarksFilter;
@@ -3,17 +3,18 @@ use std::{collections::HashMap, fmt::Write};
 use bathbot_macros::SlashCommand;
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_psql::model::osu::MapBookmark;
-use bathbot_util::{constants::GENERAL_ISSUE, CowUtils, MessageOrigin};
+use bathbot_util::{CowUtils, MessageOrigin};
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
     active::{impls::BookmarksPagination, ActiveMessages},
-    core::Context,
+    core::{locale::Issue, Context},
     util::{
         interaction::InteractionCommand,
-        query::{BookmarkCriteria, FilterCriteria, IFilterCriteria},
+        query::{BookmarkCriteria, IFilterCriteria},
         Authored, InteractionCommandExt,
     },
 };
@@ -25,7 +26,9 @@ use crate::{
     help = "List all your bookmarked maps. You can bookmark maps by:\n\
     1. Rightclicking a bot message that contains a single map\n\
     2. Click on `Apps`\n\
-    3. Click on `Bookmark map`."
+    3. Click on `Bookmark map`.\n\
+    Bookmarks can be sorted into folders and given a note. \
+    Folders can be published so that other users can view them."
 )]
 #[flags(EPHEMERAL)]
 pub struct Bookmarks {
@@ -41,6 +44,19 @@ pub struct Bookmarks {
     query: Option<String>,
     #[command(desc = "Filter out maps that don't belong to a gamemode")]
     mode: Option<GameModeOption>,
+    #[command(
+        desc = "Only show bookmarks of a folder",
+        help = "Only show bookmarks of a folder.\n\
+        You can move bookmarks into folders through the `Folder` button of the bookmarks list."
+    )]
+    folder: Option<String>,
+    #[command(
+        desc = "Show the published bookmark folder of another user",
+        help = "Show the bookmarks of another user.\n\
+        The `folder` option must be specified and the user must have published that folder \
+        through the `Publish` button of their bookmarks list."
+    )]
+    user: Option<Id<UserMarker>>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption)]
@@ -72,29 +88,78 @@ impl Default for BookmarksSort {
 pub async fn slash_bookmarks(mut command: InteractionCommand) -> Result<()> {
     let args = Bookmarks::from_interaction(command.input_data())?;
     let owner = command.user_id()?;
+    let target = args.user.unwrap_or(owner);
+    let readonly = target != owner;
+
+    if readonly && args.folder.is_none() {
+        let content = "When specifying a user you must also specify one of their published folders";
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let bookmarks_fut = Context::bookmarks().get(target);
+    let folders_fut = Context::bookmarks().folders(target);
 
-    let mut bookmarks = match Context::bookmarks().get(owner).await {
-        Ok(bookmarks) => bookmarks,
+    let (mut bookmarks, mut folders) = match tokio::try_join!(bookmarks_fut, folders_fut) {
+        Ok(tuple) => tuple,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await?;
+            let _ = command.error(Issue::General).await?;
 
             return Err(err);
         }
     };
 
-    let criteria = args.query.as_deref().map(BookmarkCriteria::create);
+    if readonly {
+        folders.retain(|folder| folder.public);
+    }
+
+    let folder = match args.folder.as_deref() {
+        Some(name) => {
+            let folder_opt = folders
+                .iter()
+                .find(|folder| folder.name.eq_ignore_ascii_case(name.trim()));
+
+            match folder_opt {
+                Some(folder) => Some(folder.name.clone()),
+                None if readonly => {
+                    let content = format!("<@{target}> has no published folder named `{name}`");
+                    command.error(content).await?;
+
+                    return Ok(());
+                }
+                None => {
+                    let content = format!("You have no bookmark folder named `{name}`");
+                    command.error(content).await?;
+
+                    return Ok(());
+                }
+            }
+        }
+        None => None,
+    };
+
+    if let Some(ref folder) = folder {
+        bookmarks.retain(|bookmark| bookmark.folder.as_ref() == Some(folder));
+    }
+
+    let filter = BookmarksFilter::new(&args);
+    filter.apply(&mut bookmarks);
 
-    process_bookmarks(&mut bookmarks, &args, criteria.as_ref());
-    let content = msg_content(&args, criteria.as_ref());
-    let filtered = criteria.is_some() || args.mode.is_some();
+    let content = filter.content(folder.as_deref(), readonly.then_some(target));
+    let filtered = filter.is_filtering() || folder.is_some();
 
     let origin = MessageOrigin::new(command.guild_id(), command.channel_id());
 
     let pagination = BookmarksPagination::builder()
         .bookmarks(bookmarks)
+        .folders(folders)
+        .folder(folder)
+        .readonly(Some(readonly))
         .origin(origin)
         .cached_entries(HashMap::default())
         .filtered_maps(Some(filtered))
+        .filter(Some(filter))
         .defer_next(false)
         .token(command.token.clone())
         .content(content)
@@ -107,126 +172,163 @@ pub async fn slash_bookmarks(mut command: InteractionCommand) -> Result<()> {
         .await
 }
 
-fn process_bookmarks(
-    bookmarks: &mut Vec<MapBookmark>,
-    args: &Bookmarks,
-    criteria: Option<&FilterCriteria<BookmarkCriteria<'_>>>,
-) {
-    if let Some(mode) = args.mode.map(GameMode::from) {
-        bookmarks.retain(|bookmark| bookmark.mode == mode);
+/// Query, mode, and order that bookmarks are listed with.
+///
+/// Kept by the pagination so that switching folders doesn't drop them.
+pub struct BookmarksFilter {
+    sort: BookmarksSort,
+    query: Option<String>,
+    mode: Option<GameMode>,
+}
+
+impl BookmarksFilter {
+    fn new(args: &Bookmarks) -> Self {
+        Self {
+            sort: args.sort.unwrap_or_default(),
+            query: args.query.clone(),
+            mode: args.mode.map(GameMode::from),
+        }
+    }
+
+    /// Whether bookmarks are filtered by query or mode.
+    pub fn is_filtering(&self) -> bool {
+        self.query.is_some() || self.mode.is_some()
     }
 
-    if let Some(criteria) = criteria {
-        bookmarks.retain(|bookmark| {
-            let mut matches = true;
+    /// Remove bookmarks that don't match and sort the remaining ones.
+    pub fn apply(&self, bookmarks: &mut Vec<MapBookmark>) {
+        if let Some(mode) = self.mode {
+            bookmarks.retain(|bookmark| bookmark.mode == mode);
+        }
 
-            matches &= criteria.ar.contains(bookmark.ar);
-            matches &= criteria.cs.contains(bookmark.cs);
-            matches &= criteria.hp.contains(bookmark.hp);
-            matches &= criteria.od.contains(bookmark.od);
-            matches &= criteria.length.contains(bookmark.seconds_drain as f32);
-            matches &= criteria.bpm.contains(bookmark.bpm);
+        let criteria = self.query.as_deref().map(BookmarkCriteria::create);
 
-            matches &= criteria.insert_date.contains(bookmark.insert_date.date());
-            matches &= bookmark.ranked_date.map_or(false, |datetime| {
-                criteria.ranked_date.contains(datetime.date())
-            });
+        if let Some(ref criteria) = criteria {
+            bookmarks.retain(|bookmark| {
+                let mut matches = true;
 
-            let version = bookmark.version.cow_to_ascii_lowercase();
-            matches &= criteria.version.matches(&version);
+                matches &= criteria.ar.contains(bookmark.ar);
+                matches &= criteria.cs.contains(bookmark.cs);
+                matches &= criteria.hp.contains(bookmark.hp);
+                matches &= criteria.od.contains(bookmark.od);
+                matches &= criteria.length.contains(bookmark.seconds_drain as f32);
+                matches &= criteria.bpm.contains(bookmark.bpm);
 
-            let artist = bookmark.artist.cow_to_ascii_lowercase();
-            matches &= criteria.artist.matches(&artist);
+                matches &= criteria.insert_date.contains(bookmark.insert_date.date());
+                matches &= bookmark.ranked_date.map_or(false, |datetime| {
+                    criteria.ranked_date.contains(datetime.date())
+                });
 
-            let title = bookmark.title.cow_to_ascii_lowercase();
-            matches &= criteria.title.matches(&title);
+                let version = bookmark.version.cow_to_ascii_lowercase();
+                matches &= criteria.version.matches(&version);
 
-            let language = format!("{:?}", bookmark.language).to_lowercase();
-            matches &= criteria.language.matches(&language);
+                let artist = bookmark.artist.cow_to_ascii_lowercase();
+                matches &= criteria.artist.matches(&artist);
 
-            let genre = format!("{:?}", bookmark.genre).to_lowercase();
-            matches &= criteria.genre.matches(&genre);
+                let title = bookmark.title.cow_to_ascii_lowercase();
+                matches &= criteria.title.matches(&title);
 
-            if matches && criteria.has_search_terms() {
-                let terms = [
-                    artist.as_ref(),
-                    title.as_ref(),
-                    version.as_ref(),
-                    language.as_str(),
-                    genre.as_str(),
-                ];
+                let language = format!("{:?}", bookmark.language).to_lowercase();
+                matches &= criteria.language.matches(&language);
 
-                matches &= criteria
-                    .search_terms()
-                    .all(|term| terms.iter().any(|searchable| searchable.contains(term)))
-            }
+                let genre = format!("{:?}", bookmark.genre).to_lowercase();
+                matches &= criteria.genre.matches(&genre);
 
-            matches
-        });
-    }
+                if matches && criteria.has_search_terms() {
+                    let terms = [
+                        artist.as_ref(),
+                        title.as_ref(),
+                        version.as_ref(),
+                        language.as_str(),
+                        genre.as_str(),
+                    ];
+
+                    matches &= criteria
+                        .search_terms()
+                        .all(|term| terms.iter().any(|searchable| searchable.contains(term)))
+                }
 
-    match args.sort.unwrap_or_default() {
-        BookmarksSort::BookmarkDate => {
-            // Sorted by database
+                matches
+            });
         }
-        BookmarksSort::Artist => bookmarks.sort_unstable_by(|a, b| {
-            a.artist
-                .cow_to_ascii_lowercase()
-                .cmp(&b.artist.cow_to_ascii_lowercase())
-                .then_with(|| b.insert_date.cmp(&a.insert_date))
-        }),
-        BookmarksSort::Title => bookmarks.sort_unstable_by(|a, b| {
-            a.title
-                .cow_to_ascii_lowercase()
-                .cmp(&b.title.cow_to_ascii_lowercase())
-                .then_with(|| b.insert_date.cmp(&a.insert_date))
-        }),
-        BookmarksSort::Ar => bookmarks.sort_unstable_by(|a, b| {
-            a.ar.total_cmp(&b.ar)
-                .then_with(|| b.insert_date.cmp(&a.insert_date))
-        }),
-        BookmarksSort::Cs => bookmarks.sort_unstable_by(|a, b| {
-            a.cs.total_cmp(&b.cs)
-                .then_with(|| b.insert_date.cmp(&a.insert_date))
-        }),
-        BookmarksSort::Hp => bookmarks.sort_unstable_by(|a, b| {
-            a.hp.total_cmp(&b.hp)
-                .then_with(|| b.insert_date.cmp(&a.insert_date))
-        }),
-        BookmarksSort::Od => bookmarks.sort_unstable_by(|a, b| {
-            a.od.total_cmp(&b.od)
-                .then_with(|| b.insert_date.cmp(&a.insert_date))
-        }),
-        BookmarksSort::Length => bookmarks.sort_unstable_by(|a, b| {
-            a.seconds_drain
-                .cmp(&b.seconds_drain)
-                .then_with(|| b.insert_date.cmp(&a.insert_date))
-        }),
-    }
-}
 
-fn msg_content(
-    args: &Bookmarks,
-    criteria: Option<&FilterCriteria<BookmarkCriteria<'_>>>,
-) -> String {
-    let mut content = String::new();
-
-    if let Some(mode) = args.mode.map(GameMode::from) {
-        let _ = write!(
-            content,
-            "`Mode: {}`",
-            match mode {
-                GameMode::Osu => "osu!",
-                GameMode::Taiko => "Taiko",
-                GameMode::Catch => "Catch",
-                GameMode::Mania => "Mania",
+        match self.sort {
+            BookmarksSort::BookmarkDate => {
+                // Sorted by database
             }
-        );
+            BookmarksSort::Artist => bookmarks.sort_unstable_by(|a, b| {
+                a.artist
+                    .cow_to_ascii_lowercase()
+                    .cmp(&b.artist.cow_to_ascii_lowercase())
+                    .then_with(|| b.insert_date.cmp(&a.insert_date))
+            }),
+            BookmarksSort::Title => bookmarks.sort_unstable_by(|a, b| {
+                a.title
+                    .cow_to_ascii_lowercase()
+                    .cmp(&b.title.cow_to_ascii_lowercase())
+                    .then_with(|| b.insert_date.cmp(&a.insert_date))
+            }),
+            BookmarksSort::Ar => bookmarks.sort_unstable_by(|a, b| {
+                a.ar.total_cmp(&b.ar)
+                    .then_with(|| b.insert_date.cmp(&a.insert_date))
+            }),
+            BookmarksSort::Cs => bookmarks.sort_unstable_by(|a, b| {
+                a.cs.total_cmp(&b.cs)
+                    .then_with(|| b.insert_date.cmp(&a.insert_date))
+            }),
+            BookmarksSort::Hp => bookmarks.sort_unstable_by(|a, b| {
+                a.hp.total_cmp(&b.hp)
+                    .then_with(|| b.insert_date.cmp(&a.insert_date))
+            }),
+            BookmarksSort::Od => bookmarks.sort_unstable_by(|a, b| {
+                a.od.total_cmp(&b.od)
+                    .then_with(|| b.insert_date.cmp(&a.insert_date))
+            }),
+            BookmarksSort::Length => bookmarks.sort_unstable_by(|a, b| {
+                a.seconds_drain
+                    .cmp(&b.seconds_drain)
+                    .then_with(|| b.insert_date.cmp(&a.insert_date))
+            }),
+        }
     }
 
-    if let Some(criteria) = criteria {
-        criteria.display(&mut content);
-    }
+    /// Content of the bookmarks message.
+    pub fn content(&self, folder: Option<&str>, target: Option<Id<UserMarker>>) -> String {
+        let mut content = String::new();
+
+        if let Some(target) = target {
+            let _ = write!(content, "Bookmarks of <@{target}>");
+        }
+
+        if let Some(folder) = folder {
+            if !content.is_empty() {
+                content.push_str(" • ");
+            }
+
+            let _ = write!(content, "`Folder: {folder}`");
+        }
+
+        if let Some(mode) = self.mode {
+            if !content.is_empty() {
+                content.push_str(" • ");
+            }
+
+            let _ = write!(
+                content,
+                "`Mode: {}`",
+                match mode {
+                    GameMode::Osu => "osu!",
+                    GameMode::Taiko => "Taiko",
+                    GameMode::Catch => "Catch",
+                    GameMode::Mania => "Mania",
+                }
+            );
+        }
 
-    content
+        if let Some(ref query) = self.query {
+            BookmarkCriteria::create(query).display(&mut content);
+        }
+
+        content
+    }
 }
@@ -1,10 +1,7 @@
 use std::{borrow::Cow, mem};
 
 use bathbot_macros::{command, HasName, SlashCommand};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher, MessageBuilder, TourneyBadges,
-};
+use bathbot_util::{matcher, MessageBuilder, TourneyBadges};
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -12,7 +9,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use super::{require_link, user_not_found};
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     embeds::{BWSEmbed, EmbedData},
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
@@ -54,12 +54,15 @@ pub struct Bws<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 impl<'m> Bws<'m> {
     fn args(args: Args<'m>) -> Result<Self, Cow<'static, str>> {
         let mut name = None;
         let mut discord = None;
+        let mut account = None;
         let mut rank = None;
         let mut badges = None;
 
@@ -85,9 +88,10 @@ impl<'m> Bws<'m> {
                             return Err(content.into());
                         }
                     },
+                    "account" => account = Some(value.to_owned().into()),
                     _ => {
                         let content = format!(
-                            "Unrecognized option `{key}`.\nAvailable options are: `rank` or `badges`."
+                            "Unrecognized option `{key}`.\nAvailable options are: `rank`, `badges`, or `account`."
                         );
 
                         return Err(content.into());
@@ -105,6 +109,7 @@ impl<'m> Bws<'m> {
             rank,
             badges,
             discord,
+            account,
         })
     }
 }
@@ -148,7 +153,7 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -167,7 +172,7 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -2,13 +2,10 @@ use std::collections::HashMap;
 
 use bathbot_cards::{BathbotCard, RequiredAttributes};
 use bathbot_macros::{HasName, SlashCommand};
-use bathbot_model::command_fields::GameModeOption;
+use bathbot_model::{command_fields::GameModeOption, rosu_v2::user::User};
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    datetime::DATE_FORMAT,
-    osu::flag_url_size,
-    EmbedBuilder, IntHasher, MessageBuilder,
+    datetime::DATE_FORMAT, osu::flag_url_size, EmbedBuilder, IntHasher, MessageBuilder,
 };
 use eyre::{Report, Result, WrapErr};
 use futures::{stream::FuturesUnordered, TryStreamExt};
@@ -19,7 +16,7 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use super::{require_link, user_not_found};
 use crate::{
-    core::{commands::CommandOrigin, BotConfig, Context},
+    core::{commands::CommandOrigin, locale::Issue, BotConfig, Context},
     embeds::attachment,
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, InteractionCommandExt},
@@ -88,6 +85,8 @@ pub struct Card {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
 }
 
 async fn slash_card(mut command: InteractionCommand) -> Result<()> {
@@ -100,20 +99,21 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
+    let mode = args
+        .mode
+        .map(GameMode::from)
+        .or(orig.channel_mode().await)
+        .or(config.mode)
+        .unwrap_or(GameMode::Osu);
+
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
-
     let legacy_scores = match config.score_data {
         Some(score_data) => score_data.is_legacy(),
         None => match orig.guild_id() {
@@ -126,6 +126,72 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
     };
 
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+    let (user, bytes) = match draw_card(user_args, mode, legacy_scores).await {
+        Ok(tuple) => tuple,
+        Err(CardError::NoScores) => {
+            let content = "Looks like they don't have any scores on that mode";
+            orig.error(content).await?;
+
+            return Ok(());
+        }
+        Err(CardError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(CardError::Osu(err)) => {
+            let _ = orig.error(Issue::OsuApi).await;
+            let err = Report::new(err).wrap_err("failed to get user");
+
+            return Err(err);
+        }
+        Err(CardError::Medals(err)) => {
+            let _ = orig.error(Issue::Osekai).await;
+
+            return Err(err);
+        }
+        Err(CardError::Images(err)) => {
+            let _ = orig.error(Issue::General).await;
+
+            return Err(err);
+        }
+        Err(CardError::Draw(err)) => {
+            let _ = orig.error("Failed to draw the card :(").await;
+
+            return Err(err);
+        }
+        Err(CardError::Other(err)) => return Err(err),
+    };
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder())
+        .image(attachment("card.png"));
+
+    let builder = MessageBuilder::new()
+        .attachment("card.png", bytes)
+        .embed(embed);
+
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+pub enum CardError {
+    NoScores,
+    Osu(OsuError),
+    Medals(Report),
+    Images(Report),
+    Draw(Report),
+    Other(Report),
+}
+
+/// Gather all required data and draw the card of a user.
+pub async fn draw_card(
+    user_args: UserArgs,
+    mode: GameMode,
+    legacy_scores: bool,
+) -> Result<(RedisData<User>, Vec<u8>), CardError> {
     let scores_fut = Context::osu_scores()
         .top(legacy_scores)
         .limit(100)
@@ -141,29 +207,16 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
 
             (user, scores, medals_len)
         }
-        (Err(OsuError::NotFound), _) => {
-            let content = user_not_found(user_id).await;
-
-            return orig.error(content).await;
-        }
-        (Err(err), _) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
-            let err = Report::new(err).wrap_err("failed to get user");
-
-            return Err(err);
-        }
+        (Err(err), _) => return Err(CardError::Osu(err)),
         (_, Err(err)) => {
-            let _ = orig.error(OSEKAI_ISSUE).await;
-
-            return Err(err.wrap_err("failed to get cached medals"));
+            return Err(CardError::Medals(
+                err.wrap_err("failed to get cached medals"),
+            ))
         }
     };
 
     if scores.is_empty() {
-        let content = "Looks like they don't have any scores on that mode";
-        orig.error(content).await?;
-
-        return Ok(());
+        return Err(CardError::NoScores);
     }
 
     let maps: HashMap<_, _, IntHasher> = scores
@@ -189,7 +242,8 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
         })
         .collect::<FuturesUnordered<_>>()
         .try_collect()
-        .await?;
+        .await
+        .map_err(CardError::Other)?;
 
     let client = Context::client();
     let pfp_fut = client.get_avatar(user.avatar_url());
@@ -199,14 +253,14 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
     let (pfp, flag) = match tokio::join!(pfp_fut, flag_fut) {
         (Ok(pfp), Ok(flag)) => (pfp, flag),
         (Err(err), _) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-
-            return Err(err.wrap_err("Failed to acquire card avatar"));
+            return Err(CardError::Images(
+                err.wrap_err("Failed to acquire card avatar"),
+            ))
         }
         (_, Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-
-            return Err(err.wrap_err("Failed to acquire card flag"));
+            return Err(CardError::Images(
+                err.wrap_err("Failed to acquire card flag"),
+            ))
         }
     };
 
@@ -231,24 +285,10 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
         .assets(BotConfig::get().paths.assets.clone())
         .draw();
 
-    let bytes = match card_res {
-        Ok(bytes) => bytes,
-        Err(err) => {
-            let _ = orig.error("Failed to draw the card :(").await;
-
-            return Err(Report::new(err).wrap_err("Failed to draw card"));
-        }
-    };
-
-    let embed = EmbedBuilder::new()
-        .author(user.author_builder())
-        .image(attachment("card.png"));
-
-    let builder = MessageBuilder::new()
-        .attachment("card.png", bytes)
-        .embed(embed);
-
-    orig.create_message(builder).await?;
-
-    Ok(())
+    match card_res {
+        Ok(bytes) => Ok((user, bytes)),
+        Err(err) => Err(CardError::Draw(
+            Report::new(err).wrap_err("Failed to draw card"),
+        )),
+    }
 }
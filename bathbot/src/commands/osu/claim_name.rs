@@ -6,7 +6,7 @@ use bathbot_model::{
     rkyv_util::time::{DateRkyv, DateTimeRkyv},
     rosu_v2::user::{ArchivedUser, User, UserHighestRank as UserHighestRankRkyv, UserStatistics},
 };
-use bathbot_util::{constants::OSU_API_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use futures::{future, stream::FuturesUnordered, TryStreamExt};
 use once_cell::sync::OnceCell;
@@ -19,7 +19,7 @@ use time::{OffsetDateTime, Time};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
-    core::Context,
+    core::{locale::Issue, Context},
     embeds::{ClaimNameEmbed, EmbedData},
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, InteractionCommandExt},
@@ -93,7 +93,7 @@ async fn slash_claimname(mut command: InteractionCommand) -> Result<()> {
             return Ok(());
         }
         UserArgs::Err(err) => {
-            let _ = command.error(OSU_API_ISSUE).await;
+            let _ = command.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -195,7 +195,7 @@ async fn slash_claimname(mut command: InteractionCommand) -> Result<()> {
     let user = match user_fut.await {
         Ok(user) => user.unwrap(),
         Err(err) => {
-            let _ = command.error(OSU_API_ISSUE).await;
+            let _ = command.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -2,10 +2,7 @@ use std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt::Write, iter};
 
 use bathbot_macros::{command, SlashCommand};
 use bathbot_model::{command_fields::GameModeOption, rosu_v2::user::User};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher,
-};
+use bathbot_util::{matcher, IntHasher};
 use eyre::{Report, Result};
 use rkyv::{Deserialize, Infallible};
 use rosu_v2::{
@@ -24,7 +21,10 @@ use super::{CompareTop, AT_LEAST_ONE};
 use crate::{
     active::{impls::CompareTopPagination, ActiveMessages},
     commands::osu::{user_not_found, UserExtraction},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, osu::get_combined_thumbnail, InteractionCommandExt},
     Context,
@@ -151,7 +151,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
     let user_id1 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -162,7 +162,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
     let user_id2 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -176,7 +176,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
                 return orig.error(content).await;
             }
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -192,7 +192,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
         None => match Context::user_config().mode(owner).await {
             Ok(mode) => mode.unwrap_or(GameMode::Osu),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -215,7 +215,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
             return orig.error(content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get scores");
 
             return Err(err);
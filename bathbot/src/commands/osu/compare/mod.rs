@@ -102,6 +102,8 @@ pub struct CompareScore<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandModel)]
@@ -114,6 +116,7 @@ pub struct CompareScoreAutocomplete<'a> {
     pub mods: Option<Cow<'a, str>>,
     pub index: Option<u32>,
     pub discord: Option<Id<UserMarker>>,
+    pub account: Option<Cow<'a, str>>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption)]
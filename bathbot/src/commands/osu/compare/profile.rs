@@ -3,7 +3,6 @@ use std::{borrow::Cow, io::Cursor};
 use bathbot_macros::{command, SlashCommand};
 use bathbot_model::{command_fields::GameModeOption, RankAccPeaks, RespektiveUser};
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     matcher,
     numbers::MinMaxAvg,
     osu::{BonusPP, UserStats},
@@ -29,7 +28,10 @@ use twilight_model::{
 use super::{CompareProfile, AT_LEAST_ONE};
 use crate::{
     commands::osu::UserExtraction,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     embeds::{EmbedData, ProfileCompareEmbed},
     manager::redis::osu::UserArgs,
     util::{interaction::InteractionCommand, InteractionCommandExt},
@@ -90,7 +92,7 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
     let user_id1 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -101,7 +103,7 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
     let user_id2 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -115,7 +117,7 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
                 return orig.error(content).await;
             }
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -131,7 +133,7 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
         None => match Context::user_config().mode(orig.user_id()?).await {
             Ok(mode) => mode.unwrap_or(GameMode::Osu),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -154,7 +156,7 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user and scores");
 
             return Err(err);
@@ -3,7 +3,7 @@ use std::{
     cmp::{Ordering, Reverse},
 };
 
-use bathbot_macros::{command, HasMods, HasName, SlashCommand};
+use bathbot_macros::{command, msg_command, HasMods, HasName, SlashCommand};
 use bathbot_model::{
     embed_builder::{ScoreEmbedSettings, SettingsImage},
     ScoreSlim,
@@ -12,12 +12,7 @@ use bathbot_psql::model::{
     configs::ScoreData,
     osu::{ArchivedMapVersion, MapVersion},
 };
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::MapIdType,
-    CowUtils, MessageOrigin,
-};
+use bathbot_util::{matcher, osu::MapIdType, CowUtils, MessageOrigin};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{
@@ -45,9 +40,12 @@ use crate::{
         osu::{map_strain_graph, require_link, HasMods, ModsResult},
         utility::{ScoreEmbedData, ScoreEmbedDataPersonalBest},
     },
-    core::commands::{
-        prefix::{Args, ArgsNum},
-        CommandOrigin,
+    core::{
+        commands::{
+            prefix::{Args, ArgsNum},
+            CommandOrigin,
+        },
+        locale::Issue,
     },
     manager::{
         redis::{
@@ -115,6 +113,8 @@ pub struct Cs<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(CreateCommand, SlashCommand)]
@@ -169,6 +169,8 @@ pub struct CompareScore_<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 pub enum MapOrScore {
@@ -184,6 +186,7 @@ pub(super) struct CompareScoreArgs<'a> {
     sort: Option<ScoreOrder>,
     mods: Option<Cow<'a, str>>,
     discord: Option<Id<UserMarker>>,
+    account: Option<Cow<'a, str>>,
     index: Option<u32>,
 }
 
@@ -219,6 +222,7 @@ impl<'m> CompareScoreArgs<'m> {
             sort: None,
             mods,
             discord,
+            account: None,
             index: match index {
                 ArgsNum::Value(n) => Some(n),
                 ArgsNum::Random | ArgsNum::None => None,
@@ -261,6 +265,7 @@ impl<'a> TryFrom<CompareScoreAutocomplete<'a>> for CompareScoreArgs<'a> {
             sort: args.sort,
             mods: args.mods,
             discord: args.discord,
+            account: args.account,
             index: args.index,
         })
     }
@@ -335,9 +340,44 @@ pub async fn slash_compare(
     }
 }
 
+#[msg_command(name = "Compare score")]
+async fn msg_compare(mut command: InteractionCommand) -> Result<()> {
+    let Some(msg) = command.target_message() else {
+        let _ = command.error(Issue::General).await;
+
+        return Err(eyre!("Missing resolved message"));
+    };
+
+    let map = match Context::find_map_id_in_msg(msg).await {
+        Some(id) => MapOrScore::Map(id),
+        None => match Context::find_score_id_in_msg(msg) {
+            Some((mode, id)) => MapOrScore::Score { id, mode },
+            None => {
+                let content = "Could not find a map or score url in this message or its embeds";
+                command.error(content).await?;
+
+                return Ok(());
+            }
+        },
+    };
+
+    let args = CompareScoreArgs {
+        name: None,
+        map: Some(map),
+        difficulty: None,
+        sort: None,
+        mods: None,
+        discord: None,
+        account: None,
+        index: None,
+    };
+
+    score((&mut command).into(), args).await
+}
+
 pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -> Result<()> {
     let owner = orig.user_id()?;
-    let config = Context::user_config().with_osu_id(owner).await?;
+    let mut config = Context::user_config().with_osu_id(owner).await?;
 
     let mods = match args.mods() {
         ModsResult::Mods(mods) => Some(mods),
@@ -352,13 +392,8 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         }
     };
 
-    let user_id = match user_id!(orig, args) {
-        Some(user_id) => user_id,
-        None => match config.osu {
-            Some(user_id) => UserId::Id(user_id),
-            None => return require_link(&orig).await,
-        },
-    };
+    // Without specified user, the linked account depends on the map's mode
+    let user_id = user_id!(orig, args);
 
     let score_data = match config.score_data {
         Some(score_data) => score_data,
@@ -372,7 +407,14 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
     };
 
     let legacy_scores = score_data.is_legacy();
-    let settings = config.score_embed.unwrap_or_default();
+    let settings = Context::guild_config()
+        .score_embed(
+            orig.guild_id(),
+            orig.channel_id(),
+            config.score_embed.take(),
+        )
+        .await
+        .unwrap_or_default();
 
     let CompareScoreArgs {
         sort,
@@ -454,13 +496,22 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
             return orig.error(content).await;
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
     };
 
     let mode = map.mode();
+
+    let user_id = match user_id {
+        Some(user_id) => user_id,
+        None => match config.osu_for_mode(Some(mode)) {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
     let (user_res, score_res) = match user_args {
@@ -501,7 +552,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
             return orig.error(content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -596,7 +647,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
     let entries = match process_fut.await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to process scores"));
         }
@@ -764,7 +815,7 @@ async fn compare_from_score(
     let mut score = match Context::osu().score(score_id).mode(mode).await {
         Ok(score) => score,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get score");
 
             return Err(err);
@@ -792,7 +843,7 @@ async fn compare_from_score(
     let user = match user_res {
         Ok(user) => user,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -804,7 +855,7 @@ async fn compare_from_score(
     let map = match map_res {
         Ok(map) => map,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(Report::new(err));
         }
@@ -4,7 +4,6 @@ use bathbot_macros::{command, HasMods, HasName, SlashCommand};
 use bathbot_model::{rosu_v2::user::User, ScoreSlim};
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     matcher,
     osu::{MapIdType, ModSelection},
 };
@@ -22,7 +21,10 @@ use twilight_model::{
 
 use super::{require_link, user_not_found, HasMods, ModsResult};
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     embeds::{EmbedData, FixScoreEmbed},
     manager::{
         redis::{
@@ -63,6 +65,8 @@ pub struct Fix<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(HasMods, HasName)]
@@ -71,6 +75,7 @@ struct FixArgs<'a> {
     id: Option<MapOrScore>,
     mods: Option<Cow<'a, str>>,
     discord: Option<Id<UserMarker>>,
+    account: Option<Cow<'a, str>>,
 }
 
 enum MapOrScore {
@@ -118,6 +123,7 @@ impl<'m> FixArgs<'m> {
         Self {
             name,
             discord,
+            account: None,
             id: id_,
             mods,
         }
@@ -151,6 +157,7 @@ impl<'a> TryFrom<Fix<'a>> for FixArgs<'a> {
             id,
             mods: args.mods,
             discord: args.discord,
+            account: args.account,
         })
     }
 }
@@ -195,9 +202,16 @@ async fn fix(orig: CommandOrigin<'_>, args: FixArgs<'_>) -> Result<()> {
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
+    // A map's mode is only known after requesting it so its linked account
+    // falls back to the preferred mode's account
+    let mode = match args.id {
+        Some(MapOrScore::Score { mode, .. }) => Some(mode),
+        _ => config.mode,
+    };
+
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu {
+        None => match config.osu_for_mode(mode) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
@@ -325,7 +339,7 @@ async fn request_by_map(
             };
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return ScoreResult::Error(err);
         }
@@ -363,7 +377,7 @@ async fn request_by_map(
             };
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let wrap = "Failed to get user";
 
             return ScoreResult::Error(Report::new(err).wrap_err(wrap));
@@ -384,7 +398,7 @@ async fn request_by_map(
             }),
         },
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let wrap = "Failed to get scores";
 
             return ScoreResult::Error(Report::new(err).wrap_err(wrap));
@@ -412,7 +426,7 @@ async fn request_by_map(
             let top = match top_res {
                 Ok(scores) => scores,
                 Err(err) => {
-                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let _ = orig.error(Issue::OsuApi).await;
                     let wrap = "failed to get top scores";
 
                     return ScoreResult::Error(Report::new(err).wrap_err(wrap));
@@ -463,7 +477,7 @@ async fn request_by_score(
             };
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return ScoreResult::Error(err);
@@ -491,12 +505,12 @@ async fn request_by_score(
             };
         }
         (Err(MapError::Report(err)), _) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return ScoreResult::Error(err);
         }
         (_, Err(err)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get top scores");
 
             return ScoreResult::Error(err);
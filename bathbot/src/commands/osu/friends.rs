@@ -0,0 +1,347 @@
+use std::collections::BTreeMap;
+
+use bathbot_client::ClientError;
+use bathbot_macros::SlashCommand;
+use bathbot_model::{command_fields::GameModeOption, RankingEntries, RankingEntry, RankingKind};
+use bathbot_util::{constants::AVATAR_URL, matcher, osu::MapIdType};
+use eyre::{Report, Result};
+use rosu_v2::prelude::GameMode;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use super::leaderboard::{get_map_id, LeaderboardScore, LeaderboardSort, LeaderboardUserScore};
+use crate::{
+    active::{
+        impls::{LeaderboardPagination, RankingPagination},
+        ActiveMessages,
+    },
+    core::{commands::CommandOrigin, locale::Issue},
+    manager::{redis::osu::UserArgs, MapError, OsuUserAccess},
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    Context,
+};
+
+const NOT_AUTHORIZED: &str = "You did not allow me to read your osu! friends yet.\n\
+    Use `/link add friends:True` to authorize me.";
+
+const REVOKED: &str = "Looks like you revoked my access to your osu! friends.\n\
+    Use `/link add friends:True` to authorize me again.";
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "friends",
+    desc = "Compare yourself with your osu! friends",
+    help = "Compare yourself with your osu! friends.\n\
+    This requires that you allowed me to read your osu! friends via `/link add friends:True`."
+)]
+pub enum Friends {
+    #[command(name = "ranking")]
+    Ranking(FriendsRanking),
+    #[command(name = "leaderboard")]
+    Leaderboard(FriendsLeaderboard),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "ranking", desc = "Pp ranking of you and your osu! friends")]
+pub struct FriendsRanking {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "leaderboard",
+    desc = "Leaderboard of a map among your osu! friends",
+    help = "Display the leaderboard of a map among your osu! friends.\n\
+    Note that osu! only provides friend leaderboards to osu!supporters."
+)]
+pub struct FriendsLeaderboard {
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<String>,
+    #[command(desc = "Choose how the scores should be ordered")]
+    sort: Option<LeaderboardSort>,
+}
+
+async fn slash_friends(mut command: InteractionCommand) -> Result<()> {
+    match Friends::from_interaction(command.input_data())? {
+        Friends::Ranking(args) => ranking(command, args).await,
+        Friends::Leaderboard(args) => leaderboard((&mut command).into(), args).await,
+    }
+}
+
+async fn ranking(mut command: InteractionCommand, args: FriendsRanking) -> Result<()> {
+    let owner = command.user_id()?;
+    let orig = CommandOrigin::from(&mut command);
+
+    let Some(access) = access_token(&orig, owner).await? else {
+        return Ok(());
+    };
+
+    let mode = match args.mode {
+        Some(mode) => mode.into(),
+        None => match Context::user_config().mode(owner).await {
+            Ok(mode) => mode.unwrap_or(GameMode::Osu),
+            Err(err) => {
+                warn!(?err, "Failed to get user mode");
+
+                GameMode::Osu
+            }
+        },
+    };
+
+    let friends_fut = Context::client().get_osu_friends(&access.access_token);
+    let user_fut = Context::redis().osu_user(UserArgs::user_id(access.osu_id, mode));
+
+    let (friends_res, user_res) = tokio::join!(friends_fut, user_fut);
+
+    let friends = match friends_res {
+        Ok(friends) => friends,
+        Err(err) => return handle_client_error(&orig, owner, err).await,
+    };
+
+    let mut entries: Vec<_> = friends
+        .iter()
+        .filter_map(|friend| {
+            let stats = friend.statistics_rulesets.get(mode)?;
+
+            Some(RankingEntry {
+                country: Some(friend.country_code.as_ref().into()),
+                name: friend.username.as_ref().into(),
+                value: stats.pp.round() as u32,
+            })
+        })
+        .collect();
+
+    let author_name = match user_res {
+        Ok(user) => {
+            entries.push(RankingEntry {
+                country: Some(user.country_code().into()),
+                name: user.username().into(),
+                value: user.stats().pp().round() as u32,
+            });
+
+            Some(user.username().to_owned())
+        }
+        Err(err) => {
+            warn!(?err, "Failed to get author for friend ranking");
+
+            None
+        }
+    };
+
+    if entries.is_empty() {
+        let content = "None of your osu! friends played that mode";
+
+        return orig.error(content).await;
+    }
+
+    entries.sort_unstable_by(|a, b| b.value.cmp(&a.value));
+
+    let entries: BTreeMap<_, _> = entries.into_iter().enumerate().collect();
+    let entries = RankingEntries::PpU32(entries);
+    let author_idx = author_name.and_then(|name| entries.name_pos(&name));
+    let total = entries.len();
+
+    let pagination = RankingPagination::builder()
+        .entries(entries)
+        .total(total)
+        .author_idx(author_idx)
+        .kind(RankingKind::Friends { mode })
+        .defer(false)
+        .msg_owner(owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
+
+async fn leaderboard(orig: CommandOrigin<'_>, args: FriendsLeaderboard) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let map = match args.map {
+        Some(map) => match matcher::get_osu_map_id(&map)
+            .map(MapIdType::Map)
+            .or_else(|| matcher::get_osu_mapset_id(&map).map(MapIdType::Set))
+        {
+            Some(id) => Some(id),
+            None => {
+                let content =
+                    "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+                return orig.error(content).await;
+            }
+        },
+        None => None,
+    };
+
+    let Some(access) = access_token(&orig, owner).await? else {
+        return Ok(());
+    };
+
+    let map_id_fut = get_map_id(&orig, map);
+    let config_fut = Context::user_config().with_osu_id(owner);
+
+    let (map_id_res, config_res) = tokio::join!(map_id_fut, config_fut);
+
+    let map_id = match map_id_res {
+        Ok(map_id) => map_id,
+        Err(content) => return orig.error(content).await,
+    };
+
+    let config = config_res?;
+
+    let map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?",
+            );
+
+            return orig.error(content).await;
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(Issue::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let score_data = match config.score_data {
+        Some(score_data) => score_data,
+        None => match orig.guild_id() {
+            Some(guild_id) => Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+                .unwrap_or_default(),
+            None => Default::default(),
+        },
+    };
+
+    let mode = map.mode();
+
+    let scores_fut = Context::client().get_osu_friend_scores(
+        &access.access_token,
+        map_id,
+        mode,
+        score_data.is_legacy(),
+    );
+
+    let scores = match scores_fut.await {
+        Ok(Some(scores)) => scores,
+        Ok(None) => {
+            let content = "osu! only shows friend leaderboards to osu!supporters";
+
+            return orig.error(content).await;
+        }
+        Err(err) => return handle_client_error(&orig, owner, err).await,
+    };
+
+    let mut user_score = None;
+
+    let mut scores: Vec<_> = scores
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut score)| {
+            let user = score.user.take();
+            let username = user.map_or_else(|| "<unknown user>".into(), |user| user.username);
+
+            if score.user_id == access.osu_id {
+                user_score = Some(LeaderboardUserScore {
+                    discord_id: owner,
+                    score: LeaderboardScore::new(
+                        score.user_id,
+                        username.clone(),
+                        score.clone(),
+                        i + 1,
+                    ),
+                });
+            }
+
+            LeaderboardScore::new(score.user_id, username, score, i + 1)
+        })
+        .collect();
+
+    let mut calc = Context::pp(&map).mode(mode);
+    let attrs = calc.performance().await;
+    let stars = attrs.stars() as f32;
+    let max_combo = attrs.max_combo();
+
+    let sort = args.sort.unwrap_or_default();
+    let mut attr_map = Default::default();
+    sort.sort(&mut scores, &map, &mut attr_map, score_data)
+        .await;
+
+    let mut content = format!(
+        "I found {} scores of your friends on the map's leaderboard",
+        scores.len()
+    );
+
+    sort.push_content(&mut content);
+
+    let first_place_icon = scores.first().map(|s| format!("{AVATAR_URL}{}", s.user_id));
+
+    let pagination = LeaderboardPagination::builder()
+        .map(map)
+        .scores(scores.into_boxed_slice())
+        .stars(stars)
+        .max_combo(max_combo)
+        .attr_map(attr_map)
+        .author_data(user_score)
+        .first_place_icon(first_place_icon)
+        .score_data(score_data)
+        .content(content.into_boxed_str())
+        .msg_owner(owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
+
+/// Returns the author's osu! access token or responds with an error message if
+/// there is none.
+async fn access_token(
+    orig: &CommandOrigin<'_>,
+    owner: Id<UserMarker>,
+) -> Result<Option<OsuUserAccess>> {
+    match Context::osu_tokens().access_token(owner).await {
+        Ok(Some(access)) => Ok(Some(access)),
+        Ok(None) => {
+            orig.error(NOT_AUTHORIZED).await?;
+
+            Ok(None)
+        }
+        Err(err) => {
+            let _ = orig.error(Issue::General).await;
+
+            Err(err)
+        }
+    }
+}
+
+async fn handle_client_error(
+    orig: &CommandOrigin<'_>,
+    owner: Id<UserMarker>,
+    err: ClientError,
+) -> Result<()> {
+    if let ClientError::Unauthorized = err {
+        if let Err(err) = Context::osu_tokens().remove(owner, None).await {
+            warn!(?err, "Failed to remove revoked osu! token");
+        }
+
+        return orig.error(REVOKED).await;
+    }
+
+    let _ = orig.error(Issue::OsuApi).await;
+
+    Err(Report::new(err).wrap_err("Failed to request osu! with user token"))
+}
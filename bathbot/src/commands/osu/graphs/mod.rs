@@ -7,10 +7,7 @@ use bathbot_model::{
     Countries,
 };
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    EmbedBuilder, MessageBuilder,
-};
+use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result, WrapErr};
 use image::{DynamicImage, GenericImageView};
 use plotters::element::{Drawable, PointCollection};
@@ -21,9 +18,12 @@ use rosu_v2::{
     request::UserId,
 };
 use time::UtcOffset;
-use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_interactions::command::{
+    CommandInputData, CommandModel, CommandOption, CreateCommand, CreateOption,
+};
 use twilight_model::id::{marker::UserMarker, Id};
 
+pub use self::rank::draw_rank_graph;
 use self::{
     medals::medals_graph,
     playcount_replays::{playcount_replays_graph, ProfileGraphFlags},
@@ -36,7 +36,7 @@ use self::{
 };
 use super::{require_link, user_not_found, SnipeGameMode};
 use crate::{
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::Issue, Context},
     embeds::attachment,
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, InteractionCommandExt},
@@ -80,6 +80,8 @@ pub struct GraphMedals {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -97,6 +99,8 @@ pub struct GraphPlaycountReplays {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
     #[command(desc = "Specify if the playcount curve should be included")]
     playcount: Option<ShowHideOption>,
     #[command(desc = "Specify if the replay curve should be included")]
@@ -119,6 +123,8 @@ pub struct GraphRank {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -135,6 +141,8 @@ pub struct GraphSniped {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -154,6 +162,8 @@ pub struct GraphSnipeCount {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -179,6 +189,8 @@ pub struct GraphTop {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -197,6 +209,12 @@ async fn slash_graph(mut command: InteractionCommand) -> Result<()> {
     graph((&mut command).into(), args).await
 }
 
+pub async fn scheduled_graph(orig: CommandOrigin<'_>, data: CommandInputData<'_>) -> Result<()> {
+    let args = Graph::from_interaction(data)?;
+
+    graph(orig, args).await
+}
+
 // Takes a `CommandOrigin` since `require_link` does not take
 // `InteractionCommand`
 async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
@@ -208,7 +226,7 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
                     Ok(Some(user_id)) => UserId::Id(user_id),
                     Ok(None) => return require_link(&orig).await,
                     Err(err) => {
-                        let _ = orig.error(GENERAL_ISSUE).await;
+                        let _ = orig.error(Issue::General).await;
 
                         return Err(err);
                     }
@@ -226,7 +244,7 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
                     Ok(Some(user_id)) => UserId::Id(user_id),
                     Ok(None) => return require_link(&orig).await,
                     Err(err) => {
-                        let _ = orig.error(GENERAL_ISSUE).await;
+                        let _ = orig.error(Issue::General).await;
 
                         return Err(err);
                     }
@@ -283,7 +301,7 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
             let config = match Context::user_config().with_osu_id(owner).await {
                 Ok(config) => config,
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error(Issue::General).await;
 
                     return Err(err.wrap_err("failed to get user config"));
                 }
@@ -292,12 +310,13 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
             let mode = args
                 .mode
                 .map(GameMode::from)
+                .or(orig.channel_mode().await)
                 .or(config.mode)
                 .unwrap_or(GameMode::Osu);
 
             let (user_id, no_user_specified) = match user_id!(orig, args) {
                 Some(user_id) => (user_id, false),
-                None => match config.osu {
+                None => match config.osu_for_mode(Some(mode)) {
                     Some(user_id) => (UserId::Id(user_id), true),
                     None => return require_link(&orig).await,
                 },
@@ -369,7 +388,7 @@ async fn top_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -428,7 +447,7 @@ async fn top_graph(
     let bytes = match graph_result {
         Ok(graph) => graph,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
             warn!("{err:?}");
 
             return Ok(None);
@@ -1,15 +1,12 @@
 use bathbot_model::rosu_v2::user::User;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 
 use super::{H, W};
 use crate::{
     commands::osu::{player_snipe_stats, user_not_found},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::Issue, Context},
     manager::redis::{osu::UserArgs, RedisData},
 };
 
@@ -29,7 +26,7 @@ pub async fn snipe_count_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -80,7 +77,7 @@ pub async fn snipe_count_graph(
                 return Ok(None);
             }
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -98,7 +95,7 @@ pub async fn snipe_count_graph(
     let bytes = match graph_result {
         Ok(graph) => graph,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
             warn!(?err, "Failed to create snipe count graph");
 
             return Ok(None);
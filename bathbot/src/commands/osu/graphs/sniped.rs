@@ -1,15 +1,12 @@
 use bathbot_model::rosu_v2::user::User;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 
 use super::{H, W};
 use crate::{
     commands::osu::{sniped, user_not_found},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::Issue, Context},
     manager::redis::{osu::UserArgs, RedisData},
 };
 
@@ -29,7 +26,7 @@ pub async fn sniped_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -65,7 +62,7 @@ pub async fn sniped_graph(
         match tokio::try_join!(sniper_fut, snipee_fut) {
             Ok(tuple) => tuple,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err.wrap_err("failed to get sniper or snipee"));
             }
@@ -89,7 +86,7 @@ pub async fn sniped_graph(
             return Ok(None);
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
             warn!(?err, "Failed to create sniped graph");
 
             return Ok(None);
@@ -8,7 +8,7 @@ use bathbot_macros::{command, HasMods, SlashCommand};
 use bathbot_model::rosu_v2::user::User;
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
-    constants::{AVATAR_URL, GENERAL_ISSUE, OSU_API_ISSUE},
+    constants::AVATAR_URL,
     matcher,
     osu::{MapIdType, ModSelection},
     IntHasher, ScoreExt,
@@ -32,7 +32,10 @@ use twilight_model::{
 use super::{HasMods, ModsResult};
 use crate::{
     active::{impls::LeaderboardPagination, ActiveMessages},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::{
         redis::{osu::UserArgs, RedisData},
         MapError, Mods, OsuMap,
@@ -286,7 +289,7 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
             return orig.error(content).await;
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -328,7 +331,7 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
     );
 
     let user_fut = get_user_score(
-        config.osu,
+        config.osu_for_mode(Some(mode)),
         map_id,
         map.mode(),
         specify_mods.clone(),
@@ -353,7 +356,7 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
             })
             .collect(),
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
 
             return Err(err.wrap_err("Failed to get leaderboard"));
         }
@@ -436,7 +439,10 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
         .await
 }
 
-async fn get_map_id(orig: &CommandOrigin<'_>, map: Option<MapIdType>) -> Result<u32, &'static str> {
+pub(super) async fn get_map_id(
+    orig: &CommandOrigin<'_>,
+    map: Option<MapIdType>,
+) -> Result<u32, &'static str> {
     match map {
         Some(MapIdType::Map(id)) => Ok(id),
         Some(MapIdType::Set(_)) => {
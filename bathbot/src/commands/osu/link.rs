@@ -1,29 +1,543 @@
+use std::fmt::Write;
+
 use bathbot_macros::{command, SlashCommand};
-use bathbot_util::constants::INVITE_LINK;
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_psql::model::configs::{OsuUserId, UserConfig};
+use bathbot_util::{constants::INVITE_LINK, EmbedBuilder, MessageBuilder};
 use eyre::Result;
-use twilight_interactions::command::CreateCommand;
+use rosu_v2::prelude::GameMode;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
-    commands::utility::{config, Config, ConfigLink},
-    util::{interaction::InteractionCommand, ChannelExt},
+    commands::utility::{authenticate_osu, OsuScope},
+    core::locale::Issue,
+    util::{interaction::InteractionCommand, Authored, ChannelExt, InteractionCommandExt},
+    Context,
 };
 
-#[derive(CreateCommand, SlashCommand)]
+/// Maximum amount of linked osu! accounts per user, including the primary one
+const MAX_ACCOUNTS: usize = 5;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(
     name = "link",
-    desc = "Link your discord to an osu! profile",
-    help = "Link your discord to an osu! profile.\n\
-    To unlink, use the `/config` command.\n\
-    To link your discord to a twitch account you can also use the `/config` command."
+    desc = "Link your discord to osu! profiles",
+    help = "Link your discord to osu! profiles.\n\
+    You can link multiple osu! accounts of which one is your primary account. \
+    Commands use the primary account when no user is specified, unless you \
+    set a different account for the command's mode via `/link mode`.\n\
+    Any linked account can also be chosen in commands through the `account` option.\n\
+    With the `friends` option of `/link add` you can allow the bot to read your osu! friends \
+    for the `/friends` command. That authorization can be withdrawn via `/link revoke`.\n\
+    To link your discord to a twitch account you can use the `/config` command."
 )]
 #[flags(EPHEMERAL)]
-pub struct Link;
+pub enum Link {
+    #[command(name = "add")]
+    Add(LinkAdd),
+    #[command(name = "remove")]
+    Remove(LinkRemove),
+    #[command(name = "set-primary")]
+    SetPrimary(LinkSetPrimary),
+    #[command(name = "mode")]
+    Mode(LinkMode),
+    #[command(name = "list")]
+    List(LinkList),
+    #[command(name = "revoke")]
+    Revoke(LinkRevoke),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Link an osu! account",
+    help = "Link an osu! account.\n\
+    The first linked account becomes your primary account.\n\
+    If `friends` is set to `True`, the bot stores an encrypted osu! token that \
+    lets it read the account's friends list for the `/friends` command. \
+    Only one account's token is stored at a time. \
+    Use this on an already linked account to just grant the authorization."
+)]
+pub struct LinkAdd {
+    #[command(desc = "Use the account by default for this mode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Allow the bot to read your osu! friends for `/friends`")]
+    friends: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "remove",
+    desc = "Unlink an osu! account",
+    help = "Unlink an osu! account.\n\
+    If it was your primary account, another linked account becomes the primary one."
+)]
+pub struct LinkRemove {
+    #[command(desc = "Specify the name of the linked osu! account")]
+    account: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "set-primary", desc = "Choose your primary osu! account")]
+pub struct LinkSetPrimary {
+    #[command(desc = "Specify the name of the linked osu! account")]
+    account: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "mode",
+    desc = "Choose the default osu! account for a mode",
+    help = "Choose which of your linked osu! accounts is used by default for a mode.\n\
+    If no account is specified, your primary account will be used for the mode again."
+)]
+pub struct LinkMode {
+    #[command(desc = "Specify the mode")]
+    mode: GameModeOption,
+    #[command(desc = "Specify the name of the linked osu! account")]
+    account: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List your linked osu! accounts")]
+pub struct LinkList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "revoke",
+    desc = "Withdraw the authorization to read your osu! friends",
+    help = "Delete the stored osu! token that lets the bot read your osu! friends \
+    and revoke it on the osu! website.\n\
+    Your linked accounts are not affected."
+)]
+pub struct LinkRevoke;
+
+async fn slash_link(mut command: InteractionCommand) -> Result<()> {
+    let args = Link::from_interaction(command.input_data())?;
+    let owner = command.user_id()?;
+
+    let mut config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = command.error(Issue::General).await;
+
+            return Err(err);
+        }
+    };
+
+    match args {
+        Link::Add(args) => add(&command, owner, config, args).await,
+        Link::Remove(args) => remove(&command, owner, config, args).await,
+        Link::SetPrimary(args) => set_primary(&command, owner, config, args).await,
+        Link::Mode(args) => mode(&command, owner, &mut config, args).await,
+        Link::List(_) => list(&command, owner, &config).await,
+        Link::Revoke(_) => revoke(&command, owner).await,
+    }
+}
+
+async fn add(
+    command: &InteractionCommand,
+    owner: Id<UserMarker>,
+    mut config: UserConfig<OsuUserId>,
+    args: LinkAdd,
+) -> Result<()> {
+    let linked = match config.osu {
+        Some(_) => match Context::user_config().linked_osu_ids(owner).await {
+            Ok(linked) => linked,
+            Err(err) => {
+                let _ = command.error(Issue::General).await;
+
+                return Err(err);
+            }
+        },
+        None => Vec::new(),
+    };
+
+    let friends = args.friends == Some(true);
+    let at_limit = config.osu.is_some() && linked.len() + 1 >= MAX_ACCOUNTS;
+
+    // With `friends` the account might already be linked so it's checked later
+    if at_limit && !friends {
+        let content = format!(
+            "You can't link more than {MAX_ACCOUNTS} osu! accounts, \
+            remove one via `/link remove` first"
+        );
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let scope = if friends {
+        OsuScope::Friends
+    } else {
+        OsuScope::Identify
+    };
+
+    let (user_id, username, token) = match authenticate_osu(command, scope).await {
+        Some(Ok(auth)) => auth,
+        Some(Err(err)) => return Err(err.wrap_err("Failed to authenticate osu! account")),
+        None => return Ok(()),
+    };
+
+    let already_linked = config.osu == Some(user_id) || linked.contains(&user_id);
+
+    if already_linked && !friends {
+        let content = format!("`{username}` is already linked to your discord");
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    if !already_linked && at_limit {
+        let content = format!(
+            "You can't link more than {MAX_ACCOUNTS} osu! accounts, \
+            remove one via `/link remove` first"
+        );
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    if friends {
+        if let Err(err) = Context::osu_tokens().store(owner, user_id, &token).await {
+            let _ = command.error(Issue::General).await;
+
+            return Err(err);
+        }
+
+        if already_linked {
+            let content = format!("The bot can now read the osu! friends of `{username}`");
+            let builder = MessageBuilder::new().embed(content);
+            command.update(builder).await?;
+
+            return Ok(());
+        }
+    }
+
+    let (mut content, relink) = match config.osu {
+        Some(_) => {
+            let content = format!("Linked `{username}` to your discord");
+
+            (content, Relink::add(user_id))
+        }
+        None => {
+            config.osu = Some(user_id);
+            let content = format!("Linked `{username}` to your discord as your primary account");
+
+            (content, Relink::default())
+        }
+    };
+
+    if let Some(mode) = args.mode.map(GameMode::from) {
+        config.mode_accounts.set(mode, Some(user_id));
+        let _ = write!(content, " and set it as default for {}", mode_str(mode));
+    }
+
+    if friends {
+        content.push_str("\nThe bot can now read its osu! friends");
+    }
+
+    store_and_respond(command, owner, &config, relink, content).await
+}
+
+async fn remove(
+    command: &InteractionCommand,
+    owner: Id<UserMarker>,
+    mut config: UserConfig<OsuUserId>,
+    args: LinkRemove,
+) -> Result<()> {
+    let Some(user_id) = resolve_account(command, owner, &args.account).await? else {
+        return Ok(());
+    };
+
+    let (content, relink) = if config.osu == Some(user_id) {
+        // Promote the next linked account to be the primary one
+        let next = match Context::user_config().linked_osu_ids(owner).await {
+            Ok(linked) => linked.first().copied(),
+            Err(err) => {
+                let _ = command.error(Issue::General).await;
+
+                return Err(err);
+            }
+        };
+
+        config.osu = next;
+
+        if let Some(next) = next {
+            let name = match Context::osu_user().name(next).await {
+                Ok(name) => name.map_or_else(|| next.to_string(), |name| name.to_string()),
+                Err(err) => {
+                    warn!(?err, "Failed to get username");
+
+                    next.to_string()
+                }
+            };
+
+            let content = format!(
+                "Unlinked `{account}`, `{name}` is now your primary account",
+                account = args.account
+            );
+
+            // The promoted account is no longer among the other linked ones
+            (content, Relink::remove(next))
+        } else {
+            (format!("Unlinked `{}`", args.account), Relink::default())
+        }
+    } else {
+        (
+            format!("Unlinked `{}`", args.account),
+            Relink::remove(user_id),
+        )
+    };
+
+    config.mode_accounts.remove(user_id);
+
+    if let Err(err) = Context::osu_tokens().remove(owner, Some(user_id)).await {
+        warn!(?err, "Failed to remove osu! token of unlinked account");
+    }
 
-async fn slash_link(command: InteractionCommand) -> Result<()> {
-    let mut args = Config::default();
-    args.osu = Some(ConfigLink::Link);
+    store_and_respond(command, owner, &config, relink, content).await
+}
+
+async fn set_primary(
+    command: &InteractionCommand,
+    owner: Id<UserMarker>,
+    mut config: UserConfig<OsuUserId>,
+    args: LinkSetPrimary,
+) -> Result<()> {
+    let Some(user_id) = resolve_account(command, owner, &args.account).await? else {
+        return Ok(());
+    };
+
+    if config.osu == Some(user_id) {
+        let content = format!("`{}` already is your primary account", args.account);
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let prev = config.osu.replace(user_id);
+
+    let relink = Relink {
+        unlink: Some(user_id),
+        link: prev,
+    };
+
+    let content = format!("`{}` is now your primary account", args.account);
+
+    store_and_respond(command, owner, &config, relink, content).await
+}
+
+async fn mode(
+    command: &InteractionCommand,
+    owner: Id<UserMarker>,
+    config: &mut UserConfig<OsuUserId>,
+    args: LinkMode,
+) -> Result<()> {
+    let mode = GameMode::from(args.mode);
+
+    let content = match args.account {
+        Some(ref account) => {
+            let Some(user_id) = resolve_account(command, owner, account).await? else {
+                return Ok(());
+            };
+
+            config.mode_accounts.set(mode, Some(user_id));
+
+            format!(
+                "`{account}` is now your default account for {}",
+                mode_str(mode)
+            )
+        }
+        None => {
+            config.mode_accounts.set(mode, None);
+
+            format!("Your primary account is now used for {}", mode_str(mode))
+        }
+    };
+
+    store_and_respond(command, owner, config, Relink::default(), content).await
+}
+
+async fn list(
+    command: &InteractionCommand,
+    owner: Id<UserMarker>,
+    config: &UserConfig<OsuUserId>,
+) -> Result<()> {
+    let Some(primary) = config.osu else {
+        let content = "You have no linked osu! accounts, use `/link add` to link one";
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let linked = match Context::user_config().linked_osu_ids(owner).await {
+        Ok(linked) => linked,
+        Err(err) => {
+            let _ = command.error(Issue::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let user_ids: Vec<_> = Some(primary)
+        .into_iter()
+        .chain(linked.iter().copied())
+        .map(|user_id| user_id as i32)
+        .collect();
+
+    let names = match Context::osu_user().names(&user_ids).await {
+        Ok(names) => names,
+        Err(err) => {
+            let _ = command.error(Issue::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let name = |user_id: u32| {
+        names
+            .get(&user_id)
+            .map_or_else(|| user_id.to_string(), |name| name.to_string())
+    };
+
+    let mut description = String::with_capacity(128);
+
+    for user_id in Some(primary).into_iter().chain(linked) {
+        let _ = write!(description, "- `{}`", name(user_id));
+
+        if user_id == primary {
+            description.push_str(" (primary)");
+        }
+
+        let mut modes = config
+            .mode_accounts
+            .iter()
+            .filter(|(_, id)| *id == user_id)
+            .map(|(mode, _)| mode_str(mode));
+
+        if let Some(mode) = modes.next() {
+            let _ = write!(description, ": default for {mode}");
+
+            for mode in modes {
+                let _ = write!(description, ", {mode}");
+            }
+        }
+
+        description.push('\n');
+    }
+
+    let embed = EmbedBuilder::new()
+        .title("Linked osu! accounts")
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn revoke(command: &InteractionCommand, owner: Id<UserMarker>) -> Result<()> {
+    let content = match Context::osu_tokens().remove(owner, None).await {
+        Ok(true) => "The bot can no longer read your osu! friends",
+        Ok(false) => "You did not allow the bot to read your osu! friends",
+        Err(err) => {
+            let _ = command.error(Issue::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+/// Find the user id of the author's linked account with the given name.
+///
+/// Responds with an error message if there is no such account.
+async fn resolve_account(
+    command: &InteractionCommand,
+    owner: Id<UserMarker>,
+    account: &str,
+) -> Result<Option<u32>> {
+    match Context::user_config()
+        .linked_osu_id_by_name(owner, account)
+        .await
+    {
+        Ok(Some(user_id)) => Ok(Some(user_id)),
+        Ok(None) => {
+            let content = format!("`{account}` is not one of your linked osu! accounts");
+            command.error(content).await?;
+
+            Ok(None)
+        }
+        Err(err) => {
+            let _ = command.error(Issue::General).await;
+
+            Err(err)
+        }
+    }
+}
+
+/// Changes to the linked accounts besides the primary one
+#[derive(Default)]
+struct Relink {
+    unlink: Option<u32>,
+    link: Option<u32>,
+}
+
+impl Relink {
+    fn add(user_id: u32) -> Self {
+        Self {
+            unlink: None,
+            link: Some(user_id),
+        }
+    }
+
+    fn remove(user_id: u32) -> Self {
+        Self {
+            unlink: Some(user_id),
+            link: None,
+        }
+    }
+}
+
+/// Stores the config together with the linked account changes in one
+/// transaction and responds with the content.
+async fn store_and_respond(
+    command: &InteractionCommand,
+    owner: Id<UserMarker>,
+    config: &UserConfig<OsuUserId>,
+    relink: Relink,
+    content: String,
+) -> Result<()> {
+    let Relink { unlink, link } = relink;
+
+    if let Err(err) = Context::user_config()
+        .store_with_linked(owner, config, unlink, link)
+        .await
+    {
+        let _ = command.error(Issue::General).await;
+
+        return Err(err);
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
 
-    config(command, args).await
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu!standard",
+        GameMode::Taiko => "osu!taiko",
+        GameMode::Catch => "osu!catch",
+        GameMode::Mania => "osu!mania",
+    }
 }
 
 #[command]
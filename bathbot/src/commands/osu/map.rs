@@ -1,7 +1,7 @@
 use std::{borrow::Cow, cell::RefCell, cmp::Ordering, fmt::Write, mem, rc::Rc, time::Duration};
 
-use bathbot_macros::{command, HasMods, SlashCommand};
-use bathbot_util::{constants::OSU_API_ISSUE, matcher, osu::MapIdType, MessageOrigin};
+use bathbot_macros::{command, msg_command, HasMods, SlashCommand};
+use bathbot_util::{matcher, osu::MapIdType, MessageOrigin};
 use enterpolation::{linear::Linear, Curve};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use image::DynamicImage;
@@ -22,7 +22,10 @@ use twilight_model::{
 use super::{BitMapElement, HasMods, ModsResult};
 use crate::{
     active::{impls::MapPagination, ActiveMessages},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
     Context,
 };
@@ -230,6 +233,30 @@ async fn slash_map(mut command: InteractionCommand) -> Result<()> {
     }
 }
 
+#[msg_command(name = "Show map")]
+async fn msg_map(mut command: InteractionCommand) -> Result<()> {
+    let Some(msg) = command.target_message() else {
+        let _ = command.error(Issue::General).await;
+
+        return Err(eyre!("Missing resolved message"));
+    };
+
+    let Some(map_id) = Context::find_map_id_in_msg(msg).await else {
+        let content = "Could not find a map url in this message or its embeds";
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let args = MapArgs {
+        map: Some(map_id),
+        mods: None,
+        attrs: CustomAttrs::default(),
+    };
+
+    map((&mut command).into(), args).await
+}
+
 const W: u32 = 590;
 const H: u32 = 170;
 const LEGEND_H: u32 = 25;
@@ -296,7 +323,7 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("failed to get mapset"));
         }
@@ -2,18 +2,26 @@ use std::collections::BTreeMap;
 
 use bathbot_macros::{command, SlashCommand};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::constants::OSU_API_ISSUE;
-use eyre::{Report, Result};
+use bathbot_psql::model::osu::{MapsetSearchEntry, MapsetSearchParams};
+use bathbot_util::CowUtils;
+use eyre::{Result, WrapErr};
 use rosu_v2::prelude::{
-    BeatmapsetExtended, BeatmapsetSearchResult, BeatmapsetSearchSort, Genre, Language, Osu,
-    OsuResult, RankStatus,
+    BeatmapsetExtended, BeatmapsetSearchResult, BeatmapsetSearchSort, GameMode, Genre, Language,
+    Osu, OsuResult, RankStatus,
 };
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 
 use crate::{
     active::{impls::MapSearchPagination, ActiveMessages},
-    core::commands::{prefix::Args, CommandOrigin},
-    util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
+    util::{
+        interaction::InteractionCommand,
+        query::{IFilterCriteria, RegularCriteria},
+        ChannelExt, InteractionCommandExt,
+    },
     Context,
 };
 
@@ -40,6 +48,16 @@ pub struct Search {
     pub nsfw: Option<bool>,
     #[command(desc = "Specify whether the resulting list should be reversed")]
     pub reverse: Option<bool>,
+    #[command(
+        desc = "Search through maps stored by the bot instead of using the osu! API",
+        help = "Search through maps stored by the bot instead of using the osu! API.\n\
+        The local search supports filtering on the map's `stars`, `ar`, `cs`, `hp`, `od`, \
+        `bpm`, `length`, and `keys`, as well as `artist`, `title`, and `creator`.\n\
+        Remaining search terms are matched against the mapset's artist, title, source, and tags.\n\
+        Note that only maps that the bot has encountered before can be found and that \
+        sorting by favourites, playcount, or rating is not available."
+    )]
+    pub local: Option<bool>,
 }
 
 #[derive(CommandOption, CreateOption, Debug)]
@@ -498,6 +516,32 @@ impl Search {
             None => None,
         };
 
+        let local = match query.find("local=") {
+            Some(start) => {
+                let mut end = start + 1;
+
+                while end < query.len() && query.as_bytes()[end] != b' ' {
+                    end += 1;
+                }
+
+                let local = match &query[start + "local=".len()..end] {
+                    "true" | "t" | "1" => true,
+                    "false" | "f" | "0" => false,
+                    _ => {
+                        let content = "Failed to parse `local`. After `local=` \
+                        you must specify either `true` or `false`.";
+
+                        return Err(content);
+                    }
+                };
+
+                query.replace_range(start..end + (query.len() > end + 1) as usize, "");
+
+                Some(local)
+            }
+            None => None,
+        };
+
         let trailing_whitespace = query
             .chars()
             .rev()
@@ -527,6 +571,7 @@ impl Search {
             nsfw,
             sort,
             reverse,
+            local,
         })
     }
 
@@ -573,8 +618,105 @@ impl Search {
 
         search_fut.await
     }
+
+    async fn request_local(&self) -> Result<Vec<MapsetSearchEntry>> {
+        let criteria = self.query.as_deref().map(RegularCriteria::create);
+
+        let terms = criteria
+            .as_ref()
+            .filter(|criteria| criteria.has_search_terms())
+            .map(|criteria| criteria.search_terms().collect::<Vec<_>>().join(" "));
+
+        let statuses: Option<&[RankStatus]> = match self.status {
+            Some(SearchStatus::Any) => None,
+            Some(SearchStatus::Leaderboard) | None => Some(&[
+                RankStatus::Ranked,
+                RankStatus::Approved,
+                RankStatus::Qualified,
+                RankStatus::Loved,
+            ]),
+            Some(SearchStatus::Ranked) => Some(&[RankStatus::Ranked, RankStatus::Approved]),
+            Some(SearchStatus::Loved) => Some(&[RankStatus::Loved]),
+            Some(SearchStatus::Qualified) => Some(&[RankStatus::Qualified]),
+            Some(SearchStatus::Pending) => Some(&[RankStatus::Pending, RankStatus::WIP]),
+            Some(SearchStatus::Graveyard) => Some(&[RankStatus::Graveyard]),
+        };
+
+        let mut params = MapsetSearchParams {
+            query: terms.as_deref(),
+            mode: self.mode.map(GameMode::from),
+            statuses,
+            genre: self
+                .genre
+                .map(Genre::from)
+                .filter(|&genre| genre != Genre::Any),
+            language: self
+                .language
+                .map(Language::from)
+                .filter(|&language| language != Language::Any),
+            // Same as for the osu! API: `false` means "don't care"
+            video: self.video.filter(|&video| video),
+            storyboard: self.storyboard.filter(|&storyboard| storyboard),
+            limit: LOCAL_SEARCH_LIMIT,
+            ..Default::default()
+        };
+
+        if let Some(ref criteria) = criteria {
+            params.stars = criteria.stars.bounds().into();
+            params.ar = criteria.ar.bounds().into();
+            params.cs = criteria.cs.bounds().into();
+            params.hp = criteria.hp.bounds().into();
+            params.od = criteria.od.bounds().into();
+            params.bpm = criteria.bpm.bounds().into();
+            params.length = criteria.length.bounds().into();
+            params.keys = criteria.keys.bounds().into();
+            params.artist = criteria.artist.as_option();
+            params.title = criteria.title.as_option();
+            params.creator = criteria.creator.as_option();
+        }
+
+        let mut entries = Context::osu_map()
+            .search(&params)
+            .await
+            .wrap_err("Failed to search through local mapsets")?;
+
+        // Entries are sorted by relevance in descending order
+        match self.sort.unwrap_or_default() {
+            SearchOrder::Artist => entries.sort_by(|a, b| {
+                b.artist
+                    .cow_to_ascii_lowercase()
+                    .cmp(&a.artist.cow_to_ascii_lowercase())
+            }),
+            SearchOrder::RankedDate => entries.sort_by(|a, b| b.ranked_date.cmp(&a.ranked_date)),
+            SearchOrder::Stars => entries.sort_by(|a, b| {
+                let a = a.max_stars.unwrap_or(0.0);
+                let b = b.max_stars.unwrap_or(0.0);
+
+                b.total_cmp(&a)
+            }),
+            SearchOrder::Title => entries.sort_by(|a, b| {
+                b.title
+                    .cow_to_ascii_lowercase()
+                    .cmp(&a.title.cow_to_ascii_lowercase())
+            }),
+            // Not stored in the database
+            SearchOrder::Favourites
+            | SearchOrder::Playcount
+            | SearchOrder::Rating
+            | SearchOrder::Relevance => {}
+        }
+
+        if self.reverse == Some(true) {
+            entries.reverse();
+        }
+
+        Ok(entries)
+    }
 }
 
+/// Maximum amount of mapsets that are retrieved for local searches.
+const LOCAL_SEARCH_LIMIT: usize = 500;
+
 async fn slash_search(mut command: InteractionCommand) -> Result<()> {
     let args = Search::from_interaction(command.input_data())?;
 
@@ -600,7 +742,9 @@ async fn slash_search(mut command: InteractionCommand) -> Result<()> {
     - __`storyboard`__: `true` or `false`, defaults to `false`\n\
     - __`nsfw`__: `true` or `false`, defaults to `true` (allows nsfw, not requires nsfw)\n\
     - __`sort`__: `favourites`, `playcount`, `rankeddate`, `rating`, `relevance`, `stars`, \
-    `artist`, or `title`, defaults to `relevance`\n\n\
+    `artist`, or `title`, defaults to `relevance`\n\
+    - __`local`__: `true` or `false`, defaults to `false`. If `true`, only maps stored by \
+    the bot are searched; this also works when the osu! API is unavailable.\n\n\
     Depending on `sort`, the mapsets are ordered in descending order by default. \
     To reverse, specify `reverse=true`."
 )]
@@ -623,12 +767,22 @@ async fn prefix_search(msg: &Message, args: Args<'_>) -> Result<()> {
 }
 
 async fn search(orig: CommandOrigin<'_>, args: Search) -> Result<()> {
+    if args.local == Some(true) {
+        return search_local(orig, args, None).await;
+    }
+
     let mut search_result = match args.request(Context::osu()).await {
         Ok(response) => response,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            warn!(
+                ?err,
+                "Failed to get search results, falling back to local search"
+            );
+
+            let content = "The osu! API is currently unavailable so the results \
+                are based on maps stored by the bot";
 
-            return Err(Report::new(err).wrap_err("Failed to get search results"));
+            return search_local(orig, args, Some(content)).await;
         }
     };
 
@@ -642,3 +796,25 @@ async fn search(orig: CommandOrigin<'_>, args: Search) -> Result<()> {
         .begin(orig)
         .await
 }
+
+async fn search_local(
+    orig: CommandOrigin<'_>,
+    args: Search,
+    content: Option<&'static str>,
+) -> Result<()> {
+    let entries = match args.request_local().await {
+        Ok(entries) => entries,
+        Err(err) => {
+            let _ = orig.error(Issue::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let pagination = MapSearchPagination::new_local(entries, args, content, orig.user_id()?);
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
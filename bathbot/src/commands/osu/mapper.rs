@@ -6,10 +6,7 @@ use std::{
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_model::{command_fields::GameModeOption, embed_builder::SettingsImage};
 use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher, CowUtils,
-};
+use bathbot_util::{matcher, CowUtils};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, OsuError, Score},
@@ -28,7 +25,10 @@ use crate::{
         ActiveMessages,
     },
     commands::utility::{MissAnalyzerCheck, ScoreEmbedDataPersonalBest, ScoreEmbedDataWrap},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::{interaction::InteractionCommand, ChannelExt, CheckPermissions, InteractionCommandExt},
     Context,
@@ -61,6 +61,8 @@ pub struct Mapper<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
     #[command(
         desc = "Size of the embed",
         help = "Size of the embed.\n\
@@ -102,6 +104,7 @@ impl<'m> Mapper<'m> {
             name,
             sort: None,
             discord,
+            account: None,
             size: None,
         })
     }
@@ -215,10 +218,10 @@ async fn slash_mapper(mut command: InteractionCommand) -> Result<()> {
 async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
     let msg_owner = orig.user_id()?;
 
-    let mut config = match Context::user_config().with_osu_id(msg_owner).await {
+    let config = match Context::user_config().with_osu_id(msg_owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -227,12 +230,13 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
     let mode = args
         .mode
         .map(GameMode::from)
+        .or(orig.channel_mode().await)
         .or(config.mode)
         .unwrap_or(GameMode::Osu);
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu.take() {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
@@ -278,7 +282,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get mapper, user, or scores");
 
             return Err(err);
@@ -291,7 +295,10 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
     };
 
     let username = user.username();
-    let settings = config.score_embed.unwrap_or_default();
+    let settings = Context::guild_config()
+        .score_embed(orig.guild_id(), orig.channel_id(), config.score_embed)
+        .await
+        .unwrap_or_default();
 
     let mut with_render = match (guild_render_button, config.render_button) {
         (None | Some(true), None) => true,
@@ -308,7 +315,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
         match process_scores(scores, mapper_id, args.sort, with_render, legacy_scores).await {
             Ok(entries) => entries,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err.wrap_err("failed to process scores"));
             }
@@ -6,7 +6,7 @@ use std::{
 };
 
 use bathbot_macros::{command, SlashCommand};
-use bathbot_util::{constants::OSU_API_ISSUE, matcher, IntHasher};
+use bathbot_util::{matcher, IntHasher};
 use eyre::{Report, Result};
 use rosu_v2::{
     model::mods::GameModsIntermode,
@@ -18,9 +18,12 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 
 use crate::{
     active::{impls::MatchCostPagination, ActiveMessages},
-    core::commands::{
-        prefix::{Args, ArgsNum},
-        CommandOrigin,
+    core::{
+        commands::{
+            prefix::{Args, ArgsNum},
+            CommandOrigin,
+        },
+        locale::Issue,
     },
     util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
     Context,
@@ -201,7 +204,7 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get match");
 
             return Err(err);
@@ -3,10 +3,7 @@ use std::borrow::Cow;
 use bathbot_macros::{command, SlashCommand};
 use bathbot_model::command_fields::ThreadChannel;
 use bathbot_util::{
-    constants::{
-        GENERAL_ISSUE, INVALID_ACTION_FOR_CHANNEL_TYPE, OSU_API_ISSUE, OSU_BASE,
-        THREADS_UNAVAILABLE,
-    },
+    constants::{INVALID_ACTION_FOR_CHANNEL_TYPE, OSU_API_ISSUE, OSU_BASE, THREADS_UNAVAILABLE},
     matcher, MessageBuilder,
 };
 use eyre::{Report, Result, WrapErr};
@@ -15,7 +12,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::{thread::AutoArchiveDuration, ChannelType};
 
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     matchlive::MatchTrackResult,
     util::{interaction::InteractionCommand, ChannelExt, CheckPermissions, InteractionCommandExt},
     Context,
@@ -29,6 +26,7 @@ use crate::{
     keep a channel up to date about events in a multiplayer match."
 )]
 #[flags(AUTHORITY)]
+#[group(Tracking)]
 pub enum Matchlive<'a> {
     #[command(name = "track")]
     Add(MatchliveAdd<'a>),
@@ -146,7 +144,7 @@ async fn matchlive(orig: CommandOrigin<'_>, args: MatchliveAdd<'_>) -> Result<()
 
     if let ThreadChannel::Thread = thread {
         if orig.guild_id().is_none() {
-            return orig.error(THREADS_UNAVAILABLE).await;
+            return orig.error(Issue::ThreadsUnavailable).await;
         }
 
         if !orig.can_create_thread() {
@@ -181,7 +179,7 @@ async fn matchlive(orig: CommandOrigin<'_>, args: MatchliveAdd<'_>) -> Result<()
                 match content {
                     Some(content) => return orig.error(content).await,
                     None => {
-                        let _ = orig.error(GENERAL_ISSUE).await;
+                        let _ = orig.error(Issue::General).await;
                         let report = Report::new(err).wrap_err("failed to create thread");
 
                         return Err(report);
@@ -193,7 +191,7 @@ async fn matchlive(orig: CommandOrigin<'_>, args: MatchliveAdd<'_>) -> Result<()
 
     let content: &str = match Context::add_match_track(channel, match_id).await {
         MatchTrackResult::Added => match orig {
-            CommandOrigin::Message { .. } => return Ok(()),
+            CommandOrigin::Message { .. } | CommandOrigin::Scheduled { .. } => return Ok(()),
             CommandOrigin::Interaction { command } => {
                 Context::interaction()
                     .delete_response(&command.token)
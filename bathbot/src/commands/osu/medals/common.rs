@@ -7,10 +7,7 @@ use bathbot_macros::command;
 use bathbot_model::{
     rkyv_util::time::DateTimeRkyv, rosu_v2::user::User, MedalGroup, OsekaiMedal, Rarity,
 };
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher,
-};
+use bathbot_util::{matcher, IntHasher};
 use eyre::{Report, Result};
 use hashbrown::HashMap;
 use rkyv::{with::DeserializeWith, Infallible};
@@ -25,7 +22,7 @@ use super::{MedalCommon, MedalCommonFilter, MedalCommonOrder};
 use crate::{
     active::{impls::MedalsCommonPagination, ActiveMessages},
     commands::osu::UserExtraction,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     manager::redis::{osu::UserArgs, RedisData},
     util::osu::get_combined_thumbnail,
     Context,
@@ -82,7 +79,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
     let user_id1 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -98,7 +95,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
     let user_id2 = match extract_user_id(&mut args).await {
         UserExtraction::Id(user_id) => user_id,
         UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -112,7 +109,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
                 return orig.error(content).await;
             }
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -144,7 +141,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
             return orig.error(content).await;
         }
         (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("failed to get user"));
         }
@@ -153,7 +150,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
     let mut all_medals = match all_medals_res {
         Ok(medals) => medals.into_original(),
         Err(err) => {
-            let _ = orig.error(OSEKAI_ISSUE).await;
+            let _ = orig.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached medals"));
         }
@@ -269,7 +266,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
                         });
                     }
                     Err(err) => {
-                        let _ = orig.error(OSEKAI_ISSUE).await;
+                        let _ = orig.error(Issue::Osekai).await;
 
                         return Err(err.wrap_err("failed to get cached rarity ranking"));
                     }
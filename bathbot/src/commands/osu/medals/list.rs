@@ -1,10 +1,7 @@
 use std::cmp::{Ordering, Reverse};
 
 use bathbot_model::{rkyv_util::time::DateTimeRkyv, OsekaiMedal, Rarity};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    IntHasher,
-};
+use bathbot_util::IntHasher;
 use eyre::{Report, Result};
 use hashbrown::HashMap;
 use rkyv::{with::DeserializeWith, Infallible};
@@ -15,7 +12,7 @@ use super::{MedalList, MedalListOrder};
 use crate::{
     active::{impls::MedalsListPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     manager::redis::{osu::UserArgs, RedisData},
     Context,
 };
@@ -29,7 +26,7 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: MedalList<'_>) -> Result
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -57,13 +54,13 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: MedalList<'_>) -> Result
                 return orig.error(content).await;
             }
             (Err(err), ..) => {
-                let _ = orig.error(OSU_API_ISSUE).await;
+                let _ = orig.error(Issue::OsuApi).await;
                 let report = Report::new(err).wrap_err("failed to get user");
 
                 return Err(report);
             }
             (_, Err(err), _) | (.., Err(err)) => {
-                let _ = orig.error(OSEKAI_ISSUE).await;
+                let _ = orig.error(Issue::Osekai).await;
 
                 return Err(err.wrap_err("failed to get cached rarity ranking"));
             }
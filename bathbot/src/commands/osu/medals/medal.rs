@@ -7,7 +7,7 @@ use bathbot_macros::command;
 use bathbot_model::{MedalGroup, OsekaiComment, OsekaiMap, OsekaiMedal};
 use bathbot_psql::model::configs::HideSolutions;
 use bathbot_util::{
-    constants::{FIELD_VALUE_SIZE, OSEKAI_ISSUE, OSU_BASE},
+    constants::{FIELD_VALUE_SIZE, OSU_BASE},
     fields,
     osu::flag_url,
     string_cmp::levenshtein_similarity,
@@ -25,7 +25,7 @@ use twilight_model::{
 
 use super::{MedalAchieved, MedalInfo_};
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     manager::redis::RedisData,
     util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
     Context,
@@ -64,7 +64,7 @@ pub(super) async fn info(orig: CommandOrigin<'_>, args: MedalInfo_<'_>) -> Resul
     let mut medals = match Context::redis().medals().await {
         Ok(medals) => medals,
         Err(err) => {
-            let _ = orig.error(OSEKAI_ISSUE).await;
+            let _ = orig.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached medals"));
         }
@@ -109,7 +109,7 @@ pub(super) async fn info(orig: CommandOrigin<'_>, args: MedalInfo_<'_>) -> Resul
     let (mut maps, comments) = match tokio::try_join!(map_fut, comment_fut) {
         Ok((maps, comments)) => (maps, comments),
         Err(err) => {
-            let _ = orig.error(OSEKAI_ISSUE).await;
+            let _ = orig.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get osekai map or comments"));
         }
@@ -466,3 +466,84 @@ impl MedalEmbed {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fake;
+
+    fn prepare_osekai() {
+        fake::client()
+            .post(
+                "https://osekai.net/medals/api/medals.php",
+                r#"[{
+                    "MedalID": 1,
+                    "Name": "50,000 Plays",
+                    "Link": "https://assets.ppy.sh/medals/web/osu-plays-50000.png",
+                    "Description": "There's a reason it's called a rhythm game.",
+                    "Restriction": "NULL",
+                    "Grouping": "Skill & Dedication",
+                    "Solution": "Set 50,000 plays.",
+                    "Mods": null,
+                    "ModeOrder": 0,
+                    "Ordering": 1,
+                    "Rarity": 12.5
+                }]"#,
+            )
+            .post("https://osekai.net/medals/api/beatmaps.php", "[]")
+            .post(
+                "https://osekai.net/global/api/comment_system.php",
+                r#"[{
+                    "ID": 1,
+                    "PostText": "Just keep playing",
+                    "Parent": 0,
+                    "UserID": 2,
+                    "Username": "peppy",
+                    "VoteSum": "7"
+                }]"#,
+            );
+    }
+
+    fn medal_args(name: &str) -> MedalInfo_<'_> {
+        MedalInfo_ {
+            name: AutocompleteValue::Completed(name.into()),
+        }
+    }
+
+    #[test]
+    fn medal_info() {
+        prepare_osekai();
+
+        let mut command = fake::command("medal");
+        fake::run(info((&mut command).into(), medal_args("50,000 plays"))).unwrap();
+
+        let requests = fake::discord_requests(&command.token);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(&*requests[0].method, "PATCH");
+
+        let body = requests[0].body_str();
+        assert!(body.contains(r#""title":"50,000 Plays""#), "{body}");
+        assert!(body.contains("Set 50,000 plays."), "{body}");
+        assert!(body.contains("12.50%"), "{body}");
+        assert!(body.contains("Skill & Dedication"), "{body}");
+        assert!(body.contains("Just keep playing"), "{body}");
+    }
+
+    #[test]
+    fn unknown_medal() {
+        prepare_osekai();
+
+        let mut command = fake::command("medal");
+        fake::run(info((&mut command).into(), medal_args("50,000 plyas"))).unwrap();
+
+        let requests = fake::discord_requests(&command.token);
+        assert_eq!(requests.len(), 1);
+
+        let body = requests[0].body_str();
+        assert!(
+            body.contains("No medal found with the name `50,000 plyas`."),
+            "{body}"
+        );
+        assert!(body.contains("Did you mean `50,000 plays`?"), "{body}");
+    }
+}
@@ -2,10 +2,7 @@ use std::{borrow::Cow, cmp::Ordering};
 
 use bathbot_macros::command;
 use bathbot_model::{MedalGroup, OsekaiMedal, MEDAL_GROUPS};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher,
-};
+use bathbot_util::{matcher, IntHasher};
 use eyre::{Report, Result};
 use hashbrown::HashSet;
 use rkyv::{Deserialize, Infallible};
@@ -15,7 +12,7 @@ use super::{MedalMissing, MedalMissingOrder};
 use crate::{
     active::{impls::MedalsMissingPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     manager::redis::{osu::UserArgs, RedisData},
     Context,
 };
@@ -33,11 +30,13 @@ async fn prefix_medalsmissing(msg: &Message, mut args: Args<'_>) -> Result<()> {
                 name: None,
                 sort: None,
                 discord: Some(id),
+                account: None,
             },
             None => MedalMissing {
                 name: Some(Cow::Borrowed(arg)),
                 sort: None,
                 discord: None,
+                account: None,
             },
         },
         None => MedalMissing::default(),
@@ -55,7 +54,7 @@ pub(super) async fn missing(orig: CommandOrigin<'_>, args: MedalMissing<'_>) ->
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -74,12 +73,12 @@ pub(super) async fn missing(orig: CommandOrigin<'_>, args: MedalMissing<'_>) ->
             return orig.error(content).await;
         }
         (_, Err(err)) => {
-            let _ = orig.error(OSEKAI_ISSUE).await;
+            let _ = orig.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached medals"));
         }
         (Err(err), _) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get user");
 
             return Err(report);
@@ -165,6 +165,8 @@ pub struct MedalList<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -203,6 +205,8 @@ pub struct MedalMissing<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption)]
@@ -241,6 +245,8 @@ pub struct MedalRecent<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandModel, CreateCommand, Default, HasName)]
@@ -255,6 +261,8 @@ pub struct MedalStats<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 pub async fn slash_medal(mut command: InteractionCommand) -> Result<()> {
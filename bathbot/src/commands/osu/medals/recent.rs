@@ -3,10 +3,7 @@ use std::{cmp::Reverse, collections::HashMap, mem};
 use bathbot_macros::command;
 use bathbot_model::rosu_v2::user::{MedalCompact as MedalCompactRkyv, User};
 use bathbot_psql::model::configs::HideSolutions;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher, MessageBuilder,
-};
+use bathbot_util::{matcher, IntHasher, MessageBuilder};
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
 use rkyv::{
@@ -24,7 +21,7 @@ use super::{MedalEmbed, MedalRecent};
 use crate::{
     active::{impls::MedalsRecentPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     manager::redis::{osu::UserArgs, RedisData},
     Context,
 };
@@ -65,7 +62,7 @@ pub(super) async fn recent(orig: CommandOrigin<'_>, args: MedalRecent<'_>) -> Re
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -84,13 +81,13 @@ pub(super) async fn recent(orig: CommandOrigin<'_>, args: MedalRecent<'_>) -> Re
             return orig.error(content).await;
         }
         (Err(err), _) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get user");
 
             return Err(report);
         }
         (_, Err(err)) => {
-            let _ = orig.error(OSEKAI_ISSUE).await;
+            let _ = orig.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached medals"));
         }
@@ -153,7 +150,7 @@ pub(super) async fn recent(orig: CommandOrigin<'_>, args: MedalRecent<'_>) -> Re
     let medal = match all_medals.iter().position(|m| m.medal_id == medal_id) {
         Some(idx) => &all_medals[idx],
         None => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             bail!("No medal with id `{medal_id}`");
         }
@@ -2,10 +2,7 @@ use std::{borrow::Cow, mem};
 
 use bathbot_macros::command;
 use bathbot_model::rosu_v2::user::MedalCompact as MedalCompactRkyv;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher, MessageBuilder,
-};
+use bathbot_util::{matcher, IntHasher, MessageBuilder};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use hashbrown::HashMap;
 use plotters::prelude::*;
@@ -26,7 +23,7 @@ use twilight_model::guild::Permissions;
 use super::MedalStats;
 use crate::{
     commands::osu::{require_link, user_not_found},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     embeds::{EmbedData, MedalStatsEmbed, StatsMedal},
     manager::redis::{osu::UserArgs, RedisData},
     util::Monthly,
@@ -49,10 +46,12 @@ async fn prefix_medalstats(
             Some(id) => MedalStats {
                 name: None,
                 discord: Some(id),
+                account: None,
             },
             None => MedalStats {
                 name: Some(Cow::Borrowed(arg)),
                 discord: None,
+                account: None,
             },
         },
         None => MedalStats::default(),
@@ -68,7 +67,7 @@ pub(super) async fn stats(orig: CommandOrigin<'_>, args: MedalStats<'_>) -> Resu
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -87,12 +86,12 @@ pub(super) async fn stats(orig: CommandOrigin<'_>, args: MedalStats<'_>) -> Resu
             return orig.error(content).await;
         }
         (_, Err(err)) => {
-            let _ = orig.error(OSEKAI_ISSUE).await;
+            let _ = orig.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("Failed to get cached medals"));
         }
         (Err(err), _) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -1,9 +1,20 @@
-/// Try to extract an osu! user from the `args`' fields `name` or `discord`
+/// Try to extract an osu! user from the `args`' fields `name`, `discord`, or
+/// `account`
 macro_rules! user_id {
     ($orig:ident, $args:ident) => {
         match crate::commands::osu::HasName::user_id(&$args) {
             crate::commands::osu::UserIdResult::Id(user_id) => Some(user_id),
-            crate::commands::osu::UserIdResult::None => None,
+            crate::commands::osu::UserIdResult::None => {
+                match crate::commands::osu::HasName::account(&$args) {
+                    Some(account) => {
+                        match crate::commands::osu::linked_account(&$orig, account).await? {
+                            Some(user_id) => Some(user_id),
+                            None => return Ok(()),
+                        }
+                    }
+                    None => None,
+                }
+            }
             crate::commands::osu::UserIdResult::Future(fut) => match fut.await {
                 crate::commands::osu::UserIdFutureResult::Id(user_id) => Some(user_id),
                 crate::commands::osu::UserIdFutureResult::NotLinked(user_id) => {
@@ -12,7 +23,7 @@ macro_rules! user_id {
                     return $orig.error(content).await;
                 }
                 crate::commands::osu::UserIdFutureResult::Err(err) => {
-                    let content = bathbot_util::constants::GENERAL_ISSUE;
+                    let content = crate::core::locale::Issue::General;
                     let _ = $orig.error(content).await;
 
                     return Err(err);
@@ -37,10 +48,14 @@ macro_rules! user_id_mode {
             if let Some(mode) = mode {
                 (user_id, mode)
             } else {
-                let mode = crate::core::Context::user_config()
-                    .mode($orig.user_id()?)
-                    .await?
-                    .unwrap_or(rosu_v2::prelude::GameMode::Osu);
+                let mode = match $orig.channel_mode().await {
+                    Some(mode) => Some(mode),
+                    None => crate::core::Context::user_config()
+                        .mode($orig.user_id()?)
+                        .await?,
+                };
+
+                let mode = mode.unwrap_or(rosu_v2::prelude::GameMode::Osu);
 
                 (user_id, mode)
             }
@@ -50,10 +65,11 @@ macro_rules! user_id_mode {
                 .await?;
 
             let mode = mode
+                .or($orig.channel_mode().await)
                 .or(config.mode)
                 .unwrap_or(rosu_v2::prelude::GameMode::Osu);
 
-            match config.osu {
+            match config.osu_for_mode(Some(mode)) {
                 Some(user_id) => (rosu_v2::request::UserId::Id(user_id), mode),
                 None => return crate::commands::osu::require_link(&$orig).await,
             }
@@ -63,19 +79,46 @@ macro_rules! user_id_mode {
 
 use std::{future::Future, pin::Pin};
 
-use bathbot_util::osu::ModSelection;
+use bathbot_util::{constants::GENERAL_ISSUE, osu::ModSelection};
 use eyre::{Report, Result, WrapErr};
+use fluent_bundle::FluentArgs;
 use rosu_v2::request::UserId;
 use twilight_interactions::command::{CommandOption, CreateOption};
 use twilight_model::id::{marker::UserMarker, Id};
 
 pub use self::{
-    badges::*, claim_name::*, compare::*, fix::*, graphs::*, leaderboard::*, map::*, map_search::*,
-    match_compare::*, match_costs::*, medals::*, nochoke::*, osustats::*, profile::*, recent::*,
-    region_top::*, render::*, scores::*, simulate::*, snipe::*, top::*, whatif::*,
+    badges::*,
+    bookmarks::*,
+    cards::{draw_card, CardError},
+    claim_name::*,
+    compare::*,
+    fix::*,
+    graphs::*,
+    leaderboard::*,
+    map::*,
+    map_search::*,
+    match_compare::*,
+    match_costs::*,
+    medals::*,
+    nochoke::*,
+    osustats::*,
+    profile::*,
+    ranking::*,
+    recent::*,
+    region_top::*,
+    render::*,
+    scores::*,
+    serverleaderboard::*,
+    simulate::*,
+    snipe::*,
+    top::*,
+    whatif::*,
 };
 use crate::{
-    core::commands::{interaction::InteractionCommands, CommandOrigin},
+    core::{
+        commands::{interaction::InteractionCommands, CommandOrigin},
+        locale::{tr_args, Issue},
+    },
     Context,
 };
 
@@ -116,6 +159,9 @@ mod snipe;
 mod top;
 mod whatif;
 
+#[cfg(feature = "server")]
+mod friends;
+
 #[cfg(feature = "server")]
 mod link;
 
@@ -134,6 +180,11 @@ pub enum ModsResult {
 
 pub trait HasName {
     fn user_id(&self) -> UserIdResult;
+
+    /// Name of one of the author's linked accounts
+    fn account(&self) -> Option<&str> {
+        None
+    }
 }
 
 pub enum UserIdResult {
@@ -150,18 +201,53 @@ pub enum UserIdFutureResult {
 
 pub async fn require_link(orig: &CommandOrigin<'_>) -> Result<()> {
     let link = InteractionCommands::get_command("link").map_or_else(
-        || "`/link`".to_owned(),
-        |cmd| cmd.mention("link").to_string(),
+        || "`/link add`".to_owned(),
+        |cmd| cmd.mention("link add").to_string(),
     );
 
-    let content =
-        format!("Either specify an osu! username or link yourself to an osu! profile via {link}");
+    let mut args = FluentArgs::new();
+    args.set("link", link);
+    let content = tr_args(orig.locale().await, "require-link", Some(&args));
 
     orig.error(content)
         .await
         .wrap_err("Failed to send require-link message")
 }
 
+/// Resolve the name of one of the author's linked accounts.
+///
+/// Responds with an error and returns `None` if the author has no such
+/// linked account.
+pub async fn linked_account(orig: &CommandOrigin<'_>, account: &str) -> Result<Option<UserId>> {
+    let owner = orig.user_id()?;
+
+    match Context::user_config()
+        .linked_osu_id_by_name(owner, account)
+        .await
+    {
+        Ok(Some(user_id)) => Ok(Some(UserId::Id(user_id))),
+        Ok(None) => {
+            let mut args = FluentArgs::new();
+            args.set("account", account);
+            let content = tr_args(orig.locale().await, "not-linked-account", Some(&args));
+            orig.error(content).await?;
+
+            Ok(None)
+        }
+        Err(err) => {
+            let _ = orig.error(Issue::General).await;
+
+            Err(err)
+        }
+    }
+}
+
+/// Prefix commands specify one of the author's linked accounts through
+/// `account=<name>` in place of a username.
+pub fn prefix_account(arg: &str) -> Option<&str> {
+    arg.strip_prefix("account=").filter(|name| !name.is_empty())
+}
+
 pub async fn user_not_found(user_id: UserId) -> String {
     let user_id = match user_id {
         user_id @ UserId::Name(_) => user_id,
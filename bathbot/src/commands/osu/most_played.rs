@@ -1,10 +1,7 @@
 use std::borrow::Cow;
 
 use bathbot_macros::{command, HasName, SlashCommand};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-};
+use bathbot_util::matcher;
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -13,7 +10,7 @@ use twilight_model::id::{marker::UserMarker, Id};
 use super::{require_link, user_not_found};
 use crate::{
     active::{impls::MostPlayedPagination, ActiveMessages},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     manager::redis::osu::UserArgs,
     util::{interaction::InteractionCommand, InteractionCommandExt},
     Context,
@@ -31,6 +28,8 @@ pub struct MostPlayed<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 async fn slash_mostplayed(mut command: InteractionCommand) -> Result<()> {
@@ -51,10 +50,12 @@ async fn prefix_mostplayed(msg: &Message, mut args: Args<'_>) -> Result<()> {
             Some(id) => MostPlayed {
                 name: None,
                 discord: Some(id),
+                account: None,
             },
             None => MostPlayed {
                 name: Some(Cow::Borrowed(arg)),
                 discord: None,
+                account: None,
             },
         },
         None => MostPlayed::default(),
@@ -72,7 +73,7 @@ async fn mostplayed(orig: CommandOrigin<'_>, args: MostPlayed<'_>) -> Result<()>
             Ok(Some(user_id)) => UserId::Id(user_id),
             Ok(None) => return require_link(&orig).await,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -90,7 +91,7 @@ async fn mostplayed(orig: CommandOrigin<'_>, args: MostPlayed<'_>) -> Result<()>
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -102,7 +103,7 @@ async fn mostplayed(orig: CommandOrigin<'_>, args: MostPlayed<'_>) -> Result<()>
     let maps = match maps_fut.await {
         Ok(maps) => maps,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get maps");
 
             return Err(err);
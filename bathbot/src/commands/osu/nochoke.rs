@@ -3,11 +3,7 @@ use std::borrow::Cow;
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_model::ScoreSlim;
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::calculate_grade,
-};
+use bathbot_util::{matcher, osu::calculate_grade};
 use eyre::{Report, Result};
 use rosu_pp::any::DifficultyAttributes;
 use rosu_v2::{
@@ -21,7 +17,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 use super::{require_link, user_not_found};
 use crate::{
     active::{impls::NoChokePagination, ActiveMessages},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::{redis::osu::UserArgs, OsuMap},
     util::{interaction::InteractionCommand, osu::IfFc, InteractionCommandExt},
     Context,
@@ -64,6 +63,8 @@ pub struct Nochoke<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption)]
@@ -133,6 +134,7 @@ impl<'m> Nochoke<'m> {
             version: None,
             filter: None,
             discord,
+            account: None,
         }
     }
 }
@@ -199,19 +201,24 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
+    let mode = match args
+        .mode
+        .map(GameMode::from)
+        .or(orig.channel_mode().await)
+        .or(config.mode)
+    {
+        None | Some(GameMode::Mania) => GameMode::Osu,
+        Some(mode) => mode,
+    };
+
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
-        None | Some(GameMode::Mania) => GameMode::Osu,
-        Some(mode) => mode,
-    };
-
     let legacy_scores = match config.score_data {
         Some(score_data) => score_data.is_legacy(),
         None => match orig.guild_id() {
@@ -245,7 +252,7 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -257,7 +264,7 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
     let mut entries = match process_scores(scores, miss_limit, version).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("failed to process scores"));
         }
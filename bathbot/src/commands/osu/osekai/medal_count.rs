@@ -1,12 +1,12 @@
 use std::borrow::Cow;
 
 use bathbot_model::{Countries, MedalCount};
-use bathbot_util::constants::OSEKAI_ISSUE;
 use eyre::Result;
 
 use super::OsekaiMedalCount;
 use crate::{
     active::{impls::MedalCountPagination, ActiveMessages},
+    core::locale::Issue,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
@@ -42,7 +42,7 @@ pub(super) async fn medal_count(
     let mut ranking = match osekai_res {
         Ok(ranking) => ranking.into_original(),
         Err(err) => {
-            let _ = command.error(OSEKAI_ISSUE).await;
+            let _ = command.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached medal count ranking"));
         }
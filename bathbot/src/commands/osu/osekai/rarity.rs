@@ -1,9 +1,9 @@
 use bathbot_model::Rarity;
-use bathbot_util::constants::OSEKAI_ISSUE;
 use eyre::Result;
 
 use crate::{
     active::{impls::MedalRarityPagination, ActiveMessages},
+    core::locale::Issue,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
@@ -12,7 +12,7 @@ pub(super) async fn rarity(mut command: InteractionCommand) -> Result<()> {
     let ranking = match Context::redis().osekai_ranking::<Rarity>().await {
         Ok(ranking) => ranking.into_original(),
         Err(err) => {
-            let _ = command.error(OSEKAI_ISSUE).await;
+            let _ = command.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached rarity ranking"));
         }
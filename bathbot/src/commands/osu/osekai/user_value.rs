@@ -4,12 +4,12 @@ use bathbot_model::{
     ArchivedOsekaiRankingEntry, Countries, OsekaiRanking, OsekaiRankingEntry, RankingEntries,
     RankingEntry, RankingKind,
 };
-use bathbot_util::constants::OSEKAI_ISSUE;
 use eyre::Result;
 use rosu_v2::prelude::Username;
 
 use crate::{
     active::{impls::RankingPagination, ActiveMessages},
+    core::locale::Issue,
     manager::redis::RedisData,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
@@ -46,7 +46,7 @@ where
     let ranking = match osekai_res {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = command.error(OSEKAI_ISSUE).await;
+            let _ = command.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached osekai ranking"));
         }
@@ -100,7 +100,7 @@ where
     let ranking = match osekai_res {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = command.error(OSEKAI_ISSUE).await;
+            let _ = command.error(Issue::Osekai).await;
 
             return Err(err.wrap_err("failed to get cached osekai ranking"));
         }
@@ -1,11 +1,10 @@
-use bathbot_util::constants::OSUSTATS_API_ISSUE;
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
 
 use super::{OsuStatsBest, OsuStatsBestSort};
 use crate::{
     active::{impls::OsuStatsBestPagination, ActiveMessages},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::Issue, Context},
 };
 
 pub(super) async fn recentbest(orig: CommandOrigin<'_>, args: OsuStatsBest) -> Result<()> {
@@ -15,7 +14,7 @@ pub(super) async fn recentbest(orig: CommandOrigin<'_>, args: OsuStatsBest) -> R
     let mut scores = match scores_fut.await {
         Ok(scores) => scores.into_original(),
         Err(err) => {
-            let _ = orig.error(OSUSTATS_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuStatsApi).await;
 
             return Err(err);
         }
@@ -2,10 +2,7 @@ use std::borrow::Cow;
 
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::{
-    constants::{OSUSTATS_API_ISSUE, OSU_API_ISSUE},
-    matcher, MessageBuilder,
-};
+use bathbot_util::{matcher, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -14,7 +11,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 use super::OsuStatsCount;
 use crate::{
     commands::osu::user_not_found,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     embeds::{EmbedData, OsuStatsCountsEmbed},
     manager::redis::osu::UserArgs,
     util::{interaction::InteractionCommand, osu::TopCounts, InteractionCommandExt},
@@ -38,6 +38,8 @@ pub struct Osc<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 impl<'m> From<Osc<'m>> for OsuStatsCount<'m> {
@@ -46,6 +48,7 @@ impl<'m> From<Osc<'m>> for OsuStatsCount<'m> {
             mode: args.mode,
             name: args.name,
             discord: args.discord,
+            account: args.account,
         }
     }
 }
@@ -57,12 +60,14 @@ impl<'m> OsuStatsCount<'m> {
                 Some(id) => Self {
                     mode,
                     discord: Some(id),
+                    account: None,
                     name: None,
                 },
                 None => Self {
                     mode,
                     name: Some(arg.into()),
                     discord: None,
+                    account: None,
                 },
             },
             None => Self {
@@ -163,7 +168,7 @@ pub(super) async fn count(orig: CommandOrigin<'_>, args: OsuStatsCount<'_>) -> R
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -173,7 +178,7 @@ pub(super) async fn count(orig: CommandOrigin<'_>, args: OsuStatsCount<'_>) -> R
     let counts = match TopCounts::request(&user, mode).await {
         Ok(counts) => counts,
         Err(err) => {
-            let _ = orig.error(OSUSTATS_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuStatsApi).await;
 
             return Err(err.wrap_err("failed to get top counts"));
         }
@@ -5,12 +5,7 @@ use bathbot_model::{
     command_fields::GameModeOption, OsuStatsParams, OsuStatsScore, OsuStatsScoresOrder,
     OsuStatsScoresRaw, ScoreSlim,
 };
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSUSTATS_API_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::ModSelection,
-    CowUtils,
-};
+use bathbot_util::{matcher, osu::ModSelection, CowUtils};
 use eyre::{Report, Result};
 use rosu_v2::{
     model::score::LegacyScoreStatistics,
@@ -21,7 +16,10 @@ use super::OsuStatsScores;
 use crate::{
     active::{impls::OsuStatsScoresPagination, ActiveMessages},
     commands::osu::{user_not_found, HasMods, ModsResult},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::{redis::osu::UserArgs, OsuMap},
     util::ChannelExt,
     Context,
@@ -182,7 +180,7 @@ pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) ->
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -196,7 +194,7 @@ pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) ->
     let (scores, amount) = match scores_fut.await.map(OsuStatsScoresRaw::into_scores) {
         Ok(Ok(scores)) => (scores.scores, scores.count),
         Err(err) | Ok(Err(err)) => {
-            let _ = orig.error(OSUSTATS_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuStatsApi).await;
 
             return Err(err.wrap_err("Failed to get global scores"));
         }
@@ -205,7 +203,7 @@ pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) ->
     let entries = match process_scores(scores, mode).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to process scores"));
         }
@@ -310,6 +308,7 @@ impl<'m> OsuStatsScores<'m> {
     fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Result<Self, Cow<'static, str>> {
         let mut name = None;
         let mut discord = None;
+        let mut account = None;
         let mut min_rank = None;
         let mut max_rank = None;
         let mut min_acc = None;
@@ -411,10 +410,11 @@ impl<'m> OsuStatsScores<'m> {
                         Some(_) => mods = Some(format!("+{value}!").into()),
                         None => return Err(Self::ERR_PARSE_MODS.into()),
                     },
+                    "account" => account = Some(value.to_owned().into()),
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `acc`, `rank`, `sort`, or `reverse`."
+                            Available options are: `acc`, `rank`, `sort`, `reverse`, or `account`."
                         );
 
                         return Err(content.into());
@@ -440,6 +440,7 @@ impl<'m> OsuStatsScores<'m> {
             max_acc,
             reverse,
             discord,
+            account,
         })
     }
 }
@@ -4,17 +4,17 @@ use bathbot_macros::command;
 use bathbot_model::{
     command_fields::GameModeOption, Countries, OsuStatsPlayer, OsuStatsPlayersArgs,
 };
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSUSTATS_API_ISSUE},
-    CowUtils, IntHasher,
-};
+use bathbot_util::{CowUtils, IntHasher};
 use eyre::Result;
 use rosu_v2::{model::GameMode, prelude::CountryCode};
 
 use super::OsuStatsPlayers;
 use crate::{
     active::{impls::OsuStatsPlayersPagination, ActiveMessages},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     util::ChannelExt,
     Context,
 };
@@ -38,7 +38,7 @@ pub(super) async fn players(orig: CommandOrigin<'_>, mut args: OsuStatsPlayers<'
         args.mode = match Context::user_config().mode(owner).await {
             Ok(mode) => mode.map(GameModeOption::from),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -68,7 +68,7 @@ pub(super) async fn players(orig: CommandOrigin<'_>, mut args: OsuStatsPlayers<'
     let (amount, players) = match prepare_players(&mut params).await {
         Ok(tuple) => tuple,
         Err(err) => {
-            let _ = orig.error(OSUSTATS_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuStatsApi).await;
 
             return Err(err.wrap_err("failed to prepare players"));
         }
@@ -408,3 +408,47 @@ impl<'m> OsuStatsPlayers<'m> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fake;
+
+    #[test]
+    fn country_players() {
+        fake::client().post(
+            "https://osustats.ppy.sh/api/getScoreRanking",
+            r#"[
+                {"userId": 2, "count": "1234", "osu_user": {"userName": "peppy"}},
+                {"userId": 3, "count": "42", "osu_user": {"userName": "other"}}
+            ]"#,
+        );
+
+        let mut command = fake::command("osustats");
+
+        let args = OsuStatsPlayers {
+            mode: Some(GameModeOption::Osu),
+            country: Some("be".into()),
+            min_rank: None,
+            max_rank: None,
+        };
+
+        fake::run(players((&mut command).into(), args)).unwrap();
+
+        let requests = fake::discord_requests(&command.token);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(&*requests[0].method, "PATCH");
+
+        let body = requests[0].body_str();
+        assert!(body.contains("Country: `BE`"), "{body}");
+        assert!(
+            body.contains("**#1 [peppy](https://osu.ppy.sh/users/2)**: 1,234"),
+            "{body}"
+        );
+        assert!(
+            body.contains("**#2 [other](https://osu.ppy.sh/users/3)**: 42"),
+            "{body}"
+        );
+        assert!(body.contains("Page 1/1"), "{body}");
+    }
+}
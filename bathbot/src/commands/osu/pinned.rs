@@ -6,11 +6,7 @@ use std::{
 use bathbot_macros::{HasMods, HasName, SlashCommand};
 use bathbot_model::{command_fields::GameModeOption, embed_builder::SettingsImage};
 use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    osu::ModSelection,
-    MessageOrigin,
-};
+use bathbot_util::{osu::ModSelection, MessageOrigin};
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
 use rosu_v2::{
@@ -32,7 +28,7 @@ use crate::{
     commands::utility::{
         MissAnalyzerCheck, ScoreEmbedDataHalf, ScoreEmbedDataPersonalBest, ScoreEmbedDataWrap,
     },
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     manager::redis::{
         osu::{UserArgs, UserArgsSlim},
         RedisData,
@@ -86,6 +82,8 @@ pub struct Pinned {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
     #[command(
         desc = "Size of the embed",
         help = "Size of the embed.\n\
@@ -120,7 +118,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
     let mut config = match Context::user_config().with_osu_id(msg_owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -129,6 +127,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
     let mode = args
         .mode
         .map(GameMode::from)
+        .or(orig.channel_mode().await)
         .or(config.mode)
         .unwrap_or(GameMode::Osu);
 
@@ -155,7 +154,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu.take() {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
@@ -173,7 +172,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
             return orig.error(content).await;
         }
         UserArgs::Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -221,14 +220,17 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user or prepare scores");
 
             return Err(err);
         }
     };
 
-    let settings = config.score_embed.unwrap_or_default();
+    let settings = Context::guild_config()
+        .score_embed(orig.guild_id(), orig.channel_id(), config.score_embed)
+        .await
+        .unwrap_or_default();
 
     let mut with_render = match (guild_render_button, config.render_button) {
         (None | Some(true), None) => true,
@@ -258,7 +260,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
     {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to process scores"));
         }
@@ -1,13 +1,11 @@
 use std::borrow::Cow;
 
-use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_macros::{command, user_command, HasName, SlashCommand};
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher, CowUtils, MessageOrigin,
-};
+use bathbot_util::{matcher, CowUtils, MessageOrigin};
 use eyre::{Report, Result};
+use rkyv::{Archive, Deserialize, Serialize};
 use rosu_v2::{
     prelude::{GameMode, OsuError},
     request::UserId,
@@ -18,7 +16,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 use super::{require_link, user_not_found};
 use crate::{
     active::{impls::ProfileMenu, ActiveMessages},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::redis::osu::UserArgs,
     util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
     Context,
@@ -40,9 +41,13 @@ pub struct Profile<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
-#[derive(Copy, Clone, CommandOption, CreateOption, Debug, Eq, PartialEq)]
+#[derive(
+    Archive, Copy, Clone, CommandOption, CreateOption, Debug, Deserialize, Eq, PartialEq, Serialize,
+)]
 pub enum ProfileKind {
     #[option(name = "Compact", value = "compact")]
     Compact,
@@ -83,6 +88,7 @@ impl<'m> Profile<'m> {
             name,
             embed: None,
             discord,
+            account: None,
         })
     }
 }
@@ -170,13 +176,32 @@ async fn slash_profile(mut command: InteractionCommand) -> Result<()> {
     profile((&mut command).into(), args).await
 }
 
+#[user_command(name = "osu! profile")]
+async fn user_profile(mut command: InteractionCommand) -> Result<()> {
+    let Some(target) = command.target_user() else {
+        let _ = command.error(Issue::General).await;
+
+        return Err(eyre!("Missing target user"));
+    };
+
+    let args = Profile {
+        mode: None,
+        name: None,
+        embed: None,
+        discord: Some(target),
+        account: None,
+    };
+
+    profile((&mut command).into(), args).await
+}
+
 async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
     let owner = orig.user_id()?;
 
     let config = match Context::user_config().with_osu_id(owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -185,6 +210,7 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
     let mode = args
         .mode
         .map(GameMode::from)
+        .or(orig.channel_mode().await)
         .or(config.mode)
         .unwrap_or(GameMode::Osu);
 
@@ -206,7 +232,7 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
 
     let (user_id, no_user_specified) = match user_id!(orig, args) {
         Some(user_id) => (user_id, false),
-        None => match config.osu {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => (UserId::Id(user_id), true),
             None => return require_link(&orig).await,
         },
@@ -223,7 +249,7 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -9,7 +9,6 @@ use std::{
 use bathbot_macros::command;
 use bathbot_model::{command_fields::GameModeOption, rosu_v2::user::User, Countries};
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     matcher,
     numbers::WithComma,
     osu::{approx_more_pp, pp_missing, ExtractablePp, PpListUtil},
@@ -21,7 +20,10 @@ use rosu_v2::prelude::{CountryCode, OsuError, Score, UserId, Username};
 use super::{RankPp, RankValue};
 use crate::{
     commands::osu::user_not_found,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     embeds::PersonalBestIndexFormatter,
     manager::redis::{
         osu::{UserArgs, UserArgsSlim},
@@ -77,7 +79,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -111,7 +113,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
                     return orig.error(content).await;
                 }
                 Err(err) => {
-                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let _ = orig.error(Issue::OsuApi).await;
 
                     return Err(Report::new(err).wrap_err("Failed to get target user"));
                 }
@@ -147,7 +149,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
                     return orig.error(content).await;
                 }
                 Err(err) => {
-                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let _ = orig.error(Issue::OsuApi).await;
 
                     return Err(Report::new(err).wrap_err("Failed to get user"));
                 }
@@ -220,7 +222,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
             let required_pp = match Context::approx().pp(rank, mode).await {
                 Ok(pp) => pp,
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error(Issue::General).await;
 
                     return Err(err);
                 }
@@ -253,7 +255,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
         match scores_fut.await {
             Ok(scores) => (!scores.is_empty()).then_some(scores),
             Err(err) => {
-                let _ = orig.error(OSU_API_ISSUE).await;
+                let _ = orig.error(Issue::OsuApi).await;
                 let err = Report::new(err).wrap_err("Failed to get scores");
 
                 return Err(err);
@@ -579,6 +581,7 @@ impl<'m> RankPp<'m> {
             amount: None,
             country,
             discord,
+            account: None,
         })
     }
 }
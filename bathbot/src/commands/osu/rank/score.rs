@@ -3,11 +3,8 @@ use std::{borrow::Cow, cmp, fmt::Write, iter};
 use bathbot_macros::command;
 use bathbot_model::{command_fields::GameModeOption, rosu_v2::user::User, RespektiveUser};
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
-    matcher,
-    numbers::WithComma,
-    osu::flag_url,
-    AuthorBuilder, CowUtils, EmbedBuilder, MessageBuilder,
+    constants::OSU_BASE, matcher, numbers::WithComma, osu::flag_url, AuthorBuilder, CowUtils,
+    EmbedBuilder, MessageBuilder,
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::{OsuError, UserId, Username};
@@ -15,7 +12,10 @@ use rosu_v2::prelude::{OsuError, UserId, Username};
 use super::{RankScore, RankValue};
 use crate::{
     commands::osu::user_not_found,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
     Context,
@@ -138,6 +138,7 @@ impl<'m> RankScore<'m> {
             mode,
             name,
             discord,
+            account: None,
         })
     }
 }
@@ -164,7 +165,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankScore<'_>) -> Resul
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -221,7 +222,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankScore<'_>) -> Resul
                     return orig.error(content).await;
                 }
                 Err(err) => {
-                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let _ = orig.error(Issue::OsuApi).await;
 
                     return Err(Report::new(err).wrap_err("Failed to get target user"));
                 }
@@ -232,7 +233,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankScore<'_>) -> Resul
             let rank_opt = match user_fut.await {
                 Ok(mut users) => users.next().flatten().and_then(|user| user.rank),
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error(Issue::General).await;
 
                     return Err(err.wrap_err("Failed to get respektive user"));
                 }
@@ -300,7 +301,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankScore<'_>) -> Resul
                 return orig.error(content).await;
             }
             Err(err) => {
-                let _ = orig.error(OSU_API_ISSUE).await;
+                let _ = orig.error(Issue::OsuApi).await;
                 let err = Report::new(err).wrap_err("Failed to get user");
 
                 return Err(err);
@@ -2,14 +2,13 @@ use std::collections::BTreeMap;
 
 use bathbot_macros::command;
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::constants::{GENERAL_ISSUE, OSU_API_ISSUE};
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
 
 use super::RankingCountry;
 use crate::{
     active::{impls::RankingCountriesPagination, ActiveMessages},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     Context,
 };
 
@@ -53,7 +52,7 @@ pub(super) async fn country(orig: CommandOrigin<'_>, args: RankingCountry) -> Re
         None => match Context::user_config().mode(owner).await {
             Ok(mode) => mode.unwrap_or(GameMode::Osu),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err);
             }
@@ -63,7 +62,7 @@ pub(super) async fn country(orig: CommandOrigin<'_>, args: RankingCountry) -> Re
     let mut ranking = match Context::osu().country_rankings(mode).await {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get country ranking");
 
             return Err(err);
@@ -3,10 +3,13 @@ use std::borrow::Cow;
 use bathbot_macros::SlashCommand;
 use bathbot_model::command_fields::GameModeOption;
 use eyre::Result;
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_interactions::command::{CommandInputData, CommandModel, CreateCommand};
 
 pub use self::{countries::*, players::*};
-use crate::util::{interaction::InteractionCommand, InteractionCommandExt};
+use crate::{
+    core::commands::CommandOrigin,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
 
 mod countries;
 mod players;
@@ -79,3 +82,11 @@ async fn slash_ranking(mut command: InteractionCommand) -> Result<()> {
         Ranking::Country(args) => country((&mut command).into(), args).await,
     }
 }
+
+pub async fn scheduled_ranking(orig: CommandOrigin<'_>, data: CommandInputData<'_>) -> Result<()> {
+    match Ranking::from_interaction(data)? {
+        Ranking::Pp(args) => pp(orig, args).await,
+        Ranking::Score(args) => score(orig, args).await,
+        Ranking::Country(args) => country(orig, args).await,
+    }
+}
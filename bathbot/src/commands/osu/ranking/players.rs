@@ -5,14 +5,13 @@ use bathbot_model::{
     command_fields::GameModeOption, rosu_v2::ranking::Rankings, Countries, Either, RankingEntries,
     RankingEntry, RankingKind,
 };
-use bathbot_util::constants::{GENERAL_ISSUE, OSU_API_ISSUE};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{CountryCode, GameMode, OsuResult, Rankings as RosuRankings};
 
 use super::{RankingPp, RankingScore};
 use crate::{
     active::{impls::RankingPagination, ActiveMessages},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
     Context,
@@ -34,8 +33,8 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankingPp<'_>) -> Result<(
     let owner = orig.user_id()?;
 
     let (mode, author_id) = match mode {
-        Some(mode) => match Context::user_config().osu_id(owner).await {
-            Ok(user_id) => (mode.into(), user_id),
+        Some(mode) => match Context::user_config().with_osu_id(owner).await {
+            Ok(config) => (mode.into(), config.osu_for_mode(Some(mode.into()))),
             Err(err) => {
                 warn!(?err, "Failed to get author id");
 
@@ -43,9 +42,13 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankingPp<'_>) -> Result<(
             }
         },
         None => match Context::user_config().with_osu_id(owner).await {
-            Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
+            Ok(config) => {
+                let mode = config.mode.unwrap_or(GameMode::Osu);
+
+                (mode, config.osu_for_mode(Some(mode)))
+            }
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err.wrap_err("Failed to get user config"));
             }
@@ -121,8 +124,8 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankingScore) -> Result
     let owner = orig.user_id()?;
 
     let (mode, osu_id) = match args.mode.map(GameMode::from) {
-        Some(mode) => match Context::user_config().osu_id(owner).await {
-            Ok(user_id) => (mode, user_id),
+        Some(mode) => match Context::user_config().with_osu_id(owner).await {
+            Ok(config) => (mode, config.osu_for_mode(Some(mode))),
             Err(err) => {
                 warn!("{err:?}");
 
@@ -130,9 +133,13 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankingScore) -> Result
             }
         },
         None => match Context::user_config().with_osu_id(owner).await {
-            Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
+            Ok(config) => {
+                let mode = config.mode.unwrap_or(GameMode::Osu);
+
+                (mode, config.osu_for_mode(Some(mode)))
+            }
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err.wrap_err("failed to get user config"));
             }
@@ -177,7 +184,7 @@ async fn ranking(
     let mut ranking = match result {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("failed to get ranking"));
         }
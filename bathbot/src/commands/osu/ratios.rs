@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{constants::OSU_API_ISSUE, matcher, MessageBuilder};
+use bathbot_util::{matcher, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, OsuError},
@@ -13,7 +13,7 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use super::{require_link, user_not_found};
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     embeds::{EmbedData, RatioEmbed},
     manager::redis::osu::UserArgs,
     util::{interaction::InteractionCommand, InteractionCommandExt},
@@ -43,6 +43,8 @@ pub struct Ratios<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[command]
@@ -63,10 +65,12 @@ async fn prefix_ratios(msg: &Message, mut args: Args<'_>) -> Result<()> {
             Some(id) => Ratios {
                 name: None,
                 discord: Some(id),
+                account: None,
             },
             None => Ratios {
                 name: Some(Cow::Borrowed(arg)),
                 discord: None,
+                account: None,
             },
         },
         None => Ratios::default(),
@@ -87,7 +91,7 @@ async fn ratios(orig: CommandOrigin<'_>, args: Ratios<'_>) -> Result<()> {
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu {
+        None => match config.osu_for_mode(Some(GameMode::Mania)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
@@ -120,7 +124,7 @@ async fn ratios(orig: CommandOrigin<'_>, args: Ratios<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -1,9 +1,6 @@
 use bathbot_model::ScoreSlim;
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
 use rosu_v2::{
@@ -14,7 +11,7 @@ use rosu_v2::{
 use super::RecentFix;
 use crate::{
     commands::osu::{require_link, user_not_found, FixEntry, FixScore},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::Issue, Context},
     embeds::{EmbedData, FixScoreEmbed},
     manager::redis::osu::{UserArgs, UserArgsSlim},
     util::osu::IfFc,
@@ -24,20 +21,25 @@ pub(super) async fn fix(orig: CommandOrigin<'_>, args: RecentFix) -> Result<()>
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
+    let mode = match args
+        .mode
+        .map(GameMode::from)
+        .or(orig.channel_mode().await)
+        .or(config.mode)
+    {
+        None => GameMode::Osu,
+        Some(GameMode::Mania) => return orig.error("Can't fix mania scores \\:(").await,
+        Some(mode) => mode,
+    };
+
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
-        None => GameMode::Osu,
-        Some(GameMode::Mania) => return orig.error("Can't fix mania scores \\:(").await,
-        Some(mode) => mode,
-    };
-
     let legacy_scores = match config.score_data {
         Some(score_data) => score_data.is_legacy(),
         None => match orig.guild_id() {
@@ -82,7 +84,7 @@ pub(super) async fn fix(orig: CommandOrigin<'_>, args: RecentFix) -> Result<()>
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -122,12 +124,12 @@ pub(super) async fn fix(orig: CommandOrigin<'_>, args: RecentFix) -> Result<()>
             match tokio::join!(map_fut, best_fut) {
                 (Ok(map), Ok(best)) => (score, map, best),
                 (Err(err), _) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error(Issue::General).await;
 
                     return Err(Report::new(err));
                 }
                 (_, Err(err)) => {
-                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let _ = orig.error(Issue::OsuApi).await;
                     let err = Report::new(err).wrap_err("failed to get top scores");
 
                     return Err(err);
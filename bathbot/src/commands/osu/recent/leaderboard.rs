@@ -2,11 +2,7 @@ use std::collections::HashMap;
 
 use bathbot_macros::command;
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::{
-    constants::{AVATAR_URL, GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::ModSelection,
-};
+use bathbot_util::{constants::AVATAR_URL, matcher, osu::ModSelection};
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
 use rosu_v2::{
@@ -20,7 +16,10 @@ use crate::{
     commands::osu::{
         require_link, user_not_found, HasMods, LeaderboardScore, LeaderboardUserScore, ModsResult,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::{redis::osu::UserArgs, Mods},
     Context,
 };
@@ -126,6 +125,7 @@ impl<'m> RecentLeaderboard<'m> {
             sort: None,
             index: num.to_string_opt().map(String::into),
             discord,
+            account: None,
         }
     }
 }
@@ -171,7 +171,7 @@ pub(super) async fn leaderboard(
     let config = match Context::user_config().with_osu_id(owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -180,12 +180,13 @@ pub(super) async fn leaderboard(
     let mode = args
         .mode
         .map(GameMode::from)
+        .or(orig.channel_mode().await)
         .or(config.mode)
         .unwrap_or(GameMode::Osu);
 
     let user_id = if let Some(user_id) = user_id!(orig, args) {
         user_id
-    } else if let Some(user_id) = config.osu {
+    } else if let Some(user_id) = config.osu_for_mode(Some(mode)) {
         UserId::Id(user_id)
     } else {
         return require_link(&orig).await;
@@ -254,7 +255,7 @@ pub(super) async fn leaderboard(
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get scores");
 
             return Err(err);
@@ -279,7 +280,7 @@ pub(super) async fn leaderboard(
 
     let user_score_fut = get_user_score(
         map_id,
-        config.osu,
+        config.osu_for_mode(Some(mode)),
         mode,
         specify_mods.clone(),
         legacy_scores,
@@ -291,7 +292,7 @@ pub(super) async fn leaderboard(
     let map = match map_res {
         Ok(map) => map,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(Report::new(err));
         }
@@ -313,7 +314,7 @@ pub(super) async fn leaderboard(
             })
             .collect(),
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
 
             return Err(err.wrap_err("Failed to get scores"));
         }
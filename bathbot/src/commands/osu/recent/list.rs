@@ -11,12 +11,7 @@ use bathbot_model::{
     ScoreSlim,
 };
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::ModSelection,
-    CowUtils, IntHasher,
-};
+use bathbot_util::{matcher, osu::ModSelection, CowUtils, IntHasher};
 use eyre::{Report, Result};
 use rosu_pp::any::DifficultyAttributes;
 use rosu_v2::{
@@ -28,7 +23,10 @@ use super::{RecentList, RecentListUnique};
 use crate::{
     active::{impls::RecentListPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found, HasMods, ModsResult, ScoreOrder},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::{redis::osu::UserArgs, Mods, OsuMap},
     util::{
         query::{IFilterCriteria, RegularCriteria, Searchable},
@@ -245,6 +243,7 @@ impl<'m> RecentList<'m> {
     fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Result<Self, Cow<'static, str>> {
         let mut name = None;
         let mut discord = None;
+        let mut account = None;
         let mut grade = None;
         let mut passes = None;
 
@@ -278,10 +277,11 @@ impl<'m> RecentList<'m> {
                         Ok(grade_) => grade = Some(grade_),
                         Err(content) => return Err(content.into()),
                     },
+                    "account" => account = Some(value.to_owned().into()),
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `grade` or `pass`."
+                            Available options are: `grade`, `pass`, or `account`."
                         );
 
                         return Err(content.into());
@@ -308,6 +308,7 @@ impl<'m> RecentList<'m> {
             mods: None,
             unique: None,
             discord,
+            account,
         })
     }
 }
@@ -329,19 +330,24 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
+    let mode = match args
+        .mode
+        .map(GameMode::from)
+        .or(orig.channel_mode().await)
+        .or(config.mode)
+    {
+        None => GameMode::Osu,
+        Some(mode) => mode,
+    };
+
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
-        None => GameMode::Osu,
-        Some(mode) => mode,
-    };
-
     let legacy_scores = match config.score_data {
         Some(score_data) => score_data.is_legacy(),
         None => match orig.guild_id() {
@@ -401,7 +407,7 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -411,7 +417,7 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
     let (entries, maps) = match process_scores(scores, &args, mode, mods.as_ref()).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to process scores"));
         }
@@ -77,6 +77,8 @@ pub struct RecentScore<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandModel, CreateCommand, HasMods, HasName)]
@@ -117,6 +119,8 @@ pub struct RecentBest {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
     #[command(desc = "Reverse the resulting score list")]
     reverse: Option<bool>,
     #[command(
@@ -153,6 +157,7 @@ impl<'a> TryFrom<RecentBest> for TopArgs<'a> {
         Ok(Self {
             name: args.name.map(Cow::Owned),
             discord: args.discord,
+            account: args.account.map(Cow::Owned),
             mode: args.mode.map(GameMode::from),
             mods,
             min_acc: None,
@@ -209,6 +214,8 @@ pub struct RecentLeaderboard<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandModel, CreateCommand, HasMods, HasName)]
@@ -253,6 +260,8 @@ pub struct RecentList<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(Copy, Clone, CreateOption, CommandOption)]
@@ -292,6 +301,8 @@ pub struct RecentFix {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption)]
@@ -354,6 +365,8 @@ pub struct Rb {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
     #[command(desc = "Reverse the resulting score list")]
     reverse: Option<bool>,
     #[command(
@@ -386,6 +399,7 @@ impl From<Rb> for RecentBest {
             mods: args.mods,
             index: args.index,
             discord: args.discord,
+            account: args.account,
             reverse: args.reverse,
             query: args.query,
             grade: args.grade,
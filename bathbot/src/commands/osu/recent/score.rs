@@ -1,15 +1,12 @@
 use std::{borrow::Cow, mem, sync::Arc};
 
-use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_macros::{command, user_command, HasName, SlashCommand};
 use bathbot_model::{
     command_fields::{GameModeOption, GradeOption},
     embed_builder::SettingsImage,
 };
 use bathbot_psql::model::configs::{GuildConfig, Retries, ScoreData};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher, CowUtils, MessageOrigin,
-};
+use bathbot_util::{matcher, CowUtils, MessageOrigin};
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
 use rosu_v2::{
@@ -32,7 +29,10 @@ use crate::{
         osu::{map_strain_graph, require_link, user_not_found},
         utility::{MissAnalyzerCheck, ScoreEmbedDataWrap},
     },
-    core::commands::{interaction::InteractionCommands, prefix::Args, CommandOrigin},
+    core::{
+        commands::{interaction::InteractionCommands, prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::redis::osu::{UserArgs, UserArgsSlim},
     util::{interaction::InteractionCommand, ChannelExt, CheckPermissions, InteractionCommandExt},
     Context,
@@ -270,6 +270,7 @@ impl<'m> RecentScore<'m> {
     fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Result<Self, Cow<'static, str>> {
         let mut name = None;
         let mut discord = None;
+        let mut account = None;
         let mut grade = None;
         let mut passes = None;
         let num = args.num;
@@ -304,10 +305,11 @@ impl<'m> RecentScore<'m> {
                         Ok(grade_) => grade = Some(grade_),
                         Err(content) => return Err(content.into()),
                     },
+                    "account" => account = Some(value.to_owned().into()),
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `grade` or `pass`."
+                            Available options are: `grade`, `pass`, or `account`."
                         );
 
                         return Err(content.into());
@@ -331,6 +333,7 @@ impl<'m> RecentScore<'m> {
             grade,
             passes,
             discord,
+            account,
         })
     }
 }
@@ -346,7 +349,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
     let config = match user_config_res {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -361,12 +364,13 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
     let mode = args
         .mode
         .map(GameMode::from)
+        .or(orig.channel_mode().await)
         .or(config.mode)
         .unwrap_or(GameMode::Osu);
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
@@ -429,7 +433,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -566,7 +570,10 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         (Some(false), _) => false,
     };
 
-    let (settings, missing_settings) = match config.score_embed {
+    let settings_fut =
+        Context::guild_config().score_embed(orig.guild_id(), orig.channel_id(), config.score_embed);
+
+    let (settings, missing_settings) = match settings_fut.await {
         Some(settings) => (settings, false),
         None => (Default::default(), true),
     };
@@ -850,6 +857,8 @@ pub struct Rs<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 impl<'a> From<Rs<'a>> for RecentScore<'a> {
@@ -865,6 +874,27 @@ async fn slash_rs(mut command: InteractionCommand) -> Result<()> {
     score((&mut command).into(), args.into()).await
 }
 
+#[user_command(name = "Recent score")]
+async fn user_recent(mut command: InteractionCommand) -> Result<()> {
+    let Some(target) = command.target_user() else {
+        let _ = command.error(Issue::General).await;
+
+        return Err(eyre!("Missing target user"));
+    };
+
+    let args = RecentScore {
+        mode: None,
+        name: None,
+        index: None,
+        grade: None,
+        passes: None,
+        discord: Some(target),
+        account: None,
+    };
+
+    score((&mut command).into(), args).await
+}
+
 #[derive(Default)]
 struct GuildValues {
     retries: Option<Retries>,
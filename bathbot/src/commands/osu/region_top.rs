@@ -3,7 +3,7 @@ use std::{borrow::Cow, cmp::Reverse, collections::HashMap, convert::identity, fm
 use bathbot_macros::{HasMods, SlashCommand};
 use bathbot_model::{command_fields::GameModeOption, Countries};
 use bathbot_psql::model::osu::{DbScoreBeatmap, DbScoreBeatmapset, DbTopScore, DbTopScores};
-use bathbot_util::{constants::GENERAL_ISSUE, osu::ModSelection, CowUtils, IntHasher};
+use bathbot_util::{osu::ModSelection, CowUtils, IntHasher};
 use compact_str::CompactString;
 use eyre::Result;
 use rkyv::collections::ArchivedHashMap;
@@ -15,7 +15,7 @@ use twilight_model::application::command::{CommandOptionChoice, CommandOptionCho
 use crate::{
     active::{impls::RegionTopPagination, ActiveMessages},
     commands::osu::{HasMods, ModsResult, ScoresOrder},
-    core::Context,
+    core::{locale::Issue, Context},
     manager::redis::RedisData,
     util::{
         interaction::InteractionCommand,
@@ -190,7 +190,7 @@ async fn regiontop_global(command: &mut InteractionCommand, args: RegionTopArgs)
     {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -214,7 +214,7 @@ async fn regiontop_country(
     {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -234,7 +234,7 @@ async fn regiontop_region(
     let user_ids = match Context::client().get_region_user_ids(&region.code).await {
         Ok(user_ids) => user_ids,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -246,7 +246,7 @@ async fn regiontop_region(
     let scores = match scores_fut.await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
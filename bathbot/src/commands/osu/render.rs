@@ -3,11 +3,8 @@ use std::{
     time::{Duration, Instant},
 };
 
-use bathbot_macros::SlashCommand;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, ORDR_ISSUE, OSU_API_ISSUE},
-    EmbedBuilder, MessageBuilder,
-};
+use bathbot_macros::{msg_command, SlashCommand};
+use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result, WrapErr};
 use rosu_render::{
     client::error::{
@@ -27,7 +24,7 @@ use crate::{
         impls::{CachedRender, CachedRenderData, RenderSettingsActive, SettingsImport},
         ActiveMessages,
     },
-    core::{buckets::BucketName, commands::OwnedCommandOrigin, Context},
+    core::{buckets::BucketName, commands::OwnedCommandOrigin, locale::Issue, Context},
     manager::{ReplayScore, ReplaySettings},
     tracking::OrdrReceivers,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
@@ -121,10 +118,49 @@ pub async fn slash_render(mut command: InteractionCommand) -> Result<()> {
     }
 }
 
+#[msg_command(name = "Render replay", flags(SKIP_DEFER))]
+async fn msg_render(command: InteractionCommand) -> Result<()> {
+    if Context::ordr().is_none() {
+        command
+            .error_callback("Rendering is currently unavailable")
+            .await?;
+
+        return Ok(());
+    };
+
+    let Some(msg) = command.target_message() else {
+        let _ = command.error_callback(Issue::General).await;
+
+        return Err(eyre!("Missing resolved message"));
+    };
+
+    let replay = msg
+        .attachments
+        .iter()
+        .find(|attachment| attachment.filename.ends_with(".osr"))
+        .cloned();
+
+    if let Some(replay) = replay {
+        return render_replay(command, RenderReplay { replay }).await;
+    }
+
+    match Context::find_score_id_in_msg(msg) {
+        Some((_, score_id)) => render_score(command, RenderScore { score_id }).await,
+        None => {
+            let content = "Could not find a .osr file or score url in this message or its embeds";
+            command.error_callback(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
 async fn render_replay(command: InteractionCommand, replay: RenderReplay) -> Result<()> {
     let owner = command.user_id()?;
 
-    if let Some(cooldown) = Context::check_ratelimit(owner, BucketName::Render) {
+    if let Some(cooldown) =
+        Context::check_ratelimit(owner, command.guild_id, BucketName::Render).await
+    {
         trace!("Ratelimiting user {owner} on bucket `Render` for {cooldown} seconds");
 
         let content = format!("Command on cooldown, try again in {cooldown} seconds");
@@ -148,7 +184,7 @@ async fn render_replay(command: InteractionCommand, replay: RenderReplay) -> Res
     let settings = match Context::replay().get_settings(owner).await {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -202,7 +238,7 @@ async fn render_replay(command: InteractionCommand, replay: RenderReplay) -> Res
                     Ok(())
                 }
                 _ => {
-                    let _ = command.error(ORDR_ISSUE).await;
+                    let _ = command.error(Issue::Ordr).await;
 
                     Err(Report::new(err).wrap_err("Failed to commission render"))
                 }
@@ -238,7 +274,9 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
         Err(err) => warn!(?err),
     }
 
-    if let Some(cooldown) = Context::check_ratelimit(owner, BucketName::Render) {
+    if let Some(cooldown) =
+        Context::check_ratelimit(owner, command.guild_id, BucketName::Render).await
+    {
         trace!("Ratelimiting user {owner} on bucket `Render` for {cooldown} seconds");
 
         let content = format!("Command on cooldown, try again in {cooldown} seconds");
@@ -259,7 +297,7 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(OSU_API_ISSUE).await;
+            let _ = command.error(Issue::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get score"));
         }
@@ -304,7 +342,7 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to get replay"));
         }
@@ -313,7 +351,7 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
     let settings = match settings_res {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -343,7 +381,7 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
     let render = match render_fut.await {
         Ok(render) => render,
         Err(err) => {
-            let _ = command.error(ORDR_ISSUE).await;
+            let _ = command.error(Issue::Ordr).await;
 
             return Err(Report::new(err).wrap_err("Failed to commission render"));
         }
@@ -618,7 +656,7 @@ async fn render_settings_modify(command: &mut InteractionCommand) -> Result<()>
     let settings = match Context::replay().get_settings(owner).await {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -653,14 +691,14 @@ async fn render_settings_copy(
     let settings = match replay_manager.get_settings(args.user).await {
         Ok(settings) => settings,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
     };
 
     if let Err(err) = replay_manager.set_settings(owner, &settings).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error(Issue::General).await;
 
         return Err(err);
     }
@@ -685,7 +723,7 @@ async fn render_settings_default(command: &mut InteractionCommand) -> Result<()>
     let settings = ReplaySettings::default();
 
     if let Err(err) = replay_manager.set_settings(owner, &settings).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error(Issue::General).await;
 
         return Err(err);
     }
@@ -2,7 +2,6 @@ use std::{borrow::Cow, fmt::Write};
 
 use bathbot_model::Countries;
 use bathbot_util::{
-    constants::GENERAL_ISSUE,
     matcher,
     osu::{MapIdType, ModSelection},
     CowUtils, MessageBuilder,
@@ -18,7 +17,7 @@ use crate::{
         compare::{slash_compare_score, ScoreOrder},
         CompareScoreAutocomplete, HasMods, ModsResult,
     },
-    core::Context,
+    core::{locale::Issue, Context},
     util::{
         interaction::InteractionCommand,
         query::{FilterCriteria, IFilterCriteria, ScoresCriteria},
@@ -77,6 +76,7 @@ pub async fn map_scores(mut command: InteractionCommand, args: MapScores) -> Res
             mods: mods.map(Cow::Owned),
             index,
             discord: None,
+            account: None,
         };
 
         return slash_compare_score(&mut command, args).await;
@@ -184,7 +184,7 @@ pub async fn map_scores(mut command: InteractionCommand, args: MapScores) -> Res
     let members: Vec<_> = match members_res {
         Ok(members) => members.into_iter().map(|id| id as i64).collect(),
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -210,7 +210,7 @@ pub async fn map_scores(mut command: InteractionCommand, args: MapScores) -> Res
     let mut scores = match scores_fut.await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -206,6 +206,8 @@ pub struct UserScores {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
 }
 
 #[derive(CreateCommand, CommandModel, HasMods)]
@@ -1,11 +1,7 @@
 use std::{borrow::Cow, fmt::Write};
 
 use bathbot_model::Countries;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    osu::ModSelection,
-    CowUtils,
-};
+use bathbot_util::{osu::ModSelection, CowUtils};
 use eyre::{Report, Result};
 use rosu_v2::{
     model::GameMode,
@@ -17,7 +13,7 @@ use super::{get_mode, process_scores, separate_content, MapStatus, ServerScores}
 use crate::{
     active::{impls::ScoresServerPagination, ActiveMessages},
     commands::osu::{user_not_found, HasMods, ModsResult, ScoresOrder},
-    core::Context,
+    core::{locale::Issue, Context},
     manager::redis::osu::UserArgs,
     util::{
         interaction::InteractionCommand,
@@ -81,7 +77,7 @@ pub async fn server_scores(mut command: InteractionCommand, args: ServerScores)
     let members: Vec<_> = match members_res {
         Ok(members) => members.into_iter().map(|id| id as i64).collect(),
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -107,7 +103,7 @@ pub async fn server_scores(mut command: InteractionCommand, args: ServerScores)
     let mut scores = match scores_fut.await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -124,7 +120,7 @@ pub async fn server_scores(mut command: InteractionCommand, args: ServerScores)
                 return Ok(());
             }
             UserArgs::Err(err) => {
-                let _ = command.error(OSU_API_ISSUE).await;
+                let _ = command.error(Issue::OsuApi).await;
 
                 return Err(Report::new(err).wrap_err("Failed to get mapper"));
             }
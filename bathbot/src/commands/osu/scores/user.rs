@@ -1,10 +1,7 @@
 use std::fmt::Write;
 
 use bathbot_model::rosu_v2::user::User;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    osu::ModSelection,
-};
+use bathbot_util::osu::ModSelection;
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, Grade, OsuError},
@@ -15,7 +12,7 @@ use super::{process_scores, separate_content, MapStatus, ScoresOrder, UserScores
 use crate::{
     active::{impls::ScoresUserPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found, HasMods, ModsResult},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::Issue, Context},
     manager::redis::{osu::UserArgs, RedisData},
     util::{
         interaction::InteractionCommand,
@@ -48,7 +45,7 @@ pub async fn user_scores(mut command: InteractionCommand, args: UserScores) -> R
     let user_id = {
         let orig = CommandOrigin::from(&mut command);
 
-        match user_id!(orig, args).or_else(|| config.osu.map(UserId::Id)) {
+        match user_id!(orig, args).or_else(|| config.osu_for_mode(mode).map(UserId::Id)) {
             Some(user_id) => user_id,
             None => return require_link(&orig).await,
         }
@@ -65,7 +62,7 @@ pub async fn user_scores(mut command: InteractionCommand, args: UserScores) -> R
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(OSU_API_ISSUE).await;
+            let _ = command.error(Issue::OsuApi).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -80,7 +77,7 @@ pub async fn user_scores(mut command: InteractionCommand, args: UserScores) -> R
     let mut scores = match scores_fut.await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -97,7 +94,7 @@ pub async fn user_scores(mut command: InteractionCommand, args: UserScores) -> R
                 return Ok(());
             }
             UserArgs::Err(err) => {
-                let _ = command.error(OSU_API_ISSUE).await;
+                let _ = command.error(Issue::OsuApi).await;
 
                 return Err(Report::new(err).wrap_err("Failed to get mapper"));
             }
@@ -2,15 +2,17 @@ use std::borrow::Cow;
 
 use bathbot_macros::SlashCommand;
 use bathbot_model::{Countries, RankingKind, UserModeStatsColumn, UserStatsColumn, UserStatsKind};
-use bathbot_util::constants::GENERAL_ISSUE;
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_interactions::command::{CommandInputData, CommandModel, CreateCommand};
 
 use crate::{
     active::{impls::RankingPagination, ActiveMessages},
-    core::commands::interaction::InteractionCommands,
-    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    core::{
+        commands::{interaction::InteractionCommands, CommandOrigin},
+        locale::Issue,
+    },
+    util::{interaction::InteractionCommand, InteractionCommandExt},
     Context,
 };
 
@@ -123,7 +125,7 @@ pub struct ServerLeaderboardMania {
 }
 
 async fn country_code<'a>(
-    command: &InteractionCommand,
+    orig: &CommandOrigin<'_>,
     country: &'a str,
 ) -> Result<Option<Cow<'a, str>>> {
     match Countries::name(country).to_code() {
@@ -133,7 +135,7 @@ async fn country_code<'a>(
             let content =
                 format!("Looks like `{country}` is neither a country name nor a country code");
 
-            command.error(content).await?;
+            orig.error(content).await?;
 
             Ok(None)
         }
@@ -143,14 +145,27 @@ async fn country_code<'a>(
 async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()> {
     let args = ServerLeaderboard::from_interaction(command.input_data())?;
 
-    let owner = command.user_id()?;
-    let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
+    serverleaderboard((&mut command).into(), args).await
+}
+
+pub async fn scheduled_serverleaderboard(
+    orig: CommandOrigin<'_>,
+    data: CommandInputData<'_>,
+) -> Result<()> {
+    let args = ServerLeaderboard::from_interaction(data)?;
+
+    serverleaderboard(orig, args).await
+}
+
+async fn serverleaderboard(orig: CommandOrigin<'_>, args: ServerLeaderboard) -> Result<()> {
+    let owner = orig.user_id()?;
+    let guild_id = orig.guild_id().unwrap(); // command is only processed in guilds
     let cache = Context::cache();
 
     let members: Vec<_> = match cache.members(guild_id).await {
         Ok(members) => members.into_iter().map(|id| id as i64).collect(),
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -168,7 +183,7 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
     let ((author_name_res, entries_res), kind) = match &args {
         ServerLeaderboard::AllModes(args) => {
             let country_code = match args.country.as_deref() {
-                Some(country) => match country_code(&command, country).await? {
+                Some(country) => match country_code(&orig, country).await? {
                     code @ Some(_) => code,
                     None => return Ok(()),
                 },
@@ -187,7 +202,7 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
         }
         ServerLeaderboard::Osu(args) => {
             let country_code = match args.country.as_deref() {
-                Some(country) => match country_code(&command, country).await? {
+                Some(country) => match country_code(&orig, country).await? {
                     code @ Some(_) => code,
                     None => return Ok(()),
                 },
@@ -213,7 +228,7 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
         }
         ServerLeaderboard::Taiko(args) => {
             let country_code = match args.country.as_deref() {
-                Some(country) => match country_code(&command, country).await? {
+                Some(country) => match country_code(&orig, country).await? {
                     code @ Some(_) => code,
                     None => return Ok(()),
                 },
@@ -239,7 +254,7 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
         }
         ServerLeaderboard::Catch(args) => {
             let country_code = match args.country.as_deref() {
-                Some(country) => match country_code(&command, country).await? {
+                Some(country) => match country_code(&orig, country).await? {
                     code @ Some(_) => code,
                     None => return Ok(()),
                 },
@@ -265,7 +280,7 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
         }
         ServerLeaderboard::Mania(args) => {
             let country_code = match args.country.as_deref() {
-                Some(country) => match country_code(&command, country).await? {
+                Some(country) => match country_code(&orig, country).await? {
                     code @ Some(_) => code,
                     None => return Ok(()),
                 },
@@ -294,7 +309,7 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
     let entries = match entries_res {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -314,8 +329,8 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
             "No user data found for members of this server from that country".to_owned()
         } else {
             let link = InteractionCommands::get_command("link").map_or_else(
-                || "`/link`".to_owned(),
-                |cmd| cmd.mention("link").to_string(),
+                || "`/link add`".to_owned(),
+                |cmd| cmd.mention("link add").to_string(),
             );
 
             let profile = InteractionCommands::get_command("profile").map_or_else(
@@ -334,7 +349,7 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
             )
         };
 
-        command.error(content).await?;
+        orig.error(content).await?;
 
         return Ok(());
     }
@@ -353,6 +368,6 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
-        .begin(&mut command)
+        .begin(orig)
         .await
 }
@@ -5,7 +5,7 @@ use std::borrow::Cow;
 
 use bathbot_macros::{command, HasMods, SlashCommand};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::{constants::GENERAL_ISSUE, matcher, osu::MapIdType};
+use bathbot_util::{matcher, osu::MapIdType};
 use eyre::Result;
 use rosu_v2::prelude::{GameMode, GameModsIntermode};
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -27,6 +27,7 @@ use crate::{
     commands::osu::parsed_map::AttachedSimulateMap,
     core::{
         commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
         Context,
     },
     manager::MapError,
@@ -376,7 +377,7 @@ async fn prepare_map(
             return orig.error(content).await.map(|_| None);
         }
         Err(MapError::Report(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
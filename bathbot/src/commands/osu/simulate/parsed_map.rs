@@ -1,10 +1,9 @@
-use bathbot_util::constants::GENERAL_ISSUE;
 use eyre::{Report, Result};
 use rosu_pp::{Beatmap, Difficulty};
 use rosu_v2::prelude::GameMode;
 use twilight_model::channel::Attachment;
 
-use crate::core::{commands::CommandOrigin, Context};
+use crate::core::{commands::CommandOrigin, locale::Issue, Context};
 
 pub struct AttachedSimulateMap {
     pub pp_map: Beatmap,
@@ -28,7 +27,7 @@ impl AttachedSimulateMap {
         let bytes = match Context::client().get_discord_attachment(&attachment).await {
             Ok(bytes) => bytes,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err.wrap_err("Failed to download attachment"));
             }
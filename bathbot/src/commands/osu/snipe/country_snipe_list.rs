@@ -2,10 +2,7 @@ use std::borrow::Cow;
 
 use bathbot_macros::command;
 use bathbot_model::{Countries, SnipeCountryListOrder};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    CowUtils,
-};
+use bathbot_util::CowUtils;
 use eyre::{Report, Result};
 use rosu_v2::{
     model::GameMode,
@@ -17,7 +14,10 @@ use super::{SnipeCountryList, SnipeGameMode};
 use crate::{
     active::{impls::SnipeCountryListPagination, ActiveMessages},
     commands::osu::user_not_found,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
     Context,
@@ -138,7 +138,7 @@ pub(super) async fn country_list(
                 None => config.mode.unwrap_or(GameMode::Osu),
             };
 
-            match config.osu {
+            match config.osu_for_mode(Some(mode)) {
                 Some(user_id) => {
                     let user_args = UserArgs::user_id(user_id, mode);
 
@@ -150,7 +150,7 @@ pub(super) async fn country_list(
                             return orig.error(content).await;
                         }
                         Err(err) => {
-                            let _ = orig.error(OSU_API_ISSUE).await;
+                            let _ = orig.error(Issue::OsuApi).await;
                             let err = Report::new(err).wrap_err("failed to get user");
 
                             return Err(err);
@@ -208,7 +208,7 @@ pub(super) async fn country_list(
     {
         Ok(players) => players,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("failed to get snipe country"));
         }
@@ -2,10 +2,7 @@ use std::{borrow::Cow, cmp::Ordering::Equal};
 
 use bathbot_macros::command;
 use bathbot_model::{Countries, SnipeCountryListOrder, SnipeCountryPlayer};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
-};
+use bathbot_util::MessageBuilder;
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::prelude::*;
 use plotters_skia::SkiaBackend;
@@ -20,7 +17,7 @@ use twilight_model::guild::Permissions;
 use super::{SnipeCountryStats, SnipeGameMode};
 use crate::{
     commands::osu::user_not_found,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     embeds::{CountrySnipeStatsEmbed, EmbedData},
     manager::redis::{osu::UserArgs, RedisData},
     Context,
@@ -108,19 +105,25 @@ pub(super) async fn country_stats(
     orig: CommandOrigin<'_>,
     args: SnipeCountryStats<'_>,
 ) -> Result<()> {
-    let config = match Context::user_config().with_osu_id(orig.user_id()?).await {
-        Ok(config) => config,
-        Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+    // The user config is only needed if the mode or country is missing
+    let config = if args.mode.is_some() && args.country.is_some() {
+        None
+    } else {
+        match Context::user_config().with_osu_id(orig.user_id()?).await {
+            Ok(config) => Some(config),
+            Err(err) => {
+                let _ = orig.error(Issue::General).await;
 
-            return Err(err.wrap_err("Failed to get user config"));
+                return Err(err.wrap_err("Failed to get user config"));
+            }
         }
     };
 
     let mode = args
         .mode
         .map(GameMode::from)
-        .or(config.mode)
+        .or(orig.channel_mode().await)
+        .or(config.as_ref().and_then(|config| config.mode))
         .unwrap_or(GameMode::Osu);
 
     let country_code = match args.country {
@@ -134,7 +137,10 @@ pub(super) async fn country_stats(
                 return orig.error(content).await;
             }
         },
-        None => match config.osu {
+        None => match config
+            .as_ref()
+            .and_then(|config| config.osu_for_mode(Some(mode)))
+        {
             Some(user_id) => {
                 let user_args = UserArgs::user_id(user_id, mode);
 
@@ -146,7 +152,7 @@ pub(super) async fn country_stats(
                         return orig.error(content).await;
                     }
                     Err(err) => {
-                        let _ = orig.error(OSU_API_ISSUE).await;
+                        let _ = orig.error(Issue::OsuApi).await;
                         let err = Report::new(err).wrap_err("Failed to get user");
 
                         return Err(err);
@@ -186,7 +192,7 @@ pub(super) async fn country_stats(
         match tokio::try_join!(players_fut, stats_fut,) {
             Ok((players, statistics)) => (players, statistics),
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err.wrap_err("failed to get country data"));
             }
@@ -345,3 +351,67 @@ fn graphs(players: &[SnipeCountryPlayer]) -> Result<Vec<u8>> {
 
     Ok(png_bytes)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fake;
+
+    #[test]
+    fn country_statistics() {
+        fake::client()
+            .get(
+                "https://api.huismetbenen.nl/country/all?only_with_data=true",
+                r#"[{"country_code": "be"}]"#,
+            )
+            .get(
+                "https://api.huismetbenen.nl/rankings/be/pp/weighted",
+                r#"[{
+                    "username": "player",
+                    "user_id": 3,
+                    "average_pp": 250.5,
+                    "average_sr": 5.5,
+                    "weighted_pp": 1234.5,
+                    "count_total": 10
+                }, {
+                    "username": "other",
+                    "user_id": 4,
+                    "average_pp": 200.0,
+                    "average_sr": 5.0,
+                    "weighted_pp": 987.6,
+                    "count_total": 8
+                }]"#,
+            )
+            .get(
+                "https://api.huismetbenen.nl/rankings/be/statistics",
+                r#"{
+                    "unplayed_count": 42,
+                    "most_gained_count": 7,
+                    "most_gained_player_id": 3,
+                    "most_gained_player_name": "gainer",
+                    "most_lost_count": -5,
+                    "most_lost_player_id": 4,
+                    "most_lost_player_name": "loser"
+                }"#,
+            );
+
+        let mut command = fake::command("snipe");
+
+        let args = SnipeCountryStats {
+            mode: Some(SnipeGameMode::Osu),
+            country: Some("BE".into()),
+        };
+
+        fake::run(country_stats((&mut command).into(), args)).unwrap();
+
+        let requests = fake::discord_requests(&command.token);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(&*requests[0].method, "PATCH");
+
+        let body = requests[0].body_str();
+        assert!(body.contains("Belgium's #1 statistics"), "{body}");
+        assert!(body.contains("gainer (+7)"), "{body}");
+        assert!(body.contains("loser (-5)"), "{body}");
+        assert!(body.contains("Unplayed maps: 42"), "{body}");
+    }
+}
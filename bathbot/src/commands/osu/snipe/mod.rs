@@ -141,6 +141,8 @@ pub struct SnipePlayerGain<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandModel, CreateCommand, HasMods, HasName)]
@@ -167,6 +169,8 @@ pub struct SnipePlayerList<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandModel, CreateCommand, Default, HasName)]
@@ -187,6 +191,8 @@ pub struct SnipePlayerLoss<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandModel, CreateCommand, Default, HasName)]
@@ -203,6 +209,8 @@ pub struct SnipePlayerStats<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandModel, CreateCommand, Default, HasName, SlashCommand)]
@@ -223,6 +231,8 @@ pub struct SnipePlayerSniped<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 async fn slash_snipe(mut command: InteractionCommand) -> Result<()> {
@@ -6,12 +6,7 @@ use std::{
 
 use bathbot_macros::command;
 use bathbot_model::SnipeScoreParams;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    osu::ModSelection,
-    CowUtils,
-};
+use bathbot_util::{matcher, osu::ModSelection, CowUtils};
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 
@@ -19,7 +14,10 @@ use super::{SnipeGameMode, SnipePlayerList, SnipePlayerListOrder};
 use crate::{
     active::{impls::SnipePlayerListPagination, ActiveMessages},
     commands::osu::{HasMods, ModsResult},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
     Context,
@@ -146,7 +144,7 @@ pub(super) async fn player_list(orig: CommandOrigin<'_>, args: SnipePlayerList<'
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get user");
 
             return Err(report);
@@ -197,7 +195,7 @@ pub(super) async fn player_list(orig: CommandOrigin<'_>, args: SnipePlayerList<'
             (scores, count)
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("failed to get scores or counts"));
         }
@@ -250,6 +248,7 @@ impl<'m> SnipePlayerList<'m> {
     fn args(args: Args<'m>, mode: GameMode) -> Result<Self, Cow<'static, str>> {
         let mut name = None;
         let mut discord = None;
+        let mut account = None;
         let mut sort = None;
         let mut mods = None;
         let mut reverse = None;
@@ -284,10 +283,11 @@ impl<'m> SnipePlayerList<'m> {
                             return Err(content.into());
                         }
                     },
+                    "account" => account = Some(value.to_owned().into()),
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `sort` or `reverse`."
+                            Available options are: `sort`, `reverse`, or `account`."
                         );
 
                         return Err(content.into());
@@ -309,6 +309,7 @@ impl<'m> SnipePlayerList<'m> {
             sort,
             reverse,
             discord,
+            account,
         })
     }
 }
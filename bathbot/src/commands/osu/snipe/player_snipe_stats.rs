@@ -2,10 +2,7 @@ use std::collections::BTreeMap;
 
 use bathbot_macros::command;
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher, MessageBuilder,
-};
+use bathbot_util::{matcher, MessageBuilder};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::prelude::*;
 use plotters_skia::SkiaBackend;
@@ -17,7 +14,10 @@ use twilight_model::guild::Permissions;
 use super::{SnipeGameMode, SnipePlayerStats};
 use crate::{
     commands::osu::require_link,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     embeds::{EmbedData, PlayerSnipeStatsEmbed},
     manager::redis::{osu::UserArgs, RedisData},
     util::Monthly,
@@ -94,20 +94,21 @@ pub(super) async fn player_stats(
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
+    let mode = args
+        .mode
+        .map(GameMode::from)
+        .or(orig.channel_mode().await)
+        .or(config.mode)
+        .unwrap_or(GameMode::Osu);
+
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
-
     let legacy_scores = match config.score_data {
         Some(score_data) => score_data.is_legacy(),
         None => match orig.guild_id() {
@@ -134,7 +135,7 @@ pub(super) async fn player_stats(
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let report = Report::new(err).wrap_err("failed to get user");
 
             return Err(report);
@@ -192,7 +193,7 @@ pub(super) async fn player_stats(
             return Ok(());
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
@@ -216,12 +217,12 @@ pub(super) async fn player_stats(
         match tokio::join!(score_fut, map_fut) {
             (Ok(score), Ok(map)) => Some((score.score, map)),
             (Err(err), _) => {
-                let _ = orig.error(OSU_API_ISSUE).await;
+                let _ = orig.error(Issue::OsuApi).await;
 
                 return Err(Report::new(err).wrap_err("Failed to get oldest score"));
             }
             (_, Err(err)) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(Report::new(err).wrap_err("Failed to get map of oldest score"));
             }
@@ -401,6 +402,7 @@ impl<'m> SnipePlayerStats<'m> {
             mode: mode.and_then(SnipeGameMode::try_from_mode),
             name,
             discord,
+            account: None,
         }
     }
 }
@@ -2,11 +2,7 @@ use std::ops;
 
 use bathbot_macros::command;
 use bathbot_model::SnipedWeek;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    datetime::DATE_FORMAT,
-    matcher, MessageBuilder,
-};
+use bathbot_util::{datetime::DATE_FORMAT, matcher, MessageBuilder};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::{
     coord::{
@@ -24,7 +20,10 @@ use twilight_model::guild::Permissions;
 
 use super::{SnipeGameMode, SnipePlayerSniped};
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     embeds::{EmbedData, SnipedEmbed},
     manager::redis::{osu::UserArgs, RedisData},
     Context,
@@ -111,7 +110,7 @@ pub(super) async fn player_sniped(
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user");
 
             return Err(err);
@@ -143,7 +142,7 @@ pub(super) async fn player_sniped(
         match tokio::try_join!(sniper_fut, snipee_fut) {
             Ok(tuple) => tuple,
             Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
+                let _ = orig.error(Issue::General).await;
 
                 return Err(err.wrap_err("Failed to get sniper or snipee"));
             }
@@ -455,6 +454,7 @@ impl<'m> SnipePlayerSniped<'m> {
             mode: mode.and_then(SnipeGameMode::try_from_mode),
             name,
             discord,
+            account: None,
         }
     }
 }
@@ -1,10 +1,7 @@
 use std::{cmp::Reverse, collections::HashMap};
 
 use bathbot_macros::command;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher, MessageBuilder,
-};
+use bathbot_util::{matcher, IntHasher, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use time::{Duration, OffsetDateTime};
@@ -12,7 +9,10 @@ use time::{Duration, OffsetDateTime};
 use super::{SnipeGameMode, SnipePlayerGain, SnipePlayerLoss};
 use crate::{
     active::{impls::SnipeDifferencePagination, ActiveMessages},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::redis::{osu::UserArgs, RedisData},
     Context,
 };
@@ -186,7 +186,7 @@ async fn sniped_diff(
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -232,7 +232,7 @@ async fn sniped_diff(
     let mut scores = match scores_fut.await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("failed to get snipes"));
         }
@@ -297,6 +297,7 @@ impl<'m> SnipePlayerGain<'m> {
             mode: mode.and_then(SnipeGameMode::try_from_mode),
             name,
             discord,
+            account: None,
         }
     }
 }
@@ -317,6 +318,7 @@ impl<'m> SnipePlayerLoss<'m> {
             mode: mode.and_then(SnipeGameMode::try_from_mode),
             name,
             discord,
+            account: None,
         }
     }
 }
@@ -3,13 +3,7 @@ use std::{borrow::Cow, fmt::Write};
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_model::{command_fields::GameModeOption, ScoreSlim};
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    numbers::round,
-    osu::ModSelection,
-    CowUtils,
-};
+use bathbot_util::{matcher, numbers::round, osu::ModSelection, CowUtils};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameModIntermode, GameMode, GameMods, GameModsIntermode, OsuError, Score},
@@ -21,7 +15,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 use crate::{
     active::{impls::TopIfPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::{redis::osu::UserArgs, OsuMap},
     util::{
         interaction::InteractionCommand,
@@ -73,6 +70,8 @@ pub struct TopIf<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 #[derive(Copy, Clone, Default, CommandOption, CreateOption, Eq, PartialEq)]
@@ -120,6 +119,7 @@ impl<'m> TopIf<'m> {
             query: None,
             sort: None,
             discord,
+            account: None,
         })
     }
 }
@@ -205,19 +205,24 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
+    let mode = match args
+        .mode
+        .map(GameMode::from)
+        .or(orig.channel_mode().await)
+        .or(config.mode)
+    {
+        None | Some(GameMode::Mania) => GameMode::Osu,
+        Some(mode) => mode,
+    };
+
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
-        None | Some(GameMode::Mania) => GameMode::Osu,
-        Some(mode) => mode,
-    };
-
     if let Err(content) = mods.clone().validate(mode) {
         return orig.error(content).await;
     }
@@ -248,7 +253,7 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -268,7 +273,7 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
     let mut entries = match process_scores(scores, mods, mode, sort, legacy_scores).await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("failed to modify scores"));
         }
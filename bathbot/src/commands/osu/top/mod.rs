@@ -1,25 +1,22 @@
 use std::{borrow::Cow, cmp::Reverse, fmt::Write, mem};
 
-use bathbot_macros::{command, HasMods, HasName, SlashCommand};
+use bathbot_macros::{command, user_command, HasMods, HasName, SlashCommand};
 use bathbot_model::{
     command_fields::{GameModeOption, GradeOption},
     embed_builder::SettingsImage,
 };
 use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    numbers::round,
-    osu::ModSelection,
-    CowUtils,
-};
+use bathbot_util::{matcher, numbers::round, osu::ModSelection, CowUtils};
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
+use rkyv::{Archive, Deserialize, Serialize};
 use rosu_v2::{
     prelude::{GameMode, Grade, OsuError, Score},
     request::UserId,
 };
-use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_interactions::command::{
+    CommandInputData, CommandModel, CommandOption, CreateCommand, CreateOption,
+};
 use twilight_model::{
     guild::Permissions,
     id::{marker::UserMarker, Id},
@@ -35,7 +32,10 @@ use crate::{
     commands::utility::{
         MissAnalyzerCheck, ScoreEmbedDataHalf, ScoreEmbedDataPersonalBest, ScoreEmbedDataWrap,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::redis::osu::UserArgs,
     util::{
         interaction::InteractionCommand,
@@ -81,6 +81,8 @@ pub struct Top {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
     #[command(desc = "Reverse the resulting score list")]
     reverse: Option<bool>,
     #[command(
@@ -104,7 +106,18 @@ pub struct Top {
     size: Option<ListSize>,
 }
 
-#[derive(Copy, Clone, Default, CommandOption, CreateOption, Eq, PartialEq)]
+#[derive(
+    Archive,
+    Copy,
+    Clone,
+    Default,
+    CommandOption,
+    CreateOption,
+    Deserialize,
+    Eq,
+    PartialEq,
+    Serialize,
+)]
 pub enum TopScoreOrder {
     #[option(name = "Accuracy", value = "acc")]
     Acc,
@@ -487,10 +500,52 @@ async fn slash_top(mut command: InteractionCommand) -> Result<()> {
     }
 }
 
+pub async fn scheduled_top(orig: CommandOrigin<'_>, data: CommandInputData<'_>) -> Result<()> {
+    let args = Top::from_interaction(data)?;
+
+    match TopArgs::try_from(args) {
+        Ok(args) => top(orig, args).await,
+        Err(content) => orig.error(content).await,
+    }
+}
+
+#[user_command(name = "Top plays")]
+async fn user_top(mut command: InteractionCommand) -> Result<()> {
+    let Some(target) = command.target_user() else {
+        let _ = command.error(Issue::General).await;
+
+        return Err(eyre!("Missing target user"));
+    };
+
+    let args = TopArgs {
+        name: None,
+        discord: Some(target),
+        account: None,
+        mode: None,
+        mods: None,
+        min_acc: None,
+        max_acc: None,
+        min_combo: None,
+        max_combo: None,
+        grade: None,
+        sort_by: TopScoreOrder::default(),
+        reverse: false,
+        perfect_combo: None,
+        index: None,
+        query: None,
+        size: None,
+        has_dash_r: false,
+        has_dash_p_or_i: false,
+    };
+
+    top((&mut command).into(), args).await
+}
+
 #[derive(HasName)]
 pub struct TopArgs<'a> {
     pub name: Option<Cow<'a, str>>,
     pub discord: Option<Id<UserMarker>>,
+    pub account: Option<Cow<'a, str>>,
     pub mode: Option<GameMode>,
     pub mods: Option<ModSelection>,
     pub min_acc: Option<f32>,
@@ -523,6 +578,7 @@ impl<'m> TopArgs<'m> {
     fn args(mode: Option<GameMode>, args: Args<'m>) -> Result<Self, Cow<'static, str>> {
         let mut name = None;
         let mut discord = None;
+        let mut account = None;
         let mut mods = None;
         let mut acc_min = None;
         let mut acc_max = None;
@@ -638,10 +694,11 @@ impl<'m> TopArgs<'m> {
                             return Err(content.into());
                         }
                     },
+                    "account" => account = Some(value.to_owned().into()),
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `acc`, `combo`, `sort`, `grade`, or `reverse`."
+                            Available options are: `acc`, `combo`, `sort`, `grade`, `reverse`, or `account`."
                         );
 
                         return Err(content.into());
@@ -660,6 +717,7 @@ impl<'m> TopArgs<'m> {
         let args = Self {
             name,
             discord,
+            account,
             mode,
             mods,
             min_acc: acc_min,
@@ -694,6 +752,7 @@ impl TryFrom<Top> for TopArgs<'static> {
         Ok(Self {
             name: args.name.map(Cow::Owned),
             discord: args.discord,
+            account: args.account.map(Cow::Owned),
             mode: args.mode.map(GameMode::from),
             mods,
             min_acc: None,
@@ -716,16 +775,20 @@ impl TryFrom<Top> for TopArgs<'static> {
 pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()> {
     let msg_owner = orig.user_id()?;
 
-    let mut config = match Context::user_config().with_osu_id(msg_owner).await {
+    let config = match Context::user_config().with_osu_id(msg_owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err);
         }
     };
 
-    let mode = args.mode.or(config.mode).unwrap_or(GameMode::Osu);
+    let mode = args
+        .mode
+        .or(orig.channel_mode().await)
+        .or(config.mode)
+        .unwrap_or(GameMode::Osu);
 
     if args.sort_by == TopScoreOrder::Pp && args.has_dash_r {
         let mode_long = mode_long(mode);
@@ -765,7 +828,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match config.osu.take() {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
@@ -802,14 +865,17 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
         }
     };
 
-    let settings = config.score_embed.unwrap_or_default();
+    let settings = Context::guild_config()
+        .score_embed(orig.guild_id(), orig.channel_id(), config.score_embed)
+        .await
+        .unwrap_or_default();
 
     let mut with_render = match (guild_render_button, config.render_button) {
         (None | Some(true), None) => true,
@@ -827,7 +893,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
     let entries = match process_scores(scores, &args, with_render, score_data).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("failed to process scores"));
         }
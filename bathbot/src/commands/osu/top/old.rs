@@ -3,12 +3,7 @@ use std::{borrow::Cow, cmp::Ordering};
 use bathbot_macros::{command, HasMods, HasName, SlashCommand};
 use bathbot_model::ScoreSlim;
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
-    numbers::round,
-    osu::ModSelection,
-};
+use bathbot_util::{constants::GENERAL_ISSUE, matcher, numbers::round, osu::ModSelection};
 use eyre::{Report, Result};
 use rosu_pp_older::*;
 use rosu_v2::{
@@ -23,7 +18,10 @@ use super::TopIfEntry;
 use crate::{
     active::{impls::TopIfPagination, ActiveMessages},
     commands::osu::{require_link, user_not_found, HasMods, ModsResult, TopIfScoreOrder},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     manager::{redis::osu::UserArgs, OsuMap},
     util::{
         interaction::InteractionCommand,
@@ -79,6 +77,8 @@ pub struct TopOldOsu<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
     #[command(
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
@@ -185,6 +185,8 @@ pub struct TopOldTaiko<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
     #[command(
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
@@ -261,6 +263,8 @@ pub struct TopOldCatch<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
     #[command(
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
@@ -332,6 +336,8 @@ pub struct TopOldMania<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
     #[command(
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
@@ -528,6 +534,7 @@ impl<'m> TopOld<'m> {
                     version,
                     name,
                     discord,
+                    account: None,
                     query: None,
                     sort: None,
                     mods: None,
@@ -543,6 +550,7 @@ impl<'m> TopOld<'m> {
                     version,
                     name,
                     discord,
+                    account: None,
                     query: None,
                     sort: None,
                     mods: None,
@@ -558,6 +566,7 @@ impl<'m> TopOld<'m> {
                     version,
                     name,
                     discord,
+                    account: None,
                     query: None,
                     sort: None,
                     mods: None,
@@ -573,6 +582,7 @@ impl<'m> TopOld<'m> {
                     version,
                     name,
                     discord,
+                    account: None,
                     query: None,
                     sort: None,
                     mods: None,
@@ -702,7 +712,17 @@ macro_rules! user_id_ref {
     ($orig:ident, $args:ident) => {
         match crate::commands::osu::HasName::user_id($args) {
             crate::commands::osu::UserIdResult::Id(user_id) => Some(user_id),
-            crate::commands::osu::UserIdResult::None => None,
+            crate::commands::osu::UserIdResult::None => {
+                match crate::commands::osu::HasName::account($args) {
+                    Some(account) => {
+                        match crate::commands::osu::linked_account(&$orig, account).await? {
+                            Some(user_id) => Some(user_id),
+                            None => return Ok(()),
+                        }
+                    }
+                    None => None,
+                }
+            }
             crate::commands::osu::UserIdResult::Future(fut) => match fut.await {
                 crate::commands::osu::UserIdFutureResult::Id(user_id) => Some(user_id),
                 crate::commands::osu::UserIdFutureResult::NotLinked(user_id) => {
@@ -711,7 +731,7 @@ macro_rules! user_id_ref {
                     return $orig.error(content).await;
                 }
                 crate::commands::osu::UserIdFutureResult::Err(err) => {
-                    let content = bathbot_util::constants::GENERAL_ISSUE;
+                    let content = crate::core::locale::Issue::General;
                     let _ = $orig.error(content).await;
 
                     return Err(err);
@@ -744,7 +764,7 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
 
     let user_id = match user_id {
         Some(user_id) => user_id,
-        None => match config.osu {
+        None => match config.osu_for_mode(Some(mode)) {
             Some(user_id) => UserId::Id(user_id),
             None => return require_link(&orig).await,
         },
@@ -776,7 +796,7 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get user or scores");
 
             return Err(err);
@@ -796,7 +816,7 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
     let mut entries = match process_scores(scores, &args).await {
         Ok(scores) => scores,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             return Err(err.wrap_err("failed to process scores"));
         }
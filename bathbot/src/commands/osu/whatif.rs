@@ -3,7 +3,6 @@ use std::{borrow::Cow, iter};
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_util::{
-    constants::OSU_API_ISSUE,
     matcher,
     osu::{approx_more_pp, ExtractablePp, PpListUtil},
     MessageBuilder,
@@ -15,7 +14,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use super::user_not_found;
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
+    },
     embeds::{EmbedData, WhatIfEmbed},
     manager::redis::osu::UserArgs,
     util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
@@ -73,6 +75,8 @@ pub struct WhatIf<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<Cow<'a, str>>,
 }
 
 impl<'m> WhatIf<'m> {
@@ -97,6 +101,7 @@ impl<'m> WhatIf<'m> {
             name,
             count: None,
             discord,
+            account: None,
         })
     }
 }
@@ -217,7 +222,7 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
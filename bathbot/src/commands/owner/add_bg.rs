@@ -14,7 +14,7 @@ use tokio::{
 
 use super::OwnerAddBg;
 use crate::{
-    core::BotConfig,
+    core::{locale::Issue, BotConfig},
     util::{interaction::InteractionCommand, InteractionCommandExt},
     Context,
 };
@@ -67,7 +67,7 @@ pub async fn addbg(command: InteractionCommand, bg: OwnerAddBg) -> Result<()> {
             let mut file = match File::create(&path).await {
                 Ok(file) => file,
                 Err(err) => {
-                    let _ = command.error(GENERAL_ISSUE).await;
+                    let _ = command.error(Issue::General).await;
                     let err = Report::new(err).wrap_err("failed to create file for new bg");
 
                     return Err(err);
@@ -76,7 +76,7 @@ pub async fn addbg(command: InteractionCommand, bg: OwnerAddBg) -> Result<()> {
 
             // Store in file
             if let Err(err) = file.write_all(&content).await {
-                let _ = command.error(GENERAL_ISSUE).await;
+                let _ = command.error(Issue::General).await;
                 let err = Report::new(err).wrap_err("failed writing to bg file");
 
                 return Err(err);
@@ -84,7 +84,7 @@ pub async fn addbg(command: InteractionCommand, bg: OwnerAddBg) -> Result<()> {
             path
         }
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err.wrap_err("failed to get discord attachment"));
         }
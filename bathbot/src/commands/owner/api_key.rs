@@ -0,0 +1,126 @@
+use std::fmt::Write;
+
+use bathbot_server::api::hash_api_key;
+use bathbot_util::MessageBuilder;
+use eyre::Result;
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+
+use super::{OwnerApiKey, OwnerApiKeyCreate, OwnerApiKeyRevoke};
+use crate::{
+    core::{locale::Issue, Context},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+const DEFAULT_RATE_LIMIT: u32 = 60;
+
+pub async fn api_key(command: InteractionCommand, args: OwnerApiKey) -> Result<()> {
+    match args {
+        OwnerApiKey::Create(args) => create(command, args).await,
+        OwnerApiKey::Revoke(args) => revoke(command, args).await,
+        OwnerApiKey::List(_) => list(command).await,
+    }
+}
+
+async fn create(command: InteractionCommand, args: OwnerApiKeyCreate) -> Result<()> {
+    let OwnerApiKeyCreate {
+        name,
+        rate_limit,
+        owner,
+    } = args;
+
+    let rate_limit = rate_limit.map_or(DEFAULT_RATE_LIMIT, |limit| limit as u32);
+
+    let key: String = thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(40)
+        .map(char::from)
+        .collect();
+
+    let insert_fut = Context::psql().insert_api_key(&hash_api_key(&key), &name, owner, rate_limit);
+
+    match insert_fut.await {
+        Ok(true) => {
+            let content = format!(
+                "Created api key `{name}` with {rate_limit} requests per minute:\n\
+                ||`{key}`||\nThe key is only shown once, be sure to store it."
+            );
+
+            let builder = MessageBuilder::new().embed(content);
+            command.callback(builder, true).await?;
+
+            Ok(())
+        }
+        Ok(false) => {
+            let content = format!("There already is an api key with the name `{name}`");
+            command.error_callback(content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            Err(err.wrap_err("Failed to insert api key"))
+        }
+    }
+}
+
+async fn revoke(command: InteractionCommand, args: OwnerApiKeyRevoke) -> Result<()> {
+    match Context::psql().delete_api_key(&args.name).await {
+        Ok(true) => {
+            let content = format!("Revoked api key `{}`", args.name);
+            let builder = MessageBuilder::new().embed(content);
+            command.callback(builder, true).await?;
+
+            Ok(())
+        }
+        Ok(false) => {
+            let content = format!("There is no api key with the name `{}`", args.name);
+            command.error_callback(content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            Err(err.wrap_err("Failed to delete api key"))
+        }
+    }
+}
+
+async fn list(command: InteractionCommand) -> Result<()> {
+    let keys = match Context::psql().select_api_keys().await {
+        Ok(keys) => keys,
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            return Err(err.wrap_err("Failed to get api keys"));
+        }
+    };
+
+    let mut content = String::new();
+
+    if keys.is_empty() {
+        content.push_str("No api keys yet");
+    }
+
+    for key in keys {
+        let _ = write!(
+            content,
+            "`{name}`: {limit}/min, created <t:{created}:d>",
+            name = key.name,
+            limit = key.rate_limit,
+            created = key.created_at.unix_timestamp(),
+        );
+
+        if let Some(owner) = key.owner_id {
+            let _ = write!(content, " for <@{owner}>");
+        }
+
+        content.push('\n');
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, true).await?;
+
+    Ok(())
+}
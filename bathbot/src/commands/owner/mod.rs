@@ -3,9 +3,13 @@ use bathbot_model::command_fields::GameModeOption;
 use eyre::Result;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::Attachment;
+#[cfg(feature = "server")]
+use twilight_model::id::{marker::UserMarker, Id};
 
+#[cfg(feature = "server")]
+use self::api_key::*;
 pub use self::reshard::RESHARD_TX;
-use self::{add_bg::*, cache::*, request_members::*};
+use self::{add_bg::*, cache::*, recalc::*, request_members::*};
 #[cfg(feature = "osutracking")]
 use self::{tracking_interval::*, tracking_stats::*};
 #[cfg(feature = "osutracking")]
@@ -17,9 +21,13 @@ use crate::{
 
 mod add_bg;
 mod cache;
+mod recalc;
 mod request_members;
 mod reshard;
 
+#[cfg(feature = "server")]
+mod api_key;
+
 #[cfg(feature = "osutracking")]
 mod tracking_interval;
 
@@ -33,8 +41,13 @@ mod tracking_stats;
 pub enum Owner {
     #[command(name = "add_bg")]
     AddBg(OwnerAddBg),
+    #[cfg(feature = "server")]
+    #[command(name = "apikey")]
+    ApiKey(OwnerApiKey),
     #[command(name = "cache")]
     Cache(OwnerCache),
+    #[command(name = "recalc")]
+    Recalc(OwnerRecalc),
     #[command(name = "requestmembers")]
     RequestMembers(OwnerRequestMembers),
     #[command(name = "reshard")]
@@ -53,10 +66,90 @@ pub struct OwnerAddBg {
     mode: Option<GameModeOption>,
 }
 
+#[cfg(feature = "server")]
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "apikey", desc = "Manage keys for the public api")]
+pub enum OwnerApiKey {
+    #[command(name = "create")]
+    Create(OwnerApiKeyCreate),
+    #[command(name = "revoke")]
+    Revoke(OwnerApiKeyRevoke),
+    #[command(name = "list")]
+    List(OwnerApiKeyList),
+}
+
+#[cfg(feature = "server")]
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "create", desc = "Create a new api key")]
+pub struct OwnerApiKeyCreate {
+    #[command(max_length = 32, desc = "Name to identify the key")]
+    name: String,
+    #[command(
+        min_value = 1,
+        max_value = 10_000,
+        desc = "Maximum amount of requests per minute, defaults to 60"
+    )]
+    rate_limit: Option<i64>,
+    #[command(desc = "Discord user the key belongs to")]
+    owner: Option<Id<UserMarker>>,
+}
+
+#[cfg(feature = "server")]
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "revoke", desc = "Revoke an api key")]
+pub struct OwnerApiKeyRevoke {
+    #[command(max_length = 32, desc = "Name of the key")]
+    name: String,
+}
+
+#[cfg(feature = "server")]
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List all api keys")]
+pub struct OwnerApiKeyList;
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "cache", desc = "Display stats about the internal cache")]
 pub struct OwnerCache;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "recalc",
+    desc = "Recalculate stored difficulty attributes and pp"
+)]
+pub enum OwnerRecalc {
+    #[command(name = "start")]
+    Start(OwnerRecalcStart),
+    #[command(name = "pause")]
+    Pause(OwnerRecalcPause),
+    #[command(name = "resume")]
+    Resume(OwnerRecalcResume),
+    #[command(name = "status")]
+    Status(OwnerRecalcStatus),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "start", desc = "Start recalculating all stale entries")]
+pub struct OwnerRecalcStart {
+    #[command(
+        min_value = 1,
+        max_value = 10_000,
+        desc = "Amount of maps or scores per batch, defaults to 500"
+    )]
+    batch_size: Option<i64>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "pause", desc = "Pause the running recalculation")]
+pub struct OwnerRecalcPause;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "resume", desc = "Resume the paused recalculation")]
+pub struct OwnerRecalcResume;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "status", desc = "Display progress of the recalculation")]
+pub struct OwnerRecalcStatus;
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "requestmembers",
@@ -104,7 +197,10 @@ pub struct OwnerTrackingToggle;
 async fn slash_owner(mut command: InteractionCommand) -> Result<()> {
     match Owner::from_interaction(command.input_data())? {
         Owner::AddBg(bg) => addbg(command, bg).await,
+        #[cfg(feature = "server")]
+        Owner::ApiKey(args) => api_key(command, args).await,
         Owner::Cache(_) => cache(command).await,
+        Owner::Recalc(args) => recalc(command, args).await,
         Owner::RequestMembers(args) => request_members(command, &args.guild_id).await,
         Owner::Reshard(_) => reshard(command).await,
         #[cfg(feature = "osutracking")]
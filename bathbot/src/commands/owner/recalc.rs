@@ -0,0 +1,127 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    time::Duration,
+};
+
+use bathbot_psql::model::osu::PP_CALC_VERSION;
+use bathbot_util::{numbers::round, EmbedBuilder, FooterBuilder, MessageBuilder};
+use eyre::Result;
+
+use super::OwnerRecalc;
+use crate::{
+    core::Context,
+    manager::{RecalcStage, StageProgress},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+const DEFAULT_BATCH_SIZE: usize = 500;
+
+pub async fn recalc(command: InteractionCommand, args: OwnerRecalc) -> Result<()> {
+    let recalc = Context::pp_recalc();
+
+    let content = match args {
+        OwnerRecalc::Start(args) => {
+            let batch_size = args
+                .batch_size
+                .map_or(DEFAULT_BATCH_SIZE, |size| size as usize);
+
+            if recalc.start(batch_size) {
+                format!("Started pp recalculation for calculator version {PP_CALC_VERSION}")
+            } else {
+                "A recalculation is already running".to_owned()
+            }
+        }
+        OwnerRecalc::Pause(_) => {
+            if recalc.pause() {
+                "Paused the recalculation".to_owned()
+            } else {
+                "There is no running recalculation".to_owned()
+            }
+        }
+        OwnerRecalc::Resume(_) => {
+            if recalc.resume() {
+                "Resumed the recalculation".to_owned()
+            } else {
+                "There is no paused recalculation".to_owned()
+            }
+        }
+        OwnerRecalc::Status(_) => {
+            let progress = recalc.progress();
+
+            let mut description = format!("Stage: `{:?}`", progress.stage);
+
+            if recalc.is_paused() {
+                description.push_str(" (paused)");
+            }
+
+            if progress.stage != RecalcStage::Idle {
+                description.push_str("\nDifficulties: ");
+                write_stage(&mut description, &progress.difficulties);
+                description.push_str("\nScores: ");
+                write_stage(&mut description, &progress.scores);
+
+                if progress.failed > 0 {
+                    let _ = write!(description, "\nSkipped: {}", progress.failed);
+                }
+            }
+
+            let footer = FooterBuilder::new(format!("Calculator version {PP_CALC_VERSION}"));
+
+            let mut embed = EmbedBuilder::new()
+                .description(description)
+                .footer(footer)
+                .title("pp recalculation");
+
+            if let Some(started_at) = progress.started_at {
+                embed = embed.timestamp(started_at);
+            }
+
+            let builder = MessageBuilder::new().embed(embed);
+            command.callback(builder, false).await?;
+
+            return Ok(());
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+fn write_stage(description: &mut String, stage: &StageProgress) {
+    let percent = if stage.total == 0 {
+        100.0
+    } else {
+        100.0 * stage.done as f32 / stage.total as f32
+    };
+
+    let _ = write!(
+        description,
+        "{done}/{total} ({percent}%)",
+        done = stage.done,
+        total = stage.total,
+        percent = round(percent),
+    );
+
+    if let Some(eta) = stage.eta().filter(|_| stage.done < stage.total) {
+        let _ = write!(description, " • ETA: {}", FmtDuration(eta));
+    }
+}
+
+struct FmtDuration(Duration);
+
+impl Display for FmtDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let secs = self.0.as_secs();
+        let (hours, mins, secs) = (secs / 3600, (secs / 60) % 60, secs % 60);
+
+        if hours > 0 {
+            write!(f, "{hours}h {mins}m")
+        } else if mins > 0 {
+            write!(f, "{mins}m {secs}s")
+        } else {
+            write!(f, "{secs}s")
+        }
+    }
+}
@@ -1,9 +1,9 @@
-use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use twilight_model::id::Id;
 
 use crate::{
-    core::Context,
+    core::{locale::Issue, Context},
     util::{interaction::InteractionCommand, InteractionCommandExt},
 };
 
@@ -40,7 +40,7 @@ pub async fn request_members(command: InteractionCommand, guild_id: &str) -> Res
             Ok(())
         }
         Err(err) => {
-            let _ = command.error_callback(GENERAL_ISSUE).await;
+            let _ = command.error_callback(Issue::General).await;
 
             Err(Report::new(err).wrap_err("Failed to forward member request"))
         }
@@ -35,7 +35,10 @@ pub use self::{
     wordsneversaid::*, zenzenzense::*,
 };
 use crate::{
-    core::{buckets::BucketName, commands::CommandOrigin},
+    core::{
+        buckets::{BucketName, BucketTarget},
+        commands::CommandOrigin,
+    },
     util::{interaction::InteractionCommand, CheckPermissions, InteractionCommandExt, MessageExt},
     Context,
 };
@@ -50,19 +53,21 @@ async fn song(lyrics: &[&str], delay: u64, orig: CommandOrigin<'_>) -> Result<()
         return orig.error_callback(content).await;
     }
 
-    let (id, allow) = match orig.guild_id() {
+    let (target, allow) = match orig.guild_id() {
         Some(guild) => {
             let allow = Context::guild_config()
                 .peek(guild, |config| config.allow_songs.unwrap_or(true))
                 .await;
 
-            (guild.cast(), allow)
+            (BucketTarget::Guild(guild), allow)
         }
-        None => (orig.user_id()?, true),
+        None => (BucketTarget::User(orig.user_id()?), true),
     };
 
     // same bucket for guilds
-    if let Some(cooldown) = Context::check_ratelimit(id, BucketName::Songs) {
+    let ratelimit = Context::check_ratelimit(target, orig.guild_id(), BucketName::Songs).await;
+
+    if let Some(cooldown) = ratelimit {
         let content = format!("Command on cooldown, try again in {cooldown} seconds");
 
         return orig.error_callback(content).await;
@@ -132,6 +137,7 @@ async fn song(lyrics: &[&str], delay: u64, orig: CommandOrigin<'_>) -> Result<()
     [Zen Zen Zense](https://www.youtube.com/watch?v=607QsB38hn8&t=71s)"
 )]
 #[flags(SKIP_DEFER)]
+#[group(Songs)]
 pub struct Song {
     #[command(desc = "Choose a song title")]
     title: SongTitle,
@@ -27,6 +27,7 @@ mod untrack_all;
     desc = "Track top score updates for players"
 )]
 #[flags(AUTHORITY)]
+#[group(Tracking)]
 pub enum Track {
     #[command(name = "add")]
     Add(TrackAdd),
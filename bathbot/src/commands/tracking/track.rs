@@ -1,12 +1,12 @@
 use bathbot_macros::command;
-use bathbot_util::{constants::OSU_API_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use rosu_v2::prelude::{GameMode, OsuError};
 use time::OffsetDateTime;
 
 use super::TrackArgs;
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     embeds::{EmbedData, TrackEmbed},
     util::ChannelExt,
     Context,
@@ -55,7 +55,7 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
             return orig.error(content).await;
         }
         Err((err, _)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get names");
 
             return Err(err);
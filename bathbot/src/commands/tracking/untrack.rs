@@ -1,12 +1,12 @@
 use bathbot_macros::command;
-use bathbot_util::{constants::OSU_API_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::{Report, Result};
 use hashbrown::HashSet;
 use rosu_v2::prelude::{GameMode, OsuError, Username};
 
 use super::TrackArgs;
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, locale::Issue},
     embeds::{EmbedData, UntrackEmbed},
     util::ChannelExt,
     Context,
@@ -58,7 +58,7 @@ pub(super) async fn untrack(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<
             return orig.error(content).await;
         }
         Err((err, _)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error(Issue::OsuApi).await;
             let err = Report::new(err).wrap_err("failed to get names");
 
             return Err(err);
@@ -1,9 +1,13 @@
 use bathbot_macros::command;
-use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use bathbot_util::MessageBuilder;
 use eyre::Result;
 use rosu_v2::model::GameMode;
 
-use crate::{core::commands::CommandOrigin, util::ChannelExt, Context};
+use crate::{
+    core::{commands::CommandOrigin, locale::Issue},
+    util::ChannelExt,
+    Context,
+};
 
 #[command]
 #[desc("Untrack all users in a channel")]
@@ -50,7 +54,7 @@ pub async fn untrackall(orig: CommandOrigin<'_>, mode: Option<GameMode>) -> Resu
             Ok(())
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             Err(err.wrap_err("failed to remove channel from osu tracking"))
         }
@@ -18,6 +18,7 @@ pub mod tracked;
     When the stream goes online, a notification will be send to this channel within a few minutes."
 )]
 #[flags(AUTHORITY)]
+#[group(Twitch)]
 pub enum TrackStream {
     #[command(name = "add")]
     Add(TrackStreamAdd),
@@ -1,11 +1,12 @@
 use bathbot_macros::command;
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, TWITCH_API_ISSUE},
-    CowUtils, MessageBuilder,
-};
+use bathbot_util::{CowUtils, MessageBuilder};
 use eyre::Result;
 
-use crate::{core::commands::CommandOrigin, util::ChannelExt, Context};
+use crate::{
+    core::{commands::CommandOrigin, locale::Issue},
+    util::ChannelExt,
+    Context,
+};
 
 #[command]
 #[flags(AUTHORITY, ONLY_GUILDS)]
@@ -38,7 +39,7 @@ pub async fn removestream(orig: CommandOrigin<'_>, name: &'_ str) -> Result<()>
             return Ok(());
         }
         Err(err) => {
-            let _ = orig.error(TWITCH_API_ISSUE).await;
+            let _ = orig.error(Issue::TwitchApi).await;
 
             return Err(err.wrap_err("failed to get twitch user"));
         }
@@ -65,7 +66,7 @@ pub async fn removestream(orig: CommandOrigin<'_>, name: &'_ str) -> Result<()>
             orig.error(content).await
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error(Issue::General).await;
 
             Err(err.wrap_err("failed to remove stream track"))
         }
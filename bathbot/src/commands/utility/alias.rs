@@ -0,0 +1,667 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_psql::model::aliases::DbCommandAlias;
+use bathbot_util::{CowUtils, EmbedBuilder, MessageBuilder};
+use eyre::Result;
+use twilight_interactions::command::{
+    AutocompleteValue, CommandModel, CommandOption, CreateCommand, CreateOption,
+};
+use twilight_model::application::command::{CommandOptionChoice, CommandOptionChoiceValue};
+
+use crate::{
+    core::{
+        commands::{
+            checks::{check_authority, check_command_rules},
+            interaction::{InteractionCommandKind, InteractionCommands},
+            prefix::PrefixCommands,
+            CommandOrigin,
+        },
+        locale::Issue,
+    },
+    scheduler::{parse_command, run_command, SCHEDULABLE_COMMANDS},
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    Context,
+};
+
+/// Maximum amount of aliases per user and per guild
+const MAX_ALIASES: usize = 25;
+
+const MAX_NAME_LEN: usize = 20;
+
+/// Maximum length of a stored expansion, matches the database column
+const MAX_EXPANSION_LEN: usize = 256;
+
+#[derive(CreateCommand, SlashCommand)]
+#[command(
+    name = "alias",
+    desc = "Manage custom command aliases",
+    help = "Manage custom command aliases.\n\
+    An alias is a custom name for a command with preset arguments e.g. `mytop` \
+    for `<top -m taiko sort=date`. \
+    Aliases can then be used like any other prefix command, additional arguments \
+    are appended to the alias' arguments.\n\
+    Aliases can expand to prefix commands or, by starting with `/`, to some slash commands \
+    e.g. `/top query:\"length<90\" sort:pp`. \
+    The latter can also be used via `/alias use`.\n\
+    Aliases can be set for yourself or, with authority status, for the whole server. \
+    Your own aliases take precedence over the server's."
+)]
+#[flags(SKIP_DEFER)]
+#[allow(dead_code)]
+pub enum Alias {
+    #[command(name = "add")]
+    Add(AliasAdd),
+    #[command(name = "remove")]
+    Remove(AliasRemove),
+    #[command(name = "list")]
+    List(AliasList),
+    #[command(name = "use")]
+    Use(AliasUse),
+}
+
+#[derive(CommandModel)]
+enum Alias_ {
+    #[command(name = "add")]
+    Add(AliasAdd),
+    #[command(name = "remove")]
+    Remove(AliasRemove_),
+    #[command(name = "list")]
+    List(AliasList),
+    #[command(name = "use")]
+    Use(AliasUse_),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add", desc = "Add or replace an alias")]
+pub struct AliasAdd {
+    #[command(
+        max_length = 20,
+        desc = "Specify the name of the alias",
+        help = "Specify the name of the alias.\n\
+        The name must start with a letter and may only contain letters, digits, `-`, and `_`. \
+        It can't be the name of an existing prefix command."
+    )]
+    name: String,
+    #[command(
+        max_length = 200,
+        desc = "Specify the command e.g. `<top -m taiko sort=date` or `/top sort:pp`",
+        help = "Specify the command the alias expands to.\n\
+        For prefix commands, the prefix is optional e.g. `<top -m taiko sort=date`.\n\
+        For slash commands, start with `/` and specify subcommands and `option:value` pairs \
+        e.g. `/ranking country mode:mania`. If a value contains whitespace, quote the whole pair \
+        like `\"name:some user\"`."
+    )]
+    command: String,
+    #[command(desc = "Whether the alias is for you or the whole server, defaults to you")]
+    scope: Option<AliasScope>,
+}
+
+#[derive(CreateCommand)]
+#[command(name = "remove", desc = "Remove an alias")]
+#[allow(dead_code)]
+pub struct AliasRemove {
+    #[command(autocomplete = true, desc = "Specify the name of the alias")]
+    name: String,
+    #[command(desc = "Whether the alias is yours or the server's, defaults to yours")]
+    scope: Option<AliasScope>,
+}
+
+#[derive(CommandModel)]
+#[command(autocomplete = true)]
+struct AliasRemove_ {
+    name: AutocompleteValue<String>,
+    scope: Option<AliasScope>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List your aliases and those of this server")]
+pub struct AliasList;
+
+#[derive(CreateCommand)]
+#[command(name = "use", desc = "Use an alias of a slash command")]
+#[allow(dead_code)]
+pub struct AliasUse {
+    #[command(autocomplete = true, desc = "Specify the name of the alias")]
+    name: String,
+    #[command(desc = "Specify additional `option:value` pairs")]
+    args: Option<String>,
+}
+
+#[derive(CommandModel)]
+#[command(autocomplete = true)]
+struct AliasUse_ {
+    name: AutocompleteValue<String>,
+    args: Option<String>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum AliasScope {
+    #[default]
+    #[option(name = "User", value = "user")]
+    User,
+    #[option(name = "Server", value = "server")]
+    Server,
+}
+
+/// Split input into the command name and its arguments.
+pub fn split_command(input: &str) -> (&str, &str) {
+    input
+        .split_once(char::is_whitespace)
+        .map_or((input, ""), |(name, args)| (name, args.trim()))
+}
+
+pub fn is_valid_alias_name(name: &str) -> bool {
+    name.len() <= MAX_NAME_LEN
+        && name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+/// Validate the command of an alias and bring it into its stored form.
+///
+/// On error, returns a message describing the issue.
+fn normalize_expansion(input: &str) -> Result<String, String> {
+    let expansion = normalize_expansion_(input)?;
+
+    if expansion.len() > MAX_EXPANSION_LEN {
+        return Err(format!(
+            "The command must be at most {MAX_EXPANSION_LEN} characters long"
+        ));
+    }
+
+    Ok(expansion)
+}
+
+fn normalize_expansion_(input: &str) -> Result<String, String> {
+    let input = input.trim();
+
+    if let Some(slash) = input.strip_prefix('/') {
+        let (name, args) = split_command(slash);
+        let name = name.to_ascii_lowercase();
+
+        return match InteractionCommands::get_command(&name) {
+            Some(InteractionCommandKind::Chat(cmd))
+                if SCHEDULABLE_COMMANDS.contains(&name.as_str()) =>
+            {
+                parse_command(cmd, args)?;
+
+                Ok(format!("/{name} {args}").trim_end().to_owned())
+            }
+            _ => {
+                let commands: Vec<_> = SCHEDULABLE_COMMANDS
+                    .iter()
+                    .map(|name| format!("`/{name}`"))
+                    .collect();
+
+                Err(format!(
+                    "`/{name}` cannot be used in an alias, available slash commands: {}",
+                    commands.join(", ")
+                ))
+            }
+        };
+    }
+
+    let input = input.trim_start_matches(|c: char| c.is_ascii_punctuation());
+    let (name, args) = split_command(input);
+    let lowercase = name.to_ascii_lowercase();
+
+    // Prefix commands may be followed by a number e.g. `top5`
+    let lookup = lowercase.trim_end_matches(|c: char| c.is_ascii_digit() || c == '?');
+
+    if PrefixCommands::get().command(lookup).is_none() {
+        return Err(format!("`{name}` is not a prefix command"));
+    }
+
+    Ok(format!("{lowercase} {args}").trim_end().to_owned())
+}
+
+pub async fn slash_alias(mut command: InteractionCommand) -> Result<()> {
+    match Alias_::from_interaction(command.input_data())? {
+        Alias_::Add(args) => add(command, args).await,
+        Alias_::Remove(args) => remove(command, args).await,
+        Alias_::List(_) => list(command).await,
+        Alias_::Use(args) => use_alias(command, args).await,
+    }
+}
+
+async fn add(command: InteractionCommand, args: AliasAdd) -> Result<()> {
+    let user = command.user_id()?;
+    let scope = args.scope.unwrap_or_default();
+    let name = args.name.trim().to_ascii_lowercase();
+
+    if !is_valid_alias_name(&name) {
+        let content = "The name must start with a letter and may only contain \
+            letters, digits, `-`, and `_`";
+
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
+    let lookup = name.trim_end_matches(|c: char| c.is_ascii_digit());
+
+    if PrefixCommands::get().command(lookup).is_some() {
+        let content = format!("`{name}` is already the name of a prefix command");
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
+    let expansion = match normalize_expansion(&args.command) {
+        Ok(expansion) => expansion,
+        Err(content) => {
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let psql = Context::psql();
+
+    let aliases_res = match scope {
+        AliasScope::User => psql.select_user_command_aliases(user).await,
+        AliasScope::Server => {
+            let Some(guild) = command.guild_id else {
+                let content = "Server aliases can only be added in servers";
+                command.error_callback(content).await?;
+
+                return Ok(());
+            };
+
+            match check_authority(user, Some(guild)).await {
+                Ok(None) => {}
+                Ok(Some(content)) => {
+                    command.error_callback(content).await?;
+
+                    return Ok(());
+                }
+                Err(err) => {
+                    let _ = command.error_callback(Issue::General).await;
+
+                    return Err(err.wrap_err("Failed to check authority status"));
+                }
+            }
+
+            psql.select_guild_command_aliases(guild).await
+        }
+    };
+
+    let aliases = match aliases_res {
+        Ok(aliases) => aliases,
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            return Err(err.wrap_err("Failed to get command aliases"));
+        }
+    };
+
+    if aliases.len() >= MAX_ALIASES && aliases.iter().all(|alias| alias.name != name) {
+        let content = format!(
+            "There can be at most {MAX_ALIASES} aliases, \
+            remove one via `/alias remove` first"
+        );
+
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
+    let upsert_res = match (scope, command.guild_id) {
+        (AliasScope::Server, Some(guild)) => {
+            psql.upsert_guild_command_alias(guild, &name, &expansion)
+                .await
+        }
+        _ => {
+            psql.upsert_user_command_alias(user, &name, &expansion)
+                .await
+        }
+    };
+
+    if let Err(err) = upsert_res {
+        let _ = command.error_callback(Issue::General).await;
+
+        return Err(err.wrap_err("Failed to upsert command alias"));
+    }
+
+    Context::alias_names()
+        .pin()
+        .insert(name.as_str().into(), ());
+
+    let content = format!("Added alias `{name}` for `{expansion}`");
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+async fn remove(command: InteractionCommand, args: AliasRemove_) -> Result<()> {
+    let name = match args.name {
+        AutocompleteValue::None => return handle_autocomplete(&command, "", false).await,
+        AutocompleteValue::Focused(name) => {
+            return handle_autocomplete(&command, &name, false).await
+        }
+        AutocompleteValue::Completed(name) => name.trim().to_ascii_lowercase(),
+    };
+
+    let user = command.user_id()?;
+    let psql = Context::psql();
+
+    let delete_res = match (args.scope.unwrap_or_default(), command.guild_id) {
+        (AliasScope::User, _) => psql.delete_user_command_alias(user, &name).await,
+        (AliasScope::Server, Some(guild)) => {
+            match check_authority(user, Some(guild)).await {
+                Ok(None) => {}
+                Ok(Some(content)) => {
+                    command.error_callback(content).await?;
+
+                    return Ok(());
+                }
+                Err(err) => {
+                    let _ = command.error_callback(Issue::General).await;
+
+                    return Err(err.wrap_err("Failed to check authority status"));
+                }
+            }
+
+            psql.delete_guild_command_alias(guild, &name).await
+        }
+        (AliasScope::Server, None) => {
+            let content = "Server aliases can only be removed in servers";
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    match delete_res {
+        Ok(true) => {
+            let content = format!("Removed alias `{name}`");
+            let builder = MessageBuilder::new().embed(content);
+            command.callback(builder, false).await?;
+
+            Ok(())
+        }
+        Ok(false) => {
+            let content = format!("There is no alias `{name}`");
+            command.error_callback(content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            Err(err.wrap_err("Failed to delete command alias"))
+        }
+    }
+}
+
+async fn list(command: InteractionCommand) -> Result<()> {
+    let (user_aliases, guild_aliases) = match collect_aliases(&command).await {
+        Ok(aliases) => aliases,
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            return Err(err.wrap_err("Failed to get command aliases"));
+        }
+    };
+
+    let mut embed = EmbedBuilder::new().title("Command aliases");
+
+    if user_aliases.is_empty() && guild_aliases.is_empty() {
+        embed = embed.description("No aliases yet, add one via `/alias add`");
+    } else {
+        let mut description = String::with_capacity(512);
+
+        for (title, aliases) in [
+            ("Your aliases", user_aliases),
+            ("Server aliases", guild_aliases),
+        ] {
+            if aliases.is_empty() {
+                continue;
+            }
+
+            let _ = writeln!(description, "**{title}**");
+
+            for alias in aliases {
+                let _ = writeln!(description, "`{}` → `{}`", alias.name, alias.expansion);
+            }
+        }
+
+        embed = embed.description(description);
+    }
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+async fn use_alias(mut command: InteractionCommand, args: AliasUse_) -> Result<()> {
+    let name = match args.name {
+        AutocompleteValue::None => return handle_autocomplete(&command, "", true).await,
+        AutocompleteValue::Focused(name) => {
+            return handle_autocomplete(&command, &name, true).await
+        }
+        AutocompleteValue::Completed(name) => name.trim().to_ascii_lowercase(),
+    };
+
+    let user = command.user_id()?;
+
+    let alias_fut = Context::psql().select_command_alias(user, command.guild_id, &name);
+
+    let mut expansion = match alias_fut.await {
+        Ok(Some(alias)) => alias.expansion,
+        Ok(None) => {
+            let content = format!("There is no alias `{name}`");
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            return Err(err.wrap_err("Failed to get command alias"));
+        }
+    };
+
+    if let Some(args) = args.args.as_deref().map(str::trim) {
+        expansion.push(' ');
+        expansion.push_str(args);
+    }
+
+    let Some(slash) = expansion.strip_prefix('/') else {
+        let content = format!(
+            "Alias `{name}` expands to a prefix command so it must be used through a message"
+        );
+
+        command.error_callback(content).await?;
+
+        return Ok(());
+    };
+
+    let (cmd_name, cmd_args) = split_command(slash);
+
+    let Some(InteractionCommandKind::Chat(cmd)) = InteractionCommands::get_command(cmd_name) else {
+        let content = format!("Alias `{name}` expands to an unknown command");
+        command.error_callback(content).await?;
+
+        return Ok(());
+    };
+
+    let rules_fut = check_command_rules(
+        user,
+        command.guild_id,
+        command.channel_id,
+        &[cmd.name],
+        cmd.group,
+    );
+
+    match rules_fut.await {
+        Ok(None) => {}
+        Ok(Some(content)) => {
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            return Err(err.wrap_err("Failed to check command rules"));
+        }
+    }
+
+    if let Some(bucket) = cmd.bucket {
+        if let Some(cooldown) = Context::check_ratelimit(user, command.guild_id, bucket).await {
+            let content = format!("Command on cooldown, try again in {cooldown} seconds");
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+    }
+
+    let data = match parse_command(cmd, cmd_args) {
+        Ok(data) => data,
+        Err(content) => {
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    command.defer(false).await?;
+
+    run_command(
+        CommandOrigin::from_interaction(&mut command),
+        cmd.name,
+        data,
+    )
+    .await
+}
+
+async fn handle_autocomplete(
+    command: &InteractionCommand,
+    name: &str,
+    only_slash: bool,
+) -> Result<()> {
+    let (user_aliases, guild_aliases) = collect_aliases(command).await?;
+    let name = name.cow_to_ascii_lowercase();
+
+    let choices = user_aliases
+        .into_iter()
+        .chain(guild_aliases)
+        .filter(|alias| alias.name.starts_with(name.as_ref()))
+        .filter(|alias| !only_slash || alias.expansion.starts_with('/'))
+        .take(25)
+        .map(|alias| {
+            let mut choice_name = format!("{} → {}", alias.name, alias.expansion);
+
+            if choice_name.chars().count() > 100 {
+                choice_name = choice_name.chars().take(99).collect();
+                choice_name.push('…');
+            }
+
+            CommandOptionChoice {
+                name: choice_name,
+                name_localizations: None,
+                value: CommandOptionChoiceValue::String(alias.name),
+            }
+        })
+        .collect();
+
+    command.autocomplete(choices).await?;
+
+    Ok(())
+}
+
+/// The aliases of the command's author and those of the guild.
+async fn collect_aliases(
+    command: &InteractionCommand,
+) -> Result<(Vec<DbCommandAlias>, Vec<DbCommandAlias>)> {
+    let psql = Context::psql();
+    let user_fut = psql.select_user_command_aliases(command.user_id()?);
+
+    let (user_aliases, guild_aliases) = match command.guild_id {
+        Some(guild) => tokio::try_join!(user_fut, psql.select_guild_command_aliases(guild))?,
+        None => (user_fut.await?, Vec::new()),
+    };
+
+    Ok((user_aliases, guild_aliases))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_command_table() {
+        let cases = [
+            ("top", ("top", "")),
+            ("top -m taiko", ("top", "-m taiko")),
+            ("top   sort=date  ", ("top", "sort=date")),
+            ("", ("", "")),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(split_command(input), expected, "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn alias_name_table() {
+        let cases = [
+            ("mytop", true),
+            ("my-top_2", true),
+            ("a", true),
+            ("abcdefghijklmnopqrst", true),
+            ("abcdefghijklmnopqrstu", false),
+            ("", false),
+            ("2top", false),
+            ("-top", false),
+            ("MyTop", false),
+            ("my top", false),
+            ("my.top", false),
+            ("tøp", false),
+        ];
+
+        for (name, expected) in cases {
+            assert_eq!(is_valid_alias_name(name), expected, "name: {name:?}");
+        }
+    }
+
+    #[test]
+    fn expansion_table() {
+        let cases = [
+            ("top", Ok("top")),
+            ("<TOP -m taiko", Ok("top -m taiko")),
+            ("  !top5   sort=date ", Ok("top5 sort=date")),
+            ("/top", Ok("/top")),
+            ("/TOP", Ok("/top")),
+        ];
+
+        for (input, expected) in cases {
+            let expected = expected.map(str::to_owned);
+            assert_eq!(normalize_expansion(input), expected, "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn expansion_rejects_aliases_and_unknown_commands() {
+        // Expansions must name an actual command so aliases can't chain into
+        // other aliases or themselves
+        for input in ["mytop", "<mytop -m taiko", "", "/mytop", "/link"] {
+            assert!(normalize_expansion(input).is_err(), "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn expansion_length_limit() {
+        let args = "a".repeat(MAX_EXPANSION_LEN - "top ".len());
+        let input = format!("top {args}");
+        assert_eq!(normalize_expansion(&input).as_deref(), Ok(input.as_str()));
+
+        let input = format!("top {args}a");
+        assert!(normalize_expansion(&input).is_err());
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt::Write;
 
 use bathbot_macros::command;
 use bathbot_psql::model::configs::{Authorities, GuildConfig};
-use bathbot_util::{constants::GENERAL_ISSUE, matcher, MessageBuilder};
+use bathbot_util::{matcher, MessageBuilder};
 use eyre::Result;
 use twilight_model::{
     guild::Permissions,
@@ -12,6 +12,7 @@ use twilight_model::{
 use crate::{
     core::{
         commands::{prefix::Args, CommandOrigin},
+        locale::Issue,
         BotConfig, Context,
     },
     util::ChannelExt,
@@ -66,7 +67,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
             };
 
             if let Err(err) = Context::guild_config().update(guild_id, f).await {
-                let _ = orig.error_callback(GENERAL_ISSUE).await;
+                let _ = orig.error_callback(Issue::General).await;
 
                 return Err(err.wrap_err("failed to update guild config"));
             }
@@ -101,7 +102,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                     Ok(Some(member)) => member.roles().to_vec(),
                     Ok(None) => Vec::new(),
                     Err(err) => {
-                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+                        let _ = orig.error_callback(Issue::General).await;
 
                         return Err(err);
                     }
@@ -113,7 +114,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                             || roles.iter().any(|&new| new == role.id && new != role_id)
                     }),
                     Err(err) => {
-                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+                        let _ = orig.error_callback(Issue::General).await;
 
                         return Err(err);
                     }
@@ -130,7 +131,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
             let f = |config: &mut GuildConfig| config.authorities.retain(|id| *id != role_id);
 
             if let Err(err) = Context::guild_config().update(guild_id, f).await {
-                let _ = orig.error_callback(GENERAL_ISSUE).await;
+                let _ = orig.error_callback(Issue::General).await;
 
                 return Err(err.wrap_err("failed to update guild config"));
             }
@@ -153,7 +154,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                     Ok(Some(member)) => member.roles().to_vec(),
                     Ok(None) => Vec::new(),
                     Err(err) => {
-                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+                        let _ = orig.error_callback(Issue::General).await;
 
                         return Err(err);
                     }
@@ -165,7 +166,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                             || roles.iter().any(|&new| new == role.id)
                     }),
                     Err(err) => {
-                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+                        let _ = orig.error_callback(Issue::General).await;
 
                         return Err(err);
                     }
@@ -182,7 +183,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
             let f = |config: &mut GuildConfig| config.authorities = roles.into_iter().collect();
 
             if let Err(err) = Context::guild_config().update(guild_id, f).await {
-                let _ = orig.error_callback(GENERAL_ISSUE).await;
+                let _ = orig.error_callback(Issue::General).await;
 
                 return Err(err.wrap_err("failed to update guild config"));
             }
@@ -0,0 +1,74 @@
+use bathbot_psql::model::configs::{AutoEmbeds, GuildConfig};
+use bathbot_util::MessageBuilder;
+use eyre::Result;
+
+use super::server_config::ServerConfigAutoEmbeds;
+use crate::{
+    core::{locale::Issue, Context},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+pub async fn auto_embeds(command: InteractionCommand, args: ServerConfigAutoEmbeds) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    let ServerConfigAutoEmbeds {
+        map,
+        score,
+        user,
+        osu_match,
+    } = args;
+
+    let f = |config: &mut GuildConfig| {
+        let options = [
+            (AutoEmbeds::MAP, map),
+            (AutoEmbeds::SCORE, score),
+            (AutoEmbeds::USER, user),
+            (AutoEmbeds::MATCH, osu_match),
+        ];
+
+        for (kind, value) in options {
+            if let Some(value) = value {
+                config.auto_embeds.set(kind, value);
+            }
+        }
+
+        config.auto_embeds
+    };
+
+    let auto_embeds = match Context::guild_config().update(guild_id, f).await {
+        Ok(auto_embeds) => auto_embeds,
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            return Err(err.wrap_err("failed to update guild config"));
+        }
+    };
+
+    let kinds = [
+        (AutoEmbeds::MAP, "Beatmaps"),
+        (AutoEmbeds::SCORE, "Scores"),
+        (AutoEmbeds::USER, "Users"),
+        (AutoEmbeds::MATCH, "Matches"),
+    ];
+
+    let mut content = String::from("Auto-embeds for osu! links in this server:");
+
+    for (kind, name) in kinds {
+        let status = if auto_embeds.contains(kind) {
+            "enabled"
+        } else {
+            "disabled"
+        };
+
+        content.push_str("\n- ");
+        content.push_str(name);
+        content.push_str(": `");
+        content.push_str(status);
+        content.push('`');
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
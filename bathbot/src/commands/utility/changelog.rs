@@ -2,14 +2,14 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use bathbot_macros::SlashCommand;
 use bathbot_model::{PullRequests, PullRequestsAndTags, ReferencedIssue, Tag};
-use bathbot_util::constants::{FIELD_VALUE_SIZE, GENERAL_ISSUE};
+use bathbot_util::constants::FIELD_VALUE_SIZE;
 use eyre::{ContextCompat, Result, WrapErr};
 use time::OffsetDateTime;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     active::{impls::ChangelogPagination, ActiveMessages},
-    core::Context,
+    core::{locale::Issue, Context},
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
 };
 
@@ -21,14 +21,14 @@ async fn slash_changelog(mut command: InteractionCommand) -> Result<()> {
     let mut data = match Context::github().tags_and_prs().await {
         Ok(res) => res,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
     };
 
     if data.tags.len() != 25 {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error(Issue::General).await;
 
         bail!("Expected 25 tags, got {}", data.tags.len());
     }
@@ -63,7 +63,7 @@ async fn create_pages(
     match pages_fut.await {
         Ok(res) => Ok(res),
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             Err(err.wrap_err("Failed to build pages"))
         }
@@ -0,0 +1,339 @@
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+use bathbot_psql::model::configs::{CommandRule, CommandRules, GuildConfig, GROUP_TARGET_PREFIX};
+use bathbot_util::{EmbedBuilder, MessageBuilder};
+use eyre::Result;
+use rosu_v2::prelude::GameMode;
+
+use super::server_config::{ServerConfigChannelMode, ServerConfigCommands};
+use crate::{
+    core::{
+        commands::{
+            checks::group_target,
+            interaction::InteractionCommands,
+            prefix::{PrefixCommandGroup, PrefixCommands},
+        },
+        locale::Issue,
+        Context,
+    },
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+pub async fn command_rules(command: InteractionCommand, args: ServerConfigCommands) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    let target = match &args {
+        ServerConfigCommands::Disable(args) => Some(args.command.as_str()),
+        ServerConfigCommands::Enable(args) => Some(args.command.as_str()),
+        ServerConfigCommands::Channel(args) => Some(args.command.as_str()),
+        ServerConfigCommands::Role(args) => Some(args.command.as_str()),
+        ServerConfigCommands::Reset(args) => Some(args.command.as_str()),
+        ServerConfigCommands::List(_) => None,
+    };
+
+    let target = match target.map(validate_target) {
+        Some(Ok(target)) => target,
+        Some(Err(content)) => {
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+        None => {
+            let rules = Context::guild_config()
+                .peek(guild_id, |config| config.command_rules.clone())
+                .await;
+
+            let embed = EmbedBuilder::new()
+                .description(list_rules(&rules))
+                .title("Command restrictions");
+
+            let builder = MessageBuilder::new().embed(embed);
+            command.callback(builder, false).await?;
+
+            return Ok(());
+        }
+    };
+
+    let f = |config: &mut GuildConfig| {
+        let rules = &mut config.command_rules;
+
+        let content = match args {
+            ServerConfigCommands::Disable(args) => {
+                let Some(rule) = rules.rule_mut(&target) else {
+                    return Err(too_many_rules());
+                };
+
+                match args.channel {
+                    Some(channel) => {
+                        if !rule.disabled_channels.contains(&channel.get()) {
+                            rule.disabled_channels.push(channel.get());
+                        }
+
+                        format!("`{target}` is now disabled in <#{channel}>")
+                    }
+                    None => {
+                        rule.disabled = true;
+
+                        format!("`{target}` is now disabled in this server")
+                    }
+                }
+            }
+            ServerConfigCommands::Enable(args) => {
+                let Some(rule) = rules.rule_mut(&target) else {
+                    return Err(too_many_rules());
+                };
+
+                match args.channel {
+                    Some(channel) => {
+                        rule.disabled_channels.retain(|&id| id != channel.get());
+
+                        if rule.disabled {
+                            format!(
+                                "`{target}` is no longer disabled in <#{channel}> \
+                                but it is still disabled in the whole server"
+                            )
+                        } else {
+                            format!("`{target}` is no longer disabled in <#{channel}>")
+                        }
+                    }
+                    None => {
+                        rule.disabled = false;
+                        rule.disabled_channels.clear();
+
+                        format!("`{target}` is no longer disabled")
+                    }
+                }
+            }
+            ServerConfigCommands::Channel(args) => {
+                let Some(rule) = rules.rule_mut(&target) else {
+                    return Err(too_many_rules());
+                };
+
+                let channel = args.channel;
+
+                if toggle(&mut rule.allowed_channels, channel.get()) {
+                    format!("`{target}` can now be used in <#{channel}>")
+                } else if rule.allowed_channels.is_empty() {
+                    format!("`{target}` can now be used in all channels")
+                } else {
+                    format!("`{target}` can no longer be used in <#{channel}>")
+                }
+            }
+            ServerConfigCommands::Role(args) => {
+                let Some(rule) = rules.rule_mut(&target) else {
+                    return Err(too_many_rules());
+                };
+
+                let role = args.role;
+
+                if toggle(&mut rule.roles, role.get()) {
+                    format!("`{target}` can now be used by members with <@&{role}>")
+                } else if rule.roles.is_empty() {
+                    format!("`{target}` can now be used by all members")
+                } else {
+                    format!("`{target}` no longer allows members with <@&{role}>")
+                }
+            }
+            ServerConfigCommands::Reset(_) => {
+                if rules.remove_rule(&target) {
+                    format!("Removed all restrictions of `{target}`")
+                } else {
+                    format!("`{target}` had no restrictions")
+                }
+            }
+            ServerConfigCommands::List(_) => unreachable!(),
+        };
+
+        rules.remove_empty();
+
+        Ok(content)
+    };
+
+    match Context::guild_config().update(guild_id, f).await {
+        Ok(Ok(content)) => {
+            let builder = MessageBuilder::new().embed(content);
+            command.callback(builder, false).await?;
+
+            Ok(())
+        }
+        Ok(Err(content)) => {
+            command.error_callback(content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            Err(err.wrap_err("failed to update guild config"))
+        }
+    }
+}
+
+pub async fn channel_mode(
+    command: InteractionCommand,
+    args: ServerConfigChannelMode,
+) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+    let channel = args.channel.unwrap_or(command.channel_id);
+    let mode = args.mode.map(GameMode::from);
+
+    let f = |config: &mut GuildConfig| config.command_rules.set_channel_mode(channel, mode);
+
+    if let Err(err) = Context::guild_config().update(guild_id, f).await {
+        let _ = command.error_callback(Issue::General).await;
+
+        return Err(err.wrap_err("failed to update guild config"));
+    }
+
+    let content = match mode {
+        Some(mode) => format!(
+            "The default mode in <#{channel}> is now `{}`",
+            ModeName(mode)
+        ),
+        None => format!("Removed the default mode of <#{channel}>"),
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+/// Normalizes the target and checks whether it refers to a known command or
+/// command group.
+fn validate_target(target: &str) -> Result<String, String> {
+    let target = target.trim().to_ascii_lowercase();
+
+    if let Some(key) = target.strip_prefix(GROUP_TARGET_PREFIX) {
+        return match PrefixCommandGroup::from_key(key) {
+            Some(group) => Ok(group_target(group)),
+            None => {
+                let mut content = format!("Unknown command group `{key}`. Available groups: ");
+                let mut groups = PrefixCommandGroup::ALL.iter();
+
+                if let Some(first) = groups.next() {
+                    let _ = write!(content, "`{}`", first.key());
+
+                    for group in groups {
+                        let _ = write!(content, ", `{}`", group.key());
+                    }
+                }
+
+                Err(content)
+            }
+        };
+    }
+
+    let is_known = InteractionCommands::get().command(&target).is_some()
+        || PrefixCommands::get().command(&target).is_some();
+
+    if is_known {
+        Ok(target)
+    } else {
+        Err(format!(
+            "Unknown command `{target}`.\n\
+            To target a command group, prefix it with `{GROUP_TARGET_PREFIX}` e.g. `group:games`"
+        ))
+    }
+}
+
+/// Adds the value if it's missing, otherwise removes it.
+///
+/// Returns whether the value was added.
+fn toggle(values: &mut Vec<u64>, value: u64) -> bool {
+    match values.iter().position(|&id| id == value) {
+        Some(idx) => {
+            values.swap_remove(idx);
+
+            false
+        }
+        None => {
+            values.push(value);
+
+            true
+        }
+    }
+}
+
+fn too_many_rules() -> String {
+    format!(
+        "Servers can have at most {} restricted commands",
+        CommandRules::MAX_RULES
+    )
+}
+
+fn list_rules(rules: &CommandRules) -> String {
+    if rules.is_empty() {
+        return "There are no command restrictions or channel modes in this server".to_owned();
+    }
+
+    let mut content = String::new();
+
+    for rule in rules.rules() {
+        let _ = write!(content, "**`{}`**:", rule.target);
+        write_rule(&mut content, rule);
+        content.push('\n');
+    }
+
+    if !rules.channel_modes().is_empty() {
+        content.push_str("\n**Channel modes:**\n");
+
+        for entry in rules.channel_modes() {
+            let mode = ModeName(GameMode::from(entry.mode));
+            let _ = writeln!(content, "<#{}>: `{mode}`", entry.channel);
+        }
+    }
+
+    content
+}
+
+fn write_rule(content: &mut String, rule: &CommandRule) {
+    if rule.disabled {
+        content.push_str(" disabled in this server");
+    }
+
+    let mut disabled = rule.disabled_channels();
+
+    if let Some(first) = disabled.next() {
+        let _ = write!(content, " disabled in <#{first}>");
+
+        for channel in disabled {
+            let _ = write!(content, ", <#{channel}>");
+        }
+    }
+
+    let mut allowed = rule.allowed_channels();
+
+    if let Some(first) = allowed.next() {
+        let _ = write!(content, " only in <#{first}>");
+
+        for channel in allowed {
+            let _ = write!(content, ", <#{channel}>");
+        }
+    }
+
+    let mut roles = rule.roles();
+
+    if let Some(first) = roles.next() {
+        let _ = write!(content, " only for <@&{first}>");
+
+        for role in roles {
+            let _ = write!(content, ", <@&{role}>");
+        }
+    }
+}
+
+struct ModeName(GameMode);
+
+impl Display for ModeName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self.0 {
+            GameMode::Osu => "osu",
+            GameMode::Taiko => "taiko",
+            GameMode::Catch => "ctb",
+            GameMode::Mania => "mania",
+        };
+
+        f.write_str(name)
+    }
+}
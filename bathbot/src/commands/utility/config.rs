@@ -1,27 +1,32 @@
 use ::time::UtcOffset;
 use bathbot_macros::{command, SlashCommand};
 use bathbot_model::command_fields::{ShowHideOption, TimezoneOption};
+#[cfg(feature = "server")]
+use bathbot_model::OsuUserToken;
 use bathbot_psql::model::configs::{
-    ListSize, OsuUserId, OsuUsername, Retries, ScoreData, UserConfig,
+    ListSize, Locale, OsuUserId, OsuUsername, Retries, ScoreData, UserConfig,
 };
 #[cfg(feature = "server")]
-use bathbot_server::AuthenticationStandbyError;
+use bathbot_server::{AuthenticationStandbyError, OsuAuthorization};
 use bathbot_util::constants::GENERAL_ISSUE;
 #[cfg(feature = "server")]
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
+#[cfg(feature = "server")]
+use rosu_v2::prelude::Username;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{marker::UserMarker, Id};
 
 use super::{SkinValidation, ValidationStatus};
-#[cfg(feature = "server")]
-use crate::{core::BotConfig, util::Emote};
 use crate::{
+    core::locale::Issue,
     embeds::{ConfigEmbed, EmbedData},
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
+#[cfg(feature = "server")]
+use crate::{core::BotConfig, util::Emote};
 
 #[cfg(feature = "server")]
 #[derive(CommandModel, CreateCommand, Default, SlashCommand)]
@@ -93,6 +98,13 @@ pub struct Config {
         They have a different score and grade calculation and only lazer adds the new mods."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Specify the language of the bot's responses",
+        help = "Specify the language of the bot's responses.\n\
+        If not set, the server's language will be used or, if that's not set either, \
+        the language of your discord client."
+    )]
+    language: Option<Locale>,
 }
 
 // FIXME: Some attribute command does not register the #[cfg(feature = "")]
@@ -147,6 +159,13 @@ pub struct Config {
         They have a different score and grade calculation and only lazer adds the new mods."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Specify the language of the bot's responses",
+        help = "Specify the language of the bot's responses.\n\
+        If not set, the server's language will be used or, if that's not set either, \
+        the language of your discord client."
+    )]
+    language: Option<Locale>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -202,6 +221,7 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         mut skin_url,
         render_button,
         score_data,
+        language,
     } = config;
 
     if let Some(ref skin_url) = skin_url {
@@ -216,7 +236,7 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
     let mut config = match Context::user_config().with_osu_id(author.id).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -251,6 +271,10 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         config.score_data = Some(score_data);
     }
 
+    if let Some(language) = language {
+        config.locale = Some(language);
+    }
+
     #[cfg(feature = "server")]
     if let Some(ConfigLink::Unlink) = osu {
         config.osu.take();
@@ -282,7 +306,7 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
                 let update_fut = Context::user_config().update_skin(author.id, Some(skin_url));
 
                 if let Err(err) = update_fut.await {
-                    command.error(GENERAL_ISSUE).await?;
+                    command.error(Issue::General).await?;
 
                     return Err(err);
                 }
@@ -315,16 +339,37 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
 #[cfg(feature = "server")]
 const MSG_BADE: &str = "Contact Badewanne3 if you encounter issues with the website";
 
+/// Scopes that an osu! authentication requests.
 #[cfg(feature = "server")]
-fn osu_content(state: u8) -> String {
+#[derive(Copy, Clone)]
+pub enum OsuScope {
+    /// Only the user's identity
+    Identify,
+    /// Also read access to the user's friends
+    Friends,
+}
+
+#[cfg(feature = "server")]
+impl OsuScope {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Identify => "identify",
+            Self::Friends => "identify%20public%20friends.read",
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+fn osu_content(state: u8, scope: OsuScope) -> String {
     let config = BotConfig::get();
 
     format!(
         "{emote} [Click here](https://osu.ppy.sh/oauth/authorize?client_id={client_id}&\
-        response_type=code&scope=identify&redirect_uri={url}/auth/osu&state={state}) \
+        response_type=code&scope={scope}&redirect_uri={url}/auth/osu&state={state}) \
         to authenticate your osu! profile",
         emote = Emote::Osu,
         client_id = config.tokens.osu_client_id,
+        scope = scope.as_str(),
         url = config.server.public_url,
     )
 }
@@ -354,7 +399,7 @@ async fn handle_both_links(
 
     let content = format!(
         "{}\n{}",
-        osu_content(osu_fut.state),
+        osu_content(osu_fut.state, OsuScope::Identify),
         twitch_content(twitch_fut.state)
     );
 
@@ -364,6 +409,7 @@ async fn handle_both_links(
 
     let twitch_name = match handle_ephemeral(command, builder, fut).await {
         Some(Ok((osu, twitch))) => {
+            let osu = osu.user;
             config.osu = Some(osu.user_id);
             config.twitch_id = Some(twitch.user_id);
 
@@ -383,7 +429,7 @@ async fn handle_both_links(
     };
 
     if let Err(err) = Context::user_config().store(author.id, config).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error(Issue::General).await;
 
         return HandleResult::Err(err);
     }
@@ -420,7 +466,7 @@ async fn handle_twitch_link(
     };
 
     if let Err(err) = Context::user_config().store(author.id, config).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error(Issue::General).await;
 
         return HandleResult::Err(err);
     }
@@ -433,24 +479,8 @@ async fn handle_osu_link(
     command: &InteractionCommand,
     config: &mut UserConfig<OsuUserId>,
 ) -> HandleResult {
-    let fut = Context::auth_standby().wait_for_osu();
-
-    let embed = EmbedBuilder::new()
-        .description(osu_content(fut.state))
-        .footer(MSG_BADE);
-
-    let builder = MessageBuilder::new().embed(embed);
-
-    config.osu = match handle_ephemeral(command, builder, fut).await {
-        Some(Ok(user)) => {
-            let user_id = user.user_id;
-
-            tokio::spawn(async move {
-                Context::osu_user().store(&user, user.mode).await;
-            });
-
-            Some(user_id)
-        }
+    config.osu = match authenticate_osu(command, OsuScope::Identify).await {
+        Some(Ok((user_id, ..))) => Some(user_id),
         Some(Err(err)) => return HandleResult::Err(err),
         None => return HandleResult::Done,
     };
@@ -470,9 +500,7 @@ async fn handle_osu_link(
                 config.twitch_id.take();
             }
             Err(err) => {
-                let _ = command
-                    .error(bathbot_util::constants::TWITCH_API_ISSUE)
-                    .await;
+                let _ = command.error(Issue::TwitchApi).await;
 
                 return HandleResult::Err(err.wrap_err("failed to get twitch user by id"));
             }
@@ -480,7 +508,7 @@ async fn handle_osu_link(
     }
 
     if let Err(err) = Context::user_config().store(author.id, config).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error(Issue::General).await;
 
         return HandleResult::Err(err);
     }
@@ -488,6 +516,38 @@ async fn handle_osu_link(
     HandleResult::TwitchName(twitch_name)
 }
 
+/// Prompt the author to authenticate an osu! account and return its user id,
+/// username, and the token for the given scope.
+///
+/// Returns `None` if the author did not authenticate in time.
+#[cfg(feature = "server")]
+pub async fn authenticate_osu(
+    command: &InteractionCommand,
+    scope: OsuScope,
+) -> Option<Result<(u32, Username, OsuUserToken)>> {
+    let fut = Context::auth_standby().wait_for_osu();
+
+    let embed = EmbedBuilder::new()
+        .description(osu_content(fut.state, scope))
+        .footer(MSG_BADE);
+
+    let builder = MessageBuilder::new().embed(embed);
+
+    let res = handle_ephemeral(command, builder, fut).await?.map(|auth| {
+        let OsuAuthorization { user, token } = auth;
+        let user_id = user.user_id;
+        let username = user.username.clone();
+
+        tokio::spawn(async move {
+            Context::osu_user().store(&user, user.mode).await;
+        });
+
+        (user_id, username, token)
+    });
+
+    Some(res)
+}
+
 #[cfg(feature = "server")]
 async fn handle_ephemeral<T>(
     command: &InteractionCommand,
@@ -531,9 +591,7 @@ async fn handle_no_links(
                 config.twitch_id.take();
             }
             Err(err) => {
-                let _ = command
-                    .error(bathbot_util::constants::TWITCH_API_ISSUE)
-                    .await;
+                let _ = command.error(Issue::TwitchApi).await;
 
                 return HandleResult::Err(err.wrap_err("failed to get twitch user by id"));
             }
@@ -546,7 +604,7 @@ async fn handle_no_links(
     }
 
     if let Err(err) = Context::user_config().store(author.id, config).await {
-        let _ = command.error(GENERAL_ISSUE).await;
+        let _ = command.error(Issue::General).await;
 
         return HandleResult::Err(err);
     }
@@ -582,6 +640,8 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        mode_accounts,
+        locale,
     } = config;
 
     UserConfig {
@@ -594,6 +654,8 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        mode_accounts,
+        locale,
     }
 }
 
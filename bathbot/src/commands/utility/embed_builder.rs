@@ -1,10 +1,13 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use bathbot_macros::SlashCommand;
-use bathbot_model::{embed_builder::ScoreEmbedSettings, ScoreSlim};
+use bathbot_model::{
+    embed_builder::ScoreEmbedSettings, rosu_v2::user::User, twilight_model::id::IdNiche, ScoreSlim,
+};
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{constants::GENERAL_ISSUE, CowUtils, MessageOrigin};
-use eyre::{Report, Result};
+use bathbot_util::{CowUtils, IntHasher, MessageOrigin};
+use eyre::{Report, Result, WrapErr};
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
 use rosu_pp::model::beatmap::BeatmapAttributes;
 use rosu_v2::{
     model::{GameMode, Grade},
@@ -18,9 +21,15 @@ use twilight_model::id::{
 };
 
 use crate::{
-    active::{impls::ScoreEmbedBuilderActive, ActiveMessages},
-    core::Context,
-    manager::{redis::osu::UserArgsSlim, MapError, OsuMap, OwnedReplayScore, PpManager},
+    active::{
+        impls::{ScoreEmbedBuilderActive, ScoreEmbedTarget},
+        ActiveMessages,
+    },
+    core::{locale::Issue, Context},
+    manager::{
+        redis::{osu::UserArgsSlim, RedisData},
+        MapError, OsuMap, OwnedReplayScore, PpManager,
+    },
     util::{
         interaction::InteractionCommand,
         osu::{IfFc, PersonalBestIndex},
@@ -78,7 +87,7 @@ async fn edit(command: &mut InteractionCommand) -> Result<()> {
     let config = match Context::user_config().with_osu_id(command.user_id()?).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -97,7 +106,7 @@ async fn edit(command: &mut InteractionCommand) -> Result<()> {
 
     let settings = config.score_embed.unwrap_or_default();
 
-    exec(command, settings, score_data).await
+    exec(command, settings, score_data, ScoreEmbedTarget::User).await
 }
 
 async fn copy(command: &mut InteractionCommand, args: ScoreEmbedBuilderCopy) -> Result<()> {
@@ -109,7 +118,7 @@ async fn copy(command: &mut InteractionCommand, args: ScoreEmbedBuilderCopy) ->
     let (config1, config2) = match tokio::try_join!(config_fut1, config_fut2) {
         Ok(tuple) => tuple,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -134,7 +143,7 @@ async fn copy(command: &mut InteractionCommand, args: ScoreEmbedBuilderCopy) ->
         warn!(?err);
     }
 
-    exec(command, settings, score_data).await
+    exec(command, settings, score_data, ScoreEmbedTarget::User).await
 }
 
 async fn default(command: &mut InteractionCommand) -> Result<()> {
@@ -143,7 +152,7 @@ async fn default(command: &mut InteractionCommand) -> Result<()> {
     let config = match Context::user_config().with_osu_id(author).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -168,17 +177,37 @@ async fn default(command: &mut InteractionCommand) -> Result<()> {
         warn!(?err);
     }
 
-    exec(command, settings, score_data).await
+    exec(command, settings, score_data, ScoreEmbedTarget::User).await
 }
 
-async fn exec(
+pub(super) async fn exec(
     command: &mut InteractionCommand,
     settings: ScoreEmbedSettings,
     score_data: ScoreData,
+    target: ScoreEmbedTarget,
 ) -> Result<()> {
     let msg_owner = command.user_id()?;
-    let legacy_scores = score_data.is_legacy();
 
+    let (user, data) = match builder_data(score_data.is_legacy()).await {
+        Ok(tuple) => tuple,
+        Err(err) => {
+            let _ = command.error(Issue::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let active_msg =
+        ScoreEmbedBuilderActive::new(&user, data, settings, score_data, msg_owner, target);
+
+    ActiveMessages::builder(active_msg)
+        .start_by_update(true)
+        .begin(command)
+        .await
+}
+
+/// The user and score that are shown in the builder.
+pub async fn builder_data(legacy_scores: bool) -> Result<(RedisData<User>, ScoreEmbedDataWrap)> {
     let user_fut = Context::redis().osu_user_from_args(UserArgsSlim::user_id(USER_ID));
 
     let score_fut =
@@ -189,8 +218,6 @@ async fn exec(
     let (user, score, map) = match tokio::join!(user_fut, score_fut, map_fut) {
         (Ok(user), Ok(score), Ok(map)) => (user, score.score, map),
         (user_res, score_res, map_res) => {
-            let _ = command.error(GENERAL_ISSUE).await;
-
             let (err, wrap) = if let Err(err) = user_res {
                 (Report::new(err), "Failed to get user for builder")
             } else if let Err(err) = score_res {
@@ -212,12 +239,7 @@ async fn exec(
         data.score.statistics.count_geki = 480;
     }
 
-    let active_msg = ScoreEmbedBuilderActive::new(&user, data, settings, score_data, msg_owner);
-
-    ActiveMessages::builder(active_msg)
-        .start_by_update(true)
-        .begin(command)
-        .await
+    Ok((user, data))
 }
 
 pub struct ScoreEmbedDataWrap {
@@ -566,6 +588,83 @@ impl ScoreEmbedDataHalf {
     }
 }
 
+/// [`ScoreEmbedDataHalf`] without its map so that it can be persisted.
+#[derive(Archive, RkyvDeserialize, RkyvSerialize)]
+pub struct PersistedScoreEmbedDataHalf {
+    user_id: u32,
+    map_id: u32,
+    checksum: Option<String>,
+    score: ScoreSlim,
+    stars: f32,
+    max_combo: u32,
+    max_pp: f32,
+    pb_idx: Option<ScoreEmbedDataPersonalBest>,
+    legacy_scores: bool,
+    with_render: bool,
+    miss_analyzer_check: MissAnalyzerCheck,
+    original_idx: Option<usize>,
+}
+
+impl PersistedScoreEmbedDataHalf {
+    pub fn new(half: &ScoreEmbedDataHalf) -> Self {
+        Self {
+            user_id: half.user_id,
+            map_id: half.map.map_id(),
+            checksum: half.checksum.clone(),
+            score: half.score.clone(),
+            stars: half.stars,
+            max_combo: half.max_combo,
+            max_pp: half.max_pp,
+            pb_idx: half.pb_idx.clone(),
+            legacy_scores: half.legacy_scores,
+            with_render: half.with_render,
+            miss_analyzer_check: half.miss_analyzer_check,
+            original_idx: half.original_idx,
+        }
+    }
+
+    /// Fetch the maps again and restore the [`ScoreEmbedDataHalf`]s.
+    ///
+    /// Entries whose map is no longer available are skipped.
+    pub async fn restore(entries: Vec<Self>) -> Result<Box<[ScoreEmbedDataWrap]>> {
+        let maps_id_checksum: HashMap<_, _, IntHasher> = entries
+            .iter()
+            .map(|entry| (entry.map_id as i32, None))
+            .collect();
+
+        let maps = Context::osu_map()
+            .maps(&maps_id_checksum)
+            .await
+            .wrap_err("Failed to get maps")?;
+
+        let entries = entries
+            .into_iter()
+            .filter_map(|entry| {
+                let map = maps.get(&entry.map_id)?.clone().convert(entry.score.mode);
+
+                let half = ScoreEmbedDataHalf {
+                    user_id: entry.user_id,
+                    checksum: entry.checksum,
+                    score: entry.score,
+                    map,
+                    stars: entry.stars,
+                    max_combo: entry.max_combo,
+                    max_pp: entry.max_pp,
+                    pb_idx: entry.pb_idx,
+                    legacy_scores: entry.legacy_scores,
+                    with_render: entry.with_render,
+                    miss_analyzer_check: entry.miss_analyzer_check,
+                    original_idx: entry.original_idx,
+                };
+
+                Some(ScoreEmbedDataWrap::from(half))
+            })
+            .collect();
+
+        Ok(entries)
+    }
+}
+
 pub struct ScoreEmbedData {
     pub score: ScoreSlim,
     pub map: OsuMap,
@@ -919,8 +1018,9 @@ impl<'m> PpAttrs<'m> {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Archive, Copy, Clone, RkyvDeserialize, RkyvSerialize)]
 pub struct MissAnalyzerCheck {
+    #[with(IdNiche)]
     guild_id: Option<Id<GuildMarker>>,
 }
 
@@ -936,6 +1036,7 @@ impl MissAnalyzerCheck {
     }
 }
 
+#[derive(Archive, Clone, RkyvDeserialize, RkyvSerialize)]
 pub struct ScoreEmbedDataPersonalBest {
     /// Note that `idx` is 0-indexed.
     pub idx: Option<usize>,
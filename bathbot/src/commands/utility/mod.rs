@@ -1,14 +1,26 @@
+mod alias;
 mod authorities;
+mod auto_embeds;
 mod changelog;
+mod command_rules;
 mod commands;
 mod config;
 mod embed_builder;
 mod invite;
 mod ping;
 mod prefix;
+mod privacy;
+mod ratelimit;
 mod roll;
+mod schedule;
+mod score_embed;
 mod server_config;
 mod skin;
+mod status;
+mod webhooks;
 
 #[allow(unused_imports)]
-pub use self::{authorities::*, changelog::*, config::*, embed_builder::*, skin::*};
+pub use self::{
+    alias::*, authorities::*, auto_embeds::*, changelog::*, command_rules::*, config::*,
+    embed_builder::*, prefix::PrefixValidator, ratelimit::*, score_embed::*, skin::*, webhooks::*,
+};
@@ -49,7 +49,7 @@ async fn ping(orig: CommandOrigin<'_>) -> Result<()> {
         CommandOrigin::Message { permissions, .. } => {
             CommandOrigin::from_msg(&response, permissions)
         }
-        orig @ CommandOrigin::Interaction { .. } => orig,
+        orig @ (CommandOrigin::Interaction { .. } | CommandOrigin::Scheduled { .. }) => orig,
     };
 
     let content = format!(":ping_pong: Pong! ({elapsed}ms)");
@@ -3,12 +3,16 @@ use std::{cmp::Ordering, fmt::Write};
 use aho_corasick::{AhoCorasick, AhoCorasickBuilder};
 use bathbot_macros::command;
 use bathbot_psql::model::configs::{GuildConfig, Prefix, Prefixes, DEFAULT_PREFIX};
-use bathbot_util::{constants::GENERAL_ISSUE, matcher, MessageBuilder};
+use bathbot_util::{matcher, MessageBuilder};
 use eyre::Result;
 use once_cell::sync::OnceCell;
 use twilight_model::guild::Permissions;
 
-use crate::{core::commands::checks::check_authority, util::ChannelExt, Context};
+use crate::{
+    core::{commands::checks::check_authority, locale::Issue},
+    util::ChannelExt,
+    Context,
+};
 
 #[command]
 #[desc("Change my prefixes for a server")]
@@ -53,7 +57,7 @@ async fn prefix_prefix(
             return Ok(());
         }
         Err(err) => {
-            let _ = msg.error(GENERAL_ISSUE).await;
+            let _ = msg.error(Issue::General).await;
 
             return Err(err.wrap_err("Failed to check authority status"));
         }
@@ -158,7 +162,7 @@ async fn prefix_prefix(
             Ok(())
         }
         Err(err) => {
-            let _ = msg.error(GENERAL_ISSUE).await;
+            let _ = msg.error(Issue::General).await;
 
             Err(err.wrap_err("failed to update guild config"))
         }
@@ -185,10 +189,10 @@ fn current_prefixes(content: &mut String, prefixes: &[Prefix]) {
     }
 }
 
-struct PrefixValidator;
+pub struct PrefixValidator;
 
 impl PrefixValidator {
-    fn is_valid(prefix: &str) -> bool {
+    pub fn is_valid(prefix: &str) -> bool {
         !VALIDATOR
             .get_or_init(|| {
                 let needles = ["ojhhf", "gbhhpu", "ijumf"]
@@ -0,0 +1,102 @@
+use bathbot_macros::SlashCommand;
+use bathbot_util::MessageBuilder;
+use eyre::{Report, Result};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    active::{impls::PrivacyDeletion, ActiveMessages},
+    core::locale::Issue,
+    util::{interaction::InteractionCommand, Authored, ChannelExt, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "privacy",
+    desc = "Export or delete the data stored about you",
+    help = "Export or delete all data that is stored about you.\n\
+    That includes your configuration, linked osu! and twitch accounts, skin url, \
+    score embed and render settings, bookmarks, game scores, aliases, scheduled commands, \
+    api keys you own, and the authorization to access your osu! friends."
+)]
+#[flags(EPHEMERAL)]
+pub enum Privacy {
+    #[command(name = "export")]
+    Export(PrivacyExport),
+    #[command(name = "delete")]
+    Delete(PrivacyDelete),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "export", desc = "Receive all data stored about you via DM")]
+pub struct PrivacyExport;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "delete", desc = "Delete all data stored about you")]
+pub struct PrivacyDelete;
+
+async fn slash_privacy(mut command: InteractionCommand) -> Result<()> {
+    match Privacy::from_interaction(command.input_data())? {
+        Privacy::Export(_) => export(command).await,
+        Privacy::Delete(_) => {
+            let active_msg = PrivacyDeletion::new(command.user_id()?);
+
+            ActiveMessages::builder(active_msg)
+                .start_by_update(true)
+                .begin(&mut command)
+                .await
+        }
+    }
+}
+
+async fn export(command: InteractionCommand) -> Result<()> {
+    let owner = command.user_id()?;
+
+    let data = match Context::user_config().export_data(owner).await {
+        Ok(data) => data,
+        Err(err) => {
+            let _ = command.error(Issue::General).await;
+
+            return Err(err);
+        }
+    };
+
+    let bytes = match serde_json::to_vec_pretty(&data) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = command.error(Issue::General).await;
+
+            return Err(Report::new(err).wrap_err("Failed to serialize user data"));
+        }
+    };
+
+    let channel = match Context::http().create_private_channel(owner).await {
+        Ok(channel_res) => channel_res.model().await?.id,
+        Err(err) => {
+            warn!(?err, "Failed to create DM channel");
+            command
+                .error("Could not DM you, perhaps you disabled it?")
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let builder = MessageBuilder::new()
+        .embed("Here is all data stored about you")
+        .attachment("bathbot_data.json", bytes);
+
+    if let Err(err) = channel.create_message(builder, None).await {
+        warn!(?err, "Failed to DM user data");
+        command
+            .error("Could not DM you, perhaps you disabled it?")
+            .await?;
+
+        return Ok(());
+    }
+
+    let builder = MessageBuilder::new().embed("Sent you a DM with your data");
+    command.update(builder).await?;
+
+    Ok(())
+}
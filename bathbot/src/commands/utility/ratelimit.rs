@@ -0,0 +1,84 @@
+use bathbot_psql::model::configs::{BucketOverride, GuildConfig};
+use bathbot_util::MessageBuilder;
+use eyre::Result;
+
+use super::server_config::{ServerConfigBucket, ServerConfigRatelimit};
+use crate::{
+    core::{
+        buckets::{BucketName, Ratelimit},
+        locale::Issue,
+        BotConfig, Context,
+    },
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+pub async fn bucket_override(
+    command: InteractionCommand,
+    args: ServerConfigRatelimit,
+) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    let ServerConfigRatelimit {
+        bucket,
+        delay,
+        time_span,
+        limit,
+    } = args;
+
+    let bucket = BucketName::from(bucket);
+    let default = BotConfig::get().ratelimit(bucket);
+
+    let custom = (delay.is_some() || time_span.is_some() || limit.is_some()).then(|| Ratelimit {
+        delay: delay.map_or(default.delay, |delay| delay as u32),
+        time_span: time_span.map_or(default.time_span, |time_span| time_span as u32),
+        limit: limit.map_or(default.limit, |limit| limit as u32),
+    });
+
+    let f = |config: &mut GuildConfig| {
+        let entry = custom.map(|ratelimit| BucketOverride {
+            bucket: bucket.key().to_owned(),
+            delay: ratelimit.delay,
+            time_span: ratelimit.time_span,
+            limit: ratelimit.limit,
+        });
+
+        config.bucket_overrides.set(bucket.key(), entry);
+    };
+
+    if let Err(err) = Context::guild_config().update(guild_id, f).await {
+        let _ = command.error_callback(Issue::General).await;
+
+        return Err(err.wrap_err("failed to update guild config"));
+    }
+
+    let name = bucket.key();
+
+    let content = match custom {
+        Some(ratelimit) => format!(
+            "The `{name}` cooldown of this server now allows {limit} use{plural} \
+            per {time_span} seconds with at least {delay} seconds in between",
+            limit = ratelimit.limit,
+            plural = if ratelimit.limit == 1 { "" } else { "s" },
+            time_span = ratelimit.time_span,
+            delay = ratelimit.delay,
+        ),
+        None => format!("The `{name}` cooldown of this server has been reset to the default"),
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+impl From<ServerConfigBucket> for BucketName {
+    fn from(bucket: ServerConfigBucket) -> Self {
+        match bucket {
+            ServerConfigBucket::AutoEmbed => Self::AutoEmbed,
+            ServerConfigBucket::MatchCompare => Self::MatchCompare,
+            ServerConfigBucket::MatchLive => Self::MatchLive,
+            ServerConfigBucket::Render => Self::Render,
+            ServerConfigBucket::Songs => Self::Songs,
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{EmbedBuilder, MessageBuilder};
+use eyre::Result;
+use time::OffsetDateTime;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use crate::{
+    core::{
+        commands::interaction::{InteractionCommandKind, InteractionCommands},
+        locale::Issue,
+    },
+    scheduler::{parse_options, CronSchedule, SCHEDULABLE_COMMANDS},
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    Context,
+};
+
+/// Maximum amount of scheduled jobs per guild
+const MAX_JOBS: usize = 10;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "schedule",
+    dm_permission = false,
+    desc = "Run commands periodically in a channel",
+    help = "Run commands periodically in a channel.\n\
+    The commands are run as if you used them yourself so your linked account \
+    and command restrictions of this server apply."
+)]
+#[flags(AUTHORITY, SKIP_DEFER)]
+pub enum Schedule {
+    #[command(name = "add")]
+    Add(ScheduleAdd),
+    #[command(name = "list")]
+    List(ScheduleList),
+    #[command(name = "remove")]
+    Remove(ScheduleRemove),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Schedule a command",
+    help = "Schedule a command.\n\
+    The command is specified like a slash command without the leading `/` i.e. \
+    the command name, then its subcommands, and then its options as `option:value` pairs, \
+    e.g. `ranking country mode:mania` or `graph rank name:peppy`.\n\
+    If a value contains whitespace, quote the whole pair like `\"name:some user\"`.\n\n\
+    The schedule consists of the five fields `minute hour day-of-month month day-of-week` in UTC, \
+    e.g. `0 18 * * mon` for every monday at 18:00 or `30 6 1 * *` for the first day \
+    of every month at 6:30.\n\
+    Fields can also be `*`, lists like `1,15`, ranges like `mon-fri`, or steps like `*/6`.\n\
+    Instead of five fields, `@hourly`, `@daily`, `@weekly`, or `@monthly` can be used.\n\
+    Commands can run at most once per hour."
+)]
+pub struct ScheduleAdd {
+    #[command(desc = "Specify the command e.g. `ranking country mode:mania`")]
+    command: String,
+    #[command(desc = "Specify a cron-like schedule in UTC e.g. `0 18 * * mon` or `@daily`")]
+    schedule: String,
+    #[command(
+        channel_types = "guild_text",
+        desc = "Specify a channel, defaults to the current channel"
+    )]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List all scheduled commands of this server")]
+pub struct ScheduleList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Remove a scheduled command")]
+pub struct ScheduleRemove {
+    #[command(
+        min_value = 1,
+        max_value = 2_147_483_647,
+        desc = "Specify the id of the scheduled command"
+    )]
+    id: i64,
+}
+
+async fn slash_schedule(mut command: InteractionCommand) -> Result<()> {
+    match Schedule::from_interaction(command.input_data())? {
+        Schedule::Add(args) => add(command, args).await,
+        Schedule::List(_) => list(command).await,
+        Schedule::Remove(args) => remove(command, args).await,
+    }
+}
+
+async fn add(command: InteractionCommand, args: ScheduleAdd) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+    let owner = command.user_id()?;
+    let channel = args.channel.unwrap_or(command.channel_id);
+
+    let schedule = match args.schedule.parse::<CronSchedule>() {
+        Ok(schedule) if schedule.is_hourly_or_less() => schedule,
+        Ok(_) => {
+            let content = "Commands can run at most once per hour; \
+                the minute field of the schedule must be a single value";
+
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+        Err(content) => {
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let now = OffsetDateTime::now_utc();
+
+    let Some(next_run) = schedule.next_after(now) else {
+        let content = "The schedule never runs, is the date valid?";
+
+        command.error_callback(content).await?;
+
+        return Ok(());
+    };
+
+    let input = args.command.trim().trim_start_matches('/');
+
+    let (name, cmd_args) = input
+        .split_once(char::is_whitespace)
+        .map_or((input, ""), |(name, args)| (name, args.trim()));
+
+    let name = name.to_ascii_lowercase();
+
+    let definition = match InteractionCommands::get_command(&name) {
+        Some(cmd @ InteractionCommandKind::Chat(_))
+            if SCHEDULABLE_COMMANDS.contains(&name.as_str()) =>
+        {
+            cmd.create()
+        }
+        _ => {
+            let commands: Vec<_> = SCHEDULABLE_COMMANDS
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .collect();
+
+            let content = format!(
+                "`{name}` cannot be scheduled, available commands: {}",
+                commands.join(", ")
+            );
+
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    if let Err(content) = parse_options(&definition.options, cmd_args) {
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
+    let psql = Context::psql();
+
+    let jobs = match psql.select_guild_scheduled_jobs(guild_id).await {
+        Ok(jobs) => jobs,
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            return Err(err.wrap_err("failed to get scheduled jobs"));
+        }
+    };
+
+    if jobs.len() >= MAX_JOBS {
+        let content = format!(
+            "This server already has {MAX_JOBS} scheduled commands, \
+            remove one via `/schedule remove` first"
+        );
+
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
+    let insert_fut = psql.insert_scheduled_job(
+        guild_id,
+        channel,
+        owner,
+        args.schedule.trim(),
+        &name,
+        cmd_args,
+        next_run,
+    );
+
+    let job_id = match insert_fut.await {
+        Ok(job_id) => job_id,
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            return Err(err.wrap_err("failed to insert scheduled job"));
+        }
+    };
+
+    let content = format!(
+        "Scheduled `/{name}{space}{cmd_args}` in <#{channel}> with id `{job_id}`, \
+        next run <t:{next}:R>",
+        space = if cmd_args.is_empty() { "" } else { " " },
+        next = next_run.unix_timestamp(),
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+async fn list(command: InteractionCommand) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    let jobs = match Context::psql().select_guild_scheduled_jobs(guild_id).await {
+        Ok(jobs) => jobs,
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            return Err(err.wrap_err("failed to get scheduled jobs"));
+        }
+    };
+
+    let description = if jobs.is_empty() {
+        "No commands are scheduled in this server".to_owned()
+    } else {
+        let mut description = String::with_capacity(jobs.len() * 128);
+
+        for job in jobs {
+            let _ = writeln!(
+                description,
+                "`{id}` `/{name}{space}{args}` in <#{channel}> by <@{user}>\n\
+                ╰ `{schedule}` • next run <t:{next}:R>",
+                id = job.job_id,
+                name = job.command,
+                space = if job.args.is_empty() { "" } else { " " },
+                args = job.args,
+                channel = job.channel_id,
+                user = job.user_id,
+                schedule = job.schedule,
+                next = job.next_run.unix_timestamp(),
+            );
+        }
+
+        description
+    };
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Scheduled commands");
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+async fn remove(command: InteractionCommand, args: ScheduleRemove) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+    let job_id = args.id as i32;
+
+    let content = match Context::psql().delete_scheduled_job(guild_id, job_id).await {
+        Ok(true) => format!("Removed the scheduled command with id `{job_id}`"),
+        Ok(false) => {
+            let content = format!("This server has no scheduled command with id `{job_id}`");
+
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_callback(Issue::General).await;
+
+            return Err(err.wrap_err("failed to delete scheduled job"));
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use bathbot_psql::model::configs::GuildConfig;
+use bathbot_util::MessageBuilder;
+use eyre::Result;
+
+use super::server_config::{ServerConfigScoreEmbed, ServerConfigScoreEmbedChannel};
+use crate::{
+    active::impls::ScoreEmbedTarget,
+    core::{locale::Issue, Context},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+pub async fn score_embed(
+    mut command: InteractionCommand,
+    args: ServerConfigScoreEmbed,
+) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    match args {
+        ServerConfigScoreEmbed::Edit(_) => {
+            command.defer(true).await?;
+
+            let (settings, score_data) = Context::guild_config()
+                .peek(guild_id, |config| {
+                    (config.score_embed.clone(), config.score_data)
+                })
+                .await;
+
+            let settings = settings.unwrap_or_default();
+            let score_data = score_data.unwrap_or_default();
+            let target = ScoreEmbedTarget::Guild(guild_id);
+
+            super::embed_builder::exec(&mut command, settings, score_data, target).await
+        }
+        ServerConfigScoreEmbed::Reset(_) => {
+            let f = |config: &mut GuildConfig| config.score_embed = None;
+
+            if let Err(err) = Context::guild_config().update(guild_id, f).await {
+                let _ = command.error_callback(Issue::General).await;
+
+                return Err(err.wrap_err("Failed to update guild config"));
+            }
+
+            let content = "Removed the server's score embed format, \
+                members' formats or the default format will be used instead";
+            let builder = MessageBuilder::new().embed(content);
+            command.callback(builder, false).await?;
+
+            Ok(())
+        }
+        ServerConfigScoreEmbed::Channel(ServerConfigScoreEmbedChannel { channel }) => {
+            let f = |config: &mut GuildConfig| {
+                let channels = &mut config.score_embed_channels;
+
+                let enabled = match channels.iter().position(|&id| id == channel) {
+                    Some(idx) => {
+                        channels.swap_remove(idx);
+
+                        false
+                    }
+                    None => {
+                        channels.push(channel);
+
+                        true
+                    }
+                };
+
+                (enabled, config.score_embed.is_some())
+            };
+
+            let (enabled, has_settings) = match Context::guild_config().update(guild_id, f).await {
+                Ok(tuple) => tuple,
+                Err(err) => {
+                    let _ = command.error_callback(Issue::General).await;
+
+                    return Err(err.wrap_err("Failed to update guild config"));
+                }
+            };
+
+            let status = if enabled { "now" } else { "no longer" };
+
+            let mut content = format!(
+                "The server's score embed format {status} overrides \
+                members' formats in <#{channel}>"
+            );
+
+            if enabled && !has_settings {
+                content.push_str(
+                    "\nNote that the server has no format yet, \
+                    you can build one via `/serverconfig scoreembed edit`",
+                );
+            }
+
+            let builder = MessageBuilder::new().embed(content);
+            command.callback(builder, false).await?;
+
+            Ok(())
+        }
+    }
+}
@@ -1,13 +1,18 @@
 use bathbot_macros::{command, SlashCommand};
-use bathbot_model::command_fields::{EnableDisable, ShowHideOption};
-use bathbot_psql::model::configs::{GuildConfig, HideSolutions, ListSize, Retries, ScoreData};
-use bathbot_util::constants::GENERAL_ISSUE;
+use bathbot_model::command_fields::{EnableDisable, GameModeOption, ShowHideOption};
+use bathbot_psql::model::configs::{
+    GuildConfig, HideSolutions, ListSize, Locale, Retries, ScoreData,
+};
 use eyre::Result;
-use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_model::id::{marker::RoleMarker, Id};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{
+    marker::{ChannelMarker, RoleMarker},
+    Id,
+};
 
 use super::AuthorityCommandKind;
 use crate::{
+    core::locale::Issue,
     embeds::{EmbedData, ServerConfigEmbed},
     util::{interaction::InteractionCommand, InteractionCommandExt},
     Context,
@@ -23,6 +28,18 @@ use crate::{
 pub enum ServerConfig {
     #[command(name = "authorities")]
     Authorities(ServerConfigAuthorities),
+    #[command(name = "commands")]
+    Commands(ServerConfigCommands),
+    #[command(name = "channelmode")]
+    ChannelMode(ServerConfigChannelMode),
+    #[command(name = "ratelimit")]
+    Ratelimit(ServerConfigRatelimit),
+    #[command(name = "autoembeds")]
+    AutoEmbeds(ServerConfigAutoEmbeds),
+    #[command(name = "scoreembed")]
+    ScoreEmbed(ServerConfigScoreEmbed),
+    #[command(name = "webhooks")]
+    Webhooks(ServerConfigWebhooks),
     #[command(name = "edit")]
     Edit(ServerConfigEdit),
 }
@@ -85,6 +102,298 @@ pub struct ServerConfigAuthoritiesRemove {
 #[command(name = "list", desc = "Display all current authority roles")]
 pub struct ServerConfigAuthoritiesList;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "commands",
+    desc = "Restrict where and by whom commands can be used",
+    help = "Restrict where and by whom commands can be used.\n\
+    Restrictions can target a single command by its name, e.g. `bg` or `track`, \
+    or a whole command group by prefixing the group with `group:`, e.g. `group:games`.\n\
+    Available groups: `allmodes`, `osu`, `taiko`, `catch`, `mania`, `tracking`, \
+    `twitch`, `games`, `utility`, `songs`.\n\
+    Slash commands and commands for all modes are also affected by the groups \
+    `osu`, `taiko`, `catch`, and `mania` since they can be used for any mode.\n\
+    Prefix command aliases only restrict the prefix command, not the slash command.\n\
+    Members with admin permissions are not affected by restrictions."
+)]
+pub enum ServerConfigCommands {
+    #[command(name = "disable")]
+    Disable(ServerConfigCommandsDisable),
+    #[command(name = "enable")]
+    Enable(ServerConfigCommandsEnable),
+    #[command(name = "channel")]
+    Channel(ServerConfigCommandsChannel),
+    #[command(name = "role")]
+    Role(ServerConfigCommandsRole),
+    #[command(name = "reset")]
+    Reset(ServerConfigCommandsReset),
+    #[command(name = "list")]
+    List(ServerConfigCommandsList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "disable",
+    desc = "Disable a command in a channel or the whole server"
+)]
+pub struct ServerConfigCommandsDisable {
+    #[command(desc = "Specify a command name or a group like `group:games`")]
+    pub command: String,
+    #[command(
+        channel_types = "guild_text",
+        desc = "Specify a channel, defaults to the whole server"
+    )]
+    pub channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "enable",
+    desc = "Enable a previously disabled command",
+    help = "Enable a previously disabled command.\n\
+    If no channel is specified, the command will be enabled in all channels."
+)]
+pub struct ServerConfigCommandsEnable {
+    #[command(desc = "Specify a command name or a group like `group:games`")]
+    pub command: String,
+    #[command(
+        channel_types = "guild_text",
+        desc = "Specify a channel, defaults to all channels"
+    )]
+    pub channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "channel",
+    desc = "Toggle a channel as allowed channel for a command",
+    help = "Toggle a channel as allowed channel for a command.\n\
+    If a command has allowed channels, it can only be used in those channels."
+)]
+pub struct ServerConfigCommandsChannel {
+    #[command(desc = "Specify a command name or a group like `group:games`")]
+    pub command: String,
+    #[command(channel_types = "guild_text", desc = "Specify the channel to toggle")]
+    pub channel: Id<ChannelMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "role",
+    desc = "Toggle a role as required role for a command",
+    help = "Toggle a role as required role for a command.\n\
+    If a command has required roles, only members with any of those roles can use it."
+)]
+pub struct ServerConfigCommandsRole {
+    #[command(desc = "Specify a command name or a group like `group:games`")]
+    pub command: String,
+    #[command(desc = "Specify the role to toggle")]
+    pub role: Id<RoleMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reset", desc = "Remove all restrictions of a command")]
+pub struct ServerConfigCommandsReset {
+    #[command(desc = "Specify a command name or a group like `group:games`")]
+    pub command: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "Display all command restrictions and channel modes"
+)]
+pub struct ServerConfigCommandsList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "channelmode",
+    desc = "Set the default mode for commands in a channel",
+    help = "Set the default mode for commands in a channel.\n\
+    The channel mode takes precedence over a member's configured mode \
+    but an explicitly specified mode always takes precedence.\n\
+    If no mode is specified, the channel's default mode will be removed."
+)]
+pub struct ServerConfigChannelMode {
+    #[command(desc = "Specify the default mode, unsets it if not specified")]
+    pub mode: Option<GameModeOption>,
+    #[command(
+        channel_types = "guild_text",
+        desc = "Specify a channel, defaults to the current channel"
+    )]
+    pub channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "ratelimit",
+    desc = "Adjust the cooldown of a command for this server",
+    help = "Adjust the cooldown of a command for this server.\n\
+    Values that are not specified keep the bot's default for the command.\n\
+    If neither `delay`, `time_span`, nor `limit` are specified, \
+    the cooldown is reset to the bot's default."
+)]
+pub struct ServerConfigRatelimit {
+    #[command(desc = "Choose the command")]
+    pub bucket: ServerConfigBucket,
+    #[command(
+        min_value = 0,
+        max_value = 3600,
+        desc = "Minimum amount of seconds between two uses"
+    )]
+    pub delay: Option<i64>,
+    #[command(
+        min_value = 0,
+        max_value = 86_400,
+        desc = "Amount of seconds in which at most `limit` uses are allowed"
+    )]
+    pub time_span: Option<i64>,
+    #[command(
+        min_value = 1,
+        max_value = 100,
+        desc = "Maximum amount of uses within `time_span`"
+    )]
+    pub limit: Option<i64>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum ServerConfigBucket {
+    #[option(name = "autoembed", value = "auto_embed")]
+    AutoEmbed,
+    #[option(name = "matchcompare", value = "match_compare")]
+    MatchCompare,
+    #[option(name = "matchlive", value = "match_live")]
+    MatchLive,
+    #[option(name = "render", value = "render")]
+    Render,
+    #[option(name = "song", value = "songs")]
+    Songs,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "autoembeds",
+    desc = "Choose which osu! links should automatically be embedded",
+    help = "Choose which osu! links should automatically be embedded.\n\
+    If enabled, I will reply to messages containing such a link with a compact embed.\n\
+    Embeds are limited per channel, the cooldown can be adjusted via \
+    `/serverconfig ratelimit bucket:autoembed`.\n\
+    Options that are not specified keep their current value."
+)]
+pub struct ServerConfigAutoEmbeds {
+    #[command(desc = "Embed beatmap links")]
+    pub map: Option<bool>,
+    #[command(desc = "Embed score links")]
+    pub score: Option<bool>,
+    #[command(desc = "Embed user profile links")]
+    pub user: Option<bool>,
+    #[command(rename = "match", desc = "Embed multiplayer match links")]
+    pub osu_match: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "scoreembed",
+    desc = "Adjust the server's score embed format",
+    help = "Adjust the server's score embed format.\n\
+    The format is built the same way as with `/builder` and is used for members \
+    that did not build a format themselves.\n\
+    In specific channels, the server's format can also override the members' formats, \
+    e.g. to have a fixed format in a tracking channel.\n\
+    Applies to `/rs`, `/cs`, `/top`, `/pinned`, `/mapper`, and osu!tracking notifications."
+)]
+pub enum ServerConfigScoreEmbed {
+    #[command(name = "edit")]
+    Edit(ServerConfigScoreEmbedEdit),
+    #[command(name = "reset")]
+    Reset(ServerConfigScoreEmbedReset),
+    #[command(name = "channel")]
+    Channel(ServerConfigScoreEmbedChannel),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "edit", desc = "Edit the server's score embed format")]
+pub struct ServerConfigScoreEmbedEdit;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "reset",
+    desc = "Remove the server's score embed format",
+    help = "Remove the server's score embed format.\n\
+    Channels in which the format overrides the members' formats are kept."
+)]
+pub struct ServerConfigScoreEmbedReset;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "channel",
+    desc = "Toggle whether the server's format overrides members' formats in a channel"
+)]
+pub struct ServerConfigScoreEmbedChannel {
+    #[command(channel_types = "guild_text", desc = "Specify the channel to toggle")]
+    pub channel: Id<ChannelMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "webhooks",
+    desc = "Deliver tracking notifications to webhooks",
+    help = "Deliver the server's tracking notifications as JSON to HTTPS webhooks.\n\
+    Events of osu!tracking, twitch stream tracking, and live match tracking are sent \
+    if one of the server's channels receives them.\n\
+    Each request contains the headers `X-Bathbot-Event`, `X-Bathbot-Timestamp`, \
+    and `X-Bathbot-Signature`. The signature is `sha256=` followed by the hex encoded \
+    HMAC-SHA256 of `{timestamp}.{body}`, keyed by the webhook's secret.\n\
+    Failed deliveries are retried a few times before they're listed in `/serverconfig webhooks failures`."
+)]
+pub enum ServerConfigWebhooks {
+    #[command(name = "add")]
+    Add(ServerConfigWebhooksAdd),
+    #[command(name = "remove")]
+    Remove(ServerConfigWebhooksRemove),
+    #[command(name = "list")]
+    List(ServerConfigWebhooksList),
+    #[command(name = "failures")]
+    Failures(ServerConfigWebhooksFailures),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Add a webhook",
+    help = "Add a webhook.\n\
+    If no event is specified, the webhook receives all events. \
+    The webhook's secret is only shown once."
+)]
+pub struct ServerConfigWebhooksAdd {
+    #[command(max_length = 512, desc = "Specify the HTTPS url of the webhook")]
+    pub url: String,
+    #[command(desc = "Whether osu!tracking notifications should be delivered")]
+    pub scores: Option<bool>,
+    #[command(desc = "Whether twitch stream notifications should be delivered")]
+    pub streams: Option<bool>,
+    #[command(desc = "Whether live match updates should be delivered")]
+    pub matches: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Remove a webhook")]
+pub struct ServerConfigWebhooksRemove {
+    #[command(desc = "Specify the url of the webhook")]
+    pub url: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List the server's webhooks")]
+pub struct ServerConfigWebhooksList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "failures",
+    desc = "List the most recent failed webhook deliveries"
+)]
+pub struct ServerConfigWebhooksFailures;
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "edit", desc = "Adjust configurations for a server")]
 pub struct ServerConfigEdit {
@@ -134,6 +443,12 @@ pub struct ServerConfigEdit {
         Applies only if the member has not specified a config for themselves."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Specify the language of the bot's responses",
+        help = "Specify the language of the bot's responses.\n\
+        Applies only if the member has not specified a config for themselves."
+    )]
+    language: Option<Locale>,
 }
 
 impl ServerConfigEdit {
@@ -147,6 +462,7 @@ impl ServerConfigEdit {
             allow_custom_skins,
             hide_medal_solutions,
             score_data,
+            language,
         } = self;
 
         song_commands.is_some()
@@ -157,6 +473,7 @@ impl ServerConfigEdit {
             || allow_custom_skins.is_some()
             || hide_medal_solutions.is_some()
             || score_data.is_some()
+            || language.is_some()
     }
 }
 
@@ -169,12 +486,12 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
         Ok(Some(guild)) => guild,
         Ok(None) => {
             warn!("Missing guild {guild_id} in cache");
-            command.error(GENERAL_ISSUE).await?;
+            command.error(Issue::General).await?;
 
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -184,6 +501,12 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
         ServerConfig::Authorities(args) => {
             return super::authorities((&mut command).into(), args.into()).await
         }
+        ServerConfig::Commands(args) => return super::command_rules(command, args).await,
+        ServerConfig::ChannelMode(args) => return super::channel_mode(command, args).await,
+        ServerConfig::Ratelimit(args) => return super::bucket_override(command, args).await,
+        ServerConfig::AutoEmbeds(args) => return super::auto_embeds(command, args).await,
+        ServerConfig::ScoreEmbed(args) => return super::score_embed(command, args).await,
+        ServerConfig::Webhooks(args) => return super::webhooks(command, args).await,
         ServerConfig::Edit(edit) => edit,
     };
 
@@ -198,6 +521,7 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
                 allow_custom_skins,
                 hide_medal_solutions,
                 score_data,
+                language,
             } = args;
 
             if let Some(list_embeds) = list_embeds {
@@ -231,10 +555,14 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
             if let Some(score_data) = score_data {
                 config.score_data = Some(score_data);
             }
+
+            if let Some(language) = language {
+                config.locale = Some(language);
+            }
         };
 
         if let Err(err) = Context::guild_config().update(guild_id, f).await {
-            let _ = command.error_callback(GENERAL_ISSUE).await;
+            let _ = command.error_callback(Issue::General).await;
 
             return Err(err.wrap_err("failed to update guild config"));
         }
@@ -4,7 +4,7 @@ use std::{
 };
 
 use bathbot_macros::{HasName, SlashCommand};
-use bathbot_util::{constants::GENERAL_ISSUE, matcher, EmbedBuilder, MessageBuilder};
+use bathbot_util::{matcher, EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result, WrapErr};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
@@ -12,7 +12,7 @@ use url::{SyntaxViolation, Url};
 
 use crate::{
     active::{self, ActiveMessages},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, locale::Issue, Context},
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
 };
 
@@ -50,6 +50,8 @@ pub struct CheckSkin {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Specify the name of one of your linked osu! accounts")]
+    account: Option<String>,
 }
 
 impl CheckSkin {
@@ -68,7 +70,7 @@ impl CheckSkin {
                     command.update(builder).await?;
                 }
                 Err(err) => {
-                    let _ = command.error(GENERAL_ISSUE).await;
+                    let _ = command.error(Issue::General).await;
 
                     return Err(err);
                 }
@@ -87,7 +89,7 @@ impl CheckSkin {
                     command.update(builder).await?;
                 }
                 Err(err) => {
-                    let _ = command.error(GENERAL_ISSUE).await;
+                    let _ = command.error(Issue::General).await;
 
                     return Err(err);
                 }
@@ -112,7 +114,7 @@ impl CheckSkin {
                     command.update(builder).await?;
                 }
                 Err(err) => {
-                    let _ = command.error(GENERAL_ISSUE).await;
+                    let _ = command.error(Issue::General).await;
 
                     return Err(err);
                 }
@@ -143,7 +145,7 @@ impl AllSkin {
                     .wrap_err("Failed to begin active message")
             }
             Err(err) => {
-                let _ = command.error(GENERAL_ISSUE).await;
+                let _ = command.error(Issue::General).await;
 
                 Err(err)
             }
@@ -187,7 +189,7 @@ impl SetSkin {
         let update_fut = Context::user_config().update_skin(command.user_id()?, Some(&url));
 
         if let Err(err) = update_fut.await {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -215,7 +217,7 @@ impl UnsetSkin {
         let update_fut = Context::user_config().update_skin(command.user_id()?, None);
 
         if let Err(err) = update_fut.await {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let _ = command.error(Issue::General).await;
 
             return Err(err);
         }
@@ -0,0 +1,95 @@
+use std::fmt::Write;
+
+use bathbot_client::SiteStatus;
+use bathbot_macros::{command, SlashCommand};
+use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder};
+use eyre::Result;
+use twilight_interactions::command::CreateCommand;
+
+use crate::{core::commands::CommandOrigin, util::interaction::InteractionCommand, Context};
+
+const STATUS_HELP: &str =
+    "Check the status of third-party sites such as osustats, huismetbenen, or osekai.\n\
+    If requests to a site fail repeatedly, its circuit opens and commands using it \
+    fail immediately instead of waiting for timeouts. \
+    After a short while a single request is let through to check whether the site recovered.\n\
+    Error rate and latency are based on the latest requests to each site.";
+
+#[derive(CreateCommand, SlashCommand)]
+#[command(name = "status", desc = "Check the status of third-party sites")]
+#[help(STATUS_HELP)]
+#[flags(SKIP_DEFER)]
+pub struct Status;
+
+#[command]
+#[desc("Check the status of third-party sites")]
+#[help(STATUS_HELP)]
+#[flags(SKIP_DEFER)]
+#[group(Utility)]
+async fn prefix_status(msg: &Message) -> Result<()> {
+    status(msg.into()).await
+}
+
+pub async fn slash_status(mut command: InteractionCommand) -> Result<()> {
+    status((&mut command).into()).await
+}
+
+async fn status(orig: CommandOrigin<'_>) -> Result<()> {
+    let statuses = Context::client().site_statuses();
+
+    let name_len = statuses
+        .iter()
+        .map(|status| status.site.as_str().len())
+        .max()
+        .unwrap_or(0);
+
+    let mut description = String::with_capacity(64 * (statuses.len() + 2));
+
+    let _ = writeln!(
+        description,
+        "```\n{:<name_len$} | {:<9} | {:>6} | {:>7}\n{:-<name_len$}-+-{:-<9}-+-{:-<6}-+-{:-<7}",
+        "Site", "State", "Errors", "Latency", "", "", "", "",
+    );
+
+    for status in statuses {
+        let state = if status.site.has_circuit_breaker() {
+            status.state.as_str()
+        } else {
+            "-"
+        };
+
+        let errors = if status.requests == 0 {
+            "-".to_owned()
+        } else {
+            format!("{:.1}%", status.error_rate * 100.0)
+        };
+
+        let latency = status.latency.map_or_else(
+            || "-".to_owned(),
+            |latency| format!("{}ms", latency.as_millis()),
+        );
+
+        let _ = writeln!(
+            description,
+            "{:<name_len$} | {state:<9} | {errors:>6} | {latency:>7}",
+            status.site.as_str(),
+        );
+    }
+
+    description.push_str("```");
+
+    let footer = format!(
+        "Based on the latest {} requests per site",
+        SiteStatus::SAMPLE_COUNT
+    );
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(FooterBuilder::new(footer))
+        .title("Status of third-party sites");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
@@ -0,0 +1,194 @@
+use std::fmt::Write;
+
+use bathbot_client::{validate_webhook_url, WebhookUrlError};
+use bathbot_util::MessageBuilder;
+use eyre::Result;
+
+use super::server_config::{
+    ServerConfigWebhooks, ServerConfigWebhooksAdd, ServerConfigWebhooksRemove,
+};
+use crate::{
+    core::{locale::Issue, Context},
+    manager::{WebhookEvents, MAX_WEBHOOKS},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+/// Maximum amount of displayed characters of a failed delivery's error
+const ERROR_LEN: usize = 200;
+
+pub async fn webhooks(command: InteractionCommand, args: ServerConfigWebhooks) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    match args {
+        ServerConfigWebhooks::Add(ServerConfigWebhooksAdd {
+            url,
+            scores,
+            streams,
+            matches,
+        }) => {
+            if let Err(err) = validate_webhook_url(&url).await {
+                let content = match err {
+                    WebhookUrlError::Invalid => "The url must be a valid `https://` url",
+                    WebhookUrlError::Unresolvable => "The url's host could not be resolved",
+                    WebhookUrlError::NonPublic => "The url must point to a public address",
+                };
+
+                command.error_callback(content).await?;
+
+                return Ok(());
+            }
+
+            let events = if scores.is_none() && streams.is_none() && matches.is_none() {
+                WebhookEvents::all()
+            } else {
+                let mut events = WebhookEvents::empty();
+                events.set(WebhookEvents::OSU_SCORES, scores == Some(true));
+                events.set(WebhookEvents::TWITCH_STREAMS, streams == Some(true));
+                events.set(WebhookEvents::MATCH_LIVE, matches == Some(true));
+
+                events
+            };
+
+            if events.is_empty() {
+                let content = "The webhook must receive at least one event";
+
+                command.error_callback(content).await?;
+
+                return Ok(());
+            }
+
+            let manager = Context::webhooks();
+
+            match manager.get(guild_id).await {
+                Ok(webhooks) if webhooks.len() >= MAX_WEBHOOKS => {
+                    let content = format!("Servers can have at most {MAX_WEBHOOKS} webhooks");
+
+                    command.error_callback(content).await?;
+
+                    return Ok(());
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    let _ = command.error_callback(Issue::General).await;
+
+                    return Err(err);
+                }
+            }
+
+            let secret = match manager.add(guild_id, &url, events).await {
+                Ok(Some(secret)) => secret,
+                Ok(None) => {
+                    let content = "The server already has a webhook with that url";
+
+                    command.error_callback(content).await?;
+
+                    return Ok(());
+                }
+                Err(err) => {
+                    let _ = command.error_callback(Issue::General).await;
+
+                    return Err(err);
+                }
+            };
+
+            let content = format!(
+                "Added the webhook for events `{events}`.\n\
+                Its secret is `{secret}`, make sure to store it because \
+                it won't be shown again.",
+                events = events.names(),
+            );
+
+            let builder = MessageBuilder::new().embed(content);
+            command.callback(builder, true).await?;
+
+            Ok(())
+        }
+        ServerConfigWebhooks::Remove(ServerConfigWebhooksRemove { url }) => {
+            match Context::webhooks().remove(guild_id, &url).await {
+                Ok(true) => {
+                    let builder = MessageBuilder::new().embed("Removed the webhook");
+                    command.callback(builder, true).await?;
+
+                    Ok(())
+                }
+                Ok(false) => {
+                    let content = "The server has no webhook with that url";
+
+                    command.error_callback(content).await?;
+
+                    Ok(())
+                }
+                Err(err) => {
+                    let _ = command.error_callback(Issue::General).await;
+
+                    Err(err)
+                }
+            }
+        }
+        ServerConfigWebhooks::List(_) => {
+            let webhooks = match Context::webhooks().get(guild_id).await {
+                Ok(webhooks) => webhooks,
+                Err(err) => {
+                    let _ = command.error_callback(Issue::General).await;
+
+                    return Err(err);
+                }
+            };
+
+            let content = if webhooks.is_empty() {
+                "The server has no webhooks".to_owned()
+            } else {
+                webhooks.iter().fold(String::new(), |mut content, webhook| {
+                    let events = WebhookEvents::from_bits_truncate(webhook.events);
+                    let _ = writeln!(content, "- <{}>: `{}`", webhook.url, events.names());
+
+                    content
+                })
+            };
+
+            let builder = MessageBuilder::new().embed(content);
+            command.callback(builder, true).await?;
+
+            Ok(())
+        }
+        ServerConfigWebhooks::Failures(_) => {
+            let letters = match Context::webhooks().dead_letters(guild_id).await {
+                Ok(letters) => letters,
+                Err(err) => {
+                    let _ = command.error_callback(Issue::General).await;
+
+                    return Err(err);
+                }
+            };
+
+            let content = if letters.is_empty() {
+                "There were no failed webhook deliveries".to_owned()
+            } else {
+                letters.iter().fold(String::new(), |mut content, letter| {
+                    // Keep the embed description within its length limit
+                    let error = match letter.error.char_indices().nth(ERROR_LEN) {
+                        Some((idx, _)) => format!("{}...", &letter.error[..idx]),
+                        None => letter.error.clone(),
+                    };
+
+                    let _ = writeln!(
+                        content,
+                        "- <t:{timestamp}:R> `{event}` to <{url}> after {attempts} attempts: {error}",
+                        timestamp = letter.failed_at.unix_timestamp(),
+                        event = letter.event,
+                        url = letter.url,
+                        attempts = letter.attempts,
+                        error = error,
+                    );
+
+                    content
+                })
+            };
+
+            let builder = MessageBuilder::new().embed(content);
+            command.callback(builder, true).await?;
+
+            Ok(())
+        }
+    }
+}
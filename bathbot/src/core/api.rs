@@ -0,0 +1,358 @@
+use std::collections::HashSet;
+
+use bathbot_server::api::{
+    ApiKey, ApiProvider, Bookmark, MapAttributes, MapParams, MatchCosts, MatchCostsPlayer,
+    MatchCostsResult, MatchCostsTeam, TopScore,
+};
+use eyre::{Report, Result};
+use futures::future::BoxFuture;
+use rosu_v2::prelude::{GameMode, OsuError};
+use twilight_model::id::Id;
+
+use crate::{
+    commands::{
+        osu::{process_match, retrieve_previous, MatchResult, TeamResult, UserMatchCostEntry},
+        utility::{MissAnalyzerCheck, ScoreEmbedDataHalf, ScoreEmbedDataPersonalBest},
+    },
+    core::Context,
+    manager::{redis::osu::UserArgsSlim, MapError, Mods},
+    util::osu::IfFc,
+};
+
+/// Provides the data of the server's public api.
+pub struct BotApiProvider;
+
+impl ApiProvider for BotApiProvider {
+    fn is_ready(&self) -> bool {
+        Context::is_initialized()
+    }
+
+    fn api_key<'a>(&'a self, key_hash: &'a str) -> BoxFuture<'a, Result<Option<ApiKey>>> {
+        Box::pin(async move {
+            let key = Context::psql().select_api_key(key_hash).await?;
+
+            Ok(key.map(|key| ApiKey {
+                name: key.name.into_boxed_str(),
+                owner: key.owner_id.map(|owner| owner as u64),
+                rate_limit: key.rate_limit as u32,
+            }))
+        })
+    }
+
+    fn map_attributes(&self, params: MapParams) -> BoxFuture<'_, Result<Option<MapAttributes>>> {
+        Box::pin(map_attributes(params))
+    }
+
+    fn user_top(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        limit: usize,
+    ) -> BoxFuture<'_, Result<Option<Vec<TopScore>>>> {
+        Box::pin(user_top(user_id, mode, limit))
+    }
+
+    fn match_costs(
+        &self,
+        match_id: u32,
+        warmups: usize,
+    ) -> BoxFuture<'_, Result<Option<MatchCosts>>> {
+        Box::pin(match_costs(match_id, warmups))
+    }
+
+    fn bookmarks(
+        &self,
+        discord_id: u64,
+        requester: Option<u64>,
+    ) -> BoxFuture<'_, Result<Vec<Bookmark>>> {
+        Box::pin(bookmarks(discord_id, requester))
+    }
+}
+
+async fn map_attributes(params: MapParams) -> Result<Option<MapAttributes>> {
+    let MapParams {
+        map_id,
+        mode,
+        mods,
+        accuracy,
+        combo,
+        misses,
+    } = params;
+
+    let mut map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => return Ok(None),
+        Err(MapError::Report(err)) => return Err(err),
+    };
+
+    if let Some(mode) = mode {
+        map = map.convert(mode);
+    }
+
+    let map_attrs = map.pp_map.attributes().mods(mods).build();
+
+    let mut calc = Context::pp(&map).mods(Mods::new(mods));
+    let attrs = calc.difficulty().await.to_owned();
+    let max_pp = calc.performance().await.pp();
+
+    let stars = attrs.stars();
+    let max_combo = attrs.max_combo();
+
+    let mut perf = attrs.performance().mods(mods);
+
+    if let Some(accuracy) = accuracy {
+        perf = perf.accuracy(f64::from(accuracy));
+    }
+
+    if let Some(combo) = combo {
+        perf = perf.combo(combo);
+    }
+
+    if let Some(misses) = misses {
+        perf = perf.misses(misses);
+    }
+
+    let pp = perf.calculate().pp();
+
+    Ok(Some(MapAttributes {
+        map_id,
+        mapset_id: map.mapset_id(),
+        mode: map.mode(),
+        artist: map.artist().into(),
+        title: map.title().into(),
+        version: map.version().into(),
+        creator: map.creator().into(),
+        mods,
+        stars,
+        max_combo,
+        ar: map_attrs.ar,
+        cs: map_attrs.cs,
+        hp: map_attrs.hp,
+        od: map_attrs.od,
+        bpm: map.bpm(),
+        seconds_drain: map.seconds_drain(),
+        accuracy: accuracy.unwrap_or(100.0),
+        pp,
+        max_pp,
+    }))
+}
+
+async fn user_top(user_id: u32, mode: GameMode, limit: usize) -> Result<Option<Vec<TopScore>>> {
+    let user_args = UserArgsSlim::user_id(user_id).mode(mode);
+
+    let scores = match Context::osu_scores()
+        .top(false)
+        .limit(limit)
+        .exec(user_args)
+        .await
+    {
+        Ok(scores) => scores,
+        Err(OsuError::NotFound) => return Ok(None),
+        Err(err) => return Err(Report::new(err).wrap_err("Failed to get top scores")),
+    };
+
+    let maps_id_checksum = scores
+        .iter()
+        .map(|score| {
+            (
+                score.map_id as i32,
+                score.map.as_ref().and_then(|map| map.checksum.as_deref()),
+            )
+        })
+        .collect();
+
+    let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
+    let mut entries = Vec::with_capacity(scores.len());
+
+    for (i, score) in scores.into_iter().enumerate() {
+        let Some(map) = maps.remove(&score.map_id) else {
+            continue;
+        };
+
+        let map = map.convert(score.mode);
+        let pb_idx = Some(ScoreEmbedDataPersonalBest::from_index(i));
+
+        let half = ScoreEmbedDataHalf::new(
+            score,
+            map,
+            None,
+            pb_idx,
+            false,
+            false,
+            MissAnalyzerCheck::without(),
+        )
+        .await;
+
+        let if_fc_pp = IfFc::new(&half.score, &half.map)
+            .await
+            .map(|if_fc| if_fc.pp);
+
+        let ScoreEmbedDataHalf {
+            score,
+            map,
+            stars,
+            max_combo,
+            max_pp,
+            ..
+        } = half;
+
+        let stats = &score.statistics;
+
+        entries.push(TopScore {
+            position: i + 1,
+            score_id: score.score_id,
+            map_id: map.map_id(),
+            mapset_id: map.mapset_id(),
+            mode: score.mode,
+            artist: map.artist().into(),
+            title: map.title().into(),
+            version: map.version().into(),
+            mods: score.mods.to_string().into_boxed_str(),
+            grade: format!("{:?}", score.grade).into_boxed_str(),
+            score: score.score,
+            accuracy: score.accuracy,
+            combo: score.max_combo,
+            max_combo,
+            count_300: stats.count_300,
+            count_100: stats.count_100,
+            count_50: stats.count_50,
+            count_miss: stats.count_miss,
+            stars,
+            pp: score.pp,
+            max_pp,
+            if_fc_pp,
+            ended_at: score.ended_at.unix_timestamp(),
+        });
+    }
+
+    Ok(Some(entries))
+}
+
+async fn match_costs(match_id: u32, warmups: usize) -> Result<Option<MatchCosts>> {
+    let osu = Context::osu();
+
+    let mut osu_match = match osu.osu_match(match_id).await {
+        Ok(osu_match) => osu_match,
+        Err(OsuError::NotFound) => return Ok(None),
+        Err(OsuError::Response { status, .. }) if status == 401 => return Ok(None),
+        Err(err) => return Err(Report::new(err).wrap_err("Failed to get match")),
+    };
+
+    retrieve_previous(&mut osu_match, osu)
+        .await
+        .map_err(|err| Report::new(err).wrap_err("Failed to get previous match events"))?;
+
+    let games: Vec<_> = osu_match
+        .drain_games()
+        .filter(|game| game.end_time.is_some())
+        .skip(warmups)
+        .map(|mut game| {
+            game.scores.retain(|score| score.score > 0);
+
+            game
+        })
+        .collect();
+
+    let finished = osu_match.end_time.is_some();
+
+    let result = if games.is_empty() {
+        MatchCostsResult::NoGames
+    } else {
+        let users = &osu_match.users;
+
+        let players = |entries: Vec<UserMatchCostEntry>| -> Vec<MatchCostsPlayer> {
+            entries
+                .into_iter()
+                .map(|entry| {
+                    let username = match users.get(&entry.user_id) {
+                        Some(user) => user.username.as_str().into(),
+                        None => entry.user_id.to_string().into_boxed_str(),
+                    };
+
+                    MatchCostsPlayer {
+                        user_id: entry.user_id,
+                        username,
+                        match_cost: entry.match_cost,
+                        avg_score: entry.avg_score,
+                        performance_cost: entry.performance_cost,
+                        participation_bonus_factor: entry.participation_bonus_factor,
+                        mods_bonus_factor: entry.mods_bonus_factor,
+                        tiebreaker_bonus: entry.tiebreaker_bonus,
+                    }
+                })
+                .collect()
+        };
+
+        let team = |team: TeamResult| MatchCostsTeam {
+            win_count: team.win_count,
+            players: players(team.players),
+        };
+
+        match process_match(&games, finished, users) {
+            MatchResult::TeamVS { blue, red, .. } => MatchCostsResult::TeamVs {
+                blue: team(blue),
+                red: team(red),
+            },
+            MatchResult::HeadToHead {
+                players: entries, ..
+            } => MatchCostsResult::HeadToHead {
+                players: players(entries),
+            },
+            MatchResult::NoGames { .. } => MatchCostsResult::NoGames,
+        }
+    };
+
+    Ok(Some(MatchCosts {
+        match_id,
+        name: osu_match.name.into_boxed_str(),
+        finished,
+        games: games.len(),
+        result,
+    }))
+}
+
+async fn bookmarks(discord_id: u64, requester: Option<u64>) -> Result<Vec<Bookmark>> {
+    let Some(user) = Id::new_checked(discord_id) else {
+        return Ok(Vec::new());
+    };
+
+    let bookmarks = Context::bookmarks().get(user).await?;
+
+    // Only the user themselves may see bookmarks outside of public folders
+    let public_folders: Option<HashSet<Box<str>>> = if requester == Some(discord_id) {
+        None
+    } else {
+        let folders = Context::bookmarks().folders(user).await?;
+
+        let public = folders
+            .into_iter()
+            .filter(|folder| folder.public)
+            .map(|folder| folder.name)
+            .collect();
+
+        Some(public)
+    };
+
+    let bookmarks = bookmarks
+        .into_iter()
+        .filter(|bookmark| match (&public_folders, &bookmark.folder) {
+            (None, _) => true,
+            (Some(public), Some(folder)) => public.contains(folder),
+            (Some(_), None) => false,
+        })
+        .map(|bookmark| Bookmark {
+            map_id: bookmark.map_id,
+            mapset_id: bookmark.mapset_id,
+            mode: bookmark.mode,
+            artist: bookmark.artist,
+            title: bookmark.title,
+            version: bookmark.version,
+            creator: bookmark.creator_name,
+            folder: bookmark.folder,
+            note: bookmark.note,
+            inserted_at: bookmark.insert_date.unix_timestamp(),
+        })
+        .collect();
+
+    Ok(bookmarks)
+}
@@ -1,57 +1,159 @@
-use std::{collections::HashMap, hash::Hash, sync::Mutex};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::Mutex,
+};
 
-use bathbot_util::IntHasher;
+use bathbot_psql::model::configs::BucketOverride;
 use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
 
-pub struct Buckets([Mutex<Bucket>; 8]);
+use super::{BotConfig, Context};
+
+/// Ratelimit buckets whose state is stored in redis so that it survives
+/// restarts and is shared between processes.
+///
+/// The [`BucketName::All`] bucket is checked for every prefix command so it
+/// only uses in-process state to avoid a redis round trip on each message.
+/// If redis is unavailable, the in-process buckets are used for all others
+/// as well.
+pub struct Buckets {
+    local: [Mutex<Bucket>; 9],
+}
 
 impl Buckets {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        let make_bucket = |delay, time_span, limit| {
-            let ratelimit = Ratelimit {
-                delay,
-                limit: Some((time_span, limit)),
-            };
+        Self {
+            local: BucketName::ALL.map(|_| Mutex::default()),
+        }
+    }
+
+    /// Take a ticket of the bucket for the user or channel within a guild.
+    ///
+    /// If no ratelimit is specified, the configured ratelimit of the bucket
+    /// will be used.
+    ///
+    /// Returns the cooldown in amount of seconds or `0` if the ticket was
+    /// taken.
+    pub async fn take(
+        &self,
+        target: BucketTarget,
+        guild_id: Option<Id<GuildMarker>>,
+        bucket: BucketName,
+        ratelimit: Option<Ratelimit>,
+    ) -> i64 {
+        let ratelimit = ratelimit.unwrap_or_else(|| BotConfig::get().ratelimit(bucket));
+        let entry = BucketEntry { target, guild_id };
 
-            Mutex::new(Bucket::new(ratelimit))
+        if bucket == BucketName::All {
+            return self.take_local(entry, bucket, ratelimit);
+        }
+
+        // Guilds may override ratelimits so buckets are tracked per guild
+        let key = match guild_id {
+            Some(guild_id) => format!("bucket_{}_{guild_id}_{target}", bucket.key()),
+            None => format!("bucket_{}_{target}", bucket.key()),
         };
 
-        Self([
-            make_bucket(0, 9, 4),    // All
-            make_bucket(1, 8, 2),    // BgBigger
-            make_bucket(0, 10, 4),   // BgHint
-            make_bucket(2, 20, 3),   // BgSkip
-            make_bucket(15, 0, 1),   // MatchCompare
-            make_bucket(5, 900, 3),  // MatchLive
-            make_bucket(60, 600, 2), // Render
-            make_bucket(20, 0, 1),   // Songs
-        ])
+        let Ratelimit {
+            delay,
+            time_span,
+            limit,
+        } = ratelimit;
+
+        match Context::cache()
+            .take_ticket(&key, delay, time_span, limit)
+            .await
+        {
+            Ok(cooldown) => cooldown,
+            Err(err) => {
+                warn!(?err, ?bucket, "Failed to take ticket, using local bucket");
+
+                self.take_local(entry, bucket, ratelimit)
+            }
+        }
+    }
+
+    fn take_local(&self, entry: BucketEntry, bucket: BucketName, ratelimit: Ratelimit) -> i64 {
+        self.local[bucket as usize]
+            .lock()
+            .unwrap()
+            .take(entry, ratelimit)
+    }
+}
+
+/// Whose uses are counted by a bucket.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BucketTarget {
+    User(Id<UserMarker>),
+    /// The cooldown applies to everyone in the channel
+    Channel(Id<ChannelMarker>),
+    /// The cooldown applies to everyone in the guild
+    Guild(Id<GuildMarker>),
+}
+
+impl From<Id<UserMarker>> for BucketTarget {
+    fn from(user_id: Id<UserMarker>) -> Self {
+        Self::User(user_id)
+    }
+}
+
+impl From<Id<ChannelMarker>> for BucketTarget {
+    fn from(channel_id: Id<ChannelMarker>) -> Self {
+        Self::Channel(channel_id)
     }
+}
 
-    pub fn get(&self, bucket: BucketName) -> &Mutex<Bucket> {
-        match bucket {
-            BucketName::All => &self.0[0],
-            BucketName::BgBigger => &self.0[1],
-            BucketName::BgHint => &self.0[2],
-            BucketName::BgSkip => &self.0[3],
-            BucketName::MatchCompare => &self.0[4],
-            BucketName::MatchLive => &self.0[5],
-            BucketName::Render => &self.0[6],
-            BucketName::Songs => &self.0[7],
+impl Display for BucketTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::User(user_id) => write!(f, "user_{user_id}"),
+            Self::Channel(channel_id) => write!(f, "channel_{channel_id}"),
+            Self::Guild(guild_id) => write!(f, "guild_{guild_id}"),
         }
     }
 }
 
+/// Identifies the target of a bucket within a guild.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BucketEntry {
+    target: BucketTarget,
+    guild_id: Option<Id<GuildMarker>>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Ratelimit {
-    pub delay: i64,
-    pub limit: Option<(i64, i32)>,
+    /// Seconds between two uses
+    pub delay: u32,
+    /// Seconds in which at most `limit` uses are allowed
+    pub time_span: u32,
+    pub limit: u32,
+}
+
+impl Ratelimit {
+    const fn new(delay: u32, time_span: u32, limit: u32) -> Self {
+        Self {
+            delay,
+            time_span,
+            limit,
+        }
+    }
+}
+
+impl From<&BucketOverride> for Ratelimit {
+    fn from(entry: &BucketOverride) -> Self {
+        Self::new(entry.delay, entry.time_span, entry.limit)
+    }
 }
 
 pub struct MemberRatelimit {
     pub last_time: i64,
     pub set_time: i64,
-    pub tickets: i32,
+    pub tickets: u32,
 }
 
 impl Default for MemberRatelimit {
@@ -64,36 +166,29 @@ impl Default for MemberRatelimit {
     }
 }
 
+#[derive(Default)]
 pub struct Bucket {
-    pub ratelimit: Ratelimit,
-    pub users: HashMap<u64, MemberRatelimit, IntHasher>,
+    pub users: HashMap<BucketEntry, MemberRatelimit>,
 }
 
 impl Bucket {
-    fn new(ratelimit: Ratelimit) -> Self {
-        Self {
-            ratelimit,
-            users: HashMap::default(),
-        }
-    }
-
-    pub fn take(&mut self, user_id: u64) -> i64 {
+    pub fn take(&mut self, entry: BucketEntry, ratelimit: Ratelimit) -> i64 {
         let time = OffsetDateTime::now_utc().unix_timestamp();
-        let user = self.users.entry(user_id).or_default();
-
-        if let Some((timespan, limit)) = self.ratelimit.limit {
-            if user.tickets + 1 > limit {
-                if time < (user.set_time + timespan) {
-                    return (user.set_time + timespan) - time;
-                } else {
-                    user.tickets = 0;
-                    user.set_time = time;
-                }
+        let user = self.users.entry(entry).or_default();
+        let delay = i64::from(ratelimit.delay);
+        let time_span = i64::from(ratelimit.time_span);
+
+        if user.tickets + 1 > ratelimit.limit {
+            if time < (user.set_time + time_span) {
+                return (user.set_time + time_span) - time;
+            } else {
+                user.tickets = 0;
+                user.set_time = time;
             }
         }
 
-        if time < user.last_time + self.ratelimit.delay {
-            (user.last_time + self.ratelimit.delay) - time
+        if time < user.last_time + delay {
+            (user.last_time + delay) - time
         } else {
             user.tickets += 1;
             user.last_time = time;
@@ -108,6 +203,7 @@ impl Bucket {
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
 pub enum BucketName {
     All,
+    AutoEmbed,
     BgBigger,
     BgHint,
     BgSkip,
@@ -116,3 +212,55 @@ pub enum BucketName {
     Render,
     Songs,
 }
+
+impl BucketName {
+    pub const ALL: [Self; 9] = [
+        Self::All,
+        Self::AutoEmbed,
+        Self::BgBigger,
+        Self::BgHint,
+        Self::BgSkip,
+        Self::MatchCompare,
+        Self::MatchLive,
+        Self::Render,
+        Self::Songs,
+    ];
+
+    /// Identifier used for redis keys, env variables, and guild overrides.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::AutoEmbed => "auto_embed",
+            Self::BgBigger => "bg_bigger",
+            Self::BgHint => "bg_hint",
+            Self::BgSkip => "bg_skip",
+            Self::MatchCompare => "match_compare",
+            Self::MatchLive => "match_live",
+            Self::Render => "render",
+            Self::Songs => "songs",
+        }
+    }
+
+    /// Ratelimit that is used unless it's configured differently.
+    pub const fn default_ratelimit(self) -> Ratelimit {
+        match self {
+            Self::All => Ratelimit::new(0, 9, 4),
+            Self::AutoEmbed => Ratelimit::new(10, 0, 1),
+            Self::BgBigger => Ratelimit::new(1, 8, 2),
+            Self::BgHint => Ratelimit::new(0, 10, 4),
+            Self::BgSkip => Ratelimit::new(2, 20, 3),
+            Self::MatchCompare => Ratelimit::new(15, 0, 1),
+            Self::MatchLive => Ratelimit::new(5, 900, 3),
+            Self::Render => Ratelimit::new(60, 600, 2),
+            Self::Songs => Ratelimit::new(20, 0, 1),
+        }
+    }
+
+    /// Whether guilds can override the ratelimit of this bucket.
+    pub fn is_overridable(self) -> bool {
+        matches!(
+            self,
+            Self::AutoEmbed | Self::MatchCompare | Self::MatchLive | Self::Render | Self::Songs
+        )
+    }
+}
@@ -5,6 +5,7 @@ use bathbot_model::twilight_model::{
     channel::{PermissionOverwrite, PermissionOverwriteTypeRkyv},
     guild::Member,
 };
+use bathbot_psql::model::configs::{CommandRule, GROUP_TARGET_PREFIX};
 use eyre::{ContextCompat, Result};
 use rkyv::{with::DeserializeWith, Archived, Infallible};
 use twilight_model::{
@@ -16,6 +17,7 @@ use twilight_model::{
     },
 };
 
+use super::prefix::PrefixCommandGroup;
 use crate::core::{BotConfig, Context};
 
 /// Is authority -> Ok(None)
@@ -81,6 +83,122 @@ pub async fn check_authority(
     Ok(None)
 }
 
+/// Checks the guild's command rules for a command.
+///
+/// `names` contains the command's name and its aliases.
+///
+/// Is allowed -> Ok(None)
+/// Not allowed -> Ok(Some(message to user))
+/// Couldn't figure out -> Err()
+pub async fn check_command_rules(
+    author: Id<UserMarker>,
+    guild: Option<Id<GuildMarker>>,
+    channel: Id<ChannelMarker>,
+    names: &[&str],
+    group: Option<PrefixCommandGroup>,
+) -> Result<Option<String>> {
+    let Some(guild_id) = guild else {
+        return Ok(None);
+    };
+
+    let matches_rule = |rule: &CommandRule| match rule.group() {
+        Some(key) => group.is_some_and(|group| group.matches_key(key)),
+        None => names.contains(&rule.target.as_str()),
+    };
+
+    let rules: Vec<CommandRule> = Context::guild_config()
+        .peek(guild_id, |config| {
+            config
+                .command_rules
+                .rules()
+                .iter()
+                .filter(|rule| matches_rule(rule))
+                .cloned()
+                .collect()
+        })
+        .await;
+
+    if rules.is_empty() {
+        return Ok(None);
+    }
+
+    let (permissions, roles) = check_guild_permissions(author, guild_id).await;
+
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        return Ok(None);
+    }
+
+    let member = if rules.iter().any(|rule| !rule.roles.is_empty()) {
+        let member = match roles {
+            RolesLookup::Found(member) => member,
+            RolesLookup::NotChecked => Context::cache()
+                .member(guild_id, author)
+                .await?
+                .wrap_err("Missing member in cache")?,
+            RolesLookup::NotFound => {
+                bail!("Missing user {author} of guild {guild_id} in cache")
+            }
+        };
+
+        Some(member)
+    } else {
+        None
+    };
+
+    for rule in rules.iter() {
+        if rule.is_disabled_in(channel) {
+            let content = if rule.disabled {
+                "This command is disabled in this server"
+            } else {
+                "This command is disabled in this channel"
+            };
+
+            return Ok(Some(content.to_owned()));
+        }
+
+        if !rule.is_allowed_in(channel) {
+            let mut content = String::from("This command can only be used in ");
+            let mut channels = rule.allowed_channels();
+
+            if let Some(first) = channels.next() {
+                let _ = write!(content, "<#{first}>");
+
+                for channel in channels {
+                    let _ = write!(content, ", <#{channel}>");
+                }
+            }
+
+            return Ok(Some(content));
+        }
+
+        let Some(member) = member.as_ref().filter(|_| !rule.roles.is_empty()) else {
+            continue;
+        };
+
+        if !rule.roles().any(|role| member.roles().contains(&role)) {
+            let mut content = String::from("You need any of these roles to use this command: ");
+            let mut roles = rule.roles();
+
+            if let Some(first) = roles.next() {
+                let _ = write!(content, "<@&{first}>");
+
+                for role in roles {
+                    let _ = write!(content, ", <@&{role}>");
+                }
+            }
+
+            return Ok(Some(content));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Turn a command group into a command rule target.
+pub fn group_target(group: PrefixCommandGroup) -> String {
+    format!("{GROUP_TARGET_PREFIX}{}", group.key())
+}
+
 pub async fn check_guild_permissions(
     user: Id<UserMarker>,
     guild: Id<GuildMarker>,
@@ -8,7 +8,13 @@ use twilight_model::id::{marker::CommandMarker, Id};
 
 use super::{twilight_command::Command, CommandResult};
 use crate::{
-    core::{buckets::BucketName, commands::flags::CommandFlags},
+    core::{
+        buckets::BucketName,
+        commands::{
+            flags::CommandFlags,
+            prefix::{PrefixCommandGroup, PrefixCommands},
+        },
+    },
     util::interaction::InteractionCommand,
 };
 
@@ -16,6 +22,7 @@ use crate::{
 pub enum InteractionCommandKind {
     Chat(&'static SlashCommand),
     Message(&'static MessageCommand),
+    User(&'static UserCommand),
 }
 
 impl InteractionCommandKind {
@@ -23,6 +30,7 @@ impl InteractionCommandKind {
         match self {
             InteractionCommandKind::Chat(cmd) => (cmd.create)().into(),
             InteractionCommandKind::Message(cmd) => (cmd.create)(),
+            InteractionCommandKind::User(cmd) => (cmd.create)(),
         }
     }
 
@@ -30,6 +38,7 @@ impl InteractionCommandKind {
         match self {
             InteractionCommandKind::Chat(cmd) => cmd.flags,
             InteractionCommandKind::Message(cmd) => cmd.flags,
+            InteractionCommandKind::User(cmd) => cmd.flags,
         }
     }
 
@@ -37,6 +46,7 @@ impl InteractionCommandKind {
         match self {
             InteractionCommandKind::Chat(cmd) => *cmd.id.get().expect("missing command id"),
             InteractionCommandKind::Message(cmd) => *cmd.id.get().expect("missing command id"),
+            InteractionCommandKind::User(cmd) => *cmd.id.get().expect("missing command id"),
         }
     }
 
@@ -53,10 +63,34 @@ pub struct SlashCommand {
     pub create: fn() -> ApplicationCommandData,
     pub exec: fn(InteractionCommand) -> CommandResult,
     pub flags: CommandFlags,
+    /// Group whose command rules also apply to this command
+    pub group: Option<PrefixCommandGroup>,
     pub name: &'static str,
     pub id: OnceLock<Id<CommandMarker>>,
 }
 
+impl SlashCommand {
+    /// Group whose command rules also apply to this command.
+    ///
+    /// Unless specified through `#[group(...)]`, the group is taken from the
+    /// prefix command of the same name. Since slash commands cover all modes
+    /// through an option, mode-specific groups are widened to
+    /// [`PrefixCommandGroup::AllModes`].
+    pub fn command_group(&self) -> Option<PrefixCommandGroup> {
+        if self.group.is_some() {
+            return self.group;
+        }
+
+        let group = PrefixCommands::get().command(self.name)?.group;
+
+        if group.is_mode() {
+            Some(PrefixCommandGroup::AllModes)
+        } else {
+            Some(group)
+        }
+    }
+}
+
 pub struct MessageCommand {
     pub create: fn() -> Command,
     pub exec: fn(InteractionCommand) -> CommandResult,
@@ -65,6 +99,14 @@ pub struct MessageCommand {
     pub id: OnceLock<Id<CommandMarker>>,
 }
 
+pub struct UserCommand {
+    pub create: fn() -> Command,
+    pub exec: fn(InteractionCommand) -> CommandResult,
+    pub flags: CommandFlags,
+    pub name: &'static str,
+    pub id: OnceLock<Id<CommandMarker>>,
+}
+
 pub struct CommandMention<'n> {
     id: Id<CommandMarker>,
     name: &'n str,
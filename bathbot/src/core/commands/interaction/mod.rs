@@ -6,7 +6,7 @@ use linkme::distributed_slice;
 use once_cell::sync::OnceCell;
 use radix_trie::{iter::Keys, Trie, TrieCommon};
 
-pub use self::command::{InteractionCommandKind, MessageCommand, SlashCommand};
+pub use self::command::{InteractionCommandKind, MessageCommand, SlashCommand, UserCommand};
 use self::twilight_command::Command;
 
 mod command;
@@ -18,6 +18,9 @@ pub static __SLASH_COMMANDS: [SlashCommand] = [..];
 #[distributed_slice]
 pub static __MSG_COMMANDS: [MessageCommand] = [..];
 
+#[distributed_slice]
+pub static __USER_COMMANDS: [UserCommand] = [..];
+
 static INTERACTION_COMMANDS: OnceCell<InteractionCommands> = OnceCell::new();
 
 pub struct InteractionCommands(Trie<&'static str, InteractionCommandKind>);
@@ -39,6 +42,10 @@ impl InteractionCommands {
                 trie.insert(cmd.name, InteractionCommandKind::Message(cmd));
             }
 
+            for cmd in __USER_COMMANDS {
+                trie.insert(cmd.name, InteractionCommandKind::User(cmd));
+            }
+
             InteractionCommands(trie)
         })
     }
@@ -86,6 +93,9 @@ impl InteractionCommands {
                 InteractionCommandKind::Message(cmd) => {
                     cmd.id.set(id).expect("command id has already been set");
                 }
+                InteractionCommandKind::User(cmd) => {
+                    cmd.id.set(id).expect("command id has already been set");
+                }
             }
         }
     }
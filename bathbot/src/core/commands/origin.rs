@@ -1,5 +1,7 @@
+use bathbot_psql::model::configs::Locale;
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{ContextCompat, Result, WrapErr};
+use rosu_v2::prelude::GameMode;
 use twilight_http::Response;
 use twilight_model::{
     channel::Message,
@@ -11,7 +13,10 @@ use twilight_model::{
 };
 
 use crate::{
-    core::Context,
+    core::{
+        locale::{self, ErrorContent},
+        Context,
+    },
     util::{
         interaction::{InteractionCommand, InteractionComponent},
         Authored, ChannelExt, InteractionCommandExt, MessageExt,
@@ -26,6 +31,14 @@ pub enum CommandOrigin<'d> {
     Interaction {
         command: &'d mut InteractionCommand,
     },
+    /// Command that is executed on behalf of a user without an invoke,
+    /// e.g. through a scheduled job.
+    Scheduled {
+        user: Id<UserMarker>,
+        channel: Id<ChannelMarker>,
+        guild: Option<Id<GuildMarker>>,
+        permissions: Option<Permissions>,
+    },
 }
 
 impl CommandOrigin<'_> {
@@ -33,6 +46,7 @@ impl CommandOrigin<'_> {
         match self {
             CommandOrigin::Message { msg, .. } => Ok(msg.author.id),
             CommandOrigin::Interaction { command } => command.user_id(),
+            CommandOrigin::Scheduled { user, .. } => Ok(*user),
         }
     }
 
@@ -40,6 +54,7 @@ impl CommandOrigin<'_> {
         match self {
             CommandOrigin::Message { msg, .. } => msg.channel_id,
             CommandOrigin::Interaction { command } => command.channel_id,
+            CommandOrigin::Scheduled { channel, .. } => *channel,
         }
     }
 
@@ -47,6 +62,30 @@ impl CommandOrigin<'_> {
         match self {
             CommandOrigin::Message { msg, .. } => msg.guild_id,
             CommandOrigin::Interaction { command } => command.guild_id,
+            CommandOrigin::Scheduled { guild, .. } => *guild,
+        }
+    }
+
+    /// The default mode of the channel in which the command was invoked.
+    ///
+    /// Takes precedence over the author's configured mode but not over a
+    /// mode that was explicitly specified.
+    pub async fn channel_mode(&self) -> Option<GameMode> {
+        Context::guild_config()
+            .channel_mode(self.guild_id(), self.channel_id())
+            .await
+    }
+
+    /// The locale in which to respond.
+    pub async fn locale(&self) -> Locale {
+        match self {
+            CommandOrigin::Message { msg, .. } => {
+                locale::resolve(msg.author.id, msg.guild_id, None).await
+            }
+            CommandOrigin::Interaction { command } => command.locale,
+            CommandOrigin::Scheduled { user, guild, .. } => {
+                locale::resolve(*user, *guild, None).await
+            }
         }
     }
 
@@ -67,6 +106,15 @@ impl CommandOrigin<'_> {
                 .await
                 .map(|_| ())
                 .wrap_err("failed to callback"),
+            Self::Scheduled {
+                channel,
+                permissions,
+                ..
+            } => channel
+                .create_message(builder, *permissions)
+                .await
+                .map(|_| ())
+                .wrap_err("failed to create message to callback"),
         }
     }
 
@@ -93,6 +141,14 @@ impl CommandOrigin<'_> {
                     .await
                     .wrap_err("failed to get response message")
             }
+            Self::Scheduled {
+                channel,
+                permissions,
+                ..
+            } => channel
+                .create_message(builder, *permissions)
+                .await
+                .wrap_err("failed to create message for response callback"),
         }
     }
 
@@ -117,6 +173,15 @@ impl CommandOrigin<'_> {
                 .await
                 .map(|_| ())
                 .wrap_err("failed to callback with flags"),
+            Self::Scheduled {
+                channel,
+                permissions,
+                ..
+            } => channel
+                .create_message(builder, *permissions)
+                .await
+                .map(|_| ())
+                .wrap_err("failed to create message for flagged callback"),
         }
     }
 
@@ -136,10 +201,21 @@ impl CommandOrigin<'_> {
                 .update(builder)
                 .await
                 .wrap_err("failed to update as response"),
+            Self::Scheduled {
+                channel,
+                permissions,
+                ..
+            } => channel
+                .create_message(builder, *permissions)
+                .await
+                .wrap_err("failed to create message as response"),
         }
     }
 
     /// Update a response and return the resulting response message.
+    ///
+    /// Scheduled commands have no response to update so a new message is
+    /// created instead.
     pub async fn update(&self, builder: MessageBuilder<'_>) -> Result<Response<Message>> {
         match self {
             Self::Message { msg, permissions } => msg
@@ -151,13 +227,23 @@ impl CommandOrigin<'_> {
                 .update(builder)
                 .await
                 .wrap_err("failed to update interaction message"),
+            Self::Scheduled {
+                channel,
+                permissions,
+                ..
+            } => channel
+                .create_message(builder, *permissions)
+                .await
+                .wrap_err("failed to create message as update"),
         }
     }
 
     /// Respond with a red embed.
     ///
     /// In case of an interaction, be sure you already called back beforehand.
-    pub async fn error(&self, content: impl Into<String>) -> Result<()> {
+    pub async fn error(&self, content: impl ErrorContent) -> Result<()> {
+        let content = content.localize(self.locale().await);
+
         match self {
             Self::Message { msg, .. } => msg
                 .error(content)
@@ -169,6 +255,11 @@ impl CommandOrigin<'_> {
                 .await
                 .map(|_| ())
                 .wrap_err("failed to respond with error"),
+            Self::Scheduled { channel, .. } => channel
+                .error(content)
+                .await
+                .map(|_| ())
+                .wrap_err("failed to respond with error"),
         }
     }
 
@@ -176,7 +267,9 @@ impl CommandOrigin<'_> {
     ///
     /// In case of an interaction, be sure this is the first and only time you
     /// call this. The response will not be ephemeral.
-    pub async fn error_callback(&self, content: impl Into<String>) -> Result<()> {
+    pub async fn error_callback(&self, content: impl ErrorContent) -> Result<()> {
+        let content = content.localize(self.locale().await);
+
         match self {
             CommandOrigin::Message { msg, .. } => msg
                 .error(content)
@@ -188,6 +281,11 @@ impl CommandOrigin<'_> {
                 .await
                 .map(|_| ())
                 .wrap_err("failed to callback with error"),
+            CommandOrigin::Scheduled { channel, .. } => channel
+                .error(content)
+                .await
+                .map(|_| ())
+                .wrap_err("failed to callback with error"),
         }
     }
 }
@@ -251,13 +349,18 @@ impl OwnedCommandOrigin {
     /// Respond with a red embed.
     ///
     /// In case of an interaction, be sure you already called back beforehand.
-    pub async fn error(&self, content: impl Into<String>) -> Result<()> {
+    ///
+    /// The author of a message is unknown so an [`Issue`] is sent in english.
+    ///
+    /// [`Issue`]: crate::core::locale::Issue
+    pub async fn error(&self, content: impl ErrorContent) -> Result<()> {
         match self {
             Self::Message {
                 msg,
                 channel,
                 permissions,
             } => {
+                let content = content.localize(Locale::English);
                 let embed = EmbedBuilder::new().color_red().description(content);
                 let builder = MessageBuilder::new().embed(embed);
 
@@ -58,6 +58,63 @@ impl PrefixCommandGroup {
             PrefixCommandGroup::Songs => "songs",
         }
     }
+
+    /// Short identifier of the group, used to refer to it in configurations.
+    pub fn key(self) -> &'static str {
+        match self {
+            PrefixCommandGroup::AllModes => "allmodes",
+            PrefixCommandGroup::Osu => "osu",
+            PrefixCommandGroup::Taiko => "taiko",
+            PrefixCommandGroup::Catch => "catch",
+            PrefixCommandGroup::Mania => "mania",
+            PrefixCommandGroup::Tracking => "tracking",
+            PrefixCommandGroup::Twitch => "twitch",
+            PrefixCommandGroup::Games => "games",
+            PrefixCommandGroup::Utility => "utility",
+            PrefixCommandGroup::Songs => "songs",
+        }
+    }
+
+    /// Whether the group contains commands for a specific mode.
+    pub fn is_mode(self) -> bool {
+        matches!(
+            self,
+            PrefixCommandGroup::Osu
+                | PrefixCommandGroup::Taiko
+                | PrefixCommandGroup::Catch
+                | PrefixCommandGroup::Mania
+        )
+    }
+
+    /// Whether command rules for the group key apply to commands of this
+    /// group.
+    ///
+    /// Commands for all modes are affected by the rules of each mode group.
+    pub fn matches_key(self, key: &str) -> bool {
+        if self.key() == key {
+            return true;
+        }
+
+        self == PrefixCommandGroup::AllModes
+            && Self::from_key(key).is_some_and(PrefixCommandGroup::is_mode)
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|group| group.key() == key)
+    }
+
+    pub const ALL: [Self; 10] = [
+        PrefixCommandGroup::AllModes,
+        PrefixCommandGroup::Osu,
+        PrefixCommandGroup::Taiko,
+        PrefixCommandGroup::Catch,
+        PrefixCommandGroup::Mania,
+        PrefixCommandGroup::Tracking,
+        PrefixCommandGroup::Twitch,
+        PrefixCommandGroup::Games,
+        PrefixCommandGroup::Utility,
+        PrefixCommandGroup::Songs,
+    ];
 }
 
 pub struct PrefixCommandGroupEmote {
@@ -1,5 +1,6 @@
-use std::{env, fmt::Debug, mem::MaybeUninit, path::PathBuf, str::FromStr};
+use std::{env, fmt::Debug, mem::MaybeUninit, path::PathBuf, str::FromStr, time::Duration};
 
+use bathbot_client::TransportMode;
 use eyre::Result;
 use once_cell::sync::OnceCell;
 use rosu_v2::model::Grade;
@@ -8,22 +9,28 @@ use twilight_model::id::{
     Id,
 };
 
+use super::buckets::{BucketName, Ratelimit};
 use crate::util::{CustomEmote, Emote};
 
 static CONFIG: OnceCell<BotConfig> = OnceCell::new();
 
+const DEFAULT_USER_SCORES_REFRESH: Duration = Duration::from_secs(24 * 3600);
+
 #[derive(Debug)]
 pub struct BotConfig {
     pub database_url: Box<str>,
+    /// Interval in which the `user_scores` materialized view is refreshed
+    pub user_scores_refresh: Duration,
+    /// Ratelimit of each bucket, indexed by [`BucketName`]
+    ratelimits: [Ratelimit; 9],
     pub tokens: Tokens,
     pub paths: Paths,
     #[cfg(feature = "server")]
     pub server: Server,
     grades: Box<[Box<str>]>,
     emotes: Box<[CustomEmote]>,
-    pub redis_host: Box<str>,
-    pub redis_port: u16,
-    pub redis_db_idx: u8,
+    pub cache: CacheConfig,
+    pub client_transport: TransportMode,
     pub owner: Id<UserMarker>,
     pub dev_guild: Id<GuildMarker>,
     pub hl_channel: Id<ChannelMarker>,
@@ -43,14 +50,32 @@ pub struct Paths {
 pub struct Server {
     pub port: u16,
     pub public_url: Box<str>,
+    /// Reverse proxies whose `X-Forwarded-For` header is trusted
+    pub trusted_proxies: Box<[std::net::IpAddr]>,
+}
+
+#[derive(Debug)]
+pub enum CacheConfig {
+    Redis {
+        host: Box<str>,
+        port: u16,
+        db_idx: u8,
+    },
+    /// Keep all cached data in memory, only intended for local development
+    Memory,
 }
 
 #[derive(Debug)]
 pub struct Tokens {
     pub discord: Box<str>,
+    #[cfg(feature = "server")]
+    pub discord_client_secret: Box<str>,
     pub osu_client_id: u64,
     pub osu_client_secret: Box<str>,
     pub osu_key: Box<str>,
+    /// Key to encrypt stored osu! user tokens
+    #[cfg(feature = "server")]
+    pub osu_token_key: [u8; 32],
     #[cfg(not(debug_assertions))]
     pub ordr_key: Box<str>,
     pub github_token: Box<str>,
@@ -92,13 +117,58 @@ impl BotConfig {
         ];
         let emotes = Self::parse_emotes::<Emote, _, 17>(emote_strs)?;
 
+        let user_scores_refresh = match env_var_opt("USER_SCORES_REFRESH_SECS")? {
+            Some(0) => bail!("USER_SCORES_REFRESH_SECS must be greater than 0"),
+            Some(secs) => Duration::from_secs(secs),
+            None => DEFAULT_USER_SCORES_REFRESH,
+        };
+
+        let mut ratelimits = BucketName::ALL.map(BucketName::default_ratelimit);
+
+        for (bucket, ratelimit) in BucketName::ALL.into_iter().zip(ratelimits.iter_mut()) {
+            let name = format!("BUCKET_{}", bucket.key().to_ascii_uppercase());
+
+            if let Some(custom) = env_var_opt(&name)? {
+                *ratelimit = custom;
+            }
+        }
+
+        let cache = match env_var_opt::<Box<str>>("CACHE_BACKEND")?.as_deref() {
+            None | Some("redis") => CacheConfig::Redis {
+                host: env_var("REDIS_HOST")?,
+                port: env_var("REDIS_PORT")?,
+                db_idx: env_var("REDIS_DB_IDX")?,
+            },
+            Some("memory") => CacheConfig::Memory,
+            Some(value) => bail!(
+                "failed to parse env variable `CACHE_BACKEND={value}`; \
+                expected `redis` or `memory`"
+            ),
+        };
+
+        let client_transport = match env_var_opt::<Box<str>>("CLIENT_TRANSPORT")?.as_deref() {
+            None | Some("live") => TransportMode::Live,
+            Some("record") => TransportMode::Record(env_var("CLIENT_FIXTURES_PATH")?),
+            Some("replay") => TransportMode::Replay(env_var("CLIENT_FIXTURES_PATH")?),
+            Some(value) => bail!(
+                "failed to parse env variable `CLIENT_TRANSPORT={value}`; \
+                expected `live`, `record`, or `replay`"
+            ),
+        };
+
         let config = BotConfig {
             database_url: env_var("DATABASE_URL")?,
+            user_scores_refresh,
+            ratelimits,
             tokens: Tokens {
                 discord: env_var("DISCORD_TOKEN")?,
+                #[cfg(feature = "server")]
+                discord_client_secret: env_var("DISCORD_CLIENT_SECRET")?,
                 osu_client_id: env_var("OSU_CLIENT_ID")?,
                 osu_client_secret: env_var("OSU_CLIENT_SECRET")?,
                 osu_key: env_var("OSU_API_KEY")?,
+                #[cfg(feature = "server")]
+                osu_token_key: env_var("OSU_TOKEN_KEY")?,
                 #[cfg(not(debug_assertions))]
                 ordr_key: env_var("ORDR_KEY")?,
                 github_token: env_var("GITHUB_TOKEN")?,
@@ -118,12 +188,12 @@ impl BotConfig {
             server: Server {
                 port: env_var("SERVER_PORT")?,
                 public_url: env_var("PUBLIC_URL")?,
+                trusted_proxies: env_var_opt("TRUSTED_PROXIES")?.unwrap_or_default(),
             },
             grades,
             emotes,
-            redis_host: env_var("REDIS_HOST")?,
-            redis_port: env_var("REDIS_PORT")?,
-            redis_db_idx: env_var("REDIS_DB_IDX")?,
+            cache,
+            client_transport,
             owner: env_var("OWNER_USER_ID")?,
             dev_guild: env_var("DEV_GUILD_ID")?,
             hl_channel: env_var("HL_IMAGE_CHANNEL")?,
@@ -163,6 +233,10 @@ impl BotConfig {
     pub fn emote(&self, emote: Emote) -> &CustomEmote {
         &self.emotes[emote as usize]
     }
+
+    pub fn ratelimit(&self, bucket: BucketName) -> Ratelimit {
+        self.ratelimits[bucket as usize]
+    }
 }
 
 trait EnvKind: Sized {
@@ -196,6 +270,46 @@ env_kind! {
     Id<ChannelMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
 }
 
+impl EnvKind for [u8; 32] {
+    const EXPECTED: &'static str = "64 hexadecimal characters";
+
+    fn from_str(s: String) -> Result<Self, String> {
+        fn inner(s: &str) -> Option<[u8; 32]> {
+            // `from_str_radix` would also accept a leading `+`
+            if s.len() != 64 || !s.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+                return None;
+            }
+
+            let mut key = [0; 32];
+
+            for (byte, chunk) in key.iter_mut().zip(s.as_bytes().chunks_exact(2)) {
+                let hex = std::str::from_utf8(chunk).ok()?;
+                *byte = u8::from_str_radix(hex, 16).ok()?;
+            }
+
+            Some(key)
+        }
+
+        inner(s.as_str()).ok_or(s)
+    }
+}
+
+#[cfg(feature = "server")]
+impl EnvKind for Box<[std::net::IpAddr]> {
+    const EXPECTED: &'static str = "a comma-separated list of IP addresses";
+
+    fn from_str(s: String) -> Result<Self, String> {
+        let res: Result<Self, _> = s
+            .split(',')
+            .map(str::trim)
+            .filter(|ip| !ip.is_empty())
+            .map(str::parse)
+            .collect();
+
+        res.map_err(|_| s)
+    }
+}
+
 impl EnvKind for CustomEmote {
     const EXPECTED: &'static str = "an emote of the form `<:name:id>`";
 
@@ -211,6 +325,26 @@ impl EnvKind for CustomEmote {
     }
 }
 
+impl EnvKind for Ratelimit {
+    const EXPECTED: &'static str = "a ratelimit of the form `delay,time_span,limit`";
+
+    fn from_str(s: String) -> Result<Self, String> {
+        fn inner(s: &str) -> Option<Ratelimit> {
+            let mut values = s.split(',').map(|value| value.trim().parse::<u32>().ok());
+
+            let ratelimit = Ratelimit {
+                delay: values.next()??,
+                time_span: values.next()??,
+                limit: values.next()??,
+            };
+
+            values.next().is_none().then_some(ratelimit)
+        }
+
+        inner(s.as_str()).ok_or(s)
+    }
+}
+
 fn env_var<T: EnvKind>(name: &str) -> Result<T> {
     let value = env::var(name).map_err(|_| eyre!("missing env variable `{name}`"))?;
 
@@ -222,6 +356,13 @@ fn env_var<T: EnvKind>(name: &str) -> Result<T> {
     })
 }
 
+fn env_var_opt<T: EnvKind>(name: &str) -> Result<Option<T>> {
+    match env::var(name) {
+        Ok(_) => env_var(name).map(Some),
+        Err(_) => Ok(None),
+    }
+}
+
 trait AsUsize {
     fn to_usize(self) -> usize;
 }
@@ -237,3 +378,31 @@ impl AsUsize for Emote {
         self as usize
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hex_key() {
+        let hex = "00ff10Ab".repeat(8);
+        let key = <[u8; 32] as EnvKind>::from_str(hex).unwrap();
+
+        assert_eq!(key[..4], [0x00, 0xff, 0x10, 0xab]);
+        assert_eq!(key[28..], [0x00, 0xff, 0x10, 0xab]);
+    }
+
+    #[test]
+    fn rejects_wrong_key_length() {
+        assert!(<[u8; 32] as EnvKind>::from_str("ab".repeat(31)).is_err());
+        assert!(<[u8; 32] as EnvKind>::from_str("ab".repeat(33)).is_err());
+        assert!(<[u8; 32] as EnvKind>::from_str(String::new()).is_err());
+    }
+
+    #[test]
+    fn rejects_non_hex_key() {
+        assert!(<[u8; 32] as EnvKind>::from_str(format!("zz{}", "ab".repeat(31))).is_err());
+        assert!(<[u8; 32] as EnvKind>::from_str(format!("+f{}", "ab".repeat(31))).is_err());
+        assert!(<[u8; 32] as EnvKind>::from_str(format!("é{}", "a".repeat(62))).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+use super::Context;
+
+impl Context {
+    /// Reloads the alias names every few minutes.
+    ///
+    /// Removed aliases are only dropped from the database and aliases might be
+    /// added by other processes so the names would otherwise go stale.
+    pub async fn alias_names_loop() {
+        let mut interval = interval(Duration::from_secs(5 * 60));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        // The names were just loaded on startup
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            let names = match Context::psql().select_command_alias_names().await {
+                Ok(names) => names,
+                Err(err) => {
+                    warn!(?err, "Failed to get alias names");
+
+                    continue;
+                }
+            };
+
+            let names: HashSet<Box<str>> = names.into_iter().map(String::into_boxed_str).collect();
+            let alias_names = Context::alias_names().pin();
+
+            // An alias that's added while this runs might be dropped until the
+            // next refresh which is acceptable since it's not lost in the database
+            for name in names.iter() {
+                alias_names.insert(name.clone(), ());
+            }
+
+            alias_names.retain(|name, _| names.contains(name));
+        }
+    }
+}
@@ -5,24 +5,35 @@ use super::Context;
 use crate::manager::{
     redis::RedisManager, ApproxManager, BookmarkManager, GameManager, GithubManager,
     GuildConfigManager, HuismetbenenCountryManager, MapManager, OsuMap, OsuUserManager, PpManager,
-    ReplayManager, ScoresManager, UserConfigManager,
+    PpRecalcManager, ReplayManager, ScoresManager, UserConfigManager, WebhookManager,
 };
 
 impl Context {
     pub fn guild_config() -> GuildConfigManager {
         let ctx = Self::get();
 
-        GuildConfigManager::new(&ctx.clients.psql, &ctx.data.guild_configs)
+        GuildConfigManager::new(
+            &ctx.clients.psql,
+            &ctx.data.guild_configs,
+            &ctx.data.score_embed_channels,
+        )
     }
 
     pub fn user_config() -> UserConfigManager {
-        UserConfigManager::new()
+        let ctx = Self::get();
+
+        UserConfigManager::new(&ctx.clients.psql, &ctx.data.user_locales)
     }
 
     pub fn osu_user() -> OsuUserManager {
         OsuUserManager::new()
     }
 
+    #[cfg(feature = "server")]
+    pub fn osu_tokens() -> crate::manager::OsuTokenManager {
+        crate::manager::OsuTokenManager::new()
+    }
+
     #[cfg(feature = "osutracking")]
     pub fn osu_tracking() -> crate::manager::OsuTrackingManager<'static> {
         crate::manager::OsuTrackingManager::new(Context::psql())
@@ -36,6 +47,12 @@ impl Context {
         PpManager::from_parsed(map, map_id).mode(mode)
     }
 
+    pub fn pp_recalc() -> PpRecalcManager {
+        let ctx = Self::get();
+
+        PpRecalcManager::new(&ctx.clients.psql, &ctx.data.pp_recalc)
+    }
+
     pub fn approx() -> ApproxManager {
         ApproxManager::new()
     }
@@ -78,4 +95,10 @@ impl Context {
     pub fn huismetbenen() -> HuismetbenenCountryManager {
         HuismetbenenCountryManager::new()
     }
+
+    pub fn webhooks() -> WebhookManager {
+        let ctx = Self::get();
+
+        WebhookManager::new(&ctx.clients.psql, &ctx.data.webhooks_by_event)
+    }
 }
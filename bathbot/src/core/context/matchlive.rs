@@ -7,7 +7,9 @@ use twilight_model::id::{marker::ChannelMarker, Id};
 
 use super::Context;
 use crate::{
+    core::BotHealth,
     embeds::MatchLiveEmbed,
+    manager::{MatchLivePayload, WebhookPayload},
     matchlive::{send_match_messages, Channel, MatchEntry, MatchTrackResult, TrackedMatch},
     util::ChannelExt,
 };
@@ -166,6 +168,7 @@ impl Context {
 
         let ctx = Context::get();
         let http = Context::http();
+        BotHealth::matchlive_loop();
 
         loop {
             interval.tick().await;
@@ -199,6 +202,18 @@ impl Context {
                         remove.push(next_match.match_id);
                     }
 
+                    if update || new_embeds.is_some() {
+                        let channels = entry.channels.iter().map(|channel| channel.id).collect();
+
+                        let payload = MatchLivePayload {
+                            match_id: next_match.match_id,
+                            name: next_match.name.to_string(),
+                            ended: next_match.end_time.is_some(),
+                        };
+
+                        Context::webhooks().notify(channels, WebhookPayload::MatchLive(payload));
+                    }
+
                     tracked_match.osu_match = next_match;
 
                     // If there was an update for the last embed
@@ -251,6 +266,8 @@ impl Context {
                 let plural = if count == 1 { "" } else { "s" };
                 debug!("Match {match_id} over, removed from tracking for {count} channel{plural}");
             }
+
+            BotHealth::matchlive_loop();
         }
     }
 
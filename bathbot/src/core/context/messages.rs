@@ -3,6 +3,7 @@ use std::time::Duration;
 use bathbot_util::{matcher, osu::MapIdType};
 use eyre::{Result, WrapErr};
 use futures::StreamExt;
+use rosu_v2::prelude::GameMode;
 use time::OffsetDateTime;
 use twilight_model::{
     channel::{message::Embed, Message},
@@ -140,4 +141,28 @@ impl Context {
 
         None
     }
+
+    /// Find an osu! score url in the message's content or embeds.
+    pub fn find_score_id_in_msg(msg: &Message) -> Option<(GameMode, u64)> {
+        if let Some(score) = matcher::get_osu_score_id(&msg.content) {
+            return Some(score);
+        }
+
+        msg.embeds.iter().find_map(|embed| {
+            let author_url = embed
+                .author
+                .as_ref()
+                .and_then(|author| author.url.as_deref());
+
+            [
+                embed.url.as_deref(),
+                author_url,
+                embed.description.as_deref(),
+            ]
+            .into_iter()
+            .flatten()
+            .chain(embed.fields.iter().map(|field| field.value.as_str()))
+            .find_map(matcher::get_osu_score_id)
+        })
+    }
 }
@@ -1,13 +1,19 @@
 use std::{
-    collections::HashMap,
+    collections::{hash_map::RandomState, HashMap},
     sync::{Arc, Mutex, OnceLock, RwLock},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use bathbot_cache::Cache;
 use bathbot_client::Client as BathbotClient;
 use bathbot_model::twilight_model::id::IdRkyv;
-use bathbot_psql::{model::configs::GuildConfig, Database};
+use bathbot_psql::{
+    model::{
+        configs::{GuildConfig, Locale},
+        webhooks::DbGuildWebhook,
+    },
+    Database,
+};
 use bathbot_util::{IntHasher, MetricsReader};
 use eyre::{Result, WrapErr};
 use flexmap::{std::StdMutexMap, tokio::TokioRwLockMap};
@@ -39,14 +45,16 @@ use twilight_standby::Standby;
 
 use self::osutrack::OsuTrackUserNotifTimestamps;
 use super::{
-    buckets::{BucketName, Buckets},
-    BotConfig, BotMetrics,
+    buckets::{BucketName, BucketTarget, Buckets, Ratelimit},
+    BotConfig, BotHealth, BotMetrics, CacheConfig,
 };
 use crate::{
     active::{impls::BackgroundGame, ActiveMessages},
+    manager::PpRecalcState,
     tracking::Ordr,
 };
 
+mod aliases;
 mod games;
 mod manager;
 mod messages;
@@ -57,13 +65,20 @@ mod shutdown;
 #[cfg(feature = "matchlive")]
 mod matchlive;
 
+#[cfg(test)]
+pub mod fake;
+
 #[cfg(feature = "twitchtracking")]
 mod twitch;
 
 type PapayaSet<K, S> = PapayaMap<K, (), S>; // TODO: await native support for sets
 type GuildShards = PapayaMap<Id<GuildMarker>, u64>;
 type GuildConfigs = PapayaMap<Id<GuildMarker>, GuildConfig, IntHasher>;
+type ScoreEmbedChannels = PapayaMap<Id<ChannelMarker>, Id<GuildMarker>, IntHasher>;
 type MissAnalyzerGuilds = PapayaSet<Id<GuildMarker>, IntHasher>;
+type AliasNames = PapayaSet<Box<str>, RandomState>;
+type UserLocales = PapayaMap<Id<UserMarker>, Option<Locale>, IntHasher>;
+type WebhooksByEvent = PapayaMap<i16, (Instant, Arc<[DbGuildWebhook]>), IntHasher>;
 
 #[cfg(feature = "twitchtracking")]
 type TrackedStreams = PapayaMap<u64, Vec<Id<ChannelMarker>>, IntHasher>;
@@ -91,6 +106,10 @@ impl Context {
         CONTEXT.get().expect("Context not yet initialized")
     }
 
+    pub fn is_initialized() -> bool {
+        CONTEXT.get().is_some()
+    }
+
     pub fn interaction() -> InteractionClient<'static> {
         let ctx = Self::get();
 
@@ -110,7 +129,11 @@ impl Context {
     }
 
     pub fn osu() -> &'static Osu {
-        &Self::get().clients.osu
+        Self::get()
+            .clients
+            .osu
+            .as_ref()
+            .expect("osu! client is only missing in tests")
     }
 
     pub fn client() -> &'static BathbotClient {
@@ -147,6 +170,12 @@ impl Context {
         Self::miss_analyzer_guilds().pin().contains_key(guild)
     }
 
+    /// Names of all stored command aliases so that unknown names don't need
+    /// a database lookup.
+    pub fn alias_names() -> &'static AliasNames {
+        &Self::get().data.alias_names
+    }
+
     #[cfg(feature = "twitch")]
     pub fn online_twitch_streams() -> &'static crate::tracking::OnlineTwitchStreams {
         &Self::get().data.online_twitch_streams
@@ -184,8 +213,8 @@ impl Context {
         let config = BotConfig::get();
 
         // Connect to psql database
-        let psql =
-            Database::new(&config.database_url).wrap_err("Failed to create database client")?;
+        let psql = Database::new(&config.database_url, config.user_scores_refresh)
+            .wrap_err("Failed to create database client")?;
 
         // Connect to discord API
         let (http, application_id) = discord_http(config)
@@ -199,9 +228,18 @@ impl Context {
             .await
             .wrap_err("Failed to create osu client")?;
 
-        let cache = Cache::new(&config.redis_host, config.redis_port, config.redis_db_idx)
-            .await
-            .wrap_err("Failed to create redis cache")?;
+        let cache = match config.cache {
+            CacheConfig::Redis {
+                ref host,
+                port,
+                db_idx,
+            } => Cache::new(host, port, db_idx)
+                .await
+                .wrap_err("Failed to create redis cache")?,
+            CacheConfig::Memory => Cache::in_memory()
+                .await
+                .wrap_err("Failed to create in-memory cache")?,
+        };
 
         let data = ContextData::new(&psql, cache, application_id)
             .await
@@ -215,10 +253,11 @@ impl Context {
 
         BotMetrics::init(&data.cache);
 
-        let client_fut = BathbotClient::new(
+        let client_fut = BathbotClient::with_transport(
             #[cfg(feature = "twitch")]
             (&config.tokens.twitch_client_id, &config.tokens.twitch_token),
             &config.tokens.github_token,
+            config.client_transport.clone(),
         );
 
         let custom_client = client_fut
@@ -252,15 +291,16 @@ impl Context {
         let shard_senders = RwLock::new(shard_senders);
 
         #[cfg(feature = "server")]
-        let (auth_standby, server_tx) = bathbot_server(config, _prometheus, reader.clone())
-            .await
-            .wrap_err("Failed to create server")?;
+        let (auth_standby, server_tx) =
+            bathbot_server(config, application_id, _prometheus, reader.clone())
+                .await
+                .wrap_err("Failed to create server")?;
 
         let clients = Clients {
             http,
             standby: Standby::new(),
             custom: custom_client,
-            osu,
+            osu: Some(osu),
             psql,
             ordr,
             #[cfg(feature = "server")]
@@ -290,15 +330,35 @@ impl Context {
         ))
     }
 
-    /// Acquire an entry for the user in the bucket and optionally return the
-    /// cooldown in amount of seconds if acquiring the entry was ratelimitted.
-    pub fn check_ratelimit(user_id: Id<UserMarker>, bucket: BucketName) -> Option<i64> {
+    /// Acquire an entry for the user or channel in the bucket and optionally
+    /// return the cooldown in amount of seconds if acquiring the entry was
+    /// ratelimitted.
+    ///
+    /// If a guild is specified, its ratelimit override for the bucket is
+    /// considered.
+    pub async fn check_ratelimit(
+        target: impl Into<BucketTarget>,
+        guild_id: Option<Id<GuildMarker>>,
+        bucket: BucketName,
+    ) -> Option<i64> {
+        let custom = match guild_id.filter(|_| bucket.is_overridable()) {
+            Some(guild_id) => {
+                Self::guild_config()
+                    .peek(guild_id, |config| {
+                        config
+                            .bucket_overrides
+                            .get(bucket.key())
+                            .map(Ratelimit::from)
+                    })
+                    .await
+            }
+            None => None,
+        };
+
         let ratelimit = Self::get()
             .buckets
-            .get(bucket)
-            .lock()
-            .unwrap()
-            .take(user_id.get());
+            .take(target.into(), guild_id, bucket, custom)
+            .await;
 
         (ratelimit > 0).then_some(ratelimit)
     }
@@ -328,6 +388,7 @@ impl Context {
 
     pub async fn reshard(shards: &mut Vec<Shard>) -> Result<()> {
         info!("Resharding...");
+        BotHealth::reset_shards();
 
         *shards = discord_gateway(BotConfig::get(), Context::http(), HashMap::default())
             .await
@@ -370,7 +431,9 @@ struct Clients {
     http: Arc<Client>,
     standby: Standby,
     custom: BathbotClient,
-    osu: Osu,
+    /// Only `None` in the fake context of tests since the client
+    /// authenticates when being created.
+    osu: Option<Osu>,
     psql: Database,
     ordr: Option<Arc<Ordr>>,
     #[cfg(feature = "server")]
@@ -388,11 +451,16 @@ struct ContextData {
     #[cfg(feature = "twitchtracking")]
     tracked_streams: TrackedStreams, // read-heavy
     guild_configs: GuildConfigs,              // read-heavy
+    score_embed_channels: ScoreEmbedChannels, // read-heavy
     guild_shards: GuildShards,                // necessary to request members for a guild
     miss_analyzer_guilds: MissAnalyzerGuilds, // read-heavy
+    alias_names: AliasNames,                  // read-heavy
+    user_locales: UserLocales,                // read-heavy
+    webhooks_by_event: WebhooksByEvent,       // read-heavy
     osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps,
     #[cfg(feature = "twitch")]
     online_twitch_streams: crate::tracking::OnlineTwitchStreams,
+    pp_recalc: PpRecalcState,
 }
 
 impl ContextData {
@@ -416,12 +484,36 @@ impl ContextData {
             Self::fetch_miss_analyzer_guilds(&cache),
         );
 
+        let alias_names = psql
+            .select_command_alias_names()
+            .await
+            .wrap_err("Failed to get alias names")?
+            .into_iter()
+            .map(|name| (name.into_boxed_str(), ()))
+            .collect();
+
+        let guild_configs: GuildConfigs = guild_configs_res
+            .wrap_err("Failed to get guild configs")?
+            .into_iter()
+            .collect();
+
+        // Index the guilds' score embed channels so that tracking doesn't
+        // need to iterate over all guild configs
+        let score_embed_channels = guild_configs
+            .pin()
+            .iter()
+            .flat_map(|(guild_id, config)| {
+                config
+                    .score_embed_channels
+                    .iter()
+                    .map(move |channel_id| (*channel_id, *guild_id))
+            })
+            .collect();
+
         Ok(Self {
             cache,
-            guild_configs: guild_configs_res
-                .wrap_err("Failed to get guild configs")?
-                .into_iter()
-                .collect(),
+            guild_configs,
+            score_embed_channels,
             #[cfg(feature = "twitchtracking")]
             tracked_streams: tracked_streams_res
                 .wrap_err("Failed to get tracked streams")?
@@ -437,9 +529,13 @@ impl ContextData {
                 .await
                 .wrap_err("Failed to create osu tracking")?,
             miss_analyzer_guilds,
+            alias_names,
+            user_locales: UserLocales::default(),
+            webhooks_by_event: WebhooksByEvent::default(),
             osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps::default(),
             #[cfg(feature = "twitch")]
             online_twitch_streams: crate::tracking::OnlineTwitchStreams::default(),
+            pp_recalc: PpRecalcState::default(),
         })
     }
 
@@ -539,6 +635,10 @@ async fn discord_gateway(
     let event_types = EventTypeFlags::CHANNEL_CREATE
         | EventTypeFlags::CHANNEL_DELETE
         | EventTypeFlags::CHANNEL_UPDATE
+        | EventTypeFlags::GATEWAY_CLOSE
+        | EventTypeFlags::GATEWAY_HEARTBEAT_ACK
+        | EventTypeFlags::GATEWAY_INVALIDATE_SESSION
+        | EventTypeFlags::GATEWAY_RECONNECT
         | EventTypeFlags::GUILD_CREATE
         | EventTypeFlags::GUILD_DELETE
         | EventTypeFlags::GUILD_UPDATE
@@ -551,6 +651,7 @@ async fn discord_gateway(
         | EventTypeFlags::MESSAGE_DELETE
         | EventTypeFlags::MESSAGE_DELETE_BULK
         | EventTypeFlags::READY
+        | EventTypeFlags::RESUMED
         | EventTypeFlags::ROLE_CREATE
         | EventTypeFlags::ROLE_DELETE
         | EventTypeFlags::ROLE_UPDATE
@@ -590,6 +691,7 @@ async fn discord_gateway(
 #[cfg(feature = "server")]
 async fn bathbot_server(
     config: &BotConfig,
+    application_id: Id<ApplicationMarker>,
     prometheus: metrics_exporter_prometheus::PrometheusHandle,
     metrics_reader: MetricsReader,
 ) -> Result<(
@@ -604,7 +706,14 @@ async fn bathbot_server(
         osu_client_secret: config.tokens.osu_client_secret.to_string(),
         twitch_client_id: config.tokens.twitch_client_id.to_string(),
         twitch_token: config.tokens.twitch_token.to_string(),
+        discord_client_id: application_id.get(),
+        discord_client_secret: config.tokens.discord_client_secret.to_string(),
         redirect_base: config.server.public_url.to_string(),
+        trusted_proxies: config.server.trusted_proxies.to_vec(),
+        api: Arc::new(crate::core::api::BotApiProvider),
+        dashboard: Arc::new(crate::core::dashboard::BotDashboardProvider),
+        images: Arc::new(crate::core::images::BotImageProvider),
+        health: Arc::new(crate::core::health::BotHealthProvider),
     };
 
     let (server, standby, tx) = bathbot_server::Server::new(builder)?;
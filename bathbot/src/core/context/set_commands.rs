@@ -1,14 +1,17 @@
+use std::collections::HashMap;
+
+use bathbot_psql::model::configs::Locale;
 use eyre::{Result, WrapErr};
 use twilight_http::{
     request::{Request, RequestBuilder},
     routing::Route,
 };
-use twilight_model::application::command::Command as TwilightCommand;
+use twilight_model::application::command::{Command as TwilightCommand, CommandOption};
 
 use super::Context;
 use crate::core::{
     commands::interaction::twilight_command::{Command, IntegrationType, InteractionContextType},
-    BotConfig,
+    locale, BotConfig,
 };
 
 impl Context {
@@ -18,6 +21,7 @@ impl Context {
         };
 
         add_integrations_and_contexts(&mut cmds);
+        add_localizations(&mut cmds);
 
         send_command_request(route, &cmds).await
     }
@@ -29,6 +33,7 @@ impl Context {
         };
 
         add_integrations_and_contexts(&mut cmds);
+        add_localizations(&mut cmds);
 
         send_command_request(route, &cmds).await
     }
@@ -49,6 +54,62 @@ fn add_integrations_and_contexts(cmds: &mut [Command]) {
     }
 }
 
+fn add_localizations(cmds: &mut [Command]) {
+    for cmd in cmds {
+        let id = format!("cmd-{}", cmd.name);
+
+        localize(
+            &id,
+            &mut cmd.name_localizations,
+            &mut cmd.description_localizations,
+        );
+
+        for option in cmd.options.iter_mut() {
+            localize_option(&id, option);
+        }
+    }
+}
+
+fn localize_option(parent_id: &str, option: &mut CommandOption) {
+    let id = format!("{parent_id}-{}", option.name);
+
+    localize(
+        &id,
+        &mut option.name_localizations,
+        &mut option.description_localizations,
+    );
+
+    for option in option.options.iter_mut().flatten() {
+        localize_option(&id, option);
+    }
+}
+
+fn localize(
+    id: &str,
+    names: &mut Option<HashMap<String, String>>,
+    descriptions: &mut Option<HashMap<String, String>>,
+) {
+    for locale in Locale::ALL {
+        if locale == Locale::English {
+            continue;
+        }
+
+        let code = locale.discord_code();
+
+        if let Some(name) = locale::command_name(locale, id) {
+            names
+                .get_or_insert_with(HashMap::new)
+                .insert(code.to_owned(), name);
+        }
+
+        if let Some(description) = locale::command_description(locale, id) {
+            descriptions
+                .get_or_insert_with(HashMap::new)
+                .insert(code.to_owned(), description);
+        }
+    }
+}
+
 async fn send_command_request(route: Route<'_>, cmds: &[Command]) -> Result<Vec<TwilightCommand>> {
     let req = Request::builder(&route)
         .json(&cmds)
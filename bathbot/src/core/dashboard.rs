@@ -0,0 +1,237 @@
+use bathbot_psql::model::configs::{Prefix, DEFAULT_PREFIX};
+use bathbot_server::dashboard::{DashboardProvider, GuildSettings, UserSettings};
+use bathbot_util::matcher;
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::GameMode;
+use twilight_model::id::Id;
+
+use crate::{
+    commands::utility::PrefixValidator,
+    core::{commands::checks::check_authority, Context},
+};
+
+/// Lets the server's dashboard read and edit configs.
+pub struct BotDashboardProvider;
+
+impl DashboardProvider for BotDashboardProvider {
+    fn is_ready(&self) -> bool {
+        Context::is_initialized()
+    }
+
+    fn user_settings(&self, user: u64) -> BoxFuture<'_, Result<UserSettings>> {
+        Box::pin(user_settings(user))
+    }
+
+    fn update_user_settings(&self, user: u64, settings: UserSettings) -> BoxFuture<'_, Result<()>> {
+        Box::pin(update_user_settings(user, settings))
+    }
+
+    fn manageable_guild(&self, user: u64, guild: u64) -> BoxFuture<'_, Result<Option<Box<str>>>> {
+        Box::pin(manageable_guild(user, guild))
+    }
+
+    fn guild_settings(&self, guild: u64) -> BoxFuture<'_, Result<GuildSettings>> {
+        Box::pin(guild_settings(guild))
+    }
+
+    fn update_guild_settings(
+        &self,
+        guild: u64,
+        settings: GuildSettings,
+    ) -> BoxFuture<'_, Result<Option<Box<str>>>> {
+        Box::pin(update_guild_settings(guild, settings))
+    }
+
+    fn untrack_channel(&self, guild: u64, channel: u64) -> BoxFuture<'_, Result<Option<usize>>> {
+        Box::pin(untrack_channel(guild, channel))
+    }
+}
+
+async fn user_settings(user: u64) -> Result<UserSettings> {
+    let Some(user_id) = Id::new_checked(user) else {
+        return Ok(UserSettings::default());
+    };
+
+    let config = Context::user_config().with_osu_id(user_id).await?;
+
+    Ok(UserSettings {
+        mode: config.mode.map(|mode| mode as u8),
+        list_size: config.list_size.map(i16::from),
+        retries: config.retries.map(i16::from),
+        render_button: config.render_button,
+        score_data: config.score_data.map(i16::from),
+        locale: config.locale.map(i16::from),
+        score_embed: config.score_embed,
+    })
+}
+
+async fn update_user_settings(user: u64, settings: UserSettings) -> Result<()> {
+    let Some(user_id) = Id::new_checked(user) else {
+        return Ok(());
+    };
+
+    let manager = Context::user_config();
+    let mut config = manager.with_osu_id(user_id).await?;
+
+    config.mode = settings.mode.map(GameMode::from);
+    config.list_size = settings.list_size.and_then(|value| value.try_into().ok());
+    config.retries = settings.retries.and_then(|value| value.try_into().ok());
+    config.render_button = settings.render_button;
+    config.score_data = settings.score_data.and_then(|value| value.try_into().ok());
+    config.locale = settings.locale.and_then(|value| value.try_into().ok());
+    if let Some(ref score_embed) = settings.score_embed {
+        if let Err(err) = score_embed.validate() {
+            bail!("Invalid score embed settings: {err}");
+        }
+    }
+
+    config.score_embed = settings.score_embed;
+
+    manager.store(user_id, &config).await
+}
+
+async fn manageable_guild(user: u64, guild: u64) -> Result<Option<Box<str>>> {
+    let (Some(user_id), Some(guild_id)) = (Id::new_checked(user), Id::new_checked(guild)) else {
+        return Ok(None);
+    };
+
+    let cache = Context::cache();
+
+    let Some(guild) = cache.guild(guild_id).await? else {
+        return Ok(None);
+    };
+
+    // Checking the authority of a non-member would fail
+    if cache.member(guild_id, user_id).await?.is_none() {
+        return Ok(None);
+    }
+
+    match check_authority(user_id, Some(guild_id)).await? {
+        None => Ok(Some(Box::from(&*guild.name))),
+        Some(_) => Ok(None),
+    }
+}
+
+async fn guild_settings(guild: u64) -> Result<GuildSettings> {
+    let Some(guild_id) = Id::new_checked(guild) else {
+        return Ok(GuildSettings::default());
+    };
+
+    let settings = Context::guild_config()
+        .peek(guild_id, |config| GuildSettings {
+            prefixes: config
+                .prefixes
+                .iter()
+                .map(|prefix| prefix.as_str().into())
+                .collect(),
+            authorities: config.authorities.iter().map(|role| role.get()).collect(),
+            list_size: config.list_size.map(i16::from),
+            retries: config.retries.map(i16::from),
+            score_data: config.score_data.map(i16::from),
+            locale: config.locale.map(i16::from),
+            hide_medal_solution: config.hide_medal_solution.map(i16::from),
+            track_limit: config.track_limit,
+            allow_songs: config.allow_songs,
+            render_button: config.render_button,
+            allow_custom_skins: config.allow_custom_skins,
+        })
+        .await;
+
+    Ok(settings)
+}
+
+async fn update_guild_settings(guild: u64, settings: GuildSettings) -> Result<Option<Box<str>>> {
+    let Some(guild_id) = Id::new_checked(guild) else {
+        return Ok(None);
+    };
+
+    let mut prefixes: Vec<Prefix> = Vec::with_capacity(settings.prefixes.len());
+
+    for prefix in settings.prefixes.iter() {
+        if matcher::is_custom_emote(prefix) || !PrefixValidator::is_valid(prefix) {
+            return Ok(Some(prefix.clone()));
+        }
+
+        let prefix = Prefix::from(&**prefix);
+
+        if !prefixes.contains(&prefix) {
+            prefixes.push(prefix);
+        }
+    }
+
+    if prefixes.is_empty() {
+        prefixes.push(DEFAULT_PREFIX.into());
+    }
+
+    // Only keep roles that exist in the guild
+    let mut authorities = Vec::with_capacity(settings.authorities.len());
+    let cache = Context::cache();
+
+    for role in settings.authorities {
+        let Some(role_id) = Id::new_checked(role) else {
+            continue;
+        };
+
+        if cache.role(guild_id, role_id).await?.is_some() {
+            authorities.push(role_id);
+        }
+    }
+
+    Context::guild_config()
+        .update(guild_id, |config| {
+            config.prefixes.clear();
+
+            for prefix in prefixes {
+                if config.prefixes.try_push(prefix).is_err() {
+                    break;
+                }
+            }
+
+            config.authorities = authorities.into_iter().collect();
+            config.list_size = settings.list_size.and_then(|value| value.try_into().ok());
+            config.retries = settings.retries.and_then(|value| value.try_into().ok());
+            config.score_data = settings.score_data.and_then(|value| value.try_into().ok());
+            config.locale = settings.locale.and_then(|value| value.try_into().ok());
+            config.hide_medal_solution = settings
+                .hide_medal_solution
+                .and_then(|value| value.try_into().ok());
+            config.track_limit = settings.track_limit;
+            config.allow_songs = settings.allow_songs;
+            config.render_button = settings.render_button;
+            config.allow_custom_skins = settings.allow_custom_skins;
+        })
+        .await?;
+
+    Ok(None)
+}
+
+async fn untrack_channel(guild: u64, channel: u64) -> Result<Option<usize>> {
+    let (Some(guild_id), Some(channel_id)) = (Id::new_checked(guild), Id::new_checked(channel))
+    else {
+        return Ok(None);
+    };
+
+    if Context::cache()
+        .channel(Some(guild_id), channel_id)
+        .await?
+        .is_none()
+    {
+        return Ok(None);
+    }
+
+    #[allow(unused_mut)]
+    let mut removed = 0;
+
+    #[cfg(feature = "osutracking")]
+    {
+        removed += Context::tracking().remove_channel(channel_id, None).await?;
+    }
+
+    #[cfg(feature = "twitch")]
+    Context::twitch().untrack_all(channel_id).await?;
+
+    info!(%guild_id, %channel_id, removed, "Untracked channel via dashboard");
+
+    Ok(Some(removed))
+}
@@ -4,6 +4,7 @@ use crate::{
     commands::{
         help::slash_help,
         osu::{slash_badges, slash_cs, slash_medal, slash_regiontop},
+        utility::slash_alias,
     },
     core::{events::EventKind, BotMetrics},
     util::interaction::InteractionCommand,
@@ -21,6 +22,7 @@ pub async fn handle_autocomplete(mut command: InteractionCommand) {
         "medal" => slash_medal(command).await,
         "cs" | "compare" | "score" => slash_cs(command).await,
         "regiontop" => slash_regiontop(command).await,
+        "alias" => slash_alias(command).await,
         _ => return error!(name, "Unknown autocomplete command"),
     };
 
@@ -1,14 +1,17 @@
 use std::{mem, time::Instant};
 
 use eyre::Result;
+use fluent_bundle::FluentArgs;
 
 use crate::{
     core::{
         commands::{
-            checks::check_authority,
+            checks::{check_authority, check_command_rules},
             interaction::{InteractionCommandKind, InteractionCommands, SlashCommand},
+            prefix::PrefixCommandGroup,
         },
         events::{EventKind, ProcessResult},
+        locale::{self, tr, tr_args},
         BotConfig, BotMetrics, Context,
     },
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
@@ -24,15 +27,24 @@ pub async fn handle_command(mut command: InteractionCommand) {
         return error!(name, "Unknown interaction command");
     };
 
+    if let Ok(user_id) = command.user_id() {
+        command.locale = locale::resolve(user_id, command.guild_id, Some(command.locale)).await;
+    }
+
     let group_sub = command.group_sub();
 
+    let kind = match cmd {
+        InteractionCommandKind::User(_) => "user",
+        InteractionCommandKind::Chat(_) | InteractionCommandKind::Message(_) => "message",
+    };
+
     match process_command(command, cmd).await {
         Ok(ProcessResult::Success) => info!(%name, "Processed interaction command"),
         Ok(reason) => info!(?reason, "Interaction command `{name}` was not processed"),
         Err(err) => {
             match group_sub.clone() {
                 Some((group, sub)) => BotMetrics::inc_slash_command_error(name.clone(), group, sub),
-                None => BotMetrics::inc_command_error("message", name.clone()),
+                None => BotMetrics::inc_command_error(kind, name.clone()),
             }
 
             error!(name, ?err, "Failed to process interaction command");
@@ -43,7 +55,7 @@ pub async fn handle_command(mut command: InteractionCommand) {
 
     match group_sub {
         Some((group, sub)) => BotMetrics::observe_slash_command(name, group, sub, elapsed),
-        None => BotMetrics::observe_command("message", name, elapsed),
+        None => BotMetrics::observe_command(kind, name, elapsed),
     }
 }
 
@@ -63,6 +75,21 @@ async fn process_command(
             }
         },
         InteractionCommandKind::Message(cmd) => {
+            if let Some(result) = check_rules(&command, &[cmd.name], None).await? {
+                return Ok(result);
+            }
+
+            if cmd.flags.defer() {
+                command.defer(cmd.flags.ephemeral()).await?;
+            }
+
+            (cmd.exec)(command).await?;
+        }
+        InteractionCommandKind::User(cmd) => {
+            if let Some(result) = check_rules(&command, &[cmd.name], None).await? {
+                return Ok(result);
+            }
+
             if cmd.flags.defer() {
                 command.defer(cmd.flags.ephemeral()).await?;
             }
@@ -82,18 +109,25 @@ async fn pre_process_command(
 
     // Only for owner?
     if slash.flags.only_owner() && user_id != BotConfig::get().owner {
-        let content = "That command can only be used by the bot owner";
+        let content = tr(command.locale, "only-owner");
         command.error_callback(content).await?;
 
         return Ok(Some(ProcessResult::NoOwner));
     }
 
+    // Restricted by the guild?
+    if let Some(result) = check_rules(command, &[slash.name], slash.command_group()).await? {
+        return Ok(Some(result));
+    }
+
     // Ratelimited?
     if let Some(bucket) = slash.bucket {
-        if let Some(cooldown) = Context::check_ratelimit(user_id, bucket) {
+        if let Some(cooldown) = Context::check_ratelimit(user_id, command.guild_id, bucket).await {
             trace!("Ratelimiting user {user_id} on bucket `{bucket:?}` for {cooldown} seconds");
 
-            let content = format!("Command on cooldown, try again in {cooldown} seconds");
+            let mut args = FluentArgs::new();
+            args.set("seconds", cooldown);
+            let content = tr_args(command.locale, "on-cooldown", Some(&args));
             command.error_callback(content).await?;
 
             return Ok(Some(ProcessResult::Ratelimited(bucket)));
@@ -110,7 +144,7 @@ async fn pre_process_command(
                 return Ok(Some(ProcessResult::NoAuthority));
             }
             Err(err) => {
-                let content = "Error while checking authority status";
+                let content = tr(command.locale, "authority-check-issue");
                 let _ = command.error_callback(content).await;
 
                 return Err(err.wrap_err("failed to check authority status"));
@@ -120,3 +154,26 @@ async fn pre_process_command(
 
     Ok(None)
 }
+
+async fn check_rules(
+    command: &InteractionCommand,
+    names: &[&str],
+    group: Option<PrefixCommandGroup>,
+) -> Result<Option<ProcessResult>> {
+    let user_id = command.user_id()?;
+
+    match check_command_rules(user_id, command.guild_id, command.channel_id, names, group).await {
+        Ok(None) => Ok(None),
+        Ok(Some(content)) => {
+            command.error_callback(content).await?;
+
+            Ok(Some(ProcessResult::Restricted))
+        }
+        Err(err) => {
+            let content = tr(command.locale, "rules-check-issue");
+            let _ = command.error_callback(content).await;
+
+            Err(err.wrap_err("failed to check command rules"))
+        }
+    }
+}
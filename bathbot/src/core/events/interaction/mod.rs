@@ -1,3 +1,4 @@
+use bathbot_psql::model::configs::Locale;
 use twilight_model::application::interaction::{Interaction, InteractionData, InteractionType};
 
 use self::{autocomplete::handle_autocomplete, command::handle_command};
@@ -17,6 +18,7 @@ pub async fn handle_interaction(interaction: Interaction) {
         guild_id,
         id,
         kind,
+        locale,
         member,
         message,
         token,
@@ -39,6 +41,10 @@ pub async fn handle_interaction(interaction: Interaction) {
                 member,
                 token,
                 user,
+                locale: locale
+                    .as_deref()
+                    .and_then(Locale::from_discord)
+                    .unwrap_or_default(),
             };
 
             match kind {
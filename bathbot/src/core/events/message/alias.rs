@@ -0,0 +1,57 @@
+use bathbot_util::CowUtils;
+use twilight_model::channel::Message;
+
+use super::parse::{Invoke, MessageInvoke};
+use crate::{
+    commands::utility::{is_valid_alias_name, split_command},
+    core::{
+        commands::interaction::{InteractionCommandKind, InteractionCommands},
+        Context,
+    },
+};
+
+/// Expand the custom alias at the start of the content.
+///
+/// Arguments after the alias are appended to its expansion.
+pub async fn expand(msg: &Message, content: &str) -> Option<String> {
+    let (name, rest) = split_command(content);
+    let name = name.cow_to_ascii_lowercase();
+
+    // Most unknown commands are no aliases so they're filtered without
+    // querying the database
+    if !is_valid_alias_name(&name) || !Context::alias_names().pin().contains_key(&*name) {
+        return None;
+    }
+
+    let alias_fut = Context::psql().select_command_alias(msg.author.id, msg.guild_id, &name);
+
+    let mut expanded = match alias_fut.await {
+        Ok(alias) => alias?.expansion,
+        Err(err) => {
+            warn!(?err, "Failed to get command alias");
+
+            return None;
+        }
+    };
+
+    if !rest.is_empty() {
+        expanded.push(' ');
+        expanded.push_str(rest);
+    }
+
+    Some(expanded)
+}
+
+/// Parse the expansion of an alias into the command it invokes.
+pub fn parse(expanded: &str) -> Option<MessageInvoke<'_>> {
+    let Some(slash) = expanded.strip_prefix('/') else {
+        return Invoke::parse(expanded).map(MessageInvoke::Prefix);
+    };
+
+    let (name, args) = split_command(slash);
+
+    match InteractionCommands::get_command(name)? {
+        InteractionCommandKind::Chat(cmd) => Some(MessageInvoke::Slash { cmd, args }),
+        _ => None,
+    }
+}
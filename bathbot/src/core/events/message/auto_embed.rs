@@ -0,0 +1,287 @@
+use std::fmt::Write;
+
+use bathbot_psql::model::configs::AutoEmbeds;
+use bathbot_util::{
+    constants::OSU_BASE,
+    datetime::SecToMinSec,
+    matcher,
+    numbers::{round, WithComma},
+    EmbedBuilder, MessageBuilder,
+};
+use eyre::{Report, Result, WrapErr};
+use rosu_v2::prelude::{GameMode, MatchEvent, OsuError, UserId};
+use twilight_model::{
+    channel::Message,
+    guild::Permissions,
+    id::{marker::GuildMarker, Id},
+};
+
+use crate::{
+    core::{buckets::BucketName, commands::checks::check_channel_permissions, Context},
+    manager::redis::osu::UserArgs,
+    util::{osu::grade_emote, MessageExt},
+};
+
+/// Reply to osu! links in the message with a compact embed if the guild
+/// enabled auto-embeds for the link's kind.
+pub async fn handle_links(msg: &Message, guild_id: Id<GuildMarker>) {
+    if !msg.content.contains(OSU_BASE) {
+        return;
+    }
+
+    let enabled = Context::guild_config()
+        .peek(guild_id, |config| config.auto_embeds)
+        .await;
+
+    let Some(link) = OsuLink::find(&msg.content, enabled) else {
+        return;
+    };
+
+    let permissions = match Context::cache().current_user().await {
+        Ok(Some(user)) => check_channel_permissions(user.id, msg.channel_id, guild_id).await,
+        Ok(None) => return warn!("Missing current user in cache"),
+        Err(err) => return warn!(?err, "Failed to get current user"),
+    };
+
+    if !permissions.contains(Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS) {
+        return;
+    }
+
+    // The cooldown applies to the whole channel instead of a single user
+    let ratelimit = Context::check_ratelimit(msg.channel_id, Some(guild_id), BucketName::AutoEmbed);
+
+    if ratelimit.await.is_some() {
+        return;
+    }
+
+    let embed = match link.embed().await {
+        Ok(Some(embed)) => embed,
+        Ok(None) => return,
+        Err(err) => return warn!(?err, "Failed to build auto-embed"),
+    };
+
+    let builder = MessageBuilder::new().embed(embed);
+
+    if let Err(err) = msg.reply(builder, Some(permissions)).await {
+        warn!(?err, "Failed to reply with auto-embed");
+    }
+}
+
+enum OsuLink {
+    Map(u32),
+    Score { id: u64, mode: GameMode },
+    User(UserId),
+    Match(u32),
+}
+
+impl OsuLink {
+    /// Find the first osu! link of an enabled kind.
+    fn find(content: &str, enabled: AutoEmbeds) -> Option<Self> {
+        if enabled.is_empty() {
+            return None;
+        }
+
+        content
+            .split_whitespace()
+            .filter(|word| word.starts_with(OSU_BASE))
+            .find_map(|word| {
+                if enabled.contains(AutoEmbeds::SCORE) {
+                    if let Some((mode, id)) = matcher::get_osu_score_id(word) {
+                        return Some(Self::Score { id, mode });
+                    }
+                }
+
+                if enabled.contains(AutoEmbeds::MAP) {
+                    if let Some(map_id) = matcher::get_osu_map_id(word) {
+                        return Some(Self::Map(map_id));
+                    }
+                }
+
+                if enabled.contains(AutoEmbeds::MATCH) {
+                    if let Some(match_id) = matcher::get_osu_match_id(word) {
+                        return Some(Self::Match(match_id));
+                    }
+                }
+
+                if enabled.contains(AutoEmbeds::USER) {
+                    if let Some(user_id) = matcher::get_osu_user_id(word) {
+                        return Some(Self::User(user_id));
+                    }
+                }
+
+                None
+            })
+    }
+
+    /// Build the embed for the link.
+    ///
+    /// Returns `None` if the link does not refer to anything.
+    async fn embed(self) -> Result<Option<EmbedBuilder>> {
+        match self {
+            Self::Map(map_id) => map_embed(map_id).await.map(Some),
+            Self::Score { id, mode } => score_embed(id, mode).await,
+            Self::User(user_id) => user_embed(user_id).await,
+            Self::Match(match_id) => match_embed(match_id).await,
+        }
+    }
+}
+
+async fn map_embed(map_id: u32) -> Result<EmbedBuilder> {
+    let map = Context::osu_map()
+        .map(map_id, None)
+        .await
+        .wrap_err("Failed to get map")?;
+
+    const ACCS: [f64; 3] = [95.0, 98.0, 99.0];
+
+    // Difficulty attributes are looked up in the database before calculating
+    let mut calc = Context::pp(&map);
+    let attrs = calc.difficulty().await.to_owned();
+    let max_pp = calc.performance().await.pp();
+
+    let stars = attrs.stars();
+    let mut pp_table = String::new();
+
+    for acc in ACCS {
+        let pp = attrs.clone().performance().accuracy(acc).calculate().pp();
+        let _ = write!(pp_table, "`{acc}%: {}pp` ", round(pp as f32));
+    }
+
+    let _ = write!(pp_table, "`100%: {}pp`", round(max_pp as f32));
+
+    let description = format!(
+        "{stars:.2}★ • {len} • {bpm} BPM • by {creator}\n\
+        `CS {cs} AR {ar} OD {od} HP {hp}`\n{pp_table}",
+        len = SecToMinSec::new(map.seconds_drain()),
+        bpm = round(map.bpm()),
+        creator = map.creator(),
+        cs = round(map.pp_map.cs),
+        ar = round(map.pp_map.ar),
+        od = round(map.pp_map.od),
+        hp = round(map.pp_map.hp),
+    );
+
+    let embed = EmbedBuilder::new()
+        .title(format!(
+            "{} - {} [{}]",
+            map.artist(),
+            map.title(),
+            map.version()
+        ))
+        .url(format!("{OSU_BASE}b/{map_id}"))
+        .thumbnail(map.thumbnail())
+        .description(description);
+
+    Ok(embed)
+}
+
+async fn score_embed(score_id: u64, mode: GameMode) -> Result<Option<EmbedBuilder>> {
+    let mut score = match Context::osu().score(score_id).mode(mode).await {
+        Ok(score) => score,
+        Err(OsuError::NotFound) => return Ok(None),
+        Err(err) => return Err(Report::new(err).wrap_err("Failed to get score")),
+    };
+
+    let Some(map) = score.map.take() else {
+        return Ok(None);
+    };
+
+    let map_fut = Context::osu_map().map(map.map_id, map.checksum.as_deref());
+    let user_fut = Context::redis().osu_user(UserArgs::user_id(score.user_id, mode));
+
+    let (map, user) = match tokio::join!(map_fut, user_fut) {
+        (Ok(map), Ok(user)) => (map, user),
+        (Err(err), _) => return Err(err.wrap_err("Failed to get map")),
+        (_, Err(err)) => return Err(Report::new(err).wrap_err("Failed to get user")),
+    };
+
+    let pp = score
+        .pp
+        .map_or_else(|| "-".to_owned(), |pp| round(pp).to_string());
+
+    let description = format!(
+        "{grade} **+{mods}** • {acc}% • {combo}x • {misses} miss • **{pp}pp**",
+        grade = grade_emote(score.grade),
+        mods = score.mods,
+        acc = round(score.accuracy),
+        combo = score.max_combo,
+        misses = score.statistics.count_miss,
+    );
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder())
+        .title(format!(
+            "{} - {} [{}]",
+            map.artist(),
+            map.title(),
+            map.version()
+        ))
+        .url(format!("{OSU_BASE}b/{}", map.map_id()))
+        .thumbnail(map.thumbnail())
+        .description(description)
+        .timestamp(score.ended_at);
+
+    Ok(Some(embed))
+}
+
+async fn user_embed(user_id: UserId) -> Result<Option<EmbedBuilder>> {
+    let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => return Ok(None),
+        Err(err) => return Err(Report::new(err).wrap_err("Failed to get user")),
+    };
+
+    let stats = user.stats();
+
+    let description = format!(
+        "Accuracy: `{acc:.2}%` • Level: `{level:.2}`\n\
+        Playcount: `{playcount}` • Max combo: `{combo}x`",
+        acc = stats.accuracy(),
+        level = stats.level().float(),
+        playcount = WithComma::new(stats.playcount()),
+        combo = stats.max_combo(),
+    );
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder())
+        .thumbnail(user.avatar_url())
+        .description(description);
+
+    Ok(Some(embed))
+}
+
+async fn match_embed(match_id: u32) -> Result<Option<EmbedBuilder>> {
+    let osu_match = match Context::osu().osu_match(match_id).await {
+        Ok(osu_match) => osu_match,
+        Err(OsuError::NotFound) => return Ok(None),
+        Err(err) => return Err(Report::new(err).wrap_err("Failed to get match")),
+    };
+
+    let games = osu_match
+        .events
+        .iter()
+        .filter(|event| matches!(event, MatchEvent::Game { .. }))
+        .count();
+
+    let description = format!(
+        "{games} game{plural} • {players} player{players_plural} • {status}",
+        plural = if games == 1 { "" } else { "s" },
+        players = osu_match.users.len(),
+        players_plural = if osu_match.users.len() == 1 { "" } else { "s" },
+        status = if osu_match.end_time.is_some() {
+            "finished"
+        } else {
+            "ongoing"
+        },
+    );
+
+    let embed = EmbedBuilder::new()
+        .title(osu_match.name)
+        .url(format!("{OSU_BASE}community/matches/{match_id}"))
+        .description(description)
+        .timestamp(osu_match.start_time);
+
+    Ok(Some(embed))
+}
@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::{slice, time::Instant};
 
 use bathbot_psql::model::configs::{GuildConfig, Prefix, DEFAULT_PREFIX};
 use eyre::Result;
@@ -13,12 +13,18 @@ use super::{EventKind, ProcessResult};
 use crate::{
     core::{
         buckets::BucketName,
-        commands::checks::{check_authority, check_channel_permissions},
+        commands::{
+            checks::{check_authority, check_channel_permissions, check_command_rules},
+            CommandOrigin,
+        },
         BotMetrics, Context,
     },
+    scheduler::{parse_command, run_command},
     util::ChannelExt,
 };
 
+mod alias;
+mod auto_embed;
 mod parse;
 
 pub async fn handle_message(msg: Message) {
@@ -49,15 +55,34 @@ pub async fn handle_message(msg: Message) {
     };
 
     let Some((content, _)) = prefix_opt else {
+        if let Some(guild_id) = msg.guild_id {
+            auto_embed::handle_links(&msg, guild_id).await;
+        }
+
         return;
     };
 
-    // Parse msg content for commands
-    let Some(invoke) = Invoke::parse(content) else {
-        return;
+    // Parse msg content for commands, expanding custom aliases if necessary
+    let expanded;
+
+    let invoke = match Invoke::parse(content) {
+        Some(invoke) => MessageInvoke::Prefix(invoke),
+        None => {
+            let Some(expansion) = alias::expand(&msg, content).await else {
+                return;
+            };
+
+            expanded = expansion;
+
+            let Some(invoke) = alias::parse(&expanded) else {
+                return;
+            };
+
+            invoke
+        }
     };
 
-    let name = invoke.cmd.name();
+    let name = invoke.name();
     EventKind::PrefixCommand.log(&msg, name).await;
 
     match process_command(invoke, &msg).await {
@@ -73,11 +98,26 @@ pub async fn handle_message(msg: Message) {
     BotMetrics::observe_command("prefix", name, elapsed);
 }
 
-async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<ProcessResult> {
-    let Invoke { cmd, args } = invoke;
+async fn process_command<'m>(invoke: MessageInvoke<'m>, msg: &'m Message) -> Result<ProcessResult> {
+    let (names, group, bucket, flags, only_guilds) = match invoke {
+        MessageInvoke::Prefix(Invoke { cmd, .. }) => (
+            cmd.names,
+            Some(cmd.group),
+            cmd.bucket,
+            cmd.flags,
+            cmd.flags.only_guilds(),
+        ),
+        MessageInvoke::Slash { cmd, .. } => (
+            slice::from_ref(&cmd.name),
+            cmd.group,
+            cmd.bucket,
+            cmd.flags,
+            (cmd.create)().dm_permission == Some(false),
+        ),
+    };
 
     // Only in guilds?
-    if (cmd.flags.authority() || cmd.flags.only_guilds()) && msg.guild_id.is_none() {
+    if (flags.authority() || only_guilds) && msg.guild_id.is_none() {
         let content = "That command is only available in servers";
         msg.error(content).await?;
 
@@ -104,14 +144,33 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
     };
 
     // Ratelimited?
-    if let Some(cooldown) = Context::check_ratelimit(msg.author.id, BucketName::All) {
+    if let Some(cooldown) =
+        Context::check_ratelimit(msg.author.id, msg.guild_id, BucketName::All).await
+    {
         trace!("Ratelimiting user {} for {cooldown} seconds", msg.author.id);
 
         return Ok(ProcessResult::Ratelimited(BucketName::All));
     }
 
-    if let Some(bucket) = cmd.bucket {
-        if let Some(cooldown) = Context::check_ratelimit(msg.author.id, bucket) {
+    // Restricted by the guild?
+    match check_command_rules(msg.author.id, msg.guild_id, channel, names, group).await {
+        Ok(None) => {}
+        Ok(Some(content)) => {
+            let _ = msg.error(content).await;
+
+            return Ok(ProcessResult::Restricted);
+        }
+        Err(err) => {
+            let content = "Error while checking command restrictions";
+            let _ = msg.error(content).await;
+
+            return Err(err.wrap_err("failed to check command rules"));
+        }
+    }
+
+    if let Some(bucket) = bucket {
+        if let Some(cooldown) = Context::check_ratelimit(msg.author.id, msg.guild_id, bucket).await
+        {
             trace!(
                 "Ratelimiting user {} on bucket `{bucket:?}` for {cooldown} seconds",
                 msg.author.id,
@@ -125,7 +184,7 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
     }
 
     // Only for authorities?
-    if cmd.flags.authority() {
+    if flags.authority() {
         match check_authority(msg.author.id, msg.guild_id).await {
             Ok(None) => {}
             Ok(Some(content)) => {
@@ -143,12 +202,27 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
     }
 
     // Broadcast typing event
-    if cmd.flags.defer() {
+    if flags.defer() {
         let _ = Context::http().create_typing_trigger(channel).await;
     }
 
     // Call command function
-    (cmd.exec)(msg, args, permissions).await?;
+    match invoke {
+        MessageInvoke::Prefix(Invoke { cmd, args }) => (cmd.exec)(msg, args, permissions).await?,
+        MessageInvoke::Slash { cmd, args } => {
+            let data = match parse_command(cmd, args) {
+                Ok(data) => data,
+                Err(content) => {
+                    msg.error(content).await?;
+
+                    return Ok(ProcessResult::InvalidArgs);
+                }
+            };
+
+            let orig = CommandOrigin::from_msg(msg, permissions);
+            run_command(orig, cmd.name, data).await?;
+        }
+    }
 
     Ok(ProcessResult::Success)
 }
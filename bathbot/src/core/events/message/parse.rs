@@ -6,7 +6,10 @@ use nom::{
     sequence::{pair, terminated},
 };
 
-use crate::core::commands::prefix::{Args, ArgsNum, PrefixCommand, PrefixCommands};
+use crate::core::commands::{
+    interaction::SlashCommand,
+    prefix::{Args, ArgsNum, PrefixCommand, PrefixCommands},
+};
 
 pub struct Invoke<'i> {
     pub cmd: &'static PrefixCommand,
@@ -48,3 +51,22 @@ impl<'i> Invoke<'i> {
         Some(Self { cmd, args })
     }
 }
+
+/// A command invoked through a message.
+pub enum MessageInvoke<'i> {
+    Prefix(Invoke<'i>),
+    /// Slash command through an alias
+    Slash {
+        cmd: &'static SlashCommand,
+        args: &'i str,
+    },
+}
+
+impl MessageInvoke<'_> {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Prefix(invoke) => invoke.cmd.name(),
+            Self::Slash { cmd, .. } => cmd.name,
+        }
+    }
+}
@@ -10,7 +10,7 @@ use twilight_gateway::{error::ReceiveMessageErrorType, stream::ShardEventStream,
 use twilight_model::{gateway::CloseCode, user::User};
 
 use self::{interaction::handle_interaction, message::handle_message};
-use super::{buckets::BucketName, BotMetrics, Context};
+use super::{buckets::BucketName, BotHealth, BotMetrics, Context};
 use crate::util::Authored;
 
 mod interaction;
@@ -27,6 +27,8 @@ enum ProcessResult {
     ),
     NoOwner,
     NoAuthority,
+    Restricted,
+    InvalidArgs,
 }
 
 pub enum EventKind {
@@ -135,6 +137,7 @@ pub async fn event_loop(shards: &mut Vec<Shard>, mut reshard_rx: Receiver<()>) {
                         let change = cache.update(&event).await;
                         BotMetrics::event(&event, change);
                         let shard_id = shard.id().number();
+                        BotHealth::event(shard_id, &event);
 
                         tokio::spawn(async move {
                             if let Err(err) = handle_event(event, shard_id).await {
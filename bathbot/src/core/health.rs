@@ -0,0 +1,392 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, AtomicI64, Ordering},
+        Mutex,
+    },
+};
+
+use time::OffsetDateTime;
+use twilight_gateway::Event;
+
+static HEALTH: HealthState = HealthState::new();
+
+/// Keeps track of when subsystems last worked.
+pub struct BotHealth;
+
+impl BotHealth {
+    /// Update the state of a shard based on its gateway events.
+    pub fn event(shard_id: u64, event: &Event) {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+
+        HEALTH
+            .shards
+            .lock()
+            .unwrap()
+            .entry(shard_id)
+            .or_default()
+            .update(event, now);
+    }
+
+    /// Forget all shards so that only the new shards are reported.
+    pub fn reset_shards() {
+        HEALTH.shards.lock().unwrap().clear();
+    }
+
+    pub fn ordr_event() {
+        HEALTH.ordr.set_now();
+        HEALTH.ordr_failing.store(false, Ordering::Relaxed);
+    }
+
+    pub fn ordr_error() {
+        HEALTH.ordr_failing.store(true, Ordering::Relaxed);
+    }
+
+    #[cfg(feature = "twitchtracking")]
+    pub fn twitch_loop() {
+        HEALTH.twitch_loop.set_now();
+    }
+
+    #[cfg(feature = "matchlive")]
+    pub fn matchlive_loop() {
+        HEALTH.matchlive_loop.set_now();
+    }
+}
+
+#[cfg_attr(not(feature = "server"), allow(dead_code))]
+struct HealthState {
+    shards: Mutex<BTreeMap<u64, ShardHealth>>,
+    postgres: LastSuccess,
+    redis: LastSuccess,
+    osu_api: LastSuccess,
+    /// Unix timestamp of the last osu!api check
+    osu_api_checked: AtomicI64,
+    osu_api_up: AtomicBool,
+    ordr: LastSuccess,
+    ordr_failing: AtomicBool,
+    #[cfg(feature = "twitchtracking")]
+    twitch_loop: LastSuccess,
+    #[cfg(feature = "matchlive")]
+    matchlive_loop: LastSuccess,
+}
+
+impl HealthState {
+    const fn new() -> Self {
+        Self {
+            shards: Mutex::new(BTreeMap::new()),
+            postgres: LastSuccess::new(),
+            redis: LastSuccess::new(),
+            osu_api: LastSuccess::new(),
+            osu_api_checked: AtomicI64::new(0),
+            osu_api_up: AtomicBool::new(false),
+            ordr: LastSuccess::new(),
+            ordr_failing: AtomicBool::new(false),
+            #[cfg(feature = "twitchtracking")]
+            twitch_loop: LastSuccess::new(),
+            #[cfg(feature = "matchlive")]
+            matchlive_loop: LastSuccess::new(),
+        }
+    }
+}
+
+#[derive(Default)]
+#[cfg_attr(not(feature = "server"), allow(dead_code))]
+struct ShardHealth {
+    connected: bool,
+    /// Unix timestamp
+    last_success: i64,
+}
+
+impl ShardHealth {
+    /// Seconds after which a shard without heartbeat ack is considered down.
+    #[cfg_attr(not(feature = "server"), allow(dead_code))]
+    const TIMEOUT: i64 = 120;
+
+    fn update(&mut self, event: &Event, now: i64) {
+        match event {
+            Event::Ready(_) | Event::Resumed => {
+                self.connected = true;
+                self.last_success = now;
+            }
+            Event::GatewayHeartbeatAck if self.connected => self.last_success = now,
+            Event::GatewayClose(_)
+            | Event::GatewayReconnect
+            | Event::GatewayInvalidateSession(_) => self.connected = false,
+            _ => {}
+        }
+    }
+
+    #[cfg_attr(not(feature = "server"), allow(dead_code))]
+    fn is_up(&self, now: i64) -> bool {
+        self.connected && now - self.last_success <= Self::TIMEOUT
+    }
+}
+
+/// Unix timestamp, zero if there was no success yet.
+struct LastSuccess(AtomicI64);
+
+impl LastSuccess {
+    const fn new() -> Self {
+        Self(AtomicI64::new(0))
+    }
+
+    fn set_now(&self) {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        self.0.store(now, Ordering::Relaxed);
+    }
+
+    #[cfg_attr(not(feature = "server"), allow(dead_code))]
+    fn get(&self) -> Option<i64> {
+        let timestamp = self.0.load(Ordering::Relaxed);
+
+        (timestamp > 0).then_some(timestamp)
+    }
+}
+
+#[cfg(feature = "server")]
+mod provider {
+    use std::{future::Future, sync::atomic::Ordering, time::Duration};
+
+    use bathbot_server::health::{ComponentHealth, ComponentStatus, HealthProvider};
+    use eyre::Result;
+    use futures::future::BoxFuture;
+    use time::OffsetDateTime;
+    use tokio::time::timeout;
+
+    use super::{LastSuccess, HEALTH};
+    use crate::core::Context;
+
+    const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Seconds for which the result of an osu!api check is reused.
+    const OSU_API_CHECK_INTERVAL: i64 = 60;
+
+    pub struct BotHealthProvider;
+
+    impl HealthProvider for BotHealthProvider {
+        fn is_ready(&self) -> bool {
+            Context::is_initialized()
+        }
+
+        fn components(&self) -> BoxFuture<'_, Vec<ComponentHealth>> {
+            Box::pin(components())
+        }
+    }
+
+    async fn components() -> Vec<ComponentHealth> {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let mut components = shards(now);
+
+        let postgres_fut = ping("postgres", &HEALTH.postgres, Context::psql().ping());
+        let redis_fut = ping("redis", &HEALTH.redis, Context::cache().ping());
+
+        let (postgres, redis, osu_api) = tokio::join!(postgres_fut, redis_fut, osu_api(now));
+        components.push(postgres.critical());
+        components.push(redis.critical());
+        components.push(osu_api);
+
+        components.push(ordr());
+
+        #[cfg(feature = "osutracking")]
+        components.push(osu_tracking(now).await);
+
+        #[cfg(feature = "twitchtracking")]
+        components.push(loop_health(
+            "twitch_loop",
+            &HEALTH.twitch_loop,
+            now,
+            30 * 60,
+        ));
+
+        #[cfg(feature = "matchlive")]
+        components.push(loop_health(
+            "matchlive_loop",
+            &HEALTH.matchlive_loop,
+            now,
+            60,
+        ));
+
+        components
+    }
+
+    fn shards(now: i64) -> Vec<ComponentHealth> {
+        let shards = HEALTH.shards.lock().unwrap();
+
+        if shards.is_empty() {
+            let component = ComponentHealth::new("shards", ComponentStatus::Down)
+                .critical()
+                .details("No shard connected yet");
+
+            return vec![component];
+        }
+
+        shards
+            .iter()
+            .map(|(shard_id, shard)| {
+                let status = if shard.is_up(now) {
+                    ComponentStatus::Up
+                } else {
+                    ComponentStatus::Down
+                };
+
+                let last_success = (shard.last_success > 0).then_some(shard.last_success);
+
+                ComponentHealth::new(format!("shard_{shard_id}"), status)
+                    .last_success(last_success)
+                    .critical()
+            })
+            .collect()
+    }
+
+    async fn ping(
+        name: &'static str,
+        last_success: &LastSuccess,
+        fut: impl Future<Output = Result<()>>,
+    ) -> ComponentHealth {
+        let component = match timeout(CHECK_TIMEOUT, fut).await {
+            Ok(Ok(_)) => {
+                last_success.set_now();
+
+                ComponentHealth::new(name, ComponentStatus::Up)
+            }
+            Ok(Err(err)) => {
+                warn!(?err, "Health check of {name} failed");
+
+                ComponentHealth::new(name, ComponentStatus::Down).details(format!("{err}"))
+            }
+            Err(_) => ComponentHealth::new(name, ComponentStatus::Down).details("Timed out"),
+        };
+
+        component.last_success(last_success.get())
+    }
+
+    /// Requesting a mapset requires a valid token.
+    async fn osu_api(now: i64) -> ComponentHealth {
+        let checked = HEALTH.osu_api_checked.load(Ordering::Relaxed);
+
+        if now - checked > OSU_API_CHECK_INTERVAL {
+            HEALTH.osu_api_checked.store(now, Ordering::Relaxed);
+
+            let up = match timeout(CHECK_TIMEOUT, Context::osu().beatmapset(1)).await {
+                Ok(Ok(_)) => {
+                    HEALTH.osu_api.set_now();
+
+                    true
+                }
+                Ok(Err(err)) => {
+                    warn!(?err, "Health check of osu!api failed");
+
+                    false
+                }
+                Err(_) => false,
+            };
+
+            HEALTH.osu_api_up.store(up, Ordering::Relaxed);
+        }
+
+        let status = if HEALTH.osu_api_up.load(Ordering::Relaxed) {
+            ComponentStatus::Up
+        } else {
+            ComponentStatus::Down
+        };
+
+        ComponentHealth::new("osu_api", status).last_success(HEALTH.osu_api.get())
+    }
+
+    fn ordr() -> ComponentHealth {
+        if Context::ordr().is_none() {
+            return ComponentHealth::new("ordr", ComponentStatus::Down)
+                .details("Failed to connect on startup");
+        }
+
+        let status = if HEALTH.ordr_failing.load(Ordering::Relaxed) {
+            ComponentStatus::Down
+        } else {
+            ComponentStatus::Up
+        };
+
+        ComponentHealth::new("ordr", status).last_success(HEALTH.ordr.get())
+    }
+
+    #[cfg(feature = "osutracking")]
+    async fn osu_tracking(now: i64) -> ComponentHealth {
+        let tracking = Context::tracking();
+
+        if tracking.stop_tracking() {
+            return ComponentHealth::new("osu_tracking", ComponentStatus::Disabled);
+        }
+
+        let stats = tracking.stats().await;
+        let last_pop = stats.last_pop.unix_timestamp();
+
+        // Every entry should be popped once per interval
+        let status = if stats.queue == 0 || now - last_pop <= stats.interval {
+            ComponentStatus::Up
+        } else {
+            ComponentStatus::Down
+        };
+
+        ComponentHealth::new("osu_tracking", status).last_success(Some(last_pop))
+    }
+
+    /// A loop is considered down if it didn't finish an iteration in
+    /// `max_delay` seconds.
+    #[cfg(any(feature = "twitchtracking", feature = "matchlive"))]
+    fn loop_health(
+        name: &'static str,
+        last_success: &LastSuccess,
+        now: i64,
+        max_delay: i64,
+    ) -> ComponentHealth {
+        let last_success = last_success.get();
+
+        let status = match last_success {
+            Some(timestamp) if now - timestamp <= max_delay => ComponentStatus::Up,
+            _ => ComponentStatus::Down,
+        };
+
+        ComponentHealth::new(name, status).last_success(last_success)
+    }
+}
+
+#[cfg(feature = "server")]
+pub use self::provider::BotHealthProvider;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shard_stays_up_while_heartbeats_arrive() {
+        let mut shard = ShardHealth::default();
+        assert!(!shard.is_up(0));
+
+        shard.update(&Event::Resumed, 1000);
+
+        for now in (1000..2000).step_by(40) {
+            shard.update(&Event::GatewayHeartbeatAck, now);
+            assert!(shard.is_up(now + 39));
+        }
+    }
+
+    #[test]
+    fn shard_goes_down_without_heartbeats() {
+        let mut shard = ShardHealth::default();
+        shard.update(&Event::Resumed, 1000);
+        shard.update(&Event::GatewayHeartbeatAck, 1040);
+
+        assert!(shard.is_up(1040 + ShardHealth::TIMEOUT));
+        assert!(!shard.is_up(1041 + ShardHealth::TIMEOUT));
+    }
+
+    #[test]
+    fn disconnected_shard_ignores_heartbeats() {
+        let mut shard = ShardHealth::default();
+        shard.update(&Event::Resumed, 1000);
+        shard.update(&Event::GatewayReconnect, 1010);
+        shard.update(&Event::GatewayHeartbeatAck, 1020);
+
+        assert!(!shard.is_up(1020));
+        assert_eq!(shard.last_success, 1000);
+    }
+}
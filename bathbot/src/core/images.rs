@@ -0,0 +1,160 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bathbot_server::images::{ImageProvider, RenderQueueFull};
+use eyre::{Report, Result, WrapErr};
+use futures::future::BoxFuture;
+use rosu_v2::prelude::{GameMode, OsuError};
+use tokio::sync::Semaphore;
+
+use crate::{
+    commands::osu::{draw_card, draw_rank_graph, CardError},
+    core::Context,
+    manager::redis::osu::UserArgs,
+};
+
+/// Seconds for which rendered images are kept in redis.
+const IMAGE_EXPIRE: u64 = 3600;
+
+/// Seconds for which it's remembered that an image could not be rendered
+/// because the user was not found.
+const NOT_FOUND_EXPIRE: u64 = 300;
+
+/// Limits concurrent rendering since the endpoints don't require
+/// authentication.
+static RENDER_PERMITS: Semaphore = Semaphore::const_new(4);
+
+/// Maximum amount of requests waiting for a render permit. Further requests
+/// are rejected right away instead of piling up.
+const MAX_QUEUED_RENDERS: usize = 16;
+
+static QUEUED_RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+/// Renders the images served by the server.
+pub struct BotImageProvider;
+
+impl ImageProvider for BotImageProvider {
+    fn is_ready(&self) -> bool {
+        Context::is_initialized()
+    }
+
+    fn card(&self, user_id: u32, mode: GameMode) -> BoxFuture<'_, Result<Option<Vec<u8>>>> {
+        Box::pin(cached_image(ImageKind::Card, user_id, mode))
+    }
+
+    fn rank_graph(&self, user_id: u32, mode: GameMode) -> BoxFuture<'_, Result<Option<Vec<u8>>>> {
+        Box::pin(cached_image(ImageKind::RankGraph, user_id, mode))
+    }
+}
+
+#[derive(Copy, Clone)]
+enum ImageKind {
+    Card,
+    RankGraph,
+}
+
+impl ImageKind {
+    fn key(self, user_id: u32, mode: GameMode) -> String {
+        let kind = match self {
+            Self::Card => "card",
+            Self::RankGraph => "rank_graph",
+        };
+
+        format!("image_{kind}_{user_id}_{}", mode as u8)
+    }
+}
+
+async fn cached_image(kind: ImageKind, user_id: u32, mode: GameMode) -> Result<Option<Vec<u8>>> {
+    let key = kind.key(user_id, mode);
+    let cache = Context::cache();
+
+    match cache.fetch_raw(&key).await {
+        // Empty bytes mark users that were recently not found
+        Ok(Some(bytes)) if bytes.is_empty() => return Ok(None),
+        Ok(Some(bytes)) => return Ok(Some(bytes)),
+        Ok(None) => {}
+        Err(err) => warn!(?err, "Failed to fetch cached image"),
+    }
+
+    let bytes = {
+        let _permit = match RENDER_PERMITS.try_acquire() {
+            Ok(permit) => permit,
+            Err(_) => {
+                let Some(_slot) = QueueSlot::new() else {
+                    return Err(RenderQueueFull.into());
+                };
+
+                RENDER_PERMITS
+                    .acquire()
+                    .await
+                    .wrap_err("Render semaphore closed")?
+            }
+        };
+
+        match kind {
+            ImageKind::Card => render_card(user_id, mode).await?,
+            ImageKind::RankGraph => render_rank_graph(user_id, mode).await?,
+        }
+    };
+
+    let (value, expire) = match bytes {
+        Some(ref bytes) => (bytes.as_slice(), IMAGE_EXPIRE),
+        None => (&[][..], NOT_FOUND_EXPIRE),
+    };
+
+    if let Err(err) = cache.store_new_raw(&key, value, expire).await {
+        warn!(?err, "Failed to store image");
+    }
+
+    Ok(bytes)
+}
+
+/// A spot in the queue of requests waiting for a render permit.
+///
+/// Frees up the spot when dropped, including when the request is cancelled.
+struct QueueSlot;
+
+impl QueueSlot {
+    /// Returns `None` if the queue is full.
+    fn new() -> Option<Self> {
+        QUEUED_RENDERS
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |queued| {
+                (queued < MAX_QUEUED_RENDERS).then_some(queued + 1)
+            })
+            .ok()
+            .map(|_| Self)
+    }
+}
+
+impl Drop for QueueSlot {
+    fn drop(&mut self) {
+        QUEUED_RENDERS.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+async fn render_card(user_id: u32, mode: GameMode) -> Result<Option<Vec<u8>>> {
+    let user_args = UserArgs::user_id(user_id, mode);
+
+    match draw_card(user_args, mode, false).await {
+        Ok((_, bytes)) => Ok(Some(bytes)),
+        Err(CardError::NoScores | CardError::Osu(OsuError::NotFound)) => Ok(None),
+        Err(CardError::Osu(err)) => Err(Report::new(err).wrap_err("Failed to get user")),
+        Err(
+            CardError::Medals(err)
+            | CardError::Images(err)
+            | CardError::Draw(err)
+            | CardError::Other(err),
+        ) => Err(err),
+    }
+}
+
+async fn render_rank_graph(user_id: u32, mode: GameMode) -> Result<Option<Vec<u8>>> {
+    let user_args = UserArgs::user_id(user_id, mode);
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => return Ok(None),
+        Err(err) => return Err(Report::new(err).wrap_err("Failed to get user")),
+    };
+
+    draw_rank_graph(&user)
+}
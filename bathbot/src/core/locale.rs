@@ -0,0 +1,357 @@
+use std::borrow::Cow;
+
+use bathbot_psql::model::configs::Locale;
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource};
+use once_cell::sync::Lazy;
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+use unic_langid::LanguageIdentifier;
+
+use crate::core::Context;
+
+type Bundle = FluentBundle<FluentResource>;
+
+/// One bundle per locale, indexed by the locale's discriminant.
+///
+/// The catalogs only cover error responses and the descriptions of some slash
+/// commands. Embeds and all other responses are in english.
+static BUNDLES: Lazy<[Bundle; 4]> = Lazy::new(|| Locale::ALL.map(create_bundle));
+
+/// Error messages that are shared across commands.
+///
+/// Passing them to the error responses of a [`CommandOrigin`] or an
+/// [`InteractionCommand`] translates them into the recipient's locale. Plain
+/// strings are sent as is.
+///
+/// [`CommandOrigin`]: crate::core::commands::CommandOrigin
+/// [`InteractionCommand`]: crate::util::interaction::InteractionCommand
+#[derive(Copy, Clone, Debug)]
+pub enum Issue {
+    General,
+    OsuApi,
+    Ordr,
+    Osekai,
+    OsuStatsApi,
+    TwitchApi,
+    ThreadsUnavailable,
+}
+
+impl Issue {
+    #[cfg(test)]
+    const ALL: [Self; 7] = [
+        Self::General,
+        Self::OsuApi,
+        Self::Ordr,
+        Self::Osekai,
+        Self::OsuStatsApi,
+        Self::TwitchApi,
+        Self::ThreadsUnavailable,
+    ];
+
+    fn id(self) -> &'static str {
+        match self {
+            Self::General => "general-issue",
+            Self::OsuApi => "osu-api-issue",
+            Self::Ordr => "ordr-issue",
+            Self::Osekai => "osekai-issue",
+            Self::OsuStatsApi => "osustats-api-issue",
+            Self::TwitchApi => "twitch-api-issue",
+            Self::ThreadsUnavailable => "threads-unavailable",
+        }
+    }
+}
+
+/// Content of an error response.
+pub trait ErrorContent {
+    fn localize(self, locale: Locale) -> String;
+}
+
+impl ErrorContent for Issue {
+    fn localize(self, locale: Locale) -> String {
+        tr(locale, self.id()).into_owned()
+    }
+}
+
+impl ErrorContent for String {
+    fn localize(self, _: Locale) -> String {
+        self
+    }
+}
+
+impl ErrorContent for &String {
+    fn localize(self, _: Locale) -> String {
+        self.clone()
+    }
+}
+
+impl ErrorContent for &str {
+    fn localize(self, _: Locale) -> String {
+        self.to_owned()
+    }
+}
+
+impl ErrorContent for Cow<'_, str> {
+    fn localize(self, _: Locale) -> String {
+        self.into_owned()
+    }
+}
+
+impl ErrorContent for Box<str> {
+    fn localize(self, _: Locale) -> String {
+        self.into_string()
+    }
+}
+
+fn source(locale: Locale) -> &'static str {
+    match locale {
+        Locale::English => include_str!("../../locales/en.ftl"),
+        Locale::German => include_str!("../../locales/de.ftl"),
+        Locale::Polish => include_str!("../../locales/pl.ftl"),
+        Locale::Portuguese => include_str!("../../locales/pt-BR.ftl"),
+    }
+}
+
+fn create_bundle(locale: Locale) -> Bundle {
+    let source = source(locale);
+
+    let lang: LanguageIdentifier = locale
+        .discord_code()
+        .parse()
+        .expect("invalid language identifier");
+
+    let resource = FluentResource::try_new(source.to_owned())
+        .unwrap_or_else(|(_, errors)| panic!("invalid fluent resource for {lang}: {errors:?}"));
+
+    let mut bundle = FluentBundle::new_concurrent(vec![lang]);
+
+    // Isolation characters would show up in discord
+    bundle.set_use_isolating(false);
+
+    if let Err(errors) = bundle.add_resource(resource) {
+        panic!("duplicate fluent messages for {locale:?}: {errors:?}");
+    }
+
+    bundle
+}
+
+fn format(locale: Locale, id: &str, args: Option<&FluentArgs<'_>>) -> Option<Cow<'static, str>> {
+    let bundle = &BUNDLES[locale as usize];
+    let pattern = bundle.get_message(id)?.value()?;
+
+    let mut errors = Vec::new();
+    let content = bundle.format_pattern(pattern, args, &mut errors);
+
+    if !errors.is_empty() {
+        warn!(id, ?locale, ?errors, "Failed to format message");
+    }
+
+    Some(content)
+}
+
+/// Translate the message with the given id.
+///
+/// Falls back to english if the locale does not contain the message and to
+/// the id itself if not even english contains it.
+pub fn tr(locale: Locale, id: &'static str) -> Cow<'static, str> {
+    tr_args(locale, id, None)
+}
+
+/// Same as [`tr`] but with arguments for the message's placeables.
+pub fn tr_args(
+    locale: Locale,
+    id: &'static str,
+    args: Option<&FluentArgs<'_>>,
+) -> Cow<'static, str> {
+    format(locale, id, args)
+        .or_else(|| format(Locale::English, id, args))
+        .unwrap_or_else(|| {
+            warn!(id, "Missing message");
+
+            Cow::Borrowed(id)
+        })
+}
+
+/// Translation of a slash command's description.
+///
+/// The id consists of `cmd` followed by the names of the command and its
+/// subcommands or options, separated by `-` e.g. `cmd-link-mode-account`.
+///
+/// Only some commands are translated so far, namely `config`, `serverconfig`,
+/// `link`, and `privacy`. All others, as well as anything without a
+/// translation, keep their english description.
+pub fn command_description(locale: Locale, id: &str) -> Option<String> {
+    format(locale, id, None).map(Cow::into_owned)
+}
+
+/// Translation of a slash command's name, stored as the `.name` attribute of
+/// its description message.
+pub fn command_name(locale: Locale, id: &str) -> Option<String> {
+    let bundle = &BUNDLES[locale as usize];
+    let pattern = bundle.get_message(id)?.get_attribute("name")?.value();
+
+    let mut errors = Vec::new();
+    let name = bundle.format_pattern(pattern, None, &mut errors);
+
+    if !errors.is_empty() {
+        warn!(id, ?locale, ?errors, "Failed to format command name");
+    }
+
+    Some(name.into_owned())
+}
+
+/// Determine the locale for a user.
+///
+/// The user's own config takes precedence over the guild's config which in
+/// turn takes precedence over the given fallback i.e. the locale of the
+/// user's discord client.
+pub async fn resolve(
+    user: Id<UserMarker>,
+    guild: Option<Id<GuildMarker>>,
+    fallback: Option<Locale>,
+) -> Locale {
+    match Context::user_config().locale(user).await {
+        Ok(Some(locale)) => return locale,
+        Ok(None) => {}
+        Err(err) => warn!(?err, "Failed to get user locale"),
+    }
+
+    if let Some(guild) = guild {
+        let guild_locale = Context::guild_config()
+            .peek(guild, |config| config.locale)
+            .await;
+
+        if let Some(locale) = guild_locale {
+            return locale;
+        }
+    }
+
+    fallback.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, fs, path::Path};
+
+    use twilight_model::application::command::CommandOption;
+
+    use super::*;
+    use crate::core::commands::interaction::InteractionCommands;
+
+    /// Ids of all messages in the locale's catalog
+    fn message_ids(locale: Locale) -> HashSet<&'static str> {
+        source(locale)
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_lowercase()))
+            .filter_map(|line| line.split_once(" =").map(|(id, _)| id.trim()))
+            .collect()
+    }
+
+    /// Ids of all messages that are passed as literal to [`tr`] or
+    /// [`tr_args`] within the given directory.
+    fn used_ids(dir: &Path, ids: &mut HashSet<String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+
+            if path.is_dir() {
+                used_ids(&path, ids);
+
+                continue;
+            } else if path.extension().map_or(true, |ext| ext != "rs")
+                || path.ends_with("core/locale.rs")
+            {
+                continue;
+            }
+
+            let content = fs::read_to_string(&path).unwrap();
+
+            for call in ["tr(", "tr_args("] {
+                for (idx, _) in content.match_indices(call) {
+                    let is_ident = content[..idx]
+                        .chars()
+                        .next_back()
+                        .is_some_and(|c| c.is_alphanumeric() || c == '_');
+
+                    if is_ident {
+                        continue;
+                    }
+
+                    let rest = &content[idx + call.len()..];
+                    let stmt = rest.split(';').next().unwrap_or(rest);
+
+                    if let Some(id) = stmt.split('"').nth(1) {
+                        ids.insert(id.to_owned());
+                    }
+                }
+            }
+        }
+    }
+
+    fn command_ids(parent_id: &str, options: &[CommandOption], ids: &mut HashSet<String>) {
+        for option in options {
+            let id = format!("{parent_id}-{}", option.name);
+            command_ids(&id, option.options.as_deref().unwrap_or_default(), ids);
+            ids.insert(id);
+        }
+    }
+
+    #[test]
+    fn catalogs_parse() {
+        for locale in Locale::ALL {
+            create_bundle(locale);
+        }
+    }
+
+    #[test]
+    fn used_messages_exist() {
+        let mut ids = HashSet::new();
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        used_ids(&src, &mut ids);
+
+        assert!(ids.contains("require-link"), "no message ids found");
+
+        ids.extend(Issue::ALL.map(|issue| issue.id().to_owned()));
+
+        for locale in Locale::ALL {
+            let bundle = create_bundle(locale);
+
+            for id in ids.iter() {
+                assert!(bundle.has_message(id), "`{id}` is missing for {locale:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn command_messages_exist() {
+        let mut valid = HashSet::new();
+
+        for cmd in InteractionCommands::get().collect() {
+            let id = format!("cmd-{}", cmd.name);
+            command_ids(&id, &cmd.options, &mut valid);
+            valid.insert(id);
+        }
+
+        let translated: HashSet<_> = message_ids(Locale::German)
+            .into_iter()
+            .filter(|id| id.starts_with("cmd-"))
+            .collect();
+
+        for locale in Locale::ALL {
+            if locale == Locale::English {
+                continue;
+            }
+
+            let ids: HashSet<_> = message_ids(locale)
+                .into_iter()
+                .filter(|id| id.starts_with("cmd-"))
+                .collect();
+
+            for id in ids.iter() {
+                assert!(valid.contains(*id), "`{id}` of {locale:?} is no command");
+            }
+
+            assert_eq!(ids, translated, "command messages differ for {locale:?}");
+        }
+    }
+}
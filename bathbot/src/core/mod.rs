@@ -1,15 +1,28 @@
 pub use self::{
-    config::BotConfig,
+    config::{BotConfig, CacheConfig},
     context::Context,
     events::{event_loop, EventKind},
+    health::BotHealth,
     metrics::BotMetrics,
 };
 
+#[cfg(test)]
+pub use self::context::fake;
+
 mod config;
 mod context;
 mod events;
+mod health;
 mod metrics;
 
+#[cfg(feature = "server")]
+mod api;
+#[cfg(feature = "server")]
+mod dashboard;
+#[cfg(feature = "server")]
+mod images;
+
 pub mod buckets;
 pub mod commands;
+pub mod locale;
 pub mod logging;
@@ -1,4 +1,5 @@
-use bathbot_util::EmbedBuilder;
+use bathbot_util::{EmbedBuilder, FooterBuilder};
+use time::OffsetDateTime;
 
 #[cfg(feature = "osutracking")]
 pub use self::tracking::*;
@@ -29,3 +30,22 @@ pub fn attachment(filename: impl AsRef<str>) -> String {
 
     format!("attachment://{filename}")
 }
+
+/// Sets the footer and, for data based on a periodically refreshed
+/// materialized view, adds a "Data as of" note with the refresh time as
+/// timestamp.
+pub fn footer_data_as_of(
+    embed: EmbedBuilder,
+    mut footer_text: String,
+    refreshed_at: Option<OffsetDateTime>,
+) -> EmbedBuilder {
+    let Some(refreshed_at) = refreshed_at else {
+        return embed.footer(FooterBuilder::new(footer_text));
+    };
+
+    footer_text.push_str(" • Data as of");
+
+    embed
+        .footer(FooterBuilder::new(footer_text))
+        .timestamp(refreshed_at)
+}
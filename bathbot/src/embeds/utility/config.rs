@@ -1,7 +1,7 @@
 use std::fmt::{Display, Write};
 
 use ::time::UtcOffset;
-use bathbot_psql::model::configs::{ListSize, OsuUsername, Retries, ScoreData, UserConfig};
+use bathbot_psql::model::configs::{ListSize, Locale, OsuUsername, Retries, ScoreData, UserConfig};
 use bathbot_util::{AuthorBuilder, EmbedBuilder, FooterBuilder};
 use rosu_v2::prelude::GameMode;
 use twilight_model::{channel::message::embed::EmbedField, user::User};
@@ -108,6 +108,17 @@ impl ConfigEmbed {
                     (Retries::IgnoreMods, "ignore mods"),
                 ],
             ),
+            create_field(
+                "Language",
+                config.locale,
+                &[
+                    (None, "auto"),
+                    (Some(Locale::English), "english"),
+                    (Some(Locale::German), "deutsch"),
+                    (Some(Locale::Polish), "polski"),
+                    (Some(Locale::Portuguese), "português"),
+                ],
+            ),
         ];
 
         if let Some(skin_url) = skin_url {
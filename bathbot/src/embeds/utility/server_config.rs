@@ -3,7 +3,9 @@ use std::fmt::Write;
 use bathbot_cache::model::CachedArchive;
 use bathbot_macros::EmbedData;
 use bathbot_model::twilight_model::guild::Guild;
-use bathbot_psql::model::configs::{GuildConfig, HideSolutions, ListSize, Retries, ScoreData};
+use bathbot_psql::model::configs::{
+    GuildConfig, HideSolutions, ListSize, Locale, Retries, ScoreData,
+};
 use bathbot_util::AuthorBuilder;
 use twilight_model::channel::message::embed::EmbedField;
 
@@ -125,6 +127,17 @@ impl ServerConfigEmbed {
                     (Retries::IgnoreMods, "ignore mods"),
                 ],
             ),
+            create_field(
+                "Language*",
+                config.locale,
+                &[
+                    (None, "auto"),
+                    (Some(Locale::English), "english"),
+                    (Some(Locale::German), "deutsch"),
+                    (Some(Locale::Polish), "polski"),
+                    (Some(Locale::Portuguese), "português"),
+                ],
+            ),
         ];
 
         Self {
@@ -9,6 +9,7 @@ mod commands;
 mod core;
 mod embeds;
 mod manager;
+mod scheduler;
 mod tracking;
 mod util;
 
@@ -109,6 +110,12 @@ async fn async_main() -> Result<()> {
         tokio::spawn(Context::match_live_loop());
     }
 
+    // Spawn scheduled jobs worker
+    tokio::spawn(scheduler::scheduled_jobs_loop());
+
+    // Spawn alias names refresher
+    tokio::spawn(Context::alias_names_loop());
+
     // Request members
     tokio::spawn(async move {
         let ctx = Context::get();
@@ -1,4 +1,7 @@
-use bathbot_psql::{model::osu::MapBookmark, Database};
+use bathbot_psql::{
+    model::osu::{BookmarkFolder, MapBookmark},
+    Database,
+};
 use eyre::{Result, WrapErr};
 use twilight_model::id::{marker::UserMarker, Id};
 
@@ -36,4 +39,47 @@ impl BookmarkManager {
             .await
             .wrap_err("Failed to delete user bookmark")
     }
+
+    pub async fn folders(self, user: Id<UserMarker>) -> Result<Vec<BookmarkFolder>> {
+        self.psql
+            .select_user_bookmark_folders(user)
+            .await
+            .wrap_err("Failed to get bookmark folders")
+    }
+
+    pub async fn set_note(
+        self,
+        user: Id<UserMarker>,
+        map_id: u32,
+        note: Option<&str>,
+    ) -> Result<()> {
+        self.psql
+            .update_user_bookmark_note(user, map_id, note)
+            .await
+            .wrap_err("Failed to update bookmark note")
+    }
+
+    pub async fn set_folder(
+        self,
+        user: Id<UserMarker>,
+        map_id: u32,
+        folder: Option<&str>,
+    ) -> Result<()> {
+        self.psql
+            .update_user_bookmark_folder(user, map_id, folder)
+            .await
+            .wrap_err("Failed to update bookmark folder")
+    }
+
+    pub async fn set_folder_public(
+        self,
+        user: Id<UserMarker>,
+        folder_id: u32,
+        public: bool,
+    ) -> Result<()> {
+        self.psql
+            .update_user_bookmark_folder_visibility(user, folder_id, public)
+            .await
+            .wrap_err("Failed to update bookmark folder visibility")
+    }
 }
@@ -1,3 +1,4 @@
+use bathbot_model::embed_builder::ScoreEmbedSettings;
 use bathbot_psql::{
     model::configs::{GuildConfig, Prefix, DEFAULT_PREFIX},
     Database,
@@ -5,21 +6,32 @@ use bathbot_psql::{
 use bathbot_util::IntHasher;
 use eyre::{Result, WrapErr};
 use papaya::HashMap as PapayaMap;
-use twilight_model::id::{marker::GuildMarker, Id};
+use rosu_v2::prelude::GameMode;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
 
 type GuildConfigs = PapayaMap<Id<GuildMarker>, GuildConfig, IntHasher>;
+type ScoreEmbedChannels = PapayaMap<Id<ChannelMarker>, Id<GuildMarker>, IntHasher>;
 
 #[derive(Copy, Clone)]
 pub struct GuildConfigManager {
     psql: &'static Database,
     guild_configs: &'static GuildConfigs,
+    score_embed_channels: &'static ScoreEmbedChannels,
 }
 
 impl GuildConfigManager {
-    pub fn new(psql: &'static Database, guild_configs: &'static GuildConfigs) -> Self {
+    pub fn new(
+        psql: &'static Database,
+        guild_configs: &'static GuildConfigs,
+        score_embed_channels: &'static ScoreEmbedChannels,
+    ) -> Self {
         Self {
             psql,
             guild_configs,
+            score_embed_channels,
         }
     }
 
@@ -55,6 +67,60 @@ impl GuildConfigManager {
         prefix_opt.unwrap_or_else(|| DEFAULT_PREFIX.into())
     }
 
+    /// The default mode that was configured for the channel, if any.
+    pub async fn channel_mode(
+        self,
+        guild_id: Option<Id<GuildMarker>>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Option<GameMode> {
+        let guild_id = guild_id?;
+
+        self.peek(guild_id, |config| {
+            config.command_rules.channel_mode(channel_id)
+        })
+        .await
+    }
+
+    /// The score embed settings to use in a channel.
+    ///
+    /// In channels for which the guild's settings override the member's
+    /// settings, the guild's settings will be used. Otherwise, the guild's
+    /// settings are only used if the member has none.
+    pub async fn score_embed(
+        self,
+        guild_id: Option<Id<GuildMarker>>,
+        channel_id: Id<ChannelMarker>,
+        user_settings: Option<ScoreEmbedSettings>,
+    ) -> Option<ScoreEmbedSettings> {
+        let Some(guild_id) = guild_id else {
+            return user_settings;
+        };
+
+        self.peek(guild_id, |config| {
+            let guild_settings = config.score_embed.as_ref();
+
+            if config.score_embed_channels.contains(&channel_id) {
+                guild_settings.cloned().or(user_settings)
+            } else {
+                user_settings.or_else(|| guild_settings.cloned())
+            }
+        })
+        .await
+    }
+
+    /// The guild's score embed settings if they override settings in the
+    /// given channel.
+    ///
+    /// Useful if the channel's guild is unknown.
+    pub fn channel_score_embed(self, channel_id: Id<ChannelMarker>) -> Option<ScoreEmbedSettings> {
+        let guild_id = *self.score_embed_channels.pin().get(&channel_id)?;
+
+        self.guild_configs
+            .pin()
+            .get(&guild_id)
+            .and_then(|config| config.score_embed.clone())
+    }
+
     pub async fn update<F, O>(self, guild_id: Id<GuildMarker>, f: F) -> Result<O>
     where
         F: FnOnce(&mut GuildConfig) -> O,
@@ -77,6 +143,18 @@ impl GuildConfigManager {
             .await
             .wrap_err("failed to store guild config");
 
+        let channels = self.score_embed_channels.pin();
+
+        if let Some(prev) = self.guild_configs.pin().get(&guild_id) {
+            for channel_id in prev.score_embed_channels.iter() {
+                channels.remove(channel_id);
+            }
+        }
+
+        for channel_id in config.score_embed_channels.iter() {
+            channels.insert(*channel_id, guild_id);
+        }
+
         self.guild_configs.pin().insert(guild_id, config);
 
         res
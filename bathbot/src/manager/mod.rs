@@ -1,3 +1,5 @@
+#[cfg(feature = "server")]
+pub use self::osu_token::{OsuTokenManager, OsuUserAccess};
 #[cfg(feature = "osutracking")]
 pub use self::osu_tracking::OsuTrackingManager;
 #[cfg(feature = "twitch")]
@@ -12,9 +14,14 @@ pub use self::{
     osu_scores::ScoresManager,
     osu_user::OsuUserManager,
     pp::{Mods, PpManager},
+    pp_recalc::{PpRecalcManager, PpRecalcState, RecalcStage, StageProgress},
     rank_pp_approx::ApproxManager,
     replay::{OwnedReplayScore, ReplayManager, ReplayScore, ReplaySettings},
     user_config::UserConfigManager,
+    webhooks::{
+        MatchLivePayload, OsuScorePayload, TwitchLivePayload, WebhookEvents, WebhookManager,
+        WebhookPayload, MAX_WEBHOOKS,
+    },
 };
 
 pub mod redis;
@@ -28,9 +35,14 @@ mod osu_map;
 mod osu_scores;
 mod osu_user;
 mod pp;
+mod pp_recalc;
 mod rank_pp_approx;
 mod replay;
 mod user_config;
+mod webhooks;
+
+#[cfg(feature = "server")]
+mod osu_token;
 
 #[cfg(feature = "osutracking")]
 mod osu_tracking;
@@ -1,7 +1,10 @@
 use std::{collections::HashMap, fmt::Debug, io::Error as IoError, ops::Deref, path::PathBuf};
 
 use bathbot_client::ClientError;
-use bathbot_psql::model::osu::{ArtistTitle, DbBeatmap, DbBeatmapset, DbMapFilename, MapVersion};
+use bathbot_psql::model::osu::{
+    ArtistTitle, DbBeatmap, DbBeatmapset, DbMapFilename, MapVersion, MapsetSearchEntry,
+    MapsetSearchParams,
+};
 use bathbot_util::{ExponentialBackoff, IntHasher};
 use eyre::{ContextCompat, Report, WrapErr};
 use rosu_pp::{any::DifficultyAttributes, model::beatmap::BeatmapAttributesBuilder, Beatmap};
@@ -243,6 +246,16 @@ impl MapManager {
         Ok(versions)
     }
 
+    /// Search through all mapsets stored in the database.
+    pub async fn search(self, params: &MapsetSearchParams<'_>) -> Result<Vec<MapsetSearchEntry>> {
+        let entries = Context::psql()
+            .search_mapsets(params)
+            .await
+            .wrap_err("Failed to search mapsets")?;
+
+        Ok(entries)
+    }
+
     pub async fn store(&self, mapset: &BeatmapsetExtended) {
         if let Err(err) = Context::psql().upsert_beatmapset(mapset).await {
             warn!(?err, "Failed to store mapset");
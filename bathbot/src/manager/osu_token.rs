@@ -0,0 +1,390 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::{Arc, Mutex as StdMutex},
+};
+
+use bathbot_client::ClientError;
+use bathbot_model::OsuUserToken;
+use bathbot_psql::{model::osu_tokens::DbOsuUserToken, Database};
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use eyre::{Report, Result, WrapErr};
+use once_cell::sync::Lazy;
+use time::{Duration, OffsetDateTime};
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::core::{BotConfig, Context};
+
+/// Tokens that expire within this many seconds are refreshed beforehand.
+const REFRESH_MARGIN: i64 = 60;
+
+const NONCE_LEN: usize = 12;
+
+/// osu! invalidates a refresh token once it's used so concurrent refreshes of
+/// the same token must not happen.
+///
+/// Entries are removed once no task holds or awaits their lock.
+static REFRESH_LOCKS: Lazy<StdMutex<HashMap<Id<UserMarker>, RefreshLock>>> =
+    Lazy::new(Default::default);
+
+/// Valid access token of a user's osu! account.
+pub struct OsuUserAccess {
+    pub osu_id: u32,
+    pub access_token: String,
+}
+
+#[derive(Copy, Clone)]
+pub struct OsuTokenManager {
+    psql: &'static Database,
+}
+
+impl OsuTokenManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    /// Encrypt and store the token of the user's osu! account, replacing any
+    /// previous token.
+    pub async fn store(
+        self,
+        user: Id<UserMarker>,
+        osu_id: u32,
+        token: &OsuUserToken,
+    ) -> Result<()> {
+        let access_token = encrypt(user, &token.access_token)?;
+        let refresh_token = encrypt(user, &token.refresh_token)?;
+        let expires_at = OffsetDateTime::now_utc() + Duration::seconds(token.expires_in);
+
+        self.psql
+            .upsert_osu_user_token(user, osu_id, &access_token, &refresh_token, expires_at)
+            .await
+            .wrap_err("Failed to store osu! user token")
+    }
+
+    /// Returns a valid access token for the user, refreshing it if necessary.
+    ///
+    /// Returns `None` if the user has no token or if the token was revoked in
+    /// which case it's removed.
+    pub async fn access_token(self, user: Id<UserMarker>) -> Result<Option<OsuUserAccess>> {
+        let Some(token) = self.get(user).await? else {
+            return Ok(None);
+        };
+
+        if !needs_refresh(&token) {
+            return access(user, &token).map(Some);
+        }
+
+        let _guard = RefreshGuard::lock(user).await;
+
+        // Another task might have refreshed the token in the meanwhile
+        let Some(token) = self.get(user).await? else {
+            return Ok(None);
+        };
+
+        if !needs_refresh(&token) {
+            return access(user, &token).map(Some);
+        }
+
+        let refresh_token = decrypt(user, &token.refresh_token)?;
+        let osu_id = token.osu_id as u32;
+        let config = BotConfig::get();
+
+        let refresh_fut = Context::client().refresh_osu_token(
+            config.tokens.osu_client_id,
+            &config.tokens.osu_client_secret,
+            &refresh_token,
+        );
+
+        match refresh_fut.await {
+            Ok(token) => {
+                self.store(user, osu_id, &token).await?;
+
+                Ok(Some(OsuUserAccess {
+                    osu_id,
+                    access_token: token.access_token.into_string(),
+                }))
+            }
+            Err(ClientError::BadRequest | ClientError::Unauthorized) => {
+                info!(%user, "osu! user token was revoked, removing it");
+
+                self.psql
+                    .delete_osu_user_token(user)
+                    .await
+                    .wrap_err("Failed to delete osu! user token")?;
+
+                Ok(None)
+            }
+            Err(err) => Err(Report::new(err).wrap_err("Failed to refresh osu! user token")),
+        }
+    }
+
+    /// Remove the user's token and revoke it on osu!'s side.
+    ///
+    /// If `osu_id` is specified, the token is only removed if it belongs to
+    /// that osu! account. Returns whether a token was removed.
+    pub async fn remove(self, user: Id<UserMarker>, osu_id: Option<u32>) -> Result<bool> {
+        let Some(token) = self.get(user).await? else {
+            return Ok(false);
+        };
+
+        if osu_id.is_some_and(|osu_id| osu_id != token.osu_id as u32) {
+            return Ok(false);
+        }
+
+        // Revoking requires a valid access token. An expired one is refreshed
+        // first since its refresh token would otherwise remain usable.
+        let revocable = if needs_refresh(&token) {
+            self.access_token(user).await
+        } else {
+            access(user, &token).map(Some)
+        };
+
+        let removed = self
+            .psql
+            .delete_osu_user_token(user)
+            .await
+            .wrap_err("Failed to delete osu! user token")?;
+
+        match revocable {
+            Ok(Some(access)) => {
+                if let Err(err) = Context::client()
+                    .revoke_osu_token(&access.access_token)
+                    .await
+                {
+                    warn!(?err, "Failed to revoke osu! user token");
+                }
+            }
+            // The token was already revoked and thus removed while refreshing
+            Ok(None) => return Ok(true),
+            Err(err) => warn!(?err, "Failed to get osu! user token for revocation"),
+        }
+
+        Ok(removed)
+    }
+
+    async fn get(self, user: Id<UserMarker>) -> Result<Option<DbOsuUserToken>> {
+        self.psql
+            .select_osu_user_token(user)
+            .await
+            .wrap_err("Failed to get osu! user token")
+    }
+}
+
+fn needs_refresh(token: &DbOsuUserToken) -> bool {
+    token.expires_at - OffsetDateTime::now_utc() < Duration::seconds(REFRESH_MARGIN)
+}
+
+fn access(user: Id<UserMarker>, token: &DbOsuUserToken) -> Result<OsuUserAccess> {
+    Ok(OsuUserAccess {
+        osu_id: token.osu_id as u32,
+        access_token: decrypt(user, &token.access_token)?,
+    })
+}
+
+#[derive(Default)]
+struct RefreshLock {
+    lock: Arc<Mutex<()>>,
+    /// Amount of guards that hold or await the lock
+    guards: usize,
+}
+
+/// Holds the refresh lock of a user.
+struct RefreshGuard {
+    user: Id<UserMarker>,
+    guard: Option<OwnedMutexGuard<()>>,
+}
+
+impl RefreshGuard {
+    async fn lock(user: Id<UserMarker>) -> Self {
+        let lock = {
+            let mut locks = REFRESH_LOCKS.lock().unwrap();
+            let entry = locks.entry(user).or_default();
+            entry.guards += 1;
+
+            Arc::clone(&entry.lock)
+        };
+
+        // Created before awaiting so that the entry is released even if this
+        // future is cancelled while waiting for the lock
+        let mut this = Self { user, guard: None };
+        this.guard = Some(lock.lock_owned().await);
+
+        this
+    }
+}
+
+impl Drop for RefreshGuard {
+    fn drop(&mut self) {
+        let mut locks = REFRESH_LOCKS.lock().unwrap();
+        self.guard.take();
+
+        if let Entry::Occupied(mut entry) = locks.entry(self.user) {
+            entry.get_mut().guards -= 1;
+
+            if entry.get().guards == 0 {
+                entry.remove();
+            }
+        }
+    }
+}
+
+fn cipher() -> ChaCha20Poly1305 {
+    let key = &BotConfig::get().tokens.osu_token_key;
+
+    ChaCha20Poly1305::new(Key::from_slice(key))
+}
+
+fn encrypt(user: Id<UserMarker>, token: &str) -> Result<Vec<u8>> {
+    encrypt_with(&cipher(), user, token)
+}
+
+fn decrypt(user: Id<UserMarker>, bytes: &[u8]) -> Result<String> {
+    decrypt_with(&cipher(), user, bytes)
+}
+
+/// Encrypts the token and prepends the nonce.
+///
+/// The user id is authenticated as associated data so that a token can't be
+/// swapped into another user's row.
+fn encrypt_with(cipher: &ChaCha20Poly1305, user: Id<UserMarker>, token: &str) -> Result<Vec<u8>> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let aad = user.get().to_be_bytes();
+
+    let payload = Payload {
+        msg: token.as_bytes(),
+        aad: &aad,
+    };
+
+    let ciphertext = cipher
+        .encrypt(&nonce, payload)
+        .map_err(|_| eyre!("Failed to encrypt token"))?;
+
+    let mut bytes = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    bytes.extend_from_slice(&nonce);
+    bytes.extend_from_slice(&ciphertext);
+
+    Ok(bytes)
+}
+
+fn decrypt_with(cipher: &ChaCha20Poly1305, user: Id<UserMarker>, bytes: &[u8]) -> Result<String> {
+    if bytes.len() < NONCE_LEN {
+        bail!("Encrypted token is too short");
+    }
+
+    let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+    let aad = user.get().to_be_bytes();
+
+    let payload = Payload {
+        msg: ciphertext,
+        aad: &aad,
+    };
+
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), payload)
+        .map_err(|_| eyre!("Failed to decrypt token"))?;
+
+    String::from_utf8(plaintext).wrap_err("Decrypted token is not valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER: Id<UserMarker> = Id::new(123_456_789);
+
+    fn test_cipher() -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(Key::from_slice(&[7; 32]))
+    }
+
+    #[test]
+    fn round_trip() {
+        let cipher = test_cipher();
+        let encrypted = encrypt_with(&cipher, USER, "some token").unwrap();
+
+        assert_eq!(encrypted.len(), NONCE_LEN + "some token".len() + 16);
+        assert_eq!(
+            decrypt_with(&cipher, USER, &encrypted).unwrap(),
+            "some token"
+        );
+    }
+
+    #[test]
+    fn nonces_differ() {
+        let cipher = test_cipher();
+        let a = encrypt_with(&cipher, USER, "some token").unwrap();
+        let b = encrypt_with(&cipher, USER, "some token").unwrap();
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn rejects_tampered_ciphertext() {
+        let cipher = test_cipher();
+        let mut encrypted = encrypt_with(&cipher, USER, "some token").unwrap();
+        encrypted[NONCE_LEN] ^= 1;
+
+        assert!(decrypt_with(&cipher, USER, &encrypted).is_err());
+    }
+
+    #[test]
+    fn rejects_tampered_nonce() {
+        let cipher = test_cipher();
+        let mut encrypted = encrypt_with(&cipher, USER, "some token").unwrap();
+        encrypted[0] ^= 1;
+
+        assert!(decrypt_with(&cipher, USER, &encrypted).is_err());
+    }
+
+    #[test]
+    fn rejects_other_user() {
+        let cipher = test_cipher();
+        let encrypted = encrypt_with(&cipher, USER, "some token").unwrap();
+
+        assert!(decrypt_with(&cipher, Id::new(1), &encrypted).is_err());
+    }
+
+    #[test]
+    fn rejects_other_key() {
+        let encrypted = encrypt_with(&test_cipher(), USER, "some token").unwrap();
+        let other = ChaCha20Poly1305::new(Key::from_slice(&[8; 32]));
+
+        assert!(decrypt_with(&other, USER, &encrypted).is_err());
+    }
+
+    #[test]
+    fn rejects_short_input() {
+        assert!(decrypt_with(&test_cipher(), USER, &[0; NONCE_LEN - 1]).is_err());
+    }
+
+    #[tokio::test]
+    async fn refresh_locks_are_removed() {
+        let user = Id::new(42);
+
+        {
+            let _guard = RefreshGuard::lock(user).await;
+            assert!(REFRESH_LOCKS.lock().unwrap().contains_key(&user));
+        }
+
+        assert!(!REFRESH_LOCKS.lock().unwrap().contains_key(&user));
+    }
+
+    #[tokio::test]
+    async fn cancelled_refresh_locks_are_removed() {
+        let user = Id::new(43);
+        let guard = RefreshGuard::lock(user).await;
+
+        let waiting = RefreshGuard::lock(user);
+        let timeout = tokio::time::timeout(std::time::Duration::from_millis(10), waiting);
+        assert!(timeout.await.is_err());
+        assert_eq!(REFRESH_LOCKS.lock().unwrap()[&user].guards, 1);
+
+        drop(guard);
+
+        assert!(!REFRESH_LOCKS.lock().unwrap().contains_key(&user));
+    }
+}
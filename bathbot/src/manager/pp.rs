@@ -4,6 +4,7 @@ use std::{
 };
 
 use bathbot_model::{OsuStatsScore, ScoreSlim};
+use bathbot_psql::model::osu::DbScore;
 use eyre::Result;
 use rosu_pp::{
     any::{DifficultyAttributes, PerformanceAttributes, ScoreState},
@@ -136,6 +137,15 @@ impl<'m> PpManager<'m> {
             }
         }
 
+        self.calculate_difficulty().await
+    }
+
+    /// Calculate difficulty attributes without considering stored values.
+    pub async fn recalculate_difficulty(&mut self) -> &DifficultyAttributes {
+        self.calculate_difficulty().await
+    }
+
+    async fn calculate_difficulty(&mut self) -> &DifficultyAttributes {
         let mut calc = Difficulty::new().mods(self.mods.bits);
 
         if let Some(clock_rate) = self.mods.clock_rate {
@@ -293,6 +303,26 @@ impl<'s> From<&'s ScoreEmbedDataRaw> for ScoreData {
     }
 }
 
+impl<'s> From<&'s DbScore> for ScoreData {
+    #[inline]
+    fn from(score: &'s DbScore) -> Self {
+        Self {
+            state: ScoreState {
+                max_combo: score.max_combo,
+                n_geki: score.statistics.count_geki,
+                n_katu: score.statistics.count_katu,
+                n300: score.statistics.count_300,
+                n100: score.statistics.count_100,
+                n50: score.statistics.count_50,
+                misses: score.statistics.count_miss,
+            },
+            mods: Mods::new(score.mods),
+            mode: Some(score.mode),
+            partial: score.grade == Grade::F,
+        }
+    }
+}
+
 /// Mods with an optional custom clock rate.
 #[derive(Copy, Clone, Default, PartialEq)]
 pub struct Mods {
@@ -0,0 +1,471 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use bathbot_psql::{
+    model::osu::{StaleDifficulty, PP_CALC_VERSION},
+    Database,
+};
+use bathbot_util::IntHasher;
+use eyre::{Result, WrapErr};
+use time::OffsetDateTime;
+use tokio::sync::watch;
+
+use super::Mods;
+use crate::core::Context;
+
+/// Shared state of the pp recalculation, stored in the [`Context`].
+pub struct PpRecalcState {
+    running: AtomicBool,
+    paused: watch::Sender<bool>,
+    progress: Mutex<RecalcProgress>,
+}
+
+impl Default for PpRecalcState {
+    fn default() -> Self {
+        Self {
+            running: AtomicBool::new(false),
+            paused: watch::channel(false).0,
+            progress: Mutex::new(RecalcProgress::default()),
+        }
+    }
+}
+
+impl PpRecalcState {
+    /// Marks the recalculation as running and resets the progress.
+    ///
+    /// Returns `false` if a recalculation is already running.
+    fn try_start(&self) -> bool {
+        if self.running.swap(true, Ordering::SeqCst) {
+            return false;
+        }
+
+        self.paused.send_replace(false);
+
+        *self.progress.lock().unwrap() = RecalcProgress {
+            stage: RecalcStage::Counting,
+            started_at: Some(OffsetDateTime::now_utc()),
+            ..Default::default()
+        };
+
+        true
+    }
+
+    fn finish(&self, stage: RecalcStage) {
+        self.progress.lock().unwrap().stage = stage;
+        self.paused.send_replace(false);
+        self.running.store(false, Ordering::SeqCst);
+    }
+
+    fn pause(&self) -> bool {
+        self.running.load(Ordering::SeqCst) && !self.paused.send_replace(true)
+    }
+
+    fn resume(&self) -> bool {
+        self.running.load(Ordering::SeqCst) && self.paused.send_replace(false)
+    }
+
+    fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+}
+
+/// Recalculates stored difficulty attributes and score pp that were computed
+/// by an outdated calculator version.
+#[derive(Copy, Clone)]
+pub struct PpRecalcManager {
+    psql: &'static Database,
+    state: &'static PpRecalcState,
+}
+
+impl PpRecalcManager {
+    pub fn new(psql: &'static Database, state: &'static PpRecalcState) -> Self {
+        Self { psql, state }
+    }
+
+    /// Start the recalculation in the background.
+    ///
+    /// Returns `false` if a recalculation is already running.
+    pub fn start(self, batch_size: usize) -> bool {
+        if !self.state.try_start() {
+            return false;
+        }
+
+        tokio::spawn(self.run(batch_size.max(1)));
+
+        true
+    }
+
+    /// Returns `false` if there is no running recalculation or it is already
+    /// paused.
+    pub fn pause(self) -> bool {
+        self.state.pause()
+    }
+
+    /// Returns `false` if there is no paused recalculation.
+    pub fn resume(self) -> bool {
+        self.state.resume()
+    }
+
+    pub fn is_paused(self) -> bool {
+        self.state.is_paused()
+    }
+
+    pub fn progress(self) -> RecalcProgress {
+        self.state.progress.lock().unwrap().clone()
+    }
+
+    async fn run(self, batch_size: usize) {
+        info!(
+            version = PP_CALC_VERSION,
+            batch_size, "Starting pp recalculation..."
+        );
+
+        let stage = match self.recalculate(batch_size).await {
+            Ok(_) => {
+                info!("Finished pp recalculation");
+
+                RecalcStage::Finished
+            }
+            Err(err) => {
+                error!(?err, "Failed to recalculate pp");
+
+                RecalcStage::Failed
+            }
+        };
+
+        self.state.finish(stage);
+    }
+
+    async fn recalculate(self, batch_size: usize) -> Result<()> {
+        let counts = self
+            .psql
+            .count_stale_pp_rows(PP_CALC_VERSION)
+            .await
+            .wrap_err("Failed to count stale rows")?;
+
+        self.update_progress(|progress| {
+            progress.stage = RecalcStage::Difficulties;
+            progress.difficulties.total = counts.difficulties;
+            progress.scores.total = counts.scores;
+        });
+
+        self.recalculate_difficulties(batch_size).await?;
+        self.update_progress(|progress| progress.stage = RecalcStage::Scores);
+
+        self.recalculate_scores(batch_size).await?;
+        self.update_progress(|progress| progress.stage = RecalcStage::Refreshing);
+
+        self.psql.refresh_user_scores().await
+    }
+
+    async fn recalculate_difficulties(self, batch_size: usize) -> Result<()> {
+        let mut after_map_id = 0;
+
+        loop {
+            self.wait_while_paused().await;
+
+            let start = Instant::now();
+
+            let stale = self
+                .psql
+                .select_stale_difficulties(PP_CALC_VERSION, after_map_id, batch_size)
+                .await
+                .wrap_err("Failed to get stale difficulties")?;
+
+            let Some(last) = stale.last() else {
+                return Ok(());
+            };
+
+            after_map_id = last.map_id;
+            let mut failed = 0;
+
+            for group in MapGroups::new(&stale) {
+                let map_id = group[0].map_id;
+
+                let map = match Context::osu_map().pp_map(map_id).await {
+                    Ok(map) => map,
+                    Err(err) => {
+                        warn!(map_id, ?err, "Failed to prepare map for recalculation");
+                        failed += group.len();
+
+                        continue;
+                    }
+                };
+
+                for entry in group {
+                    Context::pp_parsed(&map, map_id, entry.mode)
+                        .mods(Mods::new(entry.mods))
+                        .recalculate_difficulty()
+                        .await;
+                }
+            }
+
+            let elapsed = start.elapsed();
+
+            let progress = self.update_progress(|progress| {
+                progress.difficulties.advance(stale.len(), elapsed);
+                progress.failed += failed;
+            });
+
+            debug!(
+                done = progress.difficulties.done,
+                total = progress.difficulties.total,
+                "Recalculated difficulty batch"
+            );
+        }
+    }
+
+    async fn recalculate_scores(self, batch_size: usize) -> Result<()> {
+        let mut after_score_id = 0;
+        let mut score_ids = Vec::with_capacity(batch_size);
+        let mut pps = Vec::with_capacity(batch_size);
+
+        loop {
+            self.wait_while_paused().await;
+
+            let start = Instant::now();
+
+            let scores = self
+                .psql
+                .select_stale_scores(PP_CALC_VERSION, after_score_id, batch_size)
+                .await
+                .wrap_err("Failed to get stale scores")?;
+
+            let Some(last) = scores.last() else {
+                return Ok(());
+            };
+
+            after_score_id = last.score_id;
+            score_ids.clear();
+            pps.clear();
+
+            let mut maps = HashMap::with_hasher(IntHasher);
+            let mut failed = 0;
+
+            for score in scores.iter() {
+                let map_opt = match maps.entry(score.map_id) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => match Context::osu_map().pp_map(score.map_id).await {
+                        Ok(map) => entry.insert(Some(map)),
+                        Err(err) => {
+                            warn!(
+                                map_id = score.map_id,
+                                ?err,
+                                "Failed to prepare map for recalculation"
+                            );
+
+                            entry.insert(None)
+                        }
+                    },
+                };
+
+                let Some(map) = map_opt else {
+                    failed += 1;
+
+                    continue;
+                };
+
+                let attrs = Context::pp_parsed(map, score.map_id, score.mode)
+                    .score(score)
+                    .performance()
+                    .await;
+
+                score_ids.push(score.score_id as i64);
+                pps.push(attrs.pp());
+            }
+
+            self.psql
+                .update_scores_pp(&score_ids, &pps, PP_CALC_VERSION)
+                .await
+                .wrap_err("Failed to update score pp")?;
+
+            let elapsed = start.elapsed();
+
+            let progress = self.update_progress(|progress| {
+                progress.scores.advance(scores.len(), elapsed);
+                progress.failed += failed;
+            });
+
+            debug!(
+                done = progress.scores.done,
+                total = progress.scores.total,
+                "Recalculated score batch"
+            );
+        }
+    }
+
+    async fn wait_while_paused(self) {
+        let mut paused = self.state.paused.subscribe();
+
+        while *paused.borrow_and_update() {
+            if paused.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+
+    fn update_progress(self, f: impl FnOnce(&mut RecalcProgress)) -> RecalcProgress {
+        let mut progress = self.state.progress.lock().unwrap();
+        f(&mut progress);
+
+        progress.clone()
+    }
+}
+
+/// Iterates over consecutive entries with the same map id.
+struct MapGroups<'a> {
+    entries: &'a [StaleDifficulty],
+}
+
+impl<'a> MapGroups<'a> {
+    fn new(entries: &'a [StaleDifficulty]) -> Self {
+        Self { entries }
+    }
+}
+
+impl<'a> Iterator for MapGroups<'a> {
+    type Item = &'a [StaleDifficulty];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.entries.first()?;
+
+        let len = self
+            .entries
+            .iter()
+            .position(|entry| entry.map_id != first.map_id)
+            .unwrap_or(self.entries.len());
+
+        let (group, rest) = self.entries.split_at(len);
+        self.entries = rest;
+
+        Some(group)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct RecalcProgress {
+    pub stage: RecalcStage,
+    pub difficulties: StageProgress,
+    pub scores: StageProgress,
+    /// Amount of entries that were skipped because their map could not be
+    /// prepared
+    pub failed: usize,
+    pub started_at: Option<OffsetDateTime>,
+}
+
+#[derive(Copy, Clone, Default)]
+pub struct StageProgress {
+    pub done: usize,
+    pub total: usize,
+    /// Time spent on this stage, excluding pauses
+    pub elapsed: Duration,
+}
+
+impl StageProgress {
+    fn advance(&mut self, count: usize, elapsed: Duration) {
+        self.done += count;
+        self.total = self.total.max(self.done);
+        self.elapsed += elapsed;
+    }
+
+    /// Estimated remaining time based on the average duration so far.
+    pub fn eta(&self) -> Option<Duration> {
+        if self.done == 0 {
+            return None;
+        }
+
+        let remaining = (self.total - self.done) as f64 / self.done as f64;
+
+        Some(self.elapsed.mul_f64(remaining))
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RecalcStage {
+    #[default]
+    Idle,
+    Counting,
+    Difficulties,
+    Scores,
+    Refreshing,
+    Finished,
+    Failed,
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::GameMode;
+
+    use super::*;
+
+    fn stale(map_id: u32) -> StaleDifficulty {
+        StaleDifficulty {
+            map_id,
+            mods: 0,
+            mode: GameMode::Osu,
+        }
+    }
+
+    #[test]
+    fn map_groups() {
+        let entries = [stale(1), stale(1), stale(2), stale(3), stale(3), stale(3)];
+
+        let lens: Vec<_> = MapGroups::new(&entries).map(<[_]>::len).collect();
+        assert_eq!(lens, [2, 1, 3]);
+
+        assert!(MapGroups::new(&[]).next().is_none());
+    }
+
+    #[test]
+    fn stage_progress_eta() {
+        let mut progress = StageProgress {
+            total: 100,
+            ..Default::default()
+        };
+
+        assert_eq!(progress.eta(), None);
+
+        progress.advance(25, Duration::from_secs(10));
+        assert_eq!(progress.eta(), Some(Duration::from_secs(30)));
+
+        progress.advance(75, Duration::from_secs(30));
+        assert_eq!(progress.eta(), Some(Duration::ZERO));
+
+        // More entries than initially counted
+        progress.advance(10, Duration::from_secs(4));
+        assert_eq!(progress.total, 110);
+    }
+
+    #[test]
+    fn state_transitions() {
+        let state = PpRecalcState::default();
+
+        assert!(!state.pause());
+        assert!(!state.resume());
+
+        assert!(state.try_start());
+        assert!(!state.try_start());
+        assert_eq!(state.progress.lock().unwrap().stage, RecalcStage::Counting);
+
+        assert!(state.pause());
+        assert!(state.is_paused());
+        assert!(!state.pause());
+
+        assert!(state.resume());
+        assert!(!state.is_paused());
+        assert!(!state.resume());
+
+        assert!(state.pause());
+        state.finish(RecalcStage::Finished);
+        assert!(!state.is_paused());
+        assert_eq!(state.progress.lock().unwrap().stage, RecalcStage::Finished);
+
+        assert!(state.try_start());
+    }
+}
@@ -135,6 +135,24 @@ impl RedisManager {
         format!("osu_user_{user_id}_{}", mode as u8)
     }
 
+    /// Remove the cached osu! user for all modes.
+    pub async fn delete_osu_user(self, user_id: u32) {
+        let modes = [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ];
+
+        for mode in modes {
+            let key = Self::osu_user_key(user_id, mode);
+
+            if let Err(err) = Context::cache().delete(&key).await {
+                warn!(?err, "Failed to delete cached osu! user");
+            }
+        }
+    }
+
     pub async fn osu_user_from_args(self, args: UserArgsSlim) -> RedisResult<User, User, OsuError> {
         let UserArgsSlim { user_id, mode } = args;
         let key = Self::osu_user_key(user_id, mode);
@@ -1,25 +1,27 @@
 use bathbot_model::embed_builder::ScoreEmbedSettings;
 use bathbot_psql::{
-    model::configs::{OsuUserId, SkinEntry, UserConfig},
+    model::configs::{Locale, OsuUserId, SkinEntry, UserConfig},
     Database,
 };
-use bathbot_util::CowUtils;
+use bathbot_util::{CowUtils, IntHasher};
 use eyre::{Result, WrapErr};
+use papaya::HashMap as PapayaMap;
 use rosu_v2::prelude::{GameMode, Username};
 use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::core::Context;
 
+type UserLocales = PapayaMap<Id<UserMarker>, Option<Locale>, IntHasher>;
+
 #[derive(Copy, Clone)]
 pub struct UserConfigManager {
     psql: &'static Database,
+    locales: &'static UserLocales,
 }
 
 impl UserConfigManager {
-    pub fn new() -> Self {
-        Self {
-            psql: Context::psql(),
-        }
+    pub fn new(psql: &'static Database, locales: &'static UserLocales) -> Self {
+        Self { psql, locales }
     }
 
     pub async fn with_osu_id(self, user_id: Id<UserMarker>) -> Result<UserConfig<OsuUserId>> {
@@ -48,6 +50,26 @@ impl UserConfigManager {
             .wrap_err("Failed to get user mode from DB")
     }
 
+    /// The user's locale, cached because it's required for every interaction.
+    ///
+    /// The cache entry is updated whenever the config is stored through this
+    /// manager.
+    pub async fn locale(self, user_id: Id<UserMarker>) -> Result<Option<Locale>> {
+        if let Some(locale) = self.locales.pin().get(&user_id) {
+            return Ok(*locale);
+        }
+
+        let locale = self
+            .psql
+            .select_user_locale(user_id)
+            .await
+            .wrap_err("Failed to get user locale from DB")?;
+
+        self.locales.pin().insert(user_id, locale);
+
+        Ok(locale)
+    }
+
     pub async fn osu_id(self, user_id: Id<UserMarker>) -> Result<Option<u32>> {
         self.psql
             .select_osu_id_by_discord_id(user_id)
@@ -62,6 +84,50 @@ impl UserConfigManager {
             .wrap_err("failed to get username from DB")
     }
 
+    /// Linked accounts besides the primary one
+    #[cfg(feature = "server")]
+    pub async fn linked_osu_ids(self, user_id: Id<UserMarker>) -> Result<Vec<u32>> {
+        self.psql
+            .select_linked_osu_ids(user_id)
+            .await
+            .wrap_err("Failed to get linked accounts")
+    }
+
+    /// Find one of the user's linked accounts, including the primary one, by
+    /// its username.
+    pub async fn linked_osu_id_by_name(
+        self,
+        user_id: Id<UserMarker>,
+        username: &str,
+    ) -> Result<Option<u32>> {
+        let username = username.cow_replace('_', r"\_");
+
+        self.psql
+            .select_linked_osu_id_by_name(user_id, username.as_ref())
+            .await
+            .wrap_err("Failed to get linked account by name")
+    }
+
+    /// Store the config and update the linked accounts besides the primary
+    /// one within a single transaction.
+    #[cfg(feature = "server")]
+    pub async fn store_with_linked(
+        self,
+        user_id: Id<UserMarker>,
+        config: &UserConfig<OsuUserId>,
+        unlink: Option<u32>,
+        link: Option<u32>,
+    ) -> Result<()> {
+        self.psql
+            .upsert_user_config_with_linked(user_id, config, unlink, link)
+            .await
+            .wrap_err("Failed to store user config with linked accounts")?;
+
+        self.locales.pin().insert(user_id, config.locale);
+
+        Ok(())
+    }
+
     pub async fn discord_from_osu_id(self, user_id: u32) -> Result<Option<Id<UserMarker>>> {
         self.psql
             .select_user_discord_id_by_osu_id(user_id)
@@ -114,7 +180,11 @@ impl UserConfigManager {
         self.psql
             .upsert_user_config(user_id, config)
             .await
-            .wrap_err("failed to store user config")
+            .wrap_err("failed to store user config")?;
+
+        self.locales.pin().insert(user_id, config.locale);
+
+        Ok(())
     }
 
     pub async fn store_score_embed_settings(
@@ -127,4 +197,36 @@ impl UserConfigManager {
             .await
             .wrap_err("Failed to store score embed settings")
     }
+
+    /// All data stored for the user as json.
+    pub async fn export_data(self, user_id: Id<UserMarker>) -> Result<serde_json::Value> {
+        self.psql
+            .select_user_data(user_id)
+            .await
+            .wrap_err("Failed to get user data")
+    }
+
+    /// Remove all data stored for the user, including cached data of their
+    /// linked osu! accounts.
+    pub async fn delete_data(self, user_id: Id<UserMarker>) -> Result<()> {
+        // Revoke the osu! token while it can still be decrypted
+        #[cfg(feature = "server")]
+        if let Err(err) = Context::osu_tokens().remove(user_id, None).await {
+            warn!(?err, "Failed to revoke osu! token");
+        }
+
+        let osu_ids = self
+            .psql
+            .delete_user_data(user_id)
+            .await
+            .wrap_err("Failed to delete user data")?;
+
+        self.locales.pin().remove(&user_id);
+
+        for osu_id in osu_ids {
+            Context::redis().delete_osu_user(osu_id).await;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,357 @@
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    iter,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use bathbot_client::ClientError;
+use bathbot_psql::{
+    model::webhooks::{DbGuildWebhook, DbWebhookDeadLetter},
+    Database,
+};
+use bathbot_util::{ExponentialBackoff, IntHasher};
+use eyre::{Result, WrapErr};
+use papaya::HashMap as PapayaMap;
+use rand::{thread_rng, RngCore};
+use serde::Serialize;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+
+use crate::core::Context;
+
+/// Maximum amount of webhooks per guild
+pub const MAX_WEBHOOKS: usize = 5;
+
+/// Amount of delivery attempts before a payload is put into the dead letters
+const ATTEMPTS: u8 = 5;
+
+/// Duration after which cached webhooks are fetched again.
+///
+/// Adding or removing a webhook already clears the cache but a concurrent
+/// fetch could still put outdated webhooks back.
+const CACHE_DURATION: Duration = Duration::from_secs(300);
+
+/// Webhooks subscribed to an event, keyed by the event's bits.
+type WebhooksByEvent = PapayaMap<i16, (Instant, Arc<[DbGuildWebhook]>), IntHasher>;
+
+bitflags::bitflags! {
+    pub struct WebhookEvents: i16 {
+        const OSU_SCORES = 1 << 0;
+        const TWITCH_STREAMS = 1 << 1;
+        const MATCH_LIVE = 1 << 2;
+    }
+}
+
+impl WebhookEvents {
+    /// Comma-separated names of the contained events
+    pub fn names(self) -> String {
+        [
+            (Self::OSU_SCORES, "osu_score"),
+            (Self::TWITCH_STREAMS, "twitch_live"),
+            (Self::MATCH_LIVE, "match_live"),
+        ]
+        .into_iter()
+        .filter(|(event, _)| self.contains(*event))
+        .fold(String::new(), |mut names, (_, name)| {
+            if !names.is_empty() {
+                names.push_str(", ");
+            }
+
+            names.push_str(name);
+
+            names
+        })
+    }
+}
+
+/// Events that are delivered to webhooks.
+///
+/// Serialized as `{"event": "<name>", "data": {...}}`.
+#[derive(Serialize)]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+#[cfg_attr(
+    not(any(
+        feature = "osutracking",
+        feature = "twitchtracking",
+        feature = "matchlive"
+    )),
+    allow(dead_code)
+)]
+pub enum WebhookPayload {
+    OsuScore(OsuScorePayload),
+    TwitchLive(TwitchLivePayload),
+    MatchLive(MatchLivePayload),
+}
+
+impl WebhookPayload {
+    fn event(&self) -> (WebhookEvents, &'static str) {
+        match self {
+            Self::OsuScore(_) => (WebhookEvents::OSU_SCORES, "osu_score"),
+            Self::TwitchLive(_) => (WebhookEvents::TWITCH_STREAMS, "twitch_live"),
+            Self::MatchLive(_) => (WebhookEvents::MATCH_LIVE, "match_live"),
+        }
+    }
+}
+
+/// A new top play of a tracked osu! user
+#[derive(Serialize)]
+pub struct OsuScorePayload {
+    pub user_id: u32,
+    pub username: Option<String>,
+    pub mode: u8,
+    pub map_id: u32,
+    pub score_id: u64,
+    /// Position in the user's top plays
+    pub top_position: u8,
+    pub pp: Option<f32>,
+    pub accuracy: f32,
+    pub mods: String,
+    pub grade: String,
+    pub max_combo: u32,
+    /// Unix timestamp
+    pub ended_at: i64,
+}
+
+/// A tracked twitch stream went live
+#[derive(Serialize)]
+pub struct TwitchLivePayload {
+    pub user_id: u64,
+    pub username: String,
+    pub title: String,
+    pub url: String,
+}
+
+/// A tracked multiplayer match received new events
+#[derive(Serialize)]
+pub struct MatchLivePayload {
+    pub match_id: u32,
+    pub name: String,
+    pub ended: bool,
+}
+
+#[derive(Serialize)]
+struct WebhookBody<'a> {
+    guild_id: Id<GuildMarker>,
+    #[serde(flatten)]
+    payload: &'a WebhookPayload,
+}
+
+#[derive(Copy, Clone)]
+pub struct WebhookManager {
+    psql: &'static Database,
+    by_event: &'static WebhooksByEvent,
+}
+
+impl WebhookManager {
+    pub fn new(psql: &'static Database, by_event: &'static WebhooksByEvent) -> Self {
+        Self { psql, by_event }
+    }
+
+    pub async fn get(self, guild_id: Id<GuildMarker>) -> Result<Vec<DbGuildWebhook>> {
+        self.psql
+            .select_guild_webhooks(guild_id)
+            .await
+            .wrap_err("Failed to get guild webhooks")
+    }
+
+    /// Add a webhook and return its generated secret.
+    ///
+    /// Returns `None` if the guild already has a webhook for that url.
+    pub async fn add(
+        self,
+        guild_id: Id<GuildMarker>,
+        url: &str,
+        events: WebhookEvents,
+    ) -> Result<Option<String>> {
+        let secret = random_secret();
+
+        let inserted = self
+            .psql
+            .insert_guild_webhook(guild_id, url, &secret, events.bits())
+            .await
+            .wrap_err("Failed to insert guild webhook")?;
+
+        if inserted {
+            self.by_event.pin().clear();
+        }
+
+        Ok(inserted.then_some(secret))
+    }
+
+    pub async fn remove(self, guild_id: Id<GuildMarker>, url: &str) -> Result<bool> {
+        let removed = self
+            .psql
+            .delete_guild_webhook(guild_id, url)
+            .await
+            .wrap_err("Failed to delete guild webhook")?;
+
+        if removed {
+            self.by_event.pin().clear();
+        }
+
+        Ok(removed)
+    }
+
+    pub async fn dead_letters(self, guild_id: Id<GuildMarker>) -> Result<Vec<DbWebhookDeadLetter>> {
+        self.psql
+            .select_webhook_dead_letters(guild_id, 10)
+            .await
+            .wrap_err("Failed to get webhook dead letters")
+    }
+
+    /// Deliver the payload in the background to all webhooks of guilds that
+    /// contain any of the channels and subscribed to the payload's event.
+    #[cfg_attr(
+        not(any(
+            feature = "osutracking",
+            feature = "twitchtracking",
+            feature = "matchlive"
+        )),
+        allow(dead_code)
+    )]
+    pub fn notify(self, channels: Vec<Id<ChannelMarker>>, payload: WebhookPayload) {
+        if channels.is_empty() {
+            return;
+        }
+
+        tokio::spawn(async move {
+            if let Err(err) = self.deliver_all(&channels, &payload).await {
+                warn!(?err, "Failed to deliver webhooks");
+            }
+        });
+    }
+
+    async fn deliver_all(
+        self,
+        channels: &[Id<ChannelMarker>],
+        payload: &WebhookPayload,
+    ) -> Result<()> {
+        let (event, event_name) = payload.event();
+        let webhooks = self.by_event(event).await?;
+
+        // Whether a guild contains any of the channels
+        let mut guilds = HashMap::with_hasher(IntHasher);
+
+        for webhook in webhooks.iter() {
+            let guild_id = Id::new(webhook.guild_id as u64);
+
+            let contains = match guilds.get(&guild_id) {
+                Some(contains) => *contains,
+                None => {
+                    let guild_channels = Context::cache()
+                        .guild_channels(guild_id)
+                        .await
+                        .wrap_err("Failed to get guild channels")?;
+
+                    let contains = channels
+                        .iter()
+                        .any(|channel| guild_channels.contains(&channel.get()));
+
+                    guilds.insert(guild_id, contains);
+
+                    contains
+                }
+            };
+
+            if !contains {
+                continue;
+            }
+
+            let body = WebhookBody { guild_id, payload };
+            let json = serde_json::to_vec(&body).wrap_err("Failed to serialize webhook body")?;
+
+            tokio::spawn(self.deliver(webhook.clone(), event_name, json));
+        }
+
+        Ok(())
+    }
+
+    /// Webhooks that subscribed to the event.
+    async fn by_event(self, event: WebhookEvents) -> Result<Arc<[DbGuildWebhook]>> {
+        if let Some((fetched_at, webhooks)) = self.by_event.pin().get(&event.bits()) {
+            if fetched_at.elapsed() < CACHE_DURATION {
+                return Ok(Arc::clone(webhooks));
+            }
+        }
+
+        let webhooks: Arc<[DbGuildWebhook]> = self
+            .psql
+            .select_webhooks_by_events(event.bits())
+            .await
+            .wrap_err("Failed to get webhooks")?
+            .into();
+
+        self.by_event
+            .pin()
+            .insert(event.bits(), (Instant::now(), Arc::clone(&webhooks)));
+
+        Ok(webhooks)
+    }
+
+    /// Try to deliver the payload, retrying with exponential backoff, and
+    /// store it as dead letter if all attempts failed.
+    async fn deliver(self, webhook: DbGuildWebhook, event: &'static str, json: Vec<u8>) {
+        let backoff = ExponentialBackoff::new(2).factor(1000).max_delay(60_000);
+        let delays = iter::once(Duration::ZERO).chain(backoff);
+        let mut attempts = 0;
+        let mut last_err = None;
+
+        for delay in delays.take(ATTEMPTS as usize) {
+            tokio::time::sleep(delay).await;
+            attempts += 1;
+
+            let send_fut =
+                Context::client().send_webhook(&webhook.url, event, &webhook.secret, json.clone());
+
+            match send_fut.await {
+                Ok(_) => return,
+                // The receiver rejected the payload, retrying won't help
+                Err(
+                    err @ (ClientError::BadRequest
+                    | ClientError::Unauthorized
+                    | ClientError::NotFound),
+                ) => {
+                    last_err = Some(err);
+
+                    break;
+                }
+                Err(err) => last_err = Some(err),
+            }
+        }
+
+        let error = last_err.map_or_else(String::new, |err| format!("{err:#}"));
+        debug!(url = %webhook.url, %error, "Failed to deliver webhook");
+
+        let payload = String::from_utf8_lossy(&json);
+
+        let insert_fut = self.psql.insert_webhook_dead_letter(
+            webhook.webhook_id,
+            event,
+            &payload,
+            &error,
+            attempts,
+        );
+
+        if let Err(err) = insert_fut.await {
+            warn!(?err, "Failed to store webhook dead letter");
+        }
+    }
+}
+
+/// Hex-encoded random 32 bytes.
+fn random_secret() -> String {
+    let mut bytes = [0; 32];
+    thread_rng().fill_bytes(&mut bytes);
+
+    bytes
+        .iter()
+        .fold(String::with_capacity(64), |mut secret, byte| {
+            let _ = write!(secret, "{byte:02x}");
+
+            secret
+        })
+}
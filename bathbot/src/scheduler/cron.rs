@@ -0,0 +1,235 @@
+use std::str::FromStr;
+
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+const WEEKDAYS: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// Cron-like schedule in UTC.
+///
+/// Consists of the five fields `minute hour day-of-month month day-of-week`.
+/// Each field is either `*` or a comma separated list of values, ranges like
+/// `1-5`, and steps like `*/2` or `0-12/3`. Months and weekdays can also be
+/// specified through their english abbreviation e.g. `jan` or `mon`.
+///
+/// Additionally, `@hourly`, `@daily`, `@weekly`, and `@monthly` are supported.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    any_day: bool,
+    any_weekday: bool,
+}
+
+impl CronSchedule {
+    /// Whether the schedule fires at most once per hour.
+    pub fn is_hourly_or_less(&self) -> bool {
+        self.minutes.count_ones() == 1
+    }
+
+    /// The first point in time strictly after `after` at which the schedule
+    /// fires.
+    ///
+    /// Returns `None` if the schedule does not fire within the next few years
+    /// e.g. because it only specifies the 31st of February.
+    pub fn next_after(&self, after: OffsetDateTime) -> Option<OffsetDateTime> {
+        const MAX_DAYS: usize = 5 * 366;
+
+        let after = after.to_offset(UtcOffset::UTC);
+        let mut date = after.date();
+        let mut hour = after.hour();
+        let mut minute = after.minute() + 1;
+
+        for _ in 0..MAX_DAYS {
+            if self.matches_date(date) {
+                while hour < 24 {
+                    if self.hours & (1 << hour) != 0 {
+                        let next_minute = (minute..60).find(|m| self.minutes & (1 << m) != 0);
+
+                        if let Some(minute) = next_minute {
+                            let time = Time::from_hms(hour, minute, 0).ok()?;
+
+                            return Some(PrimitiveDateTime::new(date, time).assume_utc());
+                        }
+                    }
+
+                    hour += 1;
+                    minute = 0;
+                }
+            }
+
+            date = date.next_day()?;
+            hour = 0;
+            minute = 0;
+        }
+
+        None
+    }
+
+    fn matches_date(&self, date: Date) -> bool {
+        if self.months & (1 << u8::from(date.month())) == 0 {
+            return false;
+        }
+
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().number_days_from_sunday()) != 0;
+
+        // Same as cron: if both day fields are restricted, either may match
+        match (self.any_day, self.any_weekday) {
+            (false, false) => day || weekday,
+            _ => day && weekday,
+        }
+    }
+
+    fn parse_field(field: &str, min: u8, max: u8, names: &[&str]) -> Result<u64, String> {
+        let parse_value = |value: &str| -> Result<u8, String> {
+            let lowercase = value.to_ascii_lowercase();
+
+            if let Some(idx) = names.iter().position(|name| *name == lowercase) {
+                return Ok(idx as u8 + min);
+            }
+
+            match value.parse::<u8>() {
+                Ok(n) if (min..=max).contains(&n) => Ok(n),
+                _ => Err(format!(
+                    "`{value}` is not a valid value, must be between {min} and {max}"
+                )),
+            }
+        };
+
+        let mut mask = 0;
+
+        for part in field.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => match step.parse::<u8>() {
+                    Ok(step) if step > 0 => (range, step),
+                    _ => return Err(format!("`{step}` is not a valid step")),
+                },
+                None => (part, 1),
+            };
+
+            let (start, end) = match range.split_once('-') {
+                _ if range == "*" => (min, max),
+                Some((start, end)) => (parse_value(start)?, parse_value(end)?),
+                // `5/10` is short for `5-max/10`
+                None if part.contains('/') => (parse_value(range)?, max),
+                None => {
+                    let value = parse_value(range)?;
+
+                    (value, value)
+                }
+            };
+
+            if start > end {
+                return Err(format!("`{range}` is not a valid range"));
+            }
+
+            for value in (start..=end).step_by(step as usize) {
+                mask |= 1 << value;
+            }
+        }
+
+        Ok(mask)
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = match s.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" => "0 0 * * *",
+            "@weekly" => "0 0 * * sun",
+            "@monthly" => "0 0 1 * *",
+            s => s,
+        };
+
+        let fields: Vec<_> = s.split_whitespace().collect();
+
+        let [minute, hour, day, month, weekday] = fields.as_slice() else {
+            return Err("A schedule must consist of the five fields \
+                `minute hour day-of-month month day-of-week`"
+                .to_owned());
+        };
+
+        let mut weekdays = Self::parse_field(weekday, 0, 7, &WEEKDAYS)?;
+
+        // Both 0 and 7 represent sunday
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            minutes: Self::parse_field(minute, 0, 59, &[])?,
+            hours: Self::parse_field(hour, 0, 23, &[])?,
+            days: Self::parse_field(day, 1, 31, &[])?,
+            months: Self::parse_field(month, 1, 12, &MONTHS)?,
+            weekdays,
+            any_day: *day == "*",
+            any_weekday: *weekday == "*",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn datetime(day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2024, Month::November, day)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    #[test]
+    fn next_weekly() {
+        let schedule: CronSchedule = "30 12 * * mon".parse().unwrap();
+
+        // wednesday the 6th to monday the 11th
+        assert_eq!(
+            schedule.next_after(datetime(6, 13, 0)),
+            Some(datetime(11, 12, 30))
+        );
+
+        let schedule: CronSchedule = "@daily".parse().unwrap();
+
+        assert_eq!(
+            schedule.next_after(datetime(6, 0, 0)),
+            Some(datetime(7, 0, 0))
+        );
+    }
+
+    #[test]
+    fn restricted_days_match_either() {
+        let schedule: CronSchedule = "0 0 13 * fri".parse().unwrap();
+
+        // friday the 8th comes before the 13th
+        assert_eq!(
+            schedule.next_after(datetime(6, 0, 0)),
+            Some(datetime(8, 0, 0))
+        );
+
+        let schedule: CronSchedule = "0 0 31 feb *".parse().unwrap();
+
+        assert_eq!(schedule.next_after(datetime(6, 0, 0)), None);
+    }
+
+    #[test]
+    fn invalid_fields() {
+        assert!("0 0 * *".parse::<CronSchedule>().is_err());
+        assert!("60 * * * *".parse::<CronSchedule>().is_err());
+        assert!("*/0 * * * *".parse::<CronSchedule>().is_err());
+        assert!("0 5-2 * * *".parse::<CronSchedule>().is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use std::time::Instant;
+
+use bathbot_psql::model::schedule::DbScheduledJob;
+use eyre::{Result, WrapErr};
+use time::OffsetDateTime;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use twilight_interactions::command::CommandInputData;
+use twilight_model::{
+    guild::Permissions,
+    id::{
+        marker::{ChannelMarker, GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+pub use self::{cron::CronSchedule, options::parse_options};
+use crate::{
+    commands::osu::{
+        scheduled_graph, scheduled_ranking, scheduled_serverleaderboard, scheduled_top,
+    },
+    core::{
+        commands::{
+            checks::{check_channel_permissions, check_command_rules},
+            interaction::{InteractionCommandKind, InteractionCommands, SlashCommand},
+            CommandOrigin,
+        },
+        BotMetrics, Context,
+    },
+};
+
+mod cron;
+mod options;
+
+/// Names of the slash commands that can be scheduled or used in aliases.
+pub const SCHEDULABLE_COMMANDS: [&str; 4] = ["graph", "ranking", "serverleaderboard", "top"];
+
+/// Checks every minute for scheduled jobs that are due and runs them.
+pub async fn scheduled_jobs_loop() {
+    let mut interval = interval(Duration::from_secs(60));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+
+        let now = OffsetDateTime::now_utc();
+
+        let jobs = match Context::psql().select_due_scheduled_jobs(now).await {
+            Ok(jobs) => jobs,
+            Err(err) => {
+                warn!(?err, "Failed to get due scheduled jobs");
+
+                continue;
+            }
+        };
+
+        for job in jobs {
+            let next_run = job
+                .schedule
+                .parse::<CronSchedule>()
+                .ok()
+                .and_then(|schedule| schedule.next_after(now));
+
+            let Some(next_run) = next_run else {
+                warn!(job_id = job.job_id, schedule = %job.schedule, "Removing scheduled job without next run");
+                let guild = Id::new(job.guild_id as u64);
+
+                if let Err(err) = Context::psql()
+                    .delete_scheduled_job(guild, job.job_id)
+                    .await
+                {
+                    warn!(?err, "Failed to delete scheduled job");
+                }
+
+                continue;
+            };
+
+            // Claim the job by moving its next run so that it's only run once
+            // even if multiple processes check simultaneously
+            let claim_fut =
+                Context::psql().update_scheduled_job_next_run(job.job_id, job.next_run, next_run);
+
+            match claim_fut.await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(err) => {
+                    warn!(?err, "Failed to update next run of scheduled job");
+
+                    continue;
+                }
+            }
+
+            tokio::spawn(async move {
+                let start = Instant::now();
+                let name = job.command.clone();
+
+                if let Err(err) = run_job(job).await {
+                    BotMetrics::inc_command_error("scheduled", name.clone());
+                    warn!(name, ?err, "Failed to run scheduled job");
+                }
+
+                BotMetrics::observe_command("scheduled", name, start.elapsed());
+            });
+        }
+    }
+}
+
+/// Runs the job's command in its channel as if it was invoked by the job's
+/// owner.
+async fn run_job(job: DbScheduledJob) -> Result<()> {
+    let guild: Id<GuildMarker> = Id::new(job.guild_id as u64);
+    let channel: Id<ChannelMarker> = Id::new(job.channel_id as u64);
+    let user: Id<UserMarker> = Id::new(job.user_id as u64);
+    let name = job.command.as_str();
+
+    let Some(InteractionCommandKind::Chat(cmd)) = InteractionCommands::get_command(name) else {
+        bail!("Unknown scheduled command `{name}`");
+    };
+
+    let permissions = match Context::cache().current_user().await {
+        Ok(Some(current_user)) => check_channel_permissions(current_user.id, channel, guild).await,
+        Ok(None) => bail!("Missing current user in cache"),
+        Err(err) => return Err(err.wrap_err("Failed to get current user")),
+    };
+
+    if !permissions.contains(Permissions::SEND_MESSAGES) {
+        debug!(
+            job_id = job.job_id,
+            "Lacking permission to run scheduled job"
+        );
+
+        return Ok(());
+    }
+
+    // The owner must still be allowed to use the command
+    let rules_fut = check_command_rules(user, Some(guild), channel, &[cmd.name], cmd.group);
+
+    if rules_fut.await?.is_some() {
+        debug!(
+            job_id = job.job_id,
+            "Scheduled job is restricted for its owner"
+        );
+
+        return Ok(());
+    }
+
+    let data = parse_command(cmd, &job.args)
+        .map_err(|reason| eyre!("Invalid arguments for scheduled job: {reason}"))?;
+
+    let orig = CommandOrigin::Scheduled {
+        user,
+        channel,
+        guild: Some(guild),
+        permissions: Some(permissions),
+    };
+
+    run_command(orig, name, data)
+        .await
+        .wrap_err_with(|| format!("Failed to run scheduled `{name}`"))
+}
+
+/// Parse the textual arguments of a slash command into its input data.
+///
+/// On error, returns a message describing the issue.
+pub fn parse_command(
+    cmd: &'static SlashCommand,
+    args: &str,
+) -> Result<CommandInputData<'static>, String> {
+    let definition = InteractionCommandKind::Chat(cmd).create();
+    let options = parse_options(&definition.options, args)?;
+
+    Ok(CommandInputData {
+        options,
+        resolved: None,
+    })
+}
+
+/// Run one of the [`SCHEDULABLE_COMMANDS`] outside of an interaction.
+pub async fn run_command(
+    orig: CommandOrigin<'_>,
+    name: &str,
+    data: CommandInputData<'_>,
+) -> Result<()> {
+    match name {
+        "graph" => scheduled_graph(orig, data).await,
+        "ranking" => scheduled_ranking(orig, data).await,
+        "serverleaderboard" => scheduled_serverleaderboard(orig, data).await,
+        "top" => scheduled_top(orig, data).await,
+        _ => bail!("Command `{name}` cannot be run outside of an interaction"),
+    }
+}
@@ -0,0 +1,345 @@
+use bathbot_util::matcher;
+use twilight_model::{
+    application::{
+        command::{
+            CommandOption, CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType,
+        },
+        interaction::application_command::{CommandDataOption, CommandOptionValue},
+    },
+    id::Id,
+};
+
+use crate::core::commands::prefix::{Args, ArgsNum};
+
+/// Parse the textual arguments of a slash command into its options.
+///
+/// The arguments consist of the names of subcommands followed by
+/// `option:value` pairs e.g. `country mode:mania`. If a value contains
+/// whitespace, the whole pair must be quoted i.e. `"name:some user"`.
+///
+/// On error, returns a message describing the issue.
+pub fn parse_options(
+    definitions: &[CommandOption],
+    args: &str,
+) -> Result<Vec<CommandDataOption>, String> {
+    let mut items = Args::new(args, ArgsNum::Value(0));
+    let mut definitions = definitions;
+    let mut subcommands = Vec::new();
+
+    while definitions.iter().any(is_subcommand) {
+        let names = || {
+            definitions
+                .iter()
+                .map(|option| format!("`{}`", option.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let Some(name) = items.next() else {
+            return Err(format!("Missing subcommand, must be one of {}", names()));
+        };
+
+        let Some(subcommand) = definitions
+            .iter()
+            .find(|option| is_subcommand(option) && option.name.eq_ignore_ascii_case(name))
+        else {
+            return Err(format!(
+                "Unknown subcommand `{name}`, must be one of {}",
+                names()
+            ));
+        };
+
+        subcommands.push(subcommand);
+        definitions = subcommand.options.as_deref().unwrap_or_default();
+    }
+
+    let mut options: Vec<CommandDataOption> = Vec::new();
+
+    for item in items {
+        let Some((key, value)) = item.split_once(':') else {
+            return Err(format!("Expected `option:value`, got `{item}`"));
+        };
+
+        let Some(definition) = definitions.iter().find(|option| option.name == key) else {
+            return Err(format!("Unknown option `{key}`"));
+        };
+
+        if options.iter().any(|option| option.name == key) {
+            return Err(format!("Option `{key}` was specified more than once"));
+        }
+
+        options.push(CommandDataOption {
+            name: definition.name.clone(),
+            value: parse_value(definition, value)?,
+        });
+    }
+
+    let missing = definitions.iter().find(|definition| {
+        definition.required == Some(true)
+            && !options.iter().any(|option| option.name == definition.name)
+    });
+
+    if let Some(definition) = missing {
+        return Err(format!("Missing required option `{}`", definition.name));
+    }
+
+    let options = subcommands
+        .into_iter()
+        .rev()
+        .fold(options, |options, subcommand| {
+            let value = match subcommand.kind {
+                CommandOptionType::SubCommandGroup => CommandOptionValue::SubCommandGroup(options),
+                _ => CommandOptionValue::SubCommand(options),
+            };
+
+            vec![CommandDataOption {
+                name: subcommand.name.clone(),
+                value,
+            }]
+        });
+
+    Ok(options)
+}
+
+fn is_subcommand(option: &CommandOption) -> bool {
+    matches!(
+        option.kind,
+        CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+    )
+}
+
+fn parse_value(definition: &CommandOption, value: &str) -> Result<CommandOptionValue, String> {
+    let name = &definition.name;
+
+    if let Some(ref choices) = definition.choices {
+        return parse_choice(name, choices, value);
+    }
+
+    let invalid = |kind: &str| format!("`{value}` is not a valid {kind} for option `{name}`");
+
+    let value = match definition.kind {
+        CommandOptionType::String => CommandOptionValue::String(value.to_owned()),
+        CommandOptionType::Integer => value
+            .parse()
+            .map(CommandOptionValue::Integer)
+            .map_err(|_| invalid("integer"))?,
+        CommandOptionType::Number => value
+            .parse()
+            .map(CommandOptionValue::Number)
+            .map_err(|_| invalid("number"))?,
+        CommandOptionType::Boolean => value
+            .parse()
+            .map(CommandOptionValue::Boolean)
+            .map_err(|_| invalid("boolean"))?,
+        CommandOptionType::User => value
+            .parse()
+            .ok()
+            .and_then(Id::new_checked)
+            .or_else(|| matcher::get_mention_user(value))
+            .map(CommandOptionValue::User)
+            .ok_or_else(|| invalid("user"))?,
+        CommandOptionType::Role => matcher::get_mention_role(value)
+            .map(CommandOptionValue::Role)
+            .ok_or_else(|| invalid("role"))?,
+        CommandOptionType::Channel => value
+            .trim_start_matches("<#")
+            .trim_end_matches('>')
+            .parse()
+            .ok()
+            .and_then(Id::new_checked)
+            .map(CommandOptionValue::Channel)
+            .ok_or_else(|| invalid("channel"))?,
+        _ => return Err(format!("Option `{name}` cannot be used in a schedule")),
+    };
+
+    Ok(value)
+}
+
+/// Accepts either the value or the name of a choice.
+fn parse_choice(
+    name: &str,
+    choices: &[CommandOptionChoice],
+    value: &str,
+) -> Result<CommandOptionValue, String> {
+    let choice = choices.iter().find(|choice| {
+        let matches_value = match choice.value {
+            CommandOptionChoiceValue::String(ref choice) => choice.eq_ignore_ascii_case(value),
+            CommandOptionChoiceValue::Integer(choice) => value.parse() == Ok(choice),
+            CommandOptionChoiceValue::Number(choice) => value.parse() == Ok(choice),
+        };
+
+        matches_value || choice.name.eq_ignore_ascii_case(value)
+    });
+
+    let Some(choice) = choice else {
+        let names: Vec<_> = choices
+            .iter()
+            .map(|choice| format!("`{}`", choice.name))
+            .collect();
+
+        return Err(format!(
+            "`{value}` is not a valid value for option `{name}`, must be one of {}",
+            names.join(", ")
+        ));
+    };
+
+    let value = match choice.value {
+        CommandOptionChoiceValue::String(ref value) => CommandOptionValue::String(value.clone()),
+        CommandOptionChoiceValue::Integer(value) => CommandOptionValue::Integer(value),
+        CommandOptionChoiceValue::Number(value) => CommandOptionValue::Number(value),
+    };
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn option(name: &str, kind: CommandOptionType, required: bool) -> CommandOption {
+        CommandOption {
+            autocomplete: None,
+            channel_types: None,
+            choices: None,
+            description: String::new(),
+            description_localizations: None,
+            kind,
+            max_length: None,
+            max_value: None,
+            min_length: None,
+            min_value: None,
+            name: name.to_owned(),
+            name_localizations: None,
+            options: None,
+            required: Some(required),
+        }
+    }
+
+    fn subcommand(name: &str, options: Vec<CommandOption>) -> CommandOption {
+        CommandOption {
+            options: Some(options),
+            ..option(name, CommandOptionType::SubCommand, false)
+        }
+    }
+
+    fn mode_option() -> CommandOption {
+        let choice = |name: &str, value: &str| CommandOptionChoice {
+            name: name.to_owned(),
+            name_localizations: None,
+            value: CommandOptionChoiceValue::String(value.to_owned()),
+        };
+
+        CommandOption {
+            choices: Some(vec![choice("osu", "osu"), choice("mania", "mania")]),
+            ..option("mode", CommandOptionType::String, false)
+        }
+    }
+
+    fn definitions() -> Vec<CommandOption> {
+        vec![
+            subcommand(
+                "country",
+                vec![
+                    mode_option(),
+                    option("name", CommandOptionType::String, false),
+                    option("limit", CommandOptionType::Integer, false),
+                ],
+            ),
+            subcommand(
+                "user",
+                vec![
+                    option("user", CommandOptionType::User, true),
+                    option("channel", CommandOptionType::Channel, false),
+                    option("reverse", CommandOptionType::Boolean, false),
+                ],
+            ),
+        ]
+    }
+
+    fn subcommand_options(
+        mut options: Vec<CommandDataOption>,
+        name: &str,
+    ) -> Vec<CommandDataOption> {
+        assert_eq!(options.len(), 1);
+        let option = options.pop().unwrap();
+        assert_eq!(option.name, name);
+
+        match option.value {
+            CommandOptionValue::SubCommand(options) => options,
+            other => panic!("expected subcommand, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn subcommand_with_options() {
+        let options = parse_options(
+            &definitions(),
+            "Country mode:MANIA \"name:some name\" limit:5",
+        )
+        .unwrap();
+        let options = subcommand_options(options, "country");
+
+        assert_eq!(options.len(), 3);
+        assert!(matches!(&options[0].value, CommandOptionValue::String(mode) if mode == "mania"));
+        assert!(
+            matches!(&options[1].value, CommandOptionValue::String(name) if name == "some name")
+        );
+        assert!(matches!(options[2].value, CommandOptionValue::Integer(5)));
+    }
+
+    #[test]
+    fn mentions_and_ids() {
+        let options = parse_options(
+            &definitions(),
+            "user user:<@123> channel:<#456> reverse:true",
+        )
+        .unwrap();
+        let options = subcommand_options(options, "user");
+
+        assert!(matches!(options[0].value, CommandOptionValue::User(id) if id.get() == 123));
+        assert!(matches!(options[1].value, CommandOptionValue::Channel(id) if id.get() == 456));
+        assert!(matches!(
+            options[2].value,
+            CommandOptionValue::Boolean(true)
+        ));
+
+        let options = parse_options(&definitions(), "user user:789").unwrap();
+        let options = subcommand_options(options, "user");
+
+        assert!(matches!(options[0].value, CommandOptionValue::User(id) if id.get() == 789));
+    }
+
+    #[test]
+    fn errors() {
+        let definitions = definitions();
+
+        let cases = [
+            ("", "Missing subcommand"),
+            ("global", "Unknown subcommand `global`"),
+            ("country mode", "Expected `option:value`"),
+            ("country foo:bar", "Unknown option `foo`"),
+            ("country limit:1 limit:2", "specified more than once"),
+            ("country limit:ten", "not a valid integer"),
+            ("country mode:taiko", "not a valid value for option `mode`"),
+            ("user", "Missing required option `user`"),
+            ("user user:0", "not a valid user"),
+            ("user user:1 reverse:yes", "not a valid boolean"),
+        ];
+
+        for (args, expected) in cases {
+            let err = parse_options(&definitions, args).unwrap_err();
+            assert!(err.contains(expected), "`{args}`: {err}");
+        }
+    }
+
+    #[test]
+    fn no_subcommands() {
+        let definitions = [option("limit", CommandOptionType::Integer, false)];
+
+        let options = parse_options(&definitions, "limit:3").unwrap();
+        assert_eq!(options.len(), 1);
+        assert!(matches!(options[0].value, CommandOptionValue::Integer(3)));
+
+        assert!(parse_options(&definitions, "").unwrap().is_empty());
+    }
+}
@@ -13,6 +13,8 @@ use rosu_render::{
 };
 use tokio::sync::{mpsc, oneshot};
 
+use crate::core::BotHealth;
+
 pub struct Ordr {
     pub client: OrdrClient,
     pub senders: Arc<SenderMap>,
@@ -65,6 +67,7 @@ impl Ordr {
             .build();
 
         let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        BotHealth::ordr_event();
 
         tokio::spawn(handle_ordr_events(websocket, senders_clone, shutdown_rx));
 
@@ -134,6 +137,10 @@ async fn handle_ordr_events(
             }
         };
 
+        if event_res.is_ok() {
+            BotHealth::ordr_event();
+        }
+
         match event_res {
             Ok(RawEvent::RenderProgress(progress)) => {
                 let render_id = progress.render_id;
@@ -187,7 +194,10 @@ async fn handle_ordr_events(
                 }
             }
             Ok(_) => {}
-            Err(err) => warn!(err = ?Report::new(err), "o!rdr websocket error"),
+            Err(err) => {
+                BotHealth::ordr_error();
+                warn!(err = ?Report::new(err), "o!rdr websocket error");
+            }
         }
     }
 }
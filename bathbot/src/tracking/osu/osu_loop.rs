@@ -22,14 +22,14 @@ use twilight_http::{
     api_error::{ApiError, GeneralApiError},
     error::ErrorType as TwilightErrorType,
 };
-use twilight_model::id::Id;
+use twilight_model::{channel::message::Embed, id::Id};
 
 use crate::{
     active::impls::{MarkIndex, SingleScoreContent, SingleScorePagination},
     commands::utility::ScoreEmbedDataWrap,
     manager::{
         redis::{osu::UserArgs, RedisData},
-        OsuMap,
+        OsuMap, OsuScorePayload, WebhookPayload,
     },
     Context,
 };
@@ -166,10 +166,13 @@ async fn score_loop(
             }
         };
 
-        let embed = user.embed(score, map, idx).await?.build();
         let http = Context::http();
         let tracking = Context::tracking();
 
+        // Guilds may use their own settings in a tracking channel so we build
+        // one embed per distinct settings
+        let mut built_embeds: Vec<(Option<ScoreEmbedSettings>, Embed)> = Vec::new();
+
         // Send the embed to each tracking channel
         for (&channel, &limit) in channels.iter() {
             if idx > limit {
@@ -177,7 +180,26 @@ async fn score_loop(
             }
 
             let channel = Id::new(channel.get());
-            let embeds = slice::from_ref(&embed);
+            let settings = Context::guild_config().channel_score_embed(channel);
+
+            let pos = match built_embeds
+                .iter()
+                .position(|(entry, _)| *entry == settings)
+            {
+                Some(pos) => pos,
+                None => {
+                    let embed = user
+                        .embed(score, map.clone(), idx, settings.clone())
+                        .await?
+                        .build();
+
+                    built_embeds.push((settings, embed));
+
+                    built_embeds.len() - 1
+                }
+            };
+
+            let embeds = slice::from_ref(&built_embeds[pos].1);
 
             // Try to build and send the message
             match http.create_message(channel).embeds(embeds) {
@@ -211,6 +233,29 @@ async fn score_loop(
                 }
             }
         }
+
+        let notified = channels
+            .iter()
+            .filter(|(_, &limit)| idx <= limit)
+            .map(|(&channel, _)| Id::new(channel.get()))
+            .collect();
+
+        let payload = OsuScorePayload {
+            user_id: score.user_id,
+            username: user.username().map(str::to_owned),
+            mode: score.mode as u8,
+            map_id: score.map_id,
+            score_id: score.id,
+            top_position: idx,
+            pp: score.pp,
+            accuracy: score.accuracy,
+            mods: score.mods.to_string(),
+            grade: format!("{:?}", score.grade),
+            max_combo: score.max_combo,
+            ended_at: score.ended_at.unix_timestamp(),
+        };
+
+        Context::webhooks().notify(notified, WebhookPayload::OsuScore(payload));
     }
 
     Ok(())
@@ -230,7 +275,17 @@ impl<'u> TrackUser<'u> {
         }
     }
 
-    async fn embed(&mut self, score: &Score, map: OsuMap, idx: u8) -> OsuResult<EmbedBuilder> {
+    fn username(&self) -> Option<&str> {
+        self.user.as_deref().map(|user| user.username())
+    }
+
+    async fn embed(
+        &mut self,
+        score: &Score,
+        map: OsuMap,
+        idx: u8,
+        settings: Option<ScoreEmbedSettings>,
+    ) -> OsuResult<EmbedBuilder> {
         let user = match self.user.as_deref() {
             Some(user) => user,
             None => {
@@ -245,10 +300,10 @@ impl<'u> TrackUser<'u> {
             }
         };
 
-        let settings = match score.mode {
+        let settings = settings.unwrap_or_else(|| match score.mode {
             GameMode::Mania => create_mania_settings(),
             _ => create_settings(),
-        };
+        });
 
         let score_data = ScoreData::Lazer;
         let msg_owner = Id::new(1);
@@ -60,6 +60,22 @@ impl OsuTracking {
             .map(|queue| Self { queue })
     }
 
+    /// Tracking without any users so that tests don't need a database.
+    #[cfg(test)]
+    pub fn empty() -> Self {
+        let now = OffsetDateTime::now_utc();
+
+        let queue = OsuTrackingQueue {
+            queue: Mutex::new(PriorityQueue::with_default_hasher()),
+            users: TokioMutexMap::default(),
+            last_date: Mutex::new(now),
+            interval: RwLock::new(default_tracking_interval()),
+            stop_tracking: AtomicBool::new(false),
+        };
+
+        Self { queue }
+    }
+
     pub fn set_stop_tracking(&self, value: bool) {
         self.queue.stop_tracking.store(value, Ordering::SeqCst);
     }
@@ -14,7 +14,11 @@ use twilight_http::{
 };
 use twilight_model::id::{marker::ChannelMarker, Id};
 
-use crate::Context;
+use crate::{
+    core::BotHealth,
+    manager::{TwitchLivePayload, WebhookPayload},
+    Context,
+};
 
 #[cold]
 pub async fn twitch_tracking_loop() {
@@ -24,6 +28,7 @@ pub async fn twitch_tracking_loop() {
 
     let client = Context::client();
     let online_twitch_streams = Context::online_twitch_streams();
+    BotHealth::twitch_loop();
 
     loop {
         interval.tick().await;
@@ -33,7 +38,11 @@ pub async fn twitch_tracking_loop() {
 
         // Get stream data about all streams that need to be tracked
         let mut streams = match client.get_twitch_streams(&user_ids).await {
-            Ok(streams) => streams,
+            Ok(streams) => {
+                BotHealth::twitch_loop();
+
+                streams
+            }
             Err(err) => {
                 warn!(?err, "Failed to retrieve streams");
 
@@ -111,6 +120,16 @@ pub async fn twitch_tracking_loop() {
             let _ = write!(stream.thumbnail_url, "{width}x{height}.jpg");
 
             let user = &users[&stream.user_id];
+            let url = format!("{TWITCH_BASE}{}", user.display_name);
+
+            let payload = TwitchLivePayload {
+                user_id: stream.user_id,
+                username: stream.username.to_string(),
+                title: stream.title.to_string(),
+                url: url.clone(),
+            };
+
+            Context::webhooks().notify(channels.clone(), WebhookPayload::TwitchLive(payload));
 
             let embed = EmbedBuilder::new()
                 .author(AuthorBuilder::new("Now live on twitch:"))
@@ -118,7 +137,7 @@ pub async fn twitch_tracking_loop() {
                 .image(&stream.thumbnail_url)
                 .thumbnail(user.image_url.as_ref())
                 .title(stream.username.as_ref())
-                .url(format!("{TWITCH_BASE}{}", user.display_name));
+                .url(url);
 
             let mut channels = channels.into_iter();
             let last = channels.next_back();
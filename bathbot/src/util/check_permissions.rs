@@ -28,6 +28,7 @@ impl CheckPermissions for CommandOrigin<'_> {
         match self {
             CommandOrigin::Message { permissions, .. } => *permissions,
             CommandOrigin::Interaction { command } => command.permissions,
+            CommandOrigin::Scheduled { permissions, .. } => *permissions,
         }
     }
 }
@@ -1,5 +1,6 @@
 use std::{future::IntoFuture, slice};
 
+use bathbot_psql::model::configs::Locale;
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use twilight_http::response::ResponseFuture;
 use twilight_model::{
@@ -8,7 +9,7 @@ use twilight_model::{
     id::{marker::ChannelMarker, Id},
 };
 
-use crate::core::Context;
+use crate::core::{locale::ErrorContent, Context};
 
 pub trait ChannelExt {
     /// Create a message inside a green embed
@@ -19,7 +20,11 @@ pub trait ChannelExt {
     ) -> ResponseFuture<Message>;
 
     /// Create a message inside a red embed
-    fn error(&self, content: impl Into<String>) -> ResponseFuture<Message>;
+    ///
+    /// The recipient is unknown so an [`Issue`] is sent in english.
+    ///
+    /// [`Issue`]: crate::core::locale::Issue
+    fn error(&self, content: impl ErrorContent) -> ResponseFuture<Message>;
 
     /// Create a message without embed; only content
     fn plain_message(&self, content: &str) -> ResponseFuture<Message>;
@@ -60,7 +65,8 @@ impl ChannelExt for Id<ChannelMarker> {
         }
     }
 
-    fn error(&self, content: impl Into<String>) -> ResponseFuture<Message> {
+    fn error(&self, content: impl ErrorContent) -> ResponseFuture<Message> {
+        let content = content.localize(Locale::English);
         let embed = EmbedBuilder::new().color_red().description(content).build();
 
         Context::http()
@@ -88,7 +94,7 @@ impl ChannelExt for Message {
         self.channel_id.create_message(builder, permissions)
     }
 
-    fn error(&self, content: impl Into<String>) -> ResponseFuture<Message> {
+    fn error(&self, content: impl ErrorContent) -> ResponseFuture<Message> {
         self.channel_id.error(content)
     }
 
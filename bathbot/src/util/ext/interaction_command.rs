@@ -14,7 +14,7 @@ use twilight_model::{
 };
 
 use crate::{
-    core::Context,
+    core::{locale::ErrorContent, Context},
     util::{interaction::InteractionCommand, CheckPermissions},
 };
 
@@ -43,23 +43,21 @@ pub trait InteractionCommandExt {
 
     /// Update a command to some content in a red embed.
     ///
+    /// An [`Issue`] is translated into the command's locale.
+    ///
     /// Be sure the command was deferred beforehand.
-    fn error(&self, content: impl Into<String>) -> ResponseFuture<Message> {
-        let embed = EmbedBuilder::new().description(content).color_red();
-        let builder = MessageBuilder::new().embed(embed);
-
-        self.update(builder)
-    }
+    ///
+    /// [`Issue`]: crate::core::locale::Issue
+    fn error(&self, content: impl ErrorContent) -> ResponseFuture<Message>;
 
     /// Respond to a command with some content in a red embed.
     ///
+    /// An [`Issue`] is translated into the command's locale.
+    ///
     /// Be sure the command was **not** deferred beforehand.
-    fn error_callback(&self, content: impl Into<String>) -> ResponseFuture<EmptyBody> {
-        let embed = EmbedBuilder::new().description(content).color_red();
-        let builder = MessageBuilder::new().embed(embed);
-
-        self.callback(builder, false)
-    }
+    ///
+    /// [`Issue`]: crate::core::locale::Issue
+    fn error_callback(&self, content: impl ErrorContent) -> ResponseFuture<EmptyBody>;
 
     /// Callback to an autocomplete action.
     fn autocomplete(&self, choices: Vec<CommandOptionChoice>) -> ResponseFuture<EmptyBody>;
@@ -145,6 +143,22 @@ impl InteractionCommandExt for InteractionCommand {
         InteractionToken(&self.token).update(builder, self.permissions)
     }
 
+    fn error(&self, content: impl ErrorContent) -> ResponseFuture<Message> {
+        let content = content.localize(self.locale);
+        let embed = EmbedBuilder::new().description(content).color_red();
+        let builder = MessageBuilder::new().embed(embed);
+
+        self.update(builder)
+    }
+
+    fn error_callback(&self, content: impl ErrorContent) -> ResponseFuture<EmptyBody> {
+        let content = content.localize(self.locale);
+        let embed = EmbedBuilder::new().description(content).color_red();
+        let builder = MessageBuilder::new().embed(embed);
+
+        self.callback(builder, false)
+    }
+
     fn autocomplete(&self, choices: Vec<CommandOptionChoice>) -> ResponseFuture<EmptyBody> {
         let data = InteractionResponseData {
             choices: Some(choices),
@@ -1,3 +1,4 @@
+use bathbot_psql::model::configs::Locale;
 use eyre::{ContextCompat, Result};
 use twilight_model::{
     application::interaction::{
@@ -7,7 +8,7 @@ use twilight_model::{
     channel::Message,
     guild::{PartialMember, Permissions},
     id::{
-        marker::{ChannelMarker, GuildMarker, InteractionMarker},
+        marker::{ChannelMarker, GuildMarker, InteractionMarker, UserMarker},
         Id,
     },
     user::User,
@@ -25,6 +26,25 @@ pub struct InteractionCommand {
     pub member: Option<PartialMember>,
     pub token: String,
     pub user: Option<User>,
+    /// Locale in which to respond.
+    ///
+    /// Initially the locale of the user's discord client and resolved to
+    /// respect configured locales before the command is processed.
+    pub locale: Locale,
+}
+
+impl InteractionCommand {
+    /// The message that a message command was used on.
+    pub fn target_message(&self) -> Option<&Message> {
+        let target = self.data.target_id?;
+
+        self.data.resolved.as_ref()?.messages.get(&target.cast())
+    }
+
+    /// The user that a user command was used on.
+    pub fn target_user(&self) -> Option<Id<UserMarker>> {
+        self.data.target_id.map(Id::cast)
+    }
 }
 
 #[derive(Debug)]
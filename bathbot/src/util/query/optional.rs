@@ -2,7 +2,7 @@ use std::{
     borrow::Cow,
     cmp::Ordering,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
-    ops::{Add, Sub},
+    ops::{Add, Bound, Sub},
     str::FromStr,
     time::Duration,
 };
@@ -36,6 +36,11 @@ impl<'q> OptionalText<'q> {
         self.is_empty() || self.search_term == value.cow_to_ascii_lowercase()
     }
 
+    /// The lowercase search term, if any.
+    pub fn as_option(&self) -> Option<&str> {
+        (!self.is_empty()).then_some(self.search_term.as_ref())
+    }
+
     pub fn try_update(&mut self, op: Operator, value: Cow<'q, str>) -> bool {
         match op {
             Operator::Equal => {
@@ -122,6 +127,23 @@ impl<N> OptionalRange<N> {
     }
 }
 
+impl<N: Copy> OptionalRange<N> {
+    /// The lower and upper bound of the range, including whether they are
+    /// inclusive.
+    pub fn bounds(&self) -> (Bound<N>, Bound<N>) {
+        let bound = |value, is_inclusive| match value {
+            Some(value) if is_inclusive => Bound::Included(value),
+            Some(value) => Bound::Excluded(value),
+            None => Bound::Unbounded,
+        };
+
+        (
+            bound(self.min, self.is_lower_inclusive),
+            bound(self.max, self.is_upper_inclusive),
+        )
+    }
+}
+
 impl OptionalRange<Date> {
     pub fn try_update_date(&mut self, op: Operator, value: &str) -> bool {
         Date::parse(value, &DATE_FORMAT).map_or(false, |date| {